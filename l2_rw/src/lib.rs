@@ -86,33 +86,45 @@ impl<T: Read> Decoder<'_, T> {
         if b > 128 { b as i32 - 256 } else { b as i32 }
     }
 
-    pub fn decode(&mut self, modulus: BigNum, exp: BigNum) {
+    pub fn decode(&mut self, modulus: BigNum, exp: BigNum) -> Result<(), ()> {
         let mut buff = [0u8; 128];
 
-        let mut ct = self.data.read(&mut buff).unwrap();
+        let mut ct = self.data.read(&mut buff).map_err(|_| ())?;
 
-        let rsa = openssl::rsa::Rsa::from_public_components(modulus, exp).unwrap();
+        let rsa = openssl::rsa::Rsa::from_public_components(modulus, exp).map_err(|_| ())?;
 
         let mut chunk = [0u8; 128];
 
         while ct != 0 {
             rsa.public_decrypt(&buff, &mut chunk, Padding::NONE)
-                .unwrap();
+                .map_err(|_| ())?;
 
             let size = Self::byte_to_int(chunk[3]) & 0xFF;
             let pad = (-size & 0x1) + (-size & 0x2);
+
+            if size + pad > 128 {
+                return Err(());
+            }
+
             let start = (128 - size - pad) as usize;
             let end = start + size as usize;
 
             self.output.extend_from_slice(&chunk[start..end]);
 
-            ct = self.data.read(&mut buff).unwrap();
+            ct = self.data.read(&mut buff).map_err(|_| ())?;
             chunk = [0u8; 128];
         }
 
-        let res = inflate_bytes_zlib_no_checksum(&self.output[4..self.output.len() - 4]).unwrap();
+        if self.output.len() < 8 {
+            return Err(());
+        }
+
+        let res = inflate_bytes_zlib_no_checksum(&self.output[4..self.output.len() - 4])
+            .map_err(|_| ())?;
 
         *self.output = res;
+
+        Ok(())
     }
 }
 
@@ -285,11 +297,15 @@ pub fn save_dat<S: WriteUnreal + Debug, T: WriteUnreal + Debug>(
 pub fn read_encoded_file(path: &Path) -> Result<Vec<u8>, ()> {
     let mut file;
 
-    let mut f_file = File::open(path).unwrap();
-    file = Vec::with_capacity(f_file.metadata().unwrap().len() as usize);
-    f_file.read_to_end(&mut file).unwrap();
+    let mut f_file = File::open(path).map_err(|_| ())?;
+    file = Vec::with_capacity(f_file.metadata().map_err(|_| ())?.len() as usize);
+    f_file.read_to_end(&mut file).map_err(|_| ())?;
+
+    if file.starts_with(LINEAGE_HEADER) {
+        if file.len() < 48 {
+            return Err(());
+        }
 
-    if &file[0..22] == LINEAGE_HEADER {
         let enc = &file[22..28];
         let enc_version = if enc == V111 {
             EncVersion::V111
@@ -298,7 +314,7 @@ pub fn read_encoded_file(path: &Path) -> Result<Vec<u8>, ()> {
         } else if enc == V413 {
             EncVersion::V413
         } else {
-            unreachable!("Unknown enc version: {}", String::from_utf8_lossy(enc))
+            return Err(());
         };
 
         if let Some(modulus) = enc_version.get_modulus() {
@@ -309,7 +325,7 @@ pub fn read_encoded_file(path: &Path) -> Result<Vec<u8>, ()> {
                 output: &mut output,
             };
 
-            decoder.decode(modulus, exp);
+            decoder.decode(modulus, exp)?;
 
             file = output;
         } else {
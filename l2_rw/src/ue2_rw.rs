@@ -29,6 +29,13 @@ pub type STR = String;
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct CompactInt(pub(crate) i32);
+
+impl CompactInt {
+    pub fn value(&self) -> i32 {
+        self.0
+    }
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, Default)]
 pub struct ASCF(String);
 
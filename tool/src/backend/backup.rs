@@ -0,0 +1,260 @@
+use crate::backend::Backend;
+use crate::backend::log_holder::{Log, LogLevel};
+use crate::backend::util::timestamp_name;
+use crate::entity::Entity;
+use crate::logs_mut;
+use ron::ser::PrettyConfig;
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::SystemTime;
+
+pub const BACKUPS_FOLDER: &str = "./dat_backups";
+pub const DEFAULT_BACKUPS_TO_KEEP: u32 = 10;
+const MANIFEST_FILE_NAME: &str = "manifest.ron";
+
+/// Batch that dats are backed up to before being overwritten
+static CURRENT_BATCH: Mutex<Option<(PathBuf, BackupManifest)>> = Mutex::new(None);
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct BackupFile {
+    pub original: String,
+    pub name: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Default)]
+pub struct BackupManifest {
+    pub system_folder: String,
+    pub entities: Vec<String>,
+    pub files: Vec<BackupFile>,
+}
+
+impl BackupManifest {
+    fn dump(&self, folder: &Path) -> std::io::Result<()> {
+        std::fs::write(
+            folder.join(MANIFEST_FILE_NAME),
+            ron::ser::to_string_pretty(self, PrettyConfig::default()).unwrap(),
+        )
+    }
+
+    fn load(folder: &Path) -> Option<Self> {
+        let mut d = String::new();
+
+        File::open(folder.join(MANIFEST_FILE_NAME))
+            .ok()?
+            .read_to_string(&mut d)
+            .ok()?;
+
+        ron::from_str(&d).ok()
+    }
+}
+
+pub struct BackupBatch {
+    pub name: String,
+    pub folder: PathBuf,
+    pub manifest: BackupManifest,
+}
+
+#[derive(Default)]
+pub struct BackupParams {
+    pub opened: bool,
+    pub batches: Vec<BackupBatch>,
+    pub selected_batch: Option<usize>,
+    /// Indexes of selected batch files
+    pub selected_files: Vec<usize>,
+}
+
+/// Starts new batch. Files passed to [`backup_file`] go there until [`finish_backup_batch`]
+pub fn begin_backup_batch(system_folder: &str, entities: &[Entity]) {
+    let name = timestamp_name(SystemTime::now());
+    let mut folder = Path::new(BACKUPS_FOLDER).join(&name);
+
+    let mut i = 1;
+    while folder.exists() {
+        folder = Path::new(BACKUPS_FOLDER).join(format!("{name}_{i}"));
+        i += 1;
+    }
+
+    let manifest = BackupManifest {
+        system_folder: system_folder.to_string(),
+        entities: entities.iter().map(|v| v.to_string()).collect(),
+        files: vec![],
+    };
+
+    if let Err(e) = std::fs::create_dir_all(&folder).and_then(|_| manifest.dump(&folder)) {
+        logs_mut().add(Log {
+            level: LogLevel::Error,
+            producer: "Backup".to_string(),
+            log: format!("Can't create backup folder {folder:?}: {e}"),
+        });
+
+        return;
+    }
+
+    *CURRENT_BATCH.lock().unwrap() = Some((folder, manifest));
+}
+
+pub fn finish_backup_batch() {
+    *CURRENT_BATCH.lock().unwrap() = None;
+}
+
+/// Copies `path` into current batch, if any. Every file is copied once per batch
+pub fn backup_file(path: &Path) -> std::io::Result<()> {
+    let mut batch = CURRENT_BATCH.lock().unwrap();
+
+    let Some((folder, manifest)) = batch.as_mut() else {
+        return Ok(());
+    };
+
+    let original = path.to_string_lossy().to_string();
+
+    if !path.exists() || manifest.files.iter().any(|v| v.original == original) {
+        return Ok(());
+    }
+
+    let name = path
+        .file_name()
+        .map(|v| v.to_string_lossy().to_string())
+        .unwrap_or_default();
+
+    std::fs::copy(path, folder.join(&name))?;
+
+    manifest.files.push(BackupFile { original, name });
+    manifest.dump(folder)
+}
+
+/// Newest first
+pub fn list_backups() -> Vec<BackupBatch> {
+    let Ok(dir) = std::fs::read_dir(BACKUPS_FOLDER) else {
+        return vec![];
+    };
+
+    let mut res: Vec<_> = dir
+        .flatten()
+        .filter(|v| v.path().is_dir())
+        .filter_map(|v| {
+            Some(BackupBatch {
+                name: v.file_name().to_string_lossy().to_string(),
+                manifest: BackupManifest::load(&v.path())?,
+                folder: v.path(),
+            })
+        })
+        .collect();
+
+    res.sort_by(|a, b| b.name.cmp(&a.name));
+
+    res
+}
+
+/// Removes the oldest batches so that no more than `keep` are left
+pub fn prune_backups(keep: u32) {
+    for v in list_backups().into_iter().skip(keep as usize) {
+        let _ = std::fs::remove_dir_all(v.folder);
+    }
+}
+
+impl Backend {
+    pub fn backups_to_keep(&self) -> u32 {
+        self.config
+            .dat_backups_to_keep
+            .unwrap_or(DEFAULT_BACKUPS_TO_KEEP)
+    }
+
+    pub fn update_backups_to_keep(&mut self, val: u32) {
+        self.config.dat_backups_to_keep = Some(val);
+        self.config.dump();
+    }
+
+    pub fn open_backups(&mut self) {
+        self.backups.batches = list_backups();
+        self.backups.selected_batch = None;
+        self.backups.selected_files.clear();
+        self.backups.opened = true;
+    }
+
+    pub fn select_backup(&mut self, index: usize) {
+        self.backups.selected_batch = Some(index);
+        self.backups.selected_files = (0..self
+            .backups
+            .batches
+            .get(index)
+            .map_or(0, |v| v.manifest.files.len()))
+            .collect();
+    }
+
+    /// Copies selected files of selected batch back. Files being replaced are backed up as a
+    /// batch of their own. Current client is reloaded if it was touched
+    pub fn restore_backup(&mut self) {
+        let Some(batch) = self
+            .backups
+            .selected_batch
+            .and_then(|v| self.backups.batches.get(v))
+        else {
+            return;
+        };
+
+        let files: Vec<_> = self
+            .backups
+            .selected_files
+            .iter()
+            .filter_map(|v| batch.manifest.files.get(*v))
+            .cloned()
+            .collect();
+
+        if files.is_empty() {
+            return;
+        }
+
+        let name = batch.name.clone();
+        let folder = batch.folder.clone();
+        let system_folder = batch.manifest.system_folder.clone();
+
+        if self.backups_to_keep() > 0 {
+            begin_backup_batch(&system_folder, &[]);
+        }
+
+        let mut restored = 0;
+
+        for v in &files {
+            let res = backup_file(Path::new(&v.original))
+                .and_then(|_| std::fs::copy(folder.join(&v.name), &v.original));
+
+            logs_mut().add(match res {
+                Ok(_) => {
+                    restored += 1;
+
+                    Log {
+                        level: LogLevel::Info,
+                        producer: "Backup".to_string(),
+                        log: format!("{} restored from {name}", v.original),
+                    }
+                }
+                Err(e) => Log {
+                    level: LogLevel::Error,
+                    producer: "Backup".to_string(),
+                    log: format!("Can't restore {}: {e}", v.original),
+                },
+            });
+        }
+
+        finish_backup_batch();
+
+        if restored > 0 && self.config.system_folder_path.as_ref() == Some(&system_folder) {
+            self.update_system_path(system_folder.into());
+        }
+
+        self.open_backups();
+    }
+
+    /// Starts backup batch for the next write to `system_folder`, if backups are on
+    pub(crate) fn begin_dat_backup(&self, system_folder: &str, entities: &[Entity]) {
+        let keep = self.backups_to_keep();
+
+        if keep > 0 {
+            begin_backup_batch(system_folder, entities);
+            prune_backups(keep);
+        }
+    }
+}
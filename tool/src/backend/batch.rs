@@ -0,0 +1,285 @@
+//! Headless mode for build pipelines: `l2w_dat_tool --headless --system <dir> [options]`
+//!
+//! Loads system folder, runs scripts, checks integrity and writes dats and/or a dump. Exit code
+//! is `0` on success, `1` if script, integrity check or writing failed and `2` on bad arguments
+//! or unreadable system folder
+
+use crate::backend::dat_loader::{DatLoader, load_game_data_holder};
+use crate::backend::holder::{DumpFormat, RonLayout};
+use crate::backend::log_holder::LogLevel;
+use crate::backend::{Backend, Config};
+use crate::frontend::IS_SAVING;
+use crate::{logs, logs_mut};
+use std::path::Path;
+use std::sync::atomic::Ordering;
+use std::thread;
+use std::time::Duration;
+
+const USAGE: &str = "Usage: l2w_dat_tool --headless --system <dir> [options]
+
+Options:
+    --system <dir>              System folder to load
+    --script <file>             Rhai script to run, repeatable, scripts run in given order
+    --write-dats                Write changed dats
+    --output <dir>              Write dats here instead of system folder
+    --copy-unchanged            Copy unchanged dats to output folder too
+    --dump <dir>                Dump all entities to folder
+    --dump-format <ron|json>    Dump format, ron by default
+    --dump-layout <chunks|per-entity>
+                                Dump layout, chunks by default
+    --allow-integrity-errors    Write even if integrity check found errors
+    --help                      Print this message";
+
+struct Args {
+    system: String,
+    scripts: Vec<String>,
+    write_dats: bool,
+    output: Option<String>,
+    copy_unchanged: bool,
+    dump: Option<String>,
+    dump_format: DumpFormat,
+    dump_layout: RonLayout,
+    allow_integrity_errors: bool,
+}
+
+impl Args {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Option<Self>, String> {
+        let mut system = None;
+        let mut scripts = vec![];
+        let mut write_dats = false;
+        let mut output = None;
+        let mut copy_unchanged = false;
+        let mut dump = None;
+        let mut dump_format = DumpFormat::default();
+        let mut dump_layout = RonLayout::default();
+        let mut allow_integrity_errors = false;
+
+        while let Some(arg) = args.next() {
+            let mut value = || args.next().ok_or(format!("{arg} requires a value"));
+
+            match arg.as_str() {
+                "--system" => system = Some(value()?),
+                "--script" => scripts.push(value()?),
+                "--write-dats" => write_dats = true,
+                "--output" => output = Some(value()?),
+                "--copy-unchanged" => copy_unchanged = true,
+                "--dump" => dump = Some(value()?),
+                "--dump-format" => {
+                    let v = value()?;
+
+                    dump_format = DumpFormat::from_path(Path::new(&format!("_.{v}")))
+                        .ok_or(format!("Unknown dump format: {v}"))?;
+                }
+                "--dump-layout" => {
+                    dump_layout = match value()?.as_str() {
+                        "chunks" => RonLayout::Chunked,
+                        "per-entity" => RonLayout::PerEntity,
+                        v => return Err(format!("Unknown dump layout: {v}")),
+                    }
+                }
+                "--allow-integrity-errors" => allow_integrity_errors = true,
+                "--help" => return Ok(None),
+                v => return Err(format!("Unknown argument: {v}")),
+            }
+        }
+
+        let Some(system) = system else {
+            return Err("--system is required".to_string());
+        };
+
+        if !Path::new(&system).is_dir() {
+            return Err(format!("System folder not found: {system}"));
+        }
+
+        if let Some(output) = &output
+            && !Path::new(output).is_dir()
+        {
+            return Err(format!("Output folder not found: {output}"));
+        }
+
+        if output.is_some() && !write_dats {
+            return Err("--output requires --write-dats".to_string());
+        }
+
+        Ok(Some(Self {
+            system,
+            scripts,
+            write_dats,
+            output,
+            copy_unchanged,
+            dump,
+            dump_format,
+            dump_layout,
+            allow_integrity_errors,
+        }))
+    }
+}
+
+fn print_logs(from: usize) -> usize {
+    let logs = logs();
+
+    for v in &logs.logs[from..] {
+        let line = format!("[{:?}] {}: {}", v.level, v.producer, v.log);
+
+        if v.level == LogLevel::Error {
+            eprintln!("{line}");
+        } else {
+            println!("{line}");
+        }
+    }
+
+    logs.logs.len()
+}
+
+fn errors_since(from: usize) -> usize {
+    logs().logs[from..]
+        .iter()
+        .filter(|v| v.level == LogLevel::Error)
+        .count()
+}
+
+/// Returns process exit code
+pub fn run(args: impl Iterator<Item = String>) -> i32 {
+    let args = match Args::parse(args) {
+        Ok(Some(v)) => v,
+        Ok(None) => {
+            println!("{USAGE}");
+
+            return 0;
+        }
+        Err(e) => {
+            eprintln!("{e}\n\n{USAGE}");
+
+            return 2;
+        }
+    };
+
+    // Loader panics on missing or broken dats, caught so it's reported with the right exit code
+    let (holder, warnings) = match std::panic::catch_unwind(|| load_game_data_holder(&args.system))
+    {
+        Ok(Ok(v)) => v,
+        Ok(Err(e)) => {
+            eprintln!("Failed to load system folder {}: {}", args.system, e.log);
+
+            return 2;
+        }
+        Err(_) => {
+            eprintln!("Failed to load system folder: {}", args.system);
+
+            return 2;
+        }
+    };
+
+    let mut backend = Backend::from_config(Config::default(), false);
+
+    logs_mut().reset(warnings);
+
+    // Not read from or written to config.ron, batch runs don't touch editor settings.
+    // Backups are off, pipelines keep their own artifacts
+    backend.config = Config {
+        system_folder_path: Some(args.system.clone()),
+        dat_output_folder_path: args.output.clone(),
+        copy_unchanged_dats: args.copy_unchanged,
+        dat_backups_to_keep: Some(0),
+        ..Default::default()
+    };
+    backend.holders.game_data_holder = holder;
+    backend.update_last_ids();
+
+    let mut printed = print_logs(0);
+
+    for path in &args.scripts {
+        let script = match std::fs::read_to_string(path) {
+            Ok(v) => v,
+            Err(e) => {
+                eprintln!("Failed to read script {path}: {e}");
+
+                return 1;
+            }
+        };
+
+        match backend.run_script(&script) {
+            Ok(output) => println!("Script {path}: {}", output.trim_end()),
+            Err(e) => {
+                eprintln!("Script {path} failed: {e}");
+
+                return 1;
+            }
+        }
+    }
+
+    printed = print_logs(printed);
+
+    let problems = backend.holders.game_data_holder.check_integrity();
+    let mut integrity_errors = 0;
+
+    for v in &problems {
+        let line = format!(
+            "[{:?}] {} [{}]: {}",
+            v.level,
+            v.entity,
+            v.entity.raw_id(),
+            v.message
+        );
+
+        if v.level == LogLevel::Error {
+            integrity_errors += 1;
+            eprintln!("{line}");
+        } else {
+            println!("{line}");
+        }
+    }
+
+    println!(
+        "Integrity check: {integrity_errors} error(s), {} warning(s)",
+        problems.len() - integrity_errors
+    );
+
+    if integrity_errors > 0 && !args.allow_integrity_errors {
+        eprintln!("Nothing written because of integrity errors");
+
+        return 1;
+    }
+
+    if args.write_dats {
+        backend.save_to_dat_force();
+
+        while IS_SAVING.load(Ordering::Relaxed) {
+            thread::sleep(Duration::from_millis(50));
+        }
+
+        let errors = errors_since(printed);
+        printed = print_logs(printed);
+
+        if errors > 0 {
+            return 1;
+        }
+    }
+
+    if let Some(folder) = &args.dump {
+        if let Err(e) = std::fs::create_dir_all(folder) {
+            eprintln!("Failed to create dump folder {folder}: {e}");
+
+            return 1;
+        }
+
+        let res = backend.holders.game_data_holder.save_to_ron(
+            folder,
+            true,
+            args.dump_layout,
+            args.dump_format,
+        );
+
+        print_logs(printed);
+
+        if let Err(e) = res {
+            eprintln!("Dump failed: {e}");
+
+            return 1;
+        }
+
+        println!("Dumped to {folder}");
+    }
+
+    0
+}
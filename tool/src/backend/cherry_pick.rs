@@ -0,0 +1,497 @@
+use crate::backend::holder::{GameDataHolder, HolderMapOps, HolderOps};
+use crate::backend::log_holder::{Log, LogLevel};
+use crate::backend::reference_index::{RefTarget, Remap};
+use crate::backend::{Backend, Dialog};
+use crate::common::ItemId;
+use crate::entity::item::Item;
+use crate::entity::{CommonEntity, GameEntity, GameEntityT};
+use crate::logs_mut;
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+/// Puts a copy of `from` of another client under `to`. Entity that was there is replaced, so
+/// [`Backend::cherry_pick_selected`] asks before that
+fn import<Id: Hash + Eq + Copy, T: Clone + CommonEntity<Id>, M: HolderMapOps<Id, T> + HolderOps>(
+    src: &M,
+    dst: &mut M,
+    from: Id,
+    to: Id,
+    set_id: impl FnOnce(&mut T, Id),
+) {
+    let Some(mut v) = src.get(&from).cloned() else {
+        return;
+    };
+
+    if dst.get(&to).is_some_and(|v| v.deleted()) {
+        dst.dec_deleted();
+    }
+
+    set_id(&mut v, to);
+    dst.insert(to, v);
+}
+
+fn import_item<T: Clone + CommonEntity<ItemId>, M: HolderMapOps<ItemId, T> + HolderOps>(
+    src: &M,
+    dst: &mut M,
+    items: &mut HashMap<ItemId, Item>,
+    from: ItemId,
+    to: ItemId,
+    set_id: impl FnOnce(&mut T, ItemId),
+) {
+    import(src, dst, from, to, set_id);
+
+    if let Some(v) = dst.get(&to) {
+        items.insert(to, v.into());
+    }
+}
+
+/// Source entity of the second client -> where it goes in the current one
+pub struct CherryPickPlan {
+    pub pairs: Vec<(GameEntityT, GameEntityT)>,
+    /// Entities of the current client that would be overwritten, only possible with kept ids
+    pub conflicts: Vec<(GameEntityT, String)>,
+}
+
+#[derive(Default)]
+pub struct CherryPickParams {
+    pub selected: HashSet<GameEntityT>,
+    /// Otherwise next free id of the current client is taken
+    pub keep_ids: bool,
+    pub with_dependents: bool,
+}
+
+impl GameDataHolder {
+    /// Copies `from` of another client into this one under `new_id`
+    pub fn import_entity(&mut self, other: &GameDataHolder, from: GameEntityT, new_id: u32) {
+        match from {
+            GameEntityT::Quest(id) => import(
+                &other.quest_holder,
+                &mut self.quest_holder,
+                id,
+                new_id.into(),
+                |v, id| {
+                    v.id = id;
+                    v._changed = true;
+                },
+            ),
+            GameEntityT::Skill(id) => import(
+                &other.skill_holder,
+                &mut self.skill_holder,
+                id,
+                new_id.into(),
+                |v, id| {
+                    v.id = id;
+                    v._changed = true;
+                },
+            ),
+            GameEntityT::Npc(id) => import(
+                &other.npc_holder,
+                &mut self.npc_holder,
+                id,
+                new_id.into(),
+                |v, id| {
+                    v.id = id;
+                    v._changed = true;
+                },
+            ),
+            GameEntityT::Weapon(id) => import_item(
+                &other.weapon_holder,
+                &mut self.weapon_holder,
+                &mut self.item_holder,
+                id,
+                new_id.into(),
+                |v, id| {
+                    v.base_info.id = id;
+                    v._changed = true;
+                },
+            ),
+            GameEntityT::Armor(id) => import_item(
+                &other.armor_holder,
+                &mut self.armor_holder,
+                &mut self.item_holder,
+                id,
+                new_id.into(),
+                |v, id| {
+                    v.base_info.id = id;
+                    v._changed = true;
+                },
+            ),
+            GameEntityT::EtcItem(id) => import_item(
+                &other.etc_item_holder,
+                &mut self.etc_item_holder,
+                &mut self.item_holder,
+                id,
+                new_id.into(),
+                |v, id| {
+                    v.base_info.id = id;
+                    v._changed = true;
+                },
+            ),
+            GameEntityT::ItemSet(id) => import(
+                &other.item_set_holder,
+                &mut self.item_set_holder,
+                id,
+                new_id.into(),
+                |v, id| {
+                    v.id = id;
+                    v._changed = true;
+                },
+            ),
+            GameEntityT::Recipe(id) => import(
+                &other.recipe_holder,
+                &mut self.recipe_holder,
+                id,
+                new_id.into(),
+                |v, id| {
+                    v.id = id;
+                    v._changed = true;
+                },
+            ),
+            GameEntityT::HuntingZone(id) => import(
+                &other.hunting_zone_holder,
+                &mut self.hunting_zone_holder,
+                id,
+                new_id.into(),
+                |v, id| {
+                    v.id = id;
+                    v._changed = true;
+                },
+            ),
+            GameEntityT::Region(id) => import(
+                &other.region_holder,
+                &mut self.region_holder,
+                id,
+                new_id.into(),
+                |v, id| {
+                    v.id = id;
+                    v._changed = true;
+                },
+            ),
+            GameEntityT::RaidInfo(id) => import(
+                &other.raid_info_holder,
+                &mut self.raid_info_holder,
+                id,
+                new_id.into(),
+                |v, id| {
+                    v.id = id;
+                    v._changed = true;
+                },
+            ),
+            GameEntityT::DailyMission(id) => import(
+                &other.daily_mission_holder,
+                &mut self.daily_mission_holder,
+                id,
+                new_id.into(),
+                |v, id| {
+                    v.id = id;
+                    v._changed = true;
+                },
+            ),
+            GameEntityT::AnimationCombo(id) => import(
+                &other.animation_combo_holder,
+                &mut self.animation_combo_holder,
+                id,
+                new_id.into(),
+                |v, id| {
+                    v.id = id;
+                    v._changed = true;
+                },
+            ),
+            GameEntityT::Residence(id) => import(
+                &other.residence_holder,
+                &mut self.residence_holder,
+                id,
+                new_id.into(),
+                |v, id| {
+                    v.id = id;
+                    v._changed = true;
+                },
+            ),
+            GameEntityT::EnsoulOption(id) => import(
+                &other.ensoul_option_holder,
+                &mut self.ensoul_option_holder,
+                id,
+                new_id.into(),
+                |v, id| {
+                    v.id = id;
+                    v._changed = true;
+                },
+            ),
+            GameEntityT::Henna(id) => import(
+                &other.henna_holder,
+                &mut self.henna_holder,
+                id,
+                new_id.into(),
+                |v, id| {
+                    v.id = id;
+                    v._changed = true;
+                },
+            ),
+            GameEntityT::UserAction(id) => import(
+                &other.user_action_holder,
+                &mut self.user_action_holder,
+                id,
+                new_id.into(),
+                |v, id| {
+                    v.id = id;
+                    v._changed = true;
+                },
+            ),
+            GameEntityT::Product(id) => import(
+                &other.product_holder,
+                &mut self.product_holder,
+                id,
+                new_id.into(),
+                |v, id| {
+                    v.id = id;
+                    v._changed = true;
+                },
+            ),
+            GameEntityT::ClassInfo(id) => import(
+                &other.class_info_holder,
+                &mut self.class_info_holder,
+                id,
+                new_id.into(),
+                |v, id| {
+                    v.id = id;
+                    v._changed = true;
+                },
+            ),
+        }
+    }
+
+    /// Item sets of this client holding any of `items`
+    fn item_sets_with(&self, items: &HashSet<ItemId>) -> Vec<GameEntityT> {
+        let mut res: Vec<_> = self
+            .item_set_holder
+            .values()
+            .filter(|v| {
+                v.base_items
+                    .iter()
+                    .chain(&v.additional_items)
+                    .flatten()
+                    .any(|v| items.contains(v))
+            })
+            .map(|v| GameEntityT::ItemSet(v.id))
+            .collect();
+
+        res.sort_by_key(|v| v.raw_id());
+
+        res
+    }
+}
+
+impl Backend {
+    /// Npc additional parts and skill sound data live inside the entities and always come along,
+    /// dependents are item sets of picked items
+    pub fn cherry_pick_plan(&self) -> Result<CherryPickPlan, String> {
+        let Some(other) = &self.client_diff.holder else {
+            return Err("Second client is not loaded".to_string());
+        };
+
+        let params = &self.client_diff.cherry_pick;
+        let current = &self.holders.game_data_holder;
+
+        let mut sources: Vec<_> = params
+            .selected
+            .iter()
+            .copied()
+            .filter(|v| other.get_entity_name(*v).is_some())
+            .collect();
+
+        sources.sort_by_key(|v| (v.to_string(), v.raw_id()));
+
+        if params.with_dependents {
+            let items: HashSet<ItemId> = sources
+                .iter()
+                .filter(|v| {
+                    matches!(
+                        v,
+                        GameEntityT::Weapon(_) | GameEntityT::Armor(_) | GameEntityT::EtcItem(_)
+                    )
+                })
+                .map(|v| v.raw_id().into())
+                .collect();
+
+            for v in other.item_sets_with(&items) {
+                if !sources.contains(&v) {
+                    sources.push(v);
+                }
+            }
+        }
+
+        if sources.is_empty() {
+            return Err("Nothing selected".to_string());
+        }
+
+        let mut next_ids: HashMap<GameEntity, u32> = HashMap::new();
+        let mut res = CherryPickPlan {
+            pairs: vec![],
+            conflicts: vec![],
+        };
+
+        for from in sources {
+            let to = if params.keep_ids {
+                let taken_by_other_type = match from {
+                    GameEntityT::Weapon(id) => {
+                        current.armor_holder.get(&id).is_some()
+                            || current.etc_item_holder.get(&id).is_some()
+                    }
+                    GameEntityT::Armor(id) => {
+                        current.weapon_holder.get(&id).is_some()
+                            || current.etc_item_holder.get(&id).is_some()
+                    }
+                    GameEntityT::EtcItem(id) => {
+                        current.weapon_holder.get(&id).is_some()
+                            || current.armor_holder.get(&id).is_some()
+                    }
+                    _ => false,
+                };
+
+                if taken_by_other_type {
+                    return Err(format!(
+                        "ID of {from} [{}] is taken by another item type",
+                        from.raw_id()
+                    ));
+                }
+
+                from
+            } else {
+                let entity: GameEntity = from.into();
+                // Items share one id space
+                let key = match entity {
+                    GameEntity::Armor | GameEntity::EtcItem => GameEntity::Weapon,
+                    v => v,
+                };
+
+                let next = next_ids
+                    .entry(key)
+                    .or_insert_with(|| self.editors[entity].next_id().max(1));
+
+                *next = current.free_id(entity, *next);
+
+                let to = from.with_id(*next);
+                *next += 1;
+
+                to
+            };
+
+            if self.editors.has_unsaved_changes(to) {
+                return Err(format!(
+                    "{to} [{}] has unsaved changes, save or close it first",
+                    to.raw_id()
+                ));
+            }
+
+            if let Some(name) = current.get_entity_name(to) {
+                res.conflicts.push((to, name));
+            }
+
+            res.pairs.push((from, to));
+        }
+
+        Ok(res)
+    }
+
+    /// Entities of the current client that would be replaced have to be confirmed first
+    pub fn cherry_pick_selected(&mut self) {
+        let plan = match self.cherry_pick_plan() {
+            Ok(v) => v,
+            Err(e) => {
+                self.show_dialog(Dialog::ShowWarning(e));
+
+                return;
+            }
+        };
+
+        if plan.conflicts.is_empty() {
+            self.cherry_pick_selected_force();
+
+            return;
+        }
+
+        let mut message = format!(
+            "{} entity(s) of the current client will be replaced:\n",
+            plan.conflicts.len()
+        );
+
+        for (v, name) in &plan.conflicts {
+            message.push_str(&format!("{v} [{}] {name}\n", v.raw_id()));
+        }
+
+        message.push_str("Overwrite them? Turn off Keep IDs to get new ones instead");
+
+        self.show_dialog(Dialog::ConfirmCherryPick { message });
+    }
+
+    pub(crate) fn cherry_pick_selected_force(&mut self) {
+        let plan = match self.cherry_pick_plan() {
+            Ok(v) => v.pairs,
+            Err(e) => {
+                self.show_dialog(Dialog::ShowWarning(e));
+
+                return;
+            }
+        };
+
+        let Some(other) = self.client_diff.holder.take() else {
+            return;
+        };
+
+        let mut remap = Remap::default();
+
+        for (from, to) in &plan {
+            self.editors.close_if_opened(*to);
+            self.holders
+                .game_data_holder
+                .import_entity(&other, *from, to.raw_id());
+
+            if from != to
+                && let (Some(from), Some(to)) =
+                    (RefTarget::from_entity(*from), RefTarget::from_entity(*to))
+            {
+                remap.insert(from, to);
+            }
+        }
+
+        self.client_diff.holder = Some(other);
+
+        if !remap.is_empty() {
+            for (_, to) in &plan {
+                self.holders
+                    .game_data_holder
+                    .remap_entity_references(*to, &remap);
+            }
+        }
+
+        self.holders.game_data_holder.rebuild_reference_index();
+
+        for (from, to) in &plan {
+            logs_mut().add(Log {
+                level: LogLevel::Info,
+                producer: "Cherry Pick".to_string(),
+                log: format!("{from} [{}] copied as [{}]", from.raw_id(), to.raw_id()),
+            });
+        }
+
+        self.client_diff.cherry_pick.selected.clear();
+
+        let mut entities: Vec<GameEntity> = vec![];
+
+        for (_, to) in &plan {
+            let entity = (*to).into();
+
+            if !entities.contains(&entity) {
+                entities.push(entity);
+            }
+        }
+
+        for e in entities {
+            self.filter_entity_catalog(e);
+        }
+
+        self.update_last_ids();
+        self.check_for_unwrote_changed();
+        self.refresh_client_diff();
+    }
+}
@@ -0,0 +1,348 @@
+use crate::backend::Backend;
+use crate::backend::cherry_pick::CherryPickParams;
+use crate::backend::dat_loader::load_detached_game_data_holder;
+use crate::backend::holder::{GameDataHolder, HolderMapOps};
+use crate::backend::log_holder::{Log, LogLevel};
+use crate::entity::{CommonEntity, Dictionary, Entity, GameEntity};
+use crate::logs_mut;
+use ron::ser::PrettyConfig;
+use serde::{Serialize, Serializer};
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Display;
+use std::hash::Hash;
+use std::path::{Path, PathBuf};
+use strum::IntoEnumIterator;
+
+static NULL: Value = Value::Null;
+
+#[derive(Serialize, Copy, Clone, Eq, PartialEq, Debug)]
+pub enum DiffKind {
+    /// Exists only in other client
+    Added,
+    /// Exists only in current client
+    Removed,
+    Changed,
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct FieldDiff {
+    pub path: String,
+    pub current: String,
+    pub other: String,
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct EntityDiff {
+    pub id: u32,
+    pub name: String,
+    pub kind: DiffKind,
+    pub fields: Vec<FieldDiff>,
+}
+
+#[derive(Serialize)]
+pub struct EntityGroupDiff {
+    #[serde(serialize_with = "serialize_display")]
+    pub entity: Entity,
+    pub entries: Vec<EntityDiff>,
+}
+
+impl EntityGroupDiff {
+    pub fn count(&self, kind: DiffKind) -> usize {
+        self.entries.iter().filter(|v| v.kind == kind).count()
+    }
+}
+
+#[derive(Serialize, Default)]
+pub struct ClientDiff {
+    pub current: String,
+    pub other: String,
+    pub groups: Vec<EntityGroupDiff>,
+}
+
+fn serialize_display<T: Display, S: Serializer>(v: &T, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&v.to_string())
+}
+
+/// Second client, loaded only to be compared with
+#[derive(Default)]
+pub struct ClientDiffParams {
+    pub opened: bool,
+    pub path: Option<String>,
+    pub holder: Option<GameDataHolder>,
+    pub diff: ClientDiff,
+    pub filter: String,
+    pub cherry_pick: CherryPickParams,
+}
+
+fn diff_values(path: &str, current: &Value, other: &Value, res: &mut Vec<FieldDiff>) {
+    match (current, other) {
+        (Value::Object(a), Value::Object(b)) => {
+            let keys: BTreeSet<_> = a.keys().chain(b.keys()).collect();
+
+            for k in keys {
+                let path = if path.is_empty() {
+                    k.to_string()
+                } else {
+                    format!("{path}.{k}")
+                };
+
+                diff_values(
+                    &path,
+                    a.get(k).unwrap_or(&NULL),
+                    b.get(k).unwrap_or(&NULL),
+                    res,
+                );
+            }
+        }
+
+        (Value::Array(a), Value::Array(b)) => {
+            for i in 0..a.len().max(b.len()) {
+                diff_values(
+                    &format!("{path}[{i}]"),
+                    a.get(i).unwrap_or(&NULL),
+                    b.get(i).unwrap_or(&NULL),
+                    res,
+                );
+            }
+        }
+
+        (a, b) => {
+            if a != b {
+                res.push(FieldDiff {
+                    path: path.to_string(),
+                    current: a.to_string(),
+                    other: b.to_string(),
+                })
+            }
+        }
+    }
+}
+
+/// Field level differences of two serializable values
+pub fn diff_fields<T: Serialize>(current: &T, other: &T) -> Vec<FieldDiff> {
+    let mut res = vec![];
+
+    diff_values(
+        "",
+        &serde_json::to_value(current).unwrap_or_default(),
+        &serde_json::to_value(other).unwrap_or_default(),
+        &mut res,
+    );
+
+    res
+}
+
+fn diff_holder<
+    K: Hash + Eq + Copy + Into<u32>,
+    V: Clone + CommonEntity<K> + Serialize,
+    M: HolderMapOps<K, V>,
+>(
+    current: &M,
+    other: &M,
+    name: impl Fn(&V) -> String,
+    live: impl Fn(&V) -> bool,
+) -> Vec<EntityDiff> {
+    let current: BTreeMap<u32, &V> = current
+        .keys()
+        .filter_map(|k| current.get(k).filter(|v| live(v)).map(|v| ((*k).into(), v)))
+        .collect();
+
+    let other: BTreeMap<u32, &V> = other
+        .keys()
+        .filter_map(|k| other.get(k).map(|v| ((*k).into(), v)))
+        .collect();
+
+    let ids: BTreeSet<_> = current.keys().chain(other.keys()).copied().collect();
+
+    ids.into_iter()
+        .filter_map(|id| match (current.get(&id), other.get(&id)) {
+            (Some(c), Some(o)) => {
+                let fields = diff_fields(*c, *o);
+
+                (!fields.is_empty()).then(|| EntityDiff {
+                    id,
+                    name: name(o),
+                    kind: DiffKind::Changed,
+                    fields,
+                })
+            }
+            (Some(c), None) => Some(EntityDiff {
+                id,
+                name: name(c),
+                kind: DiffKind::Removed,
+                fields: vec![],
+            }),
+            (None, Some(o)) => Some(EntityDiff {
+                id,
+                name: name(o),
+                kind: DiffKind::Added,
+                fields: vec![],
+            }),
+            (None, None) => None,
+        })
+        .collect()
+}
+
+fn diff_entities<
+    K: Hash + Eq + Copy + Into<u32>,
+    V: Clone + CommonEntity<K> + Serialize,
+    M: HolderMapOps<K, V>,
+>(
+    current: &M,
+    other: &M,
+) -> Vec<EntityDiff> {
+    diff_holder(current, other, |v| v.name(), |v| !v.deleted())
+}
+
+impl GameDataHolder {
+    /// Entity level diff against another client. Deleted entities of this one are treated as
+    /// missing
+    pub fn diff_with(&self, other: &GameDataHolder) -> Vec<EntityGroupDiff> {
+        let mut res = vec![];
+
+        for entity in GameEntity::iter() {
+            let entries = match entity {
+                GameEntity::Npc => diff_entities(&self.npc_holder, &other.npc_holder),
+                GameEntity::Quest => diff_entities(&self.quest_holder, &other.quest_holder),
+                GameEntity::Skill => diff_entities(&self.skill_holder, &other.skill_holder),
+                GameEntity::Weapon => diff_entities(&self.weapon_holder, &other.weapon_holder),
+                GameEntity::Armor => diff_entities(&self.armor_holder, &other.armor_holder),
+                GameEntity::EtcItem => diff_entities(&self.etc_item_holder, &other.etc_item_holder),
+                GameEntity::ItemSet => diff_entities(&self.item_set_holder, &other.item_set_holder),
+                GameEntity::Recipe => diff_entities(&self.recipe_holder, &other.recipe_holder),
+                GameEntity::HuntingZone => {
+                    diff_entities(&self.hunting_zone_holder, &other.hunting_zone_holder)
+                }
+                GameEntity::Region => diff_entities(&self.region_holder, &other.region_holder),
+                GameEntity::RaidInfo => {
+                    diff_entities(&self.raid_info_holder, &other.raid_info_holder)
+                }
+                GameEntity::DailyMission => {
+                    diff_entities(&self.daily_mission_holder, &other.daily_mission_holder)
+                }
+                GameEntity::AnimationCombo => {
+                    diff_entities(&self.animation_combo_holder, &other.animation_combo_holder)
+                }
+                GameEntity::Residence => {
+                    diff_entities(&self.residence_holder, &other.residence_holder)
+                }
+                GameEntity::EnsoulOption => {
+                    diff_entities(&self.ensoul_option_holder, &other.ensoul_option_holder)
+                }
+                GameEntity::Henna => diff_entities(&self.henna_holder, &other.henna_holder),
+                GameEntity::UserAction => {
+                    diff_entities(&self.user_action_holder, &other.user_action_holder)
+                }
+                GameEntity::Product => diff_entities(&self.product_holder, &other.product_holder),
+                GameEntity::ClassInfo => {
+                    diff_entities(&self.class_info_holder, &other.class_info_holder)
+                }
+            };
+
+            res.push(EntityGroupDiff {
+                entity: entity.into(),
+                entries,
+            });
+        }
+
+        for dict in Dictionary::iter() {
+            let entries = match dict {
+                Dictionary::SystemStrings => diff_holder(
+                    &self.system_strings,
+                    &other.system_strings,
+                    |v| v.item.main().clone(),
+                    |_| true,
+                ),
+                Dictionary::NpcStrings => diff_holder(
+                    &self.npc_strings,
+                    &other.npc_strings,
+                    |v| v.item.main().clone(),
+                    |_| true,
+                ),
+                Dictionary::GameTips => diff_holder(
+                    &self.game_tips,
+                    &other.game_tips,
+                    |v| v.item.main().text.clone(),
+                    |_| true,
+                ),
+            };
+
+            res.push(EntityGroupDiff {
+                entity: dict.into(),
+                entries,
+            });
+        }
+
+        res
+    }
+}
+
+impl Backend {
+    pub fn load_other_client(&mut self, path: PathBuf) {
+        if !path.is_dir() {
+            return;
+        }
+
+        let path = path.to_str().unwrap().to_string();
+
+        let (holder, warnings) = match load_detached_game_data_holder(&path) {
+            Ok(v) => v,
+            Err(e) => {
+                logs_mut().add(Log {
+                    level: LogLevel::Error,
+                    producer: "Client Diff".to_string(),
+                    log: format!("Failed to load {path}: {}", e.log),
+                });
+
+                return;
+            }
+        };
+
+        logs_mut().add(Log {
+            level: LogLevel::Info,
+            producer: "Client Diff".to_string(),
+            log: format!("{path} loaded with {} warning(s)", warnings.len()),
+        });
+
+        self.client_diff.path = Some(path);
+        self.client_diff.holder = Some(holder);
+
+        self.refresh_client_diff();
+    }
+
+    pub fn refresh_client_diff(&mut self) {
+        let (Some(path), Some(other)) = (&self.client_diff.path, &self.client_diff.holder) else {
+            return;
+        };
+
+        self.client_diff.diff = ClientDiff {
+            current: self.config.system_folder_path.clone().unwrap_or_default(),
+            other: path.clone(),
+            groups: self.holders.game_data_holder.diff_with(other),
+        };
+    }
+
+    pub fn export_client_diff(&self, path: &Path, json: bool) {
+        let res = if json {
+            serde_json::to_string_pretty(&self.client_diff.diff).map_err(|e| e.to_string())
+        } else {
+            ron::ser::to_string_pretty(&self.client_diff.diff, PrettyConfig::default())
+                .map_err(|e| e.to_string())
+        }
+        .and_then(|v| std::fs::write(path, v).map_err(|e| e.to_string()));
+
+        logs_mut().add(match res {
+            Ok(_) => Log {
+                level: LogLevel::Info,
+                producer: "Client Diff".to_string(),
+                log: format!("Diff exported to {path:?}"),
+            },
+            Err(e) => Log {
+                level: LogLevel::Error,
+                producer: "Client Diff".to_string(),
+                log: format!("Diff export failed: {e}"),
+            },
+        });
+    }
+}
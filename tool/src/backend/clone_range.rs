@@ -0,0 +1,331 @@
+use crate::backend::holder::{GameDataHolder, HolderMapOps};
+use crate::backend::reference_index::{RefTarget, Remap};
+use crate::backend::util::is_in_range;
+use crate::common::ItemId;
+use crate::entity::{CommonEntity, GameEntity, GameEntityT};
+use std::collections::HashSet;
+use std::hash::Hash;
+
+/// Source entity -> its clone
+pub type ClonePlan = Vec<(GameEntityT, GameEntityT)>;
+
+pub struct CloneRangeParams {
+    pub opened: bool,
+    pub entity: GameEntity,
+    pub range: String,
+    /// New ids are `id + target` instead of starting from `target`
+    pub use_offset: bool,
+    pub target: u32,
+    pub with_sets: bool,
+    pub with_recipes: bool,
+    pub plan: Option<Result<ClonePlan, String>>,
+}
+
+impl Default for CloneRangeParams {
+    fn default() -> Self {
+        Self {
+            opened: false,
+            entity: GameEntity::Weapon,
+            range: "".to_string(),
+            use_offset: false,
+            target: 0,
+            with_sets: true,
+            with_recipes: true,
+            plan: None,
+        }
+    }
+}
+
+fn ids<Id: Hash + Eq + Copy, T: Clone + CommonEntity<Id>, M: HolderMapOps<Id, T>>(
+    holder: &M,
+    wrap: fn(Id) -> GameEntityT,
+) -> Vec<GameEntityT> {
+    holder
+        .values()
+        .filter(|v| !v.deleted())
+        .map(|v| wrap(v.id()))
+        .collect()
+}
+
+fn is_item(entity: GameEntity) -> bool {
+    matches!(
+        entity,
+        GameEntity::Weapon | GameEntity::Armor | GameEntity::EtcItem
+    )
+}
+
+impl GameDataHolder {
+    /// Not deleted entities of given type. Any item type gives all items
+    fn entity_ids(&self, entity: GameEntity) -> Vec<GameEntityT> {
+        match entity {
+            GameEntity::Weapon | GameEntity::Armor | GameEntity::EtcItem => {
+                let mut res = ids(&self.weapon_holder, GameEntityT::Weapon);
+                res.extend(ids(&self.armor_holder, GameEntityT::Armor));
+                res.extend(ids(&self.etc_item_holder, GameEntityT::EtcItem));
+
+                res
+            }
+            GameEntity::Quest => ids(&self.quest_holder, GameEntityT::Quest),
+            GameEntity::Skill => ids(&self.skill_holder, GameEntityT::Skill),
+            GameEntity::Npc => ids(&self.npc_holder, GameEntityT::Npc),
+            GameEntity::ItemSet => ids(&self.item_set_holder, GameEntityT::ItemSet),
+            GameEntity::Recipe => ids(&self.recipe_holder, GameEntityT::Recipe),
+            GameEntity::HuntingZone => ids(&self.hunting_zone_holder, GameEntityT::HuntingZone),
+            GameEntity::Region => ids(&self.region_holder, GameEntityT::Region),
+            GameEntity::RaidInfo => ids(&self.raid_info_holder, GameEntityT::RaidInfo),
+            GameEntity::DailyMission => ids(&self.daily_mission_holder, GameEntityT::DailyMission),
+            GameEntity::AnimationCombo => {
+                ids(&self.animation_combo_holder, GameEntityT::AnimationCombo)
+            }
+            GameEntity::Residence => ids(&self.residence_holder, GameEntityT::Residence),
+            GameEntity::EnsoulOption => ids(&self.ensoul_option_holder, GameEntityT::EnsoulOption),
+            GameEntity::Henna => ids(&self.henna_holder, GameEntityT::Henna),
+            GameEntity::UserAction => ids(&self.user_action_holder, GameEntityT::UserAction),
+            GameEntity::Product => ids(&self.product_holder, GameEntityT::Product),
+            GameEntity::ClassInfo => ids(&self.class_info_holder, GameEntityT::ClassInfo),
+        }
+    }
+
+    /// First id not taken by any entity of given type (deleted included), starting from `from`
+    pub(crate) fn free_id(&self, entity: GameEntity, from: u32) -> u32 {
+        let mut res = from;
+
+        while self.entity_exists(GameEntityT::from_raw(entity, res)) {
+            res += 1;
+        }
+
+        res
+    }
+
+    fn max_id(&self, entity: GameEntity) -> u32 {
+        self.entity_ids(entity)
+            .iter()
+            .map(|v| v.raw_id())
+            .max()
+            .unwrap_or(0)
+    }
+
+    pub fn clone_range_plan(&self, params: &CloneRangeParams) -> Result<ClonePlan, String> {
+        let range = params.range.trim();
+        let range = range.strip_prefix("r:").unwrap_or(range);
+
+        let mut sources: Vec<_> = self
+            .entity_ids(params.entity)
+            .into_iter()
+            .filter(|v| is_in_range(range, v.raw_id()))
+            .collect();
+
+        sources.sort_by_key(|v| v.raw_id());
+
+        let Some(first) = sources.first() else {
+            return Err("Nothing found in range".to_string());
+        };
+
+        let first = first.raw_id();
+
+        let mut res = vec![];
+
+        for v in &sources {
+            let new_id = if params.use_offset {
+                v.raw_id().checked_add(params.target)
+            } else {
+                params.target.checked_add(v.raw_id() - first)
+            };
+
+            let Some(new_id) = new_id.filter(|v| *v != 0) else {
+                return Err(format!("Can't get new ID for {v} [{}]", v.raw_id()));
+            };
+
+            let to = v.with_id(new_id);
+
+            if self.entity_exists(to) {
+                return Err(format!("{to} [{new_id}] already exists"));
+            }
+
+            res.push((*v, to));
+        }
+
+        if is_item(params.entity) {
+            let items: HashSet<ItemId> = sources.iter().map(|v| v.raw_id().into()).collect();
+
+            if params.with_sets {
+                let mut sets: Vec<_> = self
+                    .item_set_holder
+                    .values()
+                    .filter(|v| {
+                        !v.deleted()
+                            && v.base_items
+                                .iter()
+                                .chain(&v.additional_items)
+                                .flatten()
+                                .any(|v| items.contains(v))
+                    })
+                    .map(|v| v.id)
+                    .collect();
+
+                sets.sort();
+
+                let mut next_id = self.max_id(GameEntity::ItemSet) + 1;
+
+                for id in sets {
+                    next_id = self.free_id(GameEntity::ItemSet, next_id);
+
+                    res.push((
+                        GameEntityT::ItemSet(id),
+                        GameEntityT::ItemSet(next_id.into()),
+                    ));
+                    next_id += 1;
+                }
+            }
+
+            if params.with_recipes {
+                let mut recipes: Vec<_> = self
+                    .recipe_holder
+                    .values()
+                    .filter(|v| !v.deleted() && items.contains(&v.product))
+                    .map(|v| v.id)
+                    .collect();
+
+                recipes.sort();
+
+                let mut next_id = self.max_id(GameEntity::Recipe) + 1;
+
+                for id in recipes {
+                    next_id = self.free_id(GameEntity::Recipe, next_id);
+
+                    res.push((GameEntityT::Recipe(id), GameEntityT::Recipe(next_id.into())));
+                    next_id += 1;
+                }
+            }
+        }
+
+        Ok(res)
+    }
+
+    /// Copies every entity of the plan. References between copied entities are pointed to
+    /// the copies, the rest is left as is
+    pub fn clone_range(&mut self, plan: &ClonePlan) {
+        let mut remap = Remap::default();
+
+        for (from, to) in plan {
+            self.copy_entity(*from, to.raw_id(), true);
+
+            if let (Some(from), Some(to)) =
+                (RefTarget::from_entity(*from), RefTarget::from_entity(*to))
+            {
+                remap.insert(from, to);
+            }
+        }
+
+        for (_, to) in plan {
+            self.remap_entity_references(*to, &remap);
+        }
+
+        self.rebuild_reference_index();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CloneRangeParams;
+    use crate::backend::holder::HolderMapOps;
+    use crate::backend::holder::tests::items_with_recipe;
+    use crate::common::{ItemId, RecipeId};
+    use crate::entity::{GameEntity, GameEntityT};
+
+    fn params(range: &str, target: u32, use_offset: bool) -> CloneRangeParams {
+        CloneRangeParams {
+            entity: GameEntity::EtcItem,
+            range: range.to_string(),
+            target,
+            use_offset,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_clone_range_plan() {
+        let holder = items_with_recipe();
+
+        let plan = holder
+            .clone_range_plan(&params("10-12", 100, false))
+            .unwrap();
+
+        assert_eq!(
+            vec![
+                (
+                    GameEntityT::EtcItem(ItemId(10)),
+                    GameEntityT::EtcItem(ItemId(100))
+                ),
+                (
+                    GameEntityT::EtcItem(ItemId(11)),
+                    GameEntityT::EtcItem(ItemId(101))
+                ),
+                (
+                    GameEntityT::EtcItem(ItemId(12)),
+                    GameEntityT::EtcItem(ItemId(102))
+                ),
+                (
+                    GameEntityT::Recipe(RecipeId(5)),
+                    GameEntityT::Recipe(RecipeId(6))
+                ),
+            ],
+            plan
+        );
+
+        let plan = holder
+            .clone_range_plan(&params("r:11-12", 1000, true))
+            .unwrap();
+
+        assert_eq!(
+            (
+                GameEntityT::EtcItem(ItemId(12)),
+                GameEntityT::EtcItem(ItemId(1012))
+            ),
+            plan[1]
+        );
+    }
+
+    #[test]
+    fn test_clone_range_plan_errors() {
+        let holder = items_with_recipe();
+
+        assert!(
+            holder
+                .clone_range_plan(&params("50-60", 100, false))
+                .is_err()
+        );
+        // 10 -> 29, 11 -> 30 which is taken
+        assert!(
+            holder
+                .clone_range_plan(&params("10-11", 29, false))
+                .is_err()
+        );
+        assert!(
+            holder
+                .clone_range_plan(&params("10", u32::MAX, true))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_clone_range() {
+        let mut holder = items_with_recipe();
+
+        let plan = holder
+            .clone_range_plan(&params("10-11", 100, false))
+            .unwrap();
+        holder.clone_range(&plan);
+
+        assert!(holder.etc_item_holder.get(&ItemId(10)).is_some());
+        assert!(holder.etc_item_holder.get(&ItemId(100)).is_some());
+
+        // Copy points to copies, source recipe is left as is
+        let recipe = holder.recipe_holder.get(&RecipeId(6)).unwrap();
+        assert_eq!(ItemId(101), recipe.product);
+        assert_eq!(ItemId(100), recipe.materials[0].id);
+
+        let recipe = holder.recipe_holder.get(&RecipeId(5)).unwrap();
+        assert_eq!(ItemId(11), recipe.product);
+    }
+}
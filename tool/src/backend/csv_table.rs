@@ -0,0 +1,692 @@
+use crate::backend::client_diff::{FieldDiff, diff_fields};
+use crate::backend::log_holder::{Log, LogLevel};
+use crate::backend::patch::PatchValue;
+use crate::backend::{Backend, Dialog};
+use crate::entity::{Entity, GameEntity, GameEntityT};
+use crate::logs_mut;
+use csv::{ReaderBuilder, WriterBuilder};
+use serde_json::Value;
+use std::collections::btree_map::Entry;
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+
+/// Column holding index of the `[*]` array element a row was made of
+const INDEX_SUFFIX: &str = "[#]";
+const EACH: &str = "[*]";
+
+#[derive(Clone, PartialEq, Debug)]
+enum Segment {
+    Key(String),
+    Index(usize),
+    /// Element the current row is made of
+    Each,
+}
+
+/// `battle_stats.p_attack`, `skill_levels[*].mp_cost`, `drop_info[0].id`
+fn parse_path(path: &str) -> Option<Vec<Segment>> {
+    let mut res = vec![];
+
+    for part in path.split('.') {
+        let mut parts = part.split('[');
+
+        let key = parts.next()?;
+
+        if !key.is_empty() {
+            res.push(Segment::Key(key.to_string()));
+        }
+
+        for index in parts {
+            res.push(match index.strip_suffix(']')? {
+                "*" => Segment::Each,
+                v => Segment::Index(v.parse().ok()?),
+            });
+        }
+    }
+
+    (res.iter().filter(|v| **v == Segment::Each).count() <= 1).then_some(res)
+}
+
+fn get<'a>(mut value: &'a Value, path: &[Segment], each: usize) -> Option<&'a Value> {
+    for segment in path {
+        value = match segment {
+            Segment::Key(k) => value.get(k)?,
+            Segment::Index(i) => value.get(i)?,
+            Segment::Each => value.get(each)?,
+        };
+    }
+
+    Some(value)
+}
+
+fn get_mut<'a>(mut value: &'a mut Value, path: &[Segment], each: usize) -> Option<&'a mut Value> {
+    for segment in path {
+        value = match segment {
+            Segment::Key(k) => value.get_mut(k)?,
+            Segment::Index(i) => value.get_mut(i)?,
+            Segment::Each => value.get_mut(each)?,
+        };
+    }
+
+    Some(value)
+}
+
+/// Leaf paths of `value`. Elements of the outermost array are merged under `[*]`, deeper arrays
+/// keep their indexes
+fn collect_columns(value: &Value, path: String, in_each: bool, res: &mut BTreeSet<String>) {
+    match value {
+        Value::Object(v) if !v.is_empty() => {
+            for (k, v) in v {
+                let path = if path.is_empty() {
+                    k.clone()
+                } else {
+                    format!("{path}.{k}")
+                };
+
+                collect_columns(v, path, in_each, res);
+            }
+        }
+
+        Value::Array(v) if !v.is_empty() => {
+            for (i, v) in v.iter().enumerate() {
+                if in_each {
+                    collect_columns(v, format!("{path}[{i}]"), true, res);
+                } else {
+                    collect_columns(v, format!("{path}{EACH}"), true, res);
+                }
+            }
+        }
+
+        _ => {
+            res.insert(path);
+        }
+    }
+}
+
+fn to_cell(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(v) => v.clone(),
+        // Floats are mostly f32, printed the short way when nothing is lost
+        Value::Number(v) if v.is_f64() => {
+            let v = v.as_f64().unwrap_or_default();
+
+            if (v as f32) as f64 == v {
+                (v as f32).to_string()
+            } else {
+                v.to_string()
+            }
+        }
+        v => v.to_string(),
+    }
+}
+
+/// Cell is read as the type of the value it replaces
+fn from_cell(cell: &str, current: &Value) -> Result<Value, String> {
+    match current {
+        Value::String(_) => Ok(Value::String(cell.to_string())),
+
+        Value::Null if cell.is_empty() => Ok(Value::Null),
+
+        Value::Null => {
+            Ok(serde_json::from_str(cell).unwrap_or_else(|_| Value::String(cell.to_string())))
+        }
+
+        Value::Bool(_) => cell
+            .trim()
+            .parse()
+            .map(Value::Bool)
+            .map_err(|_| format!("{cell} is not true/false")),
+
+        Value::Number(v) if v.is_f64() => cell
+            .trim()
+            .parse::<f64>()
+            .ok()
+            .and_then(serde_json::Number::from_f64)
+            .map(Value::Number)
+            .ok_or_else(|| format!("{cell} is not a number")),
+
+        Value::Number(_) => {
+            let cell = cell.trim();
+
+            cell.parse::<i64>()
+                .map(Value::from)
+                .or_else(|_| cell.parse::<u64>().map(Value::from))
+                .map_err(|_| format!("{cell} is not an integer"))
+        }
+
+        _ => serde_json::from_str(cell).map_err(|e| format!("{cell}: {e}")),
+    }
+}
+
+fn delimiter(path: &Path) -> u8 {
+    if path
+        .extension()
+        .is_some_and(|v| v.eq_ignore_ascii_case("tsv"))
+    {
+        b'\t'
+    } else {
+        b','
+    }
+}
+
+pub struct CsvChange {
+    pub value: PatchValue,
+    pub name: String,
+    pub fields: Vec<FieldDiff>,
+}
+
+#[derive(Default)]
+pub struct CsvImport {
+    pub path: String,
+    pub changes: Vec<CsvChange>,
+    pub errors: Vec<String>,
+}
+
+pub struct CsvParams {
+    pub opened: bool,
+    pub entity: GameEntity,
+    /// Flattened fields of `entity`
+    pub columns: Vec<String>,
+    pub selected: BTreeSet<String>,
+    pub filter: String,
+    pub tsv: bool,
+    /// Read, but not yet applied
+    pub import: Option<CsvImport>,
+}
+
+impl Default for CsvParams {
+    fn default() -> Self {
+        Self {
+            opened: false,
+            entity: GameEntity::Weapon,
+            columns: vec![],
+            selected: BTreeSet::new(),
+            filter: "".to_string(),
+            tsv: false,
+            import: None,
+        }
+    }
+}
+
+impl Backend {
+    pub fn refresh_csv_columns(&mut self) {
+        let mut columns = BTreeSet::new();
+
+        for v in PatchValue::all_of(&self.holders.game_data_holder, self.csv.entity.into()) {
+            if let Ok(Value::Object(v)) = serde_json::to_value(&v)
+                && let Some(v) = v.values().next()
+            {
+                collect_columns(v, String::new(), false, &mut columns);
+            }
+        }
+
+        // Always goes first
+        columns.remove("id");
+
+        self.csv.selected.retain(|v| columns.contains(v));
+        self.csv.columns = columns.into_iter().collect();
+    }
+
+    fn write_csv(&self, path: &Path) -> Result<usize, String> {
+        let columns: Vec<_> = self
+            .csv
+            .columns
+            .iter()
+            .filter(|v| self.csv.selected.contains(*v))
+            .map(|v| {
+                parse_path(v)
+                    .map(|p| (v, p))
+                    .ok_or(format!("Bad column {v}"))
+            })
+            .collect::<Result<_, _>>()?;
+
+        let prefixes: BTreeSet<_> = columns
+            .iter()
+            .filter_map(|(v, _)| v.split_once(EACH).map(|v| v.0))
+            .collect();
+
+        if prefixes.len() > 1 {
+            return Err("All [*] columns must go through the same array".to_string());
+        }
+
+        let each = prefixes
+            .first()
+            .map(|v| (v.to_string(), parse_path(v).unwrap_or_default()));
+
+        let mut writer = WriterBuilder::new()
+            .delimiter(delimiter(path))
+            .from_path(path)
+            .map_err(|e| e.to_string())?;
+
+        let mut header = vec!["id".to_string()];
+
+        if let Some((prefix, _)) = &each {
+            header.push(format!("{prefix}{INDEX_SUFFIX}"));
+        }
+
+        header.extend(columns.iter().map(|(v, _)| v.to_string()));
+
+        writer.write_record(&header).map_err(|e| e.to_string())?;
+
+        let mut count = 0;
+
+        for value in PatchValue::all_of(&self.holders.game_data_holder, self.csv.entity.into()) {
+            let Ok(Value::Object(json)) = serde_json::to_value(&value) else {
+                continue;
+            };
+            let Some(json) = json.values().next() else {
+                continue;
+            };
+
+            let rows = match &each {
+                Some((_, path)) => get(json, path, 0)
+                    .and_then(|v| v.as_array())
+                    .map_or(0, |v| v.len()),
+                None => 1,
+            };
+
+            for i in 0..rows {
+                let mut record = vec![value.id().to_string()];
+
+                if each.is_some() {
+                    record.push(i.to_string());
+                }
+
+                for (_, path) in &columns {
+                    record.push(get(json, path, i).map(to_cell).unwrap_or_default());
+                }
+
+                writer.write_record(&record).map_err(|e| e.to_string())?;
+                count += 1;
+            }
+        }
+
+        writer.flush().map_err(|e| e.to_string())?;
+
+        Ok(count)
+    }
+
+    pub fn export_csv(&self, path: &Path) {
+        logs_mut().add(match self.write_csv(path) {
+            Ok(rows) => Log {
+                level: LogLevel::Info,
+                producer: "Csv".to_string(),
+                log: format!("{rows} {} row(s) exported to {path:?}", self.csv.entity),
+            },
+            Err(e) => Log {
+                level: LogLevel::Error,
+                producer: "Csv".to_string(),
+                log: format!("Export to {path:?} failed: {e}"),
+            },
+        });
+    }
+
+    /// Rows are matched to entities by `id`, changed cells of the same entity are merged.
+    /// Nothing is applied until [`Backend::apply_csv_import`]
+    fn read_csv(&self, path: &Path) -> Result<CsvImport, String> {
+        let entity: Entity = self.csv.entity.into();
+        let holder = &self.holders.game_data_holder;
+
+        let mut reader = ReaderBuilder::new()
+            .delimiter(delimiter(path))
+            .from_path(path)
+            .map_err(|e| e.to_string())?;
+
+        let header: Vec<_> = reader
+            .headers()
+            .map_err(|e| e.to_string())?
+            .iter()
+            .map(|v| v.to_string())
+            .collect();
+
+        if header.first().map(|v| v.as_str()) != Some("id") {
+            return Err("First column must be id".to_string());
+        }
+
+        let skip = if header.get(1).is_some_and(|v| v.ends_with(INDEX_SUFFIX)) {
+            2
+        } else {
+            1
+        };
+
+        let columns: Vec<_> = header[skip..]
+            .iter()
+            .map(|v| {
+                parse_path(v)
+                    .map(|p| (v, p))
+                    .ok_or(format!("Bad column {v}"))
+            })
+            .collect::<Result<_, _>>()?;
+
+        if skip == 1 && columns.iter().any(|(_, p)| p.contains(&Segment::Each)) {
+            return Err(format!(
+                "[*] columns need {INDEX_SUFFIX} column right after id"
+            ));
+        }
+
+        // Path of the array rows are made of
+        let prefixes: BTreeSet<_> = columns
+            .iter()
+            .filter_map(|(v, _)| v.split_once(EACH).map(|v| v.0))
+            .collect();
+
+        if prefixes.len() > 1 {
+            return Err("All [*] columns must go through the same array".to_string());
+        }
+
+        let each_array = prefixes.first().map(|v| parse_path(v).unwrap_or_default());
+
+        // id -> (current value, edited json)
+        let mut values: BTreeMap<u32, (PatchValue, Value)> = BTreeMap::new();
+        let mut errors = vec![];
+
+        for (row, record) in reader.records().enumerate() {
+            // Header is the first line
+            let row = row + 2;
+
+            let record = match record {
+                Ok(v) => v,
+                Err(e) => {
+                    errors.push(format!("Row {row}: {e}"));
+                    continue;
+                }
+            };
+
+            let Some(id) = record.get(0).and_then(|v| v.trim().parse::<u32>().ok()) else {
+                errors.push(format!("Row {row}: bad id"));
+                continue;
+            };
+
+            let each = if skip == 2 {
+                let Some(v) = record.get(1).and_then(|v| v.trim().parse::<usize>().ok()) else {
+                    errors.push(format!("Row {row}: bad {INDEX_SUFFIX}"));
+                    continue;
+                };
+
+                v
+            } else {
+                0
+            };
+
+            let (_, json) = match values.entry(id) {
+                Entry::Occupied(v) => v.into_mut(),
+                Entry::Vacant(v) => {
+                    let Some(current) = PatchValue::find(holder, entity, id) else {
+                        errors.push(format!("Row {row}: no {entity} with id {id}"));
+                        continue;
+                    };
+
+                    let json = serde_json::to_value(&current).map_err(|e| e.to_string())?;
+
+                    v.insert((current, json))
+                }
+            };
+
+            // Value is wrapped into its variant
+            let Some(json) = json.as_object_mut().and_then(|v| v.values_mut().next()) else {
+                continue;
+            };
+
+            // Row right after the last element appends a copy of it, there is no other template
+            if let Some(prefix) = &each_array
+                && let Some(Value::Array(array)) = get_mut(json, prefix, 0)
+            {
+                if each == array.len()
+                    && let Some(last) = array.last().cloned()
+                {
+                    array.push(last);
+                }
+
+                if each >= array.len() {
+                    errors.push(format!(
+                        "Row {row}: {INDEX_SUFFIX} {each} is past the end of {} element(s) array, \
+                         only the next index can be added",
+                        array.len()
+                    ));
+                    continue;
+                }
+            }
+
+            for ((name, path), cell) in columns.iter().zip(record.iter().skip(skip)) {
+                let res = get_mut(json, path, each)
+                    .ok_or("no such field".to_string())
+                    .and_then(|v| {
+                        *v = from_cell(cell, v)?;
+
+                        Ok(())
+                    });
+
+                if let Err(e) = res {
+                    errors.push(format!("Row {row}, {name}: {e}"));
+                }
+            }
+        }
+
+        let mut changes = vec![];
+
+        for (id, (current, json)) in values {
+            let new = match serde_json::from_value::<PatchValue>(json) {
+                Ok(v) => v,
+                Err(e) => {
+                    errors.push(format!("{entity} [{id}]: {e}"));
+                    continue;
+                }
+            };
+
+            if new.id() != id {
+                errors.push(format!("{entity} [{id}]: ID can't be changed"));
+                continue;
+            }
+
+            let mut fields = diff_fields(&current, &new);
+
+            if fields.is_empty() {
+                continue;
+            }
+
+            // Drop variant name
+            for v in &mut fields {
+                if let Some((_, path)) = v.path.split_once('.') {
+                    v.path = path.to_string();
+                }
+            }
+
+            changes.push(CsvChange {
+                name: holder
+                    .get_entity_name(GameEntityT::from_raw(self.csv.entity, id))
+                    .unwrap_or_default(),
+                value: new,
+                fields,
+            });
+        }
+
+        Ok(CsvImport {
+            path: path.to_string_lossy().to_string(),
+            changes,
+            errors,
+        })
+    }
+
+    pub fn prepare_csv_import(&mut self, path: &Path) {
+        match self.read_csv(path) {
+            Ok(v) => self.csv.import = Some(v),
+            Err(e) => self.show_dialog(Dialog::ShowWarning(format!("Can't read {path:?}: {e}"))),
+        }
+    }
+
+    /// Previewed changes land as if they were saved from editors
+    pub fn apply_csv_import(&mut self) {
+        let Some(import) = self.csv.import.take() else {
+            return;
+        };
+
+        let targets: Vec<_> = import
+            .changes
+            .iter()
+            .filter_map(|v| match v.value.entity() {
+                Entity::GameEntity(e) => Some(GameEntityT::from_raw(e, v.value.id())),
+                Entity::Dictionary(_) => None,
+            })
+            .collect();
+
+        if let Some(v) = targets
+            .iter()
+            .find(|v| self.editors.has_unsaved_changes(**v))
+        {
+            self.show_dialog(Dialog::ShowWarning(format!(
+                "{v} [{}] has unsaved changes, save or close it first",
+                v.raw_id()
+            )));
+
+            self.csv.import = Some(import);
+
+            return;
+        }
+
+        let mut entities: Vec<GameEntity> = vec![];
+
+        for v in targets {
+            self.editors.close_if_opened(v);
+
+            if !entities.contains(&v.into()) {
+                entities.push(v.into());
+            }
+        }
+
+        for v in &import.changes {
+            v.value.write_changed_to(&mut self.holders.game_data_holder);
+        }
+
+        self.holders.game_data_holder.refill_all_items();
+        self.holders.game_data_holder.rebuild_reference_index();
+
+        for e in entities {
+            self.filter_entity_catalog(e);
+        }
+
+        logs_mut().add(Log {
+            level: LogLevel::Info,
+            producer: "Csv".to_string(),
+            log: format!(
+                "{} {} entities updated from {}",
+                import.changes.len(),
+                self.csv.entity,
+                import.path
+            ),
+        });
+
+        self.check_for_unwrote_changed();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Segment, from_cell, parse_path};
+    use crate::backend::holder::HolderMapOps;
+    use crate::backend::log_holder::LogHolder;
+    use crate::backend::{Backend, Config};
+    use crate::common::{ItemId, RecipeId};
+    use crate::entity::GameEntity;
+    use crate::entity::recipe::{Recipe, RecipeMaterial};
+    use serde_json::Value;
+    use std::sync::RwLock;
+
+    fn key(v: &str) -> Segment {
+        Segment::Key(v.to_string())
+    }
+
+    #[test]
+    fn test_parse_path() {
+        assert_eq!(
+            Some(vec![key("battle_stats"), key("p_attack")]),
+            parse_path("battle_stats.p_attack")
+        );
+        assert_eq!(
+            Some(vec![key("skill_levels"), Segment::Each, key("mp_cost")]),
+            parse_path("skill_levels[*].mp_cost")
+        );
+        assert_eq!(
+            Some(vec![key("drop_info"), Segment::Index(0), key("id")]),
+            parse_path("drop_info[0].id")
+        );
+
+        assert_eq!(None, parse_path("a[*].b[*]"));
+        assert_eq!(None, parse_path("a[x]"));
+        assert_eq!(None, parse_path("a[0"));
+    }
+
+    #[test]
+    fn test_from_cell() {
+        assert_eq!(Ok(Value::from(" 1")), from_cell(" 1", &Value::from("")));
+        assert_eq!(
+            Ok(Value::Bool(true)),
+            from_cell("true", &Value::Bool(false))
+        );
+        assert_eq!(Ok(Value::from(-5)), from_cell(" -5 ", &Value::from(1)));
+        assert_eq!(Ok(Value::from(1.5)), from_cell("1.5", &Value::from(0.5)));
+        assert_eq!(Ok(Value::Null), from_cell("", &Value::Null));
+        assert_eq!(Ok(Value::from(3)), from_cell("3", &Value::Null));
+
+        assert!(from_cell("yes", &Value::Bool(false)).is_err());
+        assert!(from_cell("1.5", &Value::from(1)).is_err());
+        assert!(from_cell("x", &Value::from(0.5)).is_err());
+    }
+
+    #[test]
+    fn test_read_csv_each_rows() {
+        crate::APP_LOGS.set(RwLock::new(LogHolder::new())).ok();
+
+        let mut backend = Backend::from_config(Config::default(), false);
+        backend.csv.entity = GameEntity::Recipe;
+        backend.holders.game_data_holder.recipe_holder.insert(
+            RecipeId(1),
+            Recipe {
+                id: RecipeId(1),
+                materials: vec![RecipeMaterial {
+                    id: ItemId(57),
+                    count: 10,
+                    recipe_id: RecipeId(1),
+                }],
+                ..Default::default()
+            },
+        );
+
+        let folder = std::env::temp_dir().join(format!("l2w_csv_{}", std::process::id()));
+        std::fs::create_dir_all(&folder).unwrap();
+
+        let read = |name: &str, csv: &str| {
+            let path = folder.join(name);
+            std::fs::write(&path, csv).unwrap();
+
+            backend.read_csv(&path).unwrap()
+        };
+
+        // Next index appends a copy of the last element
+        let import = read(
+            "append.csv",
+            "id,materials[#],materials[*].count\n1,0,11\n1,1,12\n",
+        );
+        assert!(import.errors.is_empty(), "{:?}", import.errors);
+        assert_eq!(1, import.changes.len());
+
+        let super::PatchValue::Recipe(recipe) = &import.changes[0].value else {
+            panic!("not a recipe");
+        };
+        assert_eq!(2, recipe.materials.len());
+        assert_eq!(11, recipe.materials[0].count);
+        assert_eq!(ItemId(57), recipe.materials[1].id);
+        assert_eq!(12, recipe.materials[1].count);
+
+        // Gaps are rejected
+        let import = read("gap.csv", "id,materials[#],materials[*].count\n1,2,12\n");
+        assert_eq!(1, import.errors.len());
+        assert!(import.changes.is_empty());
+
+        // Rows are made of one array only
+        let path = folder.join("mixed.csv");
+        std::fs::write(&path, "id,materials[#],materials[*].count,a[*].b\n").unwrap();
+        assert!(backend.read_csv(&path).is_err());
+
+        std::fs::remove_dir_all(&folder).ok();
+    }
+}
@@ -1,8 +1,21 @@
-use crate::backend::holder::GameDataHolder;
+use crate::backend::backup::backup_file;
+use crate::backend::holder::{DumpFormat, GameDataHolder, RonLayout};
 use crate::backend::log_holder::{Log, LogLevel};
+use crate::backend::util::Localized;
+use crate::backend::{DEFAULT_LOCALIZATION, Localization};
+use l2_rw::ue2_rw::{INDEX, ReadUnreal, WriteUnreal};
+use l2_rw::{DatVariant, read_encoded_file, save_dat};
+use serde::Serialize;
 use std::collections::HashMap;
 use std::fmt::Debug;
+use std::fs::File;
 use std::hash::Hash;
+use std::io::{Cursor, ErrorKind};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+use std::thread::JoinHandle;
 use walkdir::{DirEntry, WalkDir};
 
 mod protocol_166;
@@ -18,6 +31,14 @@ impl Log {
         }
     }
 
+    fn from_loader_w(val: &str) -> Self {
+        Log {
+            level: LogLevel::Warning,
+            producer: "Dat Loader".to_string(),
+            log: val.to_string(),
+        }
+    }
+
     fn from_loader_e(val: impl Debug) -> Self {
         Log {
             level: LogLevel::Error,
@@ -25,15 +46,69 @@ impl Log {
             log: format!("{val:#?}"),
         }
     }
+
+    /// Reason the whole folder can't be loaded
+    fn from_loader_fatal(val: String) -> Self {
+        Log {
+            level: LogLevel::Error,
+            producer: "Dat Loader".to_string(),
+            log: val,
+        }
+    }
 }
 
 pub trait DatLoader {
-    fn load_from_binary(&mut self, dat_paths: HashMap<String, DirEntry>) -> Result<Vec<Log>, ()>;
-    fn save_to_binary(&mut self, ron_path: &Option<String>) -> std::io::Result<()>;
-    fn save_to_ron(&self, folder_path: &str, all: bool) -> std::io::Result<()>;
+    fn load_from_binary(&mut self, dat_paths: HashMap<String, DirEntry>) -> Result<Vec<Log>, Log>;
+    fn save_to_binary(
+        &mut self,
+        ron_path: &Option<String>,
+        ron_layout: RonLayout,
+        dump_format: DumpFormat,
+        output: Option<DatOutput>,
+    ) -> std::io::Result<()>;
+    fn save_to_ron(
+        &self,
+        folder_path: &str,
+        all: bool,
+        layout: RonLayout,
+        format: DumpFormat,
+    ) -> anyhow::Result<()>;
+    /// Fills holder from a full [`DatLoader::save_to_ron`] dump
+    fn load_from_ron(&mut self, folder_path: &str) -> anyhow::Result<Vec<Log>>;
 }
 
-pub fn load_game_data_holder(path: &str) -> Result<(GameDataHolder, Vec<Log>), ()> {
+/// [`l2_rw::deserialize_dat`] with the error naming the dat
+pub fn deserialize_dat<T: ReadUnreal + Debug>(path: &Path) -> Result<Vec<T>, Log> {
+    l2_rw::deserialize_dat(path)
+        .map_err(|_| Log::from_loader_fatal(format!("{path:?} can't be read")))
+}
+
+/// [`l2_rw::deserialize_dat_with_string_dict`] with the error naming the dat
+pub fn deserialize_dat_with_string_dict<S: ReadUnreal + Debug, T: ReadUnreal + Debug>(
+    path: &Path,
+) -> Result<(Vec<S>, Vec<T>), Log> {
+    l2_rw::deserialize_dat_with_string_dict(path)
+        .map_err(|_| Log::from_loader_fatal(format!("{path:?} can't be read")))
+}
+
+/// Loads system folder as the current one. Its locales become the known ones
+pub fn load_game_data_holder(path: &str) -> Result<(GameDataHolder, Vec<Log>), Log> {
+    let res = load_holder(path);
+
+    if let Ok((holder, _)) = &res {
+        Localization::set_known(holder.localizations.clone());
+    }
+
+    res
+}
+
+/// Loads another system folder next to the current one. Known locales are kept, the ones of
+/// loaded folder are in [`GameDataHolder::localizations`]
+pub fn load_detached_game_data_holder(path: &str) -> Result<(GameDataHolder, Vec<Log>), Log> {
+    load_holder(path)
+}
+
+fn load_holder(path: &str) -> Result<(GameDataHolder, Vec<Log>), Log> {
     let mut dat_paths = HashMap::new();
 
     for path in WalkDir::new(path).into_iter().flatten() {
@@ -43,15 +118,500 @@ pub fn load_game_data_holder(path: &str) -> Result<(GameDataHolder, Vec<Log>), (
             }
     }
 
-    let mut holder = GameDataHolder::default();
+    let mut holder = GameDataHolder {
+        localizations: discover_localizations(dat_paths.keys().map(|v| v.as_str())),
+        ..Default::default()
+    };
 
-    let warnings = holder.load_from_binary(dat_paths).unwrap();
+    let warnings = holder.load_from_binary(dat_paths)?;
 
     holder.set_all_holders_unchanged();
+    holder.rebuild_reference_index();
 
     Ok((holder, warnings))
 }
 
+/// Collects locale codes from `<name>-<code>.dat` files. The code found on the most dats goes
+/// first, ties go to [`DEFAULT_LOCALIZATION`]
+fn discover_localizations<'a>(names: impl IntoIterator<Item = &'a str>) -> Vec<Localization> {
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+
+    for name in names {
+        if let Some((_, code)) = name.trim_end_matches(".dat").rsplit_once('-')
+            && !code.is_empty()
+            && code.len() <= 4
+            && code.chars().all(|c| c.is_ascii_alphabetic())
+        {
+            *counts.entry(code).or_default() += 1;
+        }
+    }
+
+    let mut codes: Vec<_> = counts.into_iter().collect();
+    codes.sort_by(|a, b| {
+        b.1.cmp(&a.1)
+            .then((a.0 != DEFAULT_LOCALIZATION).cmp(&(b.0 != DEFAULT_LOCALIZATION)))
+            .then(a.0.cmp(b.0))
+    });
+
+    let mut res: Vec<_> = codes.iter().map(|v| Localization::new(v.0)).collect();
+
+    if let Some(main) = res.first().copied() {
+        res[1..].sort();
+        res[0] = main;
+    }
+
+    res
+}
+
+/// Records of `<stem>-<code>.dat` for every locale found in system folder
+pub struct LocalizedDat<T> {
+    /// Main one first
+    pub localizations: Vec<Localization>,
+    pub main: Vec<T>,
+    pub others: Vec<(Localization, HashMap<u32, T>)>,
+}
+
+impl<T: GetId> LocalizedDat<T> {
+    pub fn get(&self, localization: Localization, id: u32) -> Option<&T> {
+        self.others
+            .iter()
+            .find(|v| v.0 == localization)
+            .and_then(|v| v.1.get(&id))
+    }
+
+    /// Value of `main` record for main locale and of record with the same id for others.
+    /// `missing` is used when locale has no such record
+    pub fn localize<V: Clone + Serialize + PartialEq + Default>(
+        &self,
+        main: &T,
+        f: impl Fn(&T) -> V,
+        missing: V,
+    ) -> Localized<V> {
+        Localized::from_fn_in(self.localizations.iter().copied(), |l| {
+            if l == self.localizations[0] {
+                f(main)
+            } else if let Some(v) = self.get(l, main.get_id()) {
+                f(v)
+            } else {
+                missing.clone()
+            }
+        })
+    }
+}
+
+impl GameDataHolder {
+    pub fn localized_dat_paths(&self, stem: &str) -> Vec<(Localization, DirEntry)> {
+        self.localizations()
+            .into_iter()
+            .filter_map(|l| {
+                self.dat_paths
+                    .get(&format!("{stem}-{}.dat", l.code()))
+                    .map(|v| (l, v.clone()))
+            })
+            .collect()
+    }
+
+    /// Error for a dat of the main locale loading can't go without
+    pub fn missing_dat(&self, stem: &str) -> Log {
+        Log::from_loader_fatal(format!(
+            "{stem}-{}.dat not found",
+            self.main_localization().code()
+        ))
+    }
+
+    /// Warning for a dat older clients don't have. Loader leaves its holder empty then
+    pub fn missing_optional_dat(&self, stem: &str) -> Option<Log> {
+        let name = format!("{stem}-{}.dat", self.main_localization().code());
+
+        if self.dat_paths.contains_key(&name) {
+            None
+        } else {
+            Some(Log::from_loader_w(&format!("{name} not found, skipped")))
+        }
+    }
+
+    pub fn load_localized_dat<T: ReadUnreal + Debug + GetId>(
+        &self,
+        stem: &str,
+    ) -> Result<LocalizedDat<T>, Log> {
+        let main = self.main_localization();
+
+        let Some(main_path) = self.dat_paths.get(&format!("{stem}-{}.dat", main.code())) else {
+            return Err(self.missing_dat(stem));
+        };
+
+        let mut res = LocalizedDat {
+            localizations: self.localizations(),
+            main: deserialize_dat(main_path.path())?,
+            others: vec![],
+        };
+
+        for (l, path) in self.localized_dat_paths(stem) {
+            if l != main {
+                res.others
+                    .push((l, wrap_into_id_map(deserialize_dat(path.path())?)));
+            }
+        }
+
+        Ok(res)
+    }
+}
+
+/// Writes dat of every locale on a separate thread
+pub fn save_localized_dats<T: WriteUnreal + Debug + Send + 'static>(
+    dats: Vec<(Localization, DirEntry, Vec<T>)>,
+    name: &'static str,
+) -> JoinHandle<Vec<Log>> {
+    thread::spawn(move || {
+        if dats.is_empty() {
+            return vec![Log::from_loader_e(format!(
+                "{name}: dat not found in system folder, not saved"
+            ))];
+        }
+
+        dats.into_iter()
+            .map(|(l, path, data)| {
+                if let Err(e) = write_dat(path.path(), DatVariant::<(), T>::Array(data)) {
+                    Log::from_loader_e(e)
+                } else {
+                    Log::from_loader_i(&format!("{name} {l} saved"))
+                }
+            })
+            .collect()
+    })
+}
+
+/// Trailer every dat payload ends with
+const SAFE_PACKAGE: [u8; 13] = [12, 83, 97, 102, 101, 80, 97, 99, 107, 97, 103, 101, 0];
+
+/// Folder that dats are written to instead of the system folder they were loaded from
+#[derive(Clone, Debug)]
+pub struct DatOutput {
+    pub system_folder: PathBuf,
+    pub output_folder: PathBuf,
+    /// Dats missing in output folder are copied from system folder, so it can be packaged as is
+    pub copy_unchanged: bool,
+}
+
+impl DatOutput {
+    /// Folders are the same or one holds the other. Saves would walk into their own output then
+    pub fn folders_overlap(system_folder: &Path, output_folder: &Path) -> bool {
+        let (Ok(system), Ok(output)) = (system_folder.canonicalize(), output_folder.canonicalize())
+        else {
+            return system_folder == output_folder;
+        };
+
+        system.starts_with(&output) || output.starts_with(&system)
+    }
+
+    /// Same path relative to output folder. Paths outside system folder are kept as is
+    fn map(&self, path: &Path) -> PathBuf {
+        path.strip_prefix(&self.system_folder)
+            .map(|v| self.output_folder.join(v))
+            .unwrap_or_else(|_| path.to_path_buf())
+    }
+
+    fn copy_unchanged(&self, written: &[PathBuf]) -> std::io::Result<usize> {
+        let mut count = 0;
+
+        for entry in WalkDir::new(&self.system_folder).into_iter().flatten() {
+            if !entry.file_type().is_file()
+                || !entry
+                    .file_name()
+                    .to_string_lossy()
+                    .to_lowercase()
+                    .ends_with(".dat")
+            {
+                continue;
+            }
+
+            let target = self.map(entry.path());
+
+            if written.contains(&target) || target.exists() {
+                continue;
+            }
+
+            if let Some(parent) = target.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+
+            std::fs::copy(entry.path(), &target)?;
+            count += 1;
+        }
+
+        Ok(count)
+    }
+}
+
+/// Dats of current save, staged next to their targets until [`commit_write_batch`]
+#[derive(Default)]
+struct WriteBatch {
+    output: Option<DatOutput>,
+    /// (temporary file, target file)
+    staged: Vec<(PathBuf, PathBuf)>,
+    failed: Option<String>,
+}
+
+static WRITE_BATCH: Mutex<Option<WriteBatch>> = Mutex::new(None);
+
+/// Whether last [`commit_write_batch`] replaced all its dats
+static LAST_COMMIT_OK: AtomicBool = AtomicBool::new(false);
+
+pub fn last_commit_succeeded() -> bool {
+    LAST_COMMIT_OK.load(Ordering::Relaxed)
+}
+
+/// Starts new write batch. Leftovers of an unfinished one are dropped. With `output` set, dats
+/// are written there instead of their original paths
+pub fn begin_write_batch(output: Option<DatOutput>) {
+    let batch = WriteBatch {
+        output,
+        ..Default::default()
+    };
+
+    if let Some(batch) = WRITE_BATCH.lock().unwrap().replace(batch) {
+        for (tmp, _) in batch.staged {
+            let _ = std::fs::remove_file(tmp);
+        }
+    }
+}
+
+/// Renames every staged dat over its original, backing originals up first. If any dat of the
+/// batch failed, nothing is renamed and all temporary files are removed. Problems after dats are
+/// replaced don't fail the commit and come back as a warning
+pub fn commit_write_batch() -> Result<(usize, Option<String>), String> {
+    let res = commit_batch();
+
+    LAST_COMMIT_OK.store(res.is_ok(), Ordering::Relaxed);
+
+    res
+}
+
+/// Puts originals back over already replaced dats, in reverse order
+fn rollback(swapped: &[(&PathBuf, bool)]) {
+    for (path, had_original) in swapped.iter().rev() {
+        if *had_original {
+            let _ = std::fs::rename(old_path(path), path);
+        } else {
+            let _ = std::fs::remove_file(path);
+        }
+    }
+}
+
+fn commit_batch() -> Result<(usize, Option<String>), String> {
+    let Some(batch) = WRITE_BATCH.lock().unwrap().take() else {
+        return Ok((0, None));
+    };
+
+    let discard = |staged: &[(PathBuf, PathBuf)]| {
+        for (tmp, _) in staged {
+            let _ = std::fs::remove_file(tmp);
+        }
+    };
+
+    if let Some(e) = batch.failed {
+        discard(&batch.staged);
+
+        return Err(format!("{e}. No dats were replaced"));
+    }
+
+    for (_, path) in &batch.staged {
+        if let Err(e) = backup_file(path) {
+            discard(&batch.staged);
+
+            return Err(format!(
+                "Can't back up {path:?}: {e}. No dats were replaced"
+            ));
+        }
+    }
+
+    // Originals are moved aside first, so a failed rename can put every one of them back
+    let mut swapped = vec![];
+
+    for (tmp, path) in &batch.staged {
+        let had_original = path.exists();
+
+        let res = if had_original {
+            std::fs::rename(path, old_path(path))
+        } else {
+            Ok(())
+        }
+        .and_then(|_| {
+            std::fs::rename(tmp, path).inspect_err(|_| {
+                if had_original {
+                    let _ = std::fs::rename(old_path(path), path);
+                }
+            })
+        });
+
+        if let Err(e) = res {
+            rollback(&swapped);
+            discard(&batch.staged);
+
+            return Err(format!(
+                "Can't replace {path:?}: {e}. No dats were replaced"
+            ));
+        }
+
+        swapped.push((path, had_original));
+    }
+
+    for (path, had_original) in swapped {
+        if had_original {
+            let _ = std::fs::remove_file(old_path(path));
+        }
+    }
+
+    // Dats are already replaced at this point, so the save itself has succeeded
+    let mut warning = None;
+
+    if let Some(output) = &batch.output
+        && output.copy_unchanged
+    {
+        let written: Vec<_> = batch.staged.iter().map(|(_, v)| v.clone()).collect();
+
+        if let Err(e) = output.copy_unchanged(&written) {
+            warning = Some(format!(
+                "Changed dats were written, but unchanged ones can't be copied to {:?}: {e}",
+                output.output_folder
+            ));
+        }
+    }
+
+    Ok((batch.staged.len(), warning))
+}
+
+fn sibling_path(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(suffix);
+
+    path.with_file_name(name)
+}
+
+fn temp_path(path: &Path) -> PathBuf {
+    sibling_path(path, ".tmp")
+}
+
+/// Original dat is kept here while batch is committed
+fn old_path(path: &Path) -> PathBuf {
+    sibling_path(path, ".old")
+}
+
+/// Leading table of a double array dat: element count and serialized size in bytes
+type TableInfo = (usize, usize);
+
+/// Reads written dat back and checks that it decodes into expected amount of records
+fn verify_dat(path: &Path, table: Option<TableInfo>, len: usize) -> std::io::Result<()> {
+    let invalid = |e: String| std::io::Error::new(ErrorKind::InvalidData, format!("{path:?}: {e}"));
+
+    let bytes = read_encoded_file(path).map_err(|_| invalid("can't be decoded".to_string()))?;
+
+    // Table count below is read from the first bytes, the trailer guarantees there are enough
+    if !bytes.ends_with(&SAFE_PACKAGE) {
+        return Err(invalid("is truncated".to_string()));
+    }
+
+    let mut reader = Cursor::new(&bytes);
+
+    // Record count of a double array follows its table, which has no fixed size
+    if let Some((table_len, table_size)) = table {
+        let count = INDEX::read_unreal(&mut reader).value() as usize;
+
+        if count != table_len {
+            return Err(invalid(format!(
+                "{count} table entries of {table_len} were written"
+            )));
+        }
+
+        reader.set_position(reader.position() + table_size as u64);
+    }
+
+    if bytes.len() < reader.position() as usize + 4 {
+        return Err(invalid("is truncated".to_string()));
+    }
+
+    let count = u32::read_unreal(&mut reader) as usize;
+
+    if count != len {
+        return Err(invalid(format!("{count} records of {len} were written")));
+    }
+
+    Ok(())
+}
+
+/// The only way dats are written. Dat goes to a temporary sibling, is read back and verified,
+/// and waits there for [`commit_write_batch`]. Without a batch it is committed at once
+pub fn write_dat<S: WriteUnreal + Debug, T: WriteUnreal + Debug>(
+    path: &Path,
+    data: DatVariant<S, T>,
+) -> std::io::Result<()> {
+    let target = match WRITE_BATCH.lock().unwrap().as_ref() {
+        Some(WriteBatch {
+            failed: Some(e), ..
+        }) => {
+            return Err(std::io::Error::other(format!(
+                "{path:?} skipped, batch failed: {e}"
+            )));
+        }
+        Some(WriteBatch {
+            output: Some(output),
+            ..
+        }) => output.map(path),
+        _ => path.to_path_buf(),
+    };
+
+    let path = target.as_path();
+
+    let (table, len) = match &data {
+        DatVariant::Array(v) => (None, v.len()),
+        DatVariant::DoubleArray(t, v) => {
+            let mut bytes = vec![];
+
+            for v in t {
+                v.write_unreal(&mut bytes)?;
+            }
+
+            (Some((t.len(), bytes.len())), v.len())
+        }
+    };
+
+    let tmp = temp_path(path);
+
+    let res = path
+        .parent()
+        .map_or(Ok(()), std::fs::create_dir_all)
+        .and_then(|_| save_dat(&tmp, data))
+        .and_then(|_| File::open(&tmp)?.sync_all())
+        .and_then(|_| verify_dat(&tmp, table, len));
+
+    let mut batch = WRITE_BATCH.lock().unwrap();
+
+    match (res, batch.as_mut()) {
+        (Err(e), batch) => {
+            let _ = std::fs::remove_file(&tmp);
+
+            if let Some(batch) = batch {
+                batch.failed.get_or_insert_with(|| e.to_string());
+            }
+
+            Err(e)
+        }
+
+        (Ok(_), Some(batch)) => {
+            batch.staged.push((tmp, path.to_path_buf()));
+
+            Ok(())
+        }
+
+        (Ok(_), None) => {
+            drop(batch);
+
+            backup_file(path)?;
+            std::fs::rename(&tmp, path)
+        }
+    }
+}
+
 pub trait GetId {
     fn get_id(&self) -> u32;
 }
@@ -160,3 +720,103 @@ impl StrUtils for str {
         deunicode::deunicode(self).replace('\'', "")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{DatOutput, discover_localizations, verify_dat};
+    use crate::backend::Localization;
+    use l2_rw::{DatVariant, save_dat};
+
+    #[test]
+    fn test_discover_localizations() {
+        let codes = |names: &[&str]| -> Vec<&str> {
+            discover_localizations(names.iter().copied())
+                .iter()
+                .map(|v| v.code())
+                .collect()
+        };
+
+        // Most used code goes first, the rest are sorted
+        assert_eq!(
+            vec!["eu", "ru", "tw"],
+            codes(&[
+                "itemname-ru.dat",
+                "itemname-eu.dat",
+                "itemname-tw.dat",
+                "npcname-eu.dat",
+                "npcname-ru.dat",
+                "skillname-eu.dat",
+            ])
+        );
+
+        // Tie goes to the default one
+        assert_eq!(
+            vec!["ru", "eu"],
+            codes(&["itemname-eu.dat", "itemname-ru.dat"])
+        );
+
+        // Not locale suffixes
+        assert_eq!(
+            vec!["eu"],
+            codes(&[
+                "l2gamedataname.dat",
+                "item-12.dat",
+                "npcgrp.dat",
+                "skill-grp_new.dat",
+                "itemname-eu.dat",
+            ])
+        );
+
+        assert_eq!(Vec::<Localization>::new(), discover_localizations([]));
+    }
+
+    #[test]
+    fn test_verify_dat() {
+        let folder = std::env::temp_dir().join(format!("l2w_verify_dat_{}", std::process::id()));
+        std::fs::create_dir_all(&folder).unwrap();
+
+        let array = folder.join("array.dat");
+        save_dat(&array, DatVariant::<(), u32>::Array(vec![1, 2, 3])).unwrap();
+
+        assert!(verify_dat(&array, None, 3).is_ok());
+        assert!(verify_dat(&array, None, 2).is_err());
+
+        // Table of two u32 is 8 bytes
+        let double = folder.join("double.dat");
+        save_dat(
+            &double,
+            DatVariant::<u32, u32>::DoubleArray(vec![7, 8], vec![1, 2, 3]),
+        )
+        .unwrap();
+
+        assert!(verify_dat(&double, Some((2, 8)), 3).is_ok());
+        assert!(verify_dat(&double, Some((2, 8)), 4).is_err());
+        assert!(verify_dat(&double, Some((3, 8)), 3).is_err());
+
+        let broken = folder.join("broken.dat");
+        std::fs::write(&broken, [0u8; 64]).unwrap();
+
+        assert!(verify_dat(&broken, None, 0).is_err());
+
+        std::fs::remove_dir_all(&folder).ok();
+    }
+
+    #[test]
+    fn test_folders_overlap() {
+        let system = std::env::temp_dir().join(format!("l2w_overlap_{}", std::process::id()));
+        let inner = system.join("out");
+        let other = std::env::temp_dir().join(format!("l2w_overlap_out_{}", std::process::id()));
+
+        std::fs::create_dir_all(&inner).unwrap();
+        std::fs::create_dir_all(&other).unwrap();
+
+        assert!(DatOutput::folders_overlap(&system, &system));
+        assert!(DatOutput::folders_overlap(&system, &inner));
+        assert!(DatOutput::folders_overlap(&inner, &system));
+        assert!(DatOutput::folders_overlap(&system, &inner.join("..")));
+        assert!(!DatOutput::folders_overlap(&system, &other));
+
+        std::fs::remove_dir_all(&system).ok();
+        std::fs::remove_dir_all(&other).ok();
+    }
+}
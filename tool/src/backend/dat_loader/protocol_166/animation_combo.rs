@@ -1,7 +1,8 @@
 use crate::backend::log_holder::Log;
 
+use crate::backend::dat_loader::{deserialize_dat, write_dat};
+use l2_rw::DatVariant;
 use l2_rw::ue2_rw::{ASCF, DWORD, INT};
-use l2_rw::{DatVariant, deserialize_dat, save_dat};
 
 use l2_rw::ue2_rw::{ReadUnreal, UnrealReader, UnrealWriter, WriteUnreal};
 
@@ -19,7 +20,7 @@ impl GameDataHolder {
             .values()
             .filter(|v| !v._deleted)
             .map(|v| AnimationComboDat {
-                name: self.game_string_table_ru.get_index(&v.name),
+                name: self.game_string_table.get_index(&v.name),
                 anim_0: (&v.anim_0).into(),
                 anim_1: (&v.anim_1).into(),
                 anim_2: (&v.anim_2).into(),
@@ -34,7 +35,7 @@ impl GameDataHolder {
             .clone();
 
         thread::spawn(move || {
-            if let Err(e) = save_dat(
+            if let Err(e) = write_dat(
                 dat_path.path(),
                 DatVariant::<(), AnimationComboDat>::Array(raid_grp.to_vec()),
             ) {
@@ -45,7 +46,7 @@ impl GameDataHolder {
         })
     }
 
-    pub fn load_animation_combo(&mut self) -> Result<Vec<Log>, ()> {
+    pub fn load_animation_combo(&mut self) -> Result<Vec<Log>, Log> {
         let warnings = vec![];
 
         let animation_combos = deserialize_dat::<AnimationComboDat>(
@@ -56,7 +57,7 @@ impl GameDataHolder {
         )?;
 
         for (i, v) in animation_combos.iter().enumerate() {
-            let name = self.game_string_table_ru.get_o(&v.name);
+            let name = self.game_string_table.get_o(&v.name);
 
             let id = AnimationComboId(i as u32);
             self.animation_combo_holder.insert(
@@ -0,0 +1,112 @@
+use crate::backend::log_holder::Log;
+
+use l2_rw::ue2_rw::{ASCF, DWORD, INT};
+use l2_rw::ue2_rw::{ReadUnreal, UnrealReader, UnrealWriter, WriteUnreal};
+
+use crate::backend::Localization;
+use crate::backend::dat_loader::{GetId, NOT_EXIST, save_localized_dats};
+use crate::backend::holder::{GameDataHolder, HolderMapOps};
+use crate::backend::util::Localized;
+use crate::common::{ClassId, PlayerClass};
+use crate::entity::class_info::ClassInfo;
+use r#macro::{ReadUnreal, WriteUnreal};
+use num_traits::ToPrimitive;
+use std::thread;
+use std::thread::JoinHandle;
+use strum::IntoEnumIterator;
+
+impl GameDataHolder {
+    fn class_info_as_dat_vec(&self, localization: Localization) -> Vec<ClassInfoDat> {
+        let mut res: Vec<_> = self
+            .class_info_holder
+            .values()
+            .filter(|v| !v._deleted)
+            .map(|v| ClassInfoDat {
+                id: v.id.0,
+                name: (&v.name[localization]).into(),
+                parent_id: v.parent.map_or(-1, |p| p.0 as INT),
+            })
+            .collect();
+
+        res.sort_by_key(|v| v.id);
+
+        res
+    }
+
+    pub fn serialize_class_info_to_binary(&mut self) -> JoinHandle<Vec<Log>> {
+        let dats: Vec<_> = self
+            .localized_dat_paths("classinfo")
+            .into_iter()
+            .map(|(l, path)| (l, path, self.class_info_as_dat_vec(l)))
+            .collect();
+
+        if dats.is_empty() {
+            return thread::spawn(|| {
+                vec![Log::from_loader_e(
+                    "classinfo dat not found in system folder, class info not saved",
+                )]
+            });
+        }
+
+        save_localized_dats(dats, "Class Info")
+    }
+
+    pub fn load_class_infos(&mut self) -> Result<Vec<Log>, Log> {
+        if self.localized_dat_paths("classinfo").is_empty() {
+            for class in PlayerClass::iter() {
+                let id = ClassId(class.to_u32().unwrap());
+
+                self.class_info_holder.insert(
+                    id,
+                    ClassInfo {
+                        id,
+                        name: Localized::from_fn_in(self.localizations(), |_| class.to_string()),
+                        parent: None,
+
+                        _changed: false,
+                        _deleted: false,
+                    },
+                );
+            }
+
+            return Ok(vec![Log::from_loader_i(
+                "classinfo dat not found, using built-in class list",
+            )]);
+        }
+
+        let class_infos = self.load_localized_dat::<ClassInfoDat>("classinfo")?;
+
+        for v in &class_infos.main {
+            self.class_info_holder.insert(
+                v.id.into(),
+                ClassInfo {
+                    id: v.id.into(),
+                    name: class_infos.localize(v, |v| v.name.to_string(), NOT_EXIST.to_string()),
+                    parent: if v.parent_id < 0 {
+                        None
+                    } else {
+                        Some(ClassId(v.parent_id as u32))
+                    },
+
+                    _changed: false,
+                    _deleted: false,
+                },
+            );
+        }
+
+        Ok(vec![])
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, ReadUnreal, WriteUnreal)]
+struct ClassInfoDat {
+    id: DWORD,
+    name: ASCF,
+    parent_id: INT,
+}
+
+impl GetId for ClassInfoDat {
+    fn get_id(&self) -> DWORD {
+        self.id
+    }
+}
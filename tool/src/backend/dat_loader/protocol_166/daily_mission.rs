@@ -1,22 +1,19 @@
 use crate::backend::log_holder::Log;
-use std::collections::HashMap;
 use std::io::{Read, Write};
 
 use l2_rw::ue2_rw::{ASCF, DWORD};
-use l2_rw::{DatVariant, deserialize_dat, save_dat};
 
 use l2_rw::ue2_rw::{ReadUnreal, UnrealReader, UnrealWriter, WriteUnreal};
 
 use crate::backend::Localization;
-use crate::backend::dat_loader::{GetId, wrap_into_id_map};
+use crate::backend::dat_loader::{GetId, NOT_EXIST, save_localized_dats};
 use crate::backend::holder::{GameDataHolder, HolderMapOps};
-use crate::common::PlayerClass;
+use crate::common::ClassId;
 use crate::entity::daily_mission::{
     DailyMission, DailyMissionRepeatType, DailyMissionReward, DailyMissionUnk7,
 };
 use r#macro::{ReadUnreal, WriteUnreal};
 use num_traits::{FromPrimitive, ToPrimitive};
-use std::thread;
 use std::thread::JoinHandle;
 
 impl GameDataHolder {
@@ -33,7 +30,7 @@ impl GameDataHolder {
                     reward_desc: ASCF::from(&v.desc[localization]),
                     reward_period: ASCF::from(&v.category[localization]),
                     allowed_classes: if let Some(c) = &v.allowed_classes {
-                        c.iter().map(|v| v.to_u32().unwrap()).collect()
+                        c.iter().map(|v| v.0).collect()
                     } else {
                         vec![u32::MAX]
                     },
@@ -70,97 +67,46 @@ impl GameDataHolder {
     }
 
     pub fn serialize_daily_missions_to_binary(&mut self) -> JoinHandle<Vec<Log>> {
-        let onedayrewards_ru: Vec<OneDayRewardDat> = self.to_dat(Localization::RU);
+        let dats = self
+            .localized_dat_paths("onedayreward")
+            .into_iter()
+            .map(|(l, path)| (l, path, self.to_dat(l)))
+            .collect();
 
-        let dat_path_ru = self
-            .dat_paths
-            .get(&"onedayreward-ru.dat".to_string())
-            .unwrap()
-            .clone();
-
-        let dat_path_eu = self.dat_paths.get(&"onedayreward-eu.dat".to_string());
-
-        let eu = dat_path_eu.map(|v| (v.clone(), self.to_dat(Localization::EU)));
-
-        thread::spawn(move || {
-            let mut log = vec![if let Err(e) = save_dat(
-                dat_path_ru.path(),
-                DatVariant::<(), OneDayRewardDat>::Array(onedayrewards_ru.to_vec()),
-            ) {
-                Log::from_loader_e(e)
-            } else {
-                Log::from_loader_i("DailyMissions RU saved")
-            }];
-
-            if let Some((dir, vec)) = eu {
-                if let Err(e) = save_dat(dir.path(), DatVariant::<(), OneDayRewardDat>::Array(vec))
-                {
-                    log.push(Log::from_loader_e(e))
-                } else {
-                    log.push(Log::from_loader_i("DailyMissions EU saved"))
-                };
-            };
-
-            log
-        })
+        save_localized_dats(dats, "DailyMissions")
     }
 
-    pub fn load_daily_missions(&mut self) -> Result<Vec<Log>, ()> {
-        let one_day_rewards = deserialize_dat::<OneDayRewardDat>(
-            self.dat_paths
-                .get(&"onedayreward-ru.dat".to_string())
-                .unwrap()
-                .path(),
-        )?;
-
-        let one_day_rewards_eu =
-            if let Some(v) = self.dat_paths.get(&"onedayreward-eu.dat".to_string()) {
-                wrap_into_id_map(deserialize_dat::<OneDayRewardDat>(v.path())?)
-            } else {
-                HashMap::new()
-            };
-
-        for v in one_day_rewards {
-            let eu = one_day_rewards_eu.get(&v.base.id);
+    pub fn load_daily_missions(&mut self) -> Result<Vec<Log>, Log> {
+        let one_day_rewards = self.load_localized_dat::<OneDayRewardDat>("onedayreward")?;
 
+        for v in &one_day_rewards.main {
             self.daily_mission_holder.insert(
                 v.base.id.into(),
                 DailyMission {
                     id: v.base.id.into(),
                     reward_id: v.base.reward_id,
-                    name: (
-                        v.base.reward_name.to_string(),
-                        eu.map(|e| e.base.reward_name.to_string())
-                            .unwrap_or("NOT_EXIST".to_string()),
-                    )
-                        .into(),
-                    desc: (
-                        v.base.reward_desc.to_string(),
-                        eu.map(|e| e.base.reward_desc.to_string())
-                            .unwrap_or("NOT_EXIST".to_string()),
-                    )
-                        .into(),
-                    category: (
-                        v.base.reward_period.to_string(),
-                        eu.map(|e| e.base.reward_period.to_string())
-                            .unwrap_or("NOT_EXIST".to_string()),
-                    )
-                        .into(),
+                    name: one_day_rewards.localize(
+                        v,
+                        |v| v.base.reward_name.to_string(),
+                        NOT_EXIST.to_string(),
+                    ),
+                    desc: one_day_rewards.localize(
+                        v,
+                        |v| v.base.reward_desc.to_string(),
+                        NOT_EXIST.to_string(),
+                    ),
+                    category: one_day_rewards.localize(
+                        v,
+                        |v| v.base.reward_period.to_string(),
+                        NOT_EXIST.to_string(),
+                    ),
                     category_type: v.base.category,
                     allowed_classes: if v.base.allowed_classes.is_empty()
                         || v.base.allowed_classes[0] == u32::MAX
                     {
                         None
                     } else {
-                        Some(
-                            v.base
-                                .allowed_classes
-                                .iter()
-                                .map(|c| {
-                                    PlayerClass::from_u32(*c).unwrap_or_else(|| panic!("!!UNK {c}"))
-                                })
-                                .collect(),
-                        )
+                        Some(v.base.allowed_classes.iter().map(|c| ClassId(*c)).collect())
                     },
                     repeat_type: DailyMissionRepeatType::from_u32(v.base.repeat_type).unwrap(),
                     unk2: v.base.unk2,
@@ -178,7 +124,7 @@ impl GameDataHolder {
                             unk4: c.unk4,
                         })
                         .collect(),
-                    unk8: v.base.unk8,
+                    unk8: v.base.unk8.clone(),
                     rewards: v
                         .rewards
                         .iter()
@@ -0,0 +1,101 @@
+use crate::backend::log_holder::Log;
+
+use l2_rw::ue2_rw::{ASCF, DWORD};
+use l2_rw::ue2_rw::{ReadUnreal, UnrealReader, UnrealWriter, WriteUnreal};
+
+use crate::backend::Localization;
+use crate::backend::dat_loader::{GetId, NOT_EXIST, save_localized_dats};
+use crate::backend::holder::{DictItem, GameDataHolder, HolderMapOps};
+use crate::backend::util::Localized;
+use crate::entity::game_tip::GameTip;
+use r#macro::{ReadUnreal, WriteUnreal};
+use std::thread::JoinHandle;
+
+impl GameDataHolder {
+    fn game_tips_as_dat_vec(&self, localization: Localization) -> Vec<GameTipDat> {
+        let mut res: Vec<_> = self
+            .game_tips
+            .values()
+            .map(|v| {
+                let tip = &v.item[localization];
+
+                GameTipDat {
+                    id: v.id,
+                    min_lvl: tip.min_lvl,
+                    max_lvl: tip.max_lvl,
+                    condition: tip.condition,
+                    text: (&tip.text).into(),
+                }
+            })
+            .collect();
+
+        res.sort_by_key(|v| v.id);
+
+        res
+    }
+
+    pub fn serialize_game_tips_to_binary(&mut self) -> JoinHandle<Vec<Log>> {
+        let dats = self
+            .localized_dat_paths("gametip")
+            .into_iter()
+            .map(|(l, path)| (l, path, self.game_tips_as_dat_vec(l)))
+            .collect();
+
+        save_localized_dats(dats, "Game Tips")
+    }
+
+    pub fn load_game_tips(&mut self) -> Result<Vec<Log>, Log> {
+        if let Some(log) = self.missing_optional_dat("gametip") {
+            return Ok(vec![log]);
+        }
+
+        let tips = self.load_localized_dat::<GameTipDat>("gametip")?;
+
+        for v in &tips.main {
+            let item = Localized::from_fn_in(tips.localizations.iter().copied(), |l| {
+                if l == tips.localizations[0] {
+                    return v.into();
+                }
+
+                if let Some(tip) = tips.get(l, v.id) {
+                    tip.into()
+                } else {
+                    GameTip {
+                        text: NOT_EXIST.to_string(),
+                        ..v.into()
+                    }
+                }
+            });
+
+            self.game_tips.insert(v.id, DictItem::new(v.id, item));
+        }
+
+        Ok(vec![])
+    }
+}
+
+impl From<&GameTipDat> for GameTip {
+    fn from(value: &GameTipDat) -> Self {
+        GameTip {
+            text: value.text.to_string(),
+            min_lvl: value.min_lvl,
+            max_lvl: value.max_lvl,
+            condition: value.condition,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, ReadUnreal, WriteUnreal)]
+struct GameTipDat {
+    id: DWORD,
+    min_lvl: DWORD,
+    max_lvl: DWORD,
+    condition: DWORD,
+    text: ASCF,
+}
+
+impl GetId for GameTipDat {
+    fn get_id(&self) -> DWORD {
+        self.id
+    }
+}
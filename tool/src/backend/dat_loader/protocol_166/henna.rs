@@ -0,0 +1,135 @@
+use crate::backend::log_holder::Log;
+
+use l2_rw::ue2_rw::{ASCF, DWORD, INT};
+use l2_rw::ue2_rw::{ReadUnreal, UnrealReader, UnrealWriter, WriteUnreal};
+
+use crate::backend::Localization;
+use crate::backend::dat_loader::{GetId, NOT_EXIST, save_localized_dats};
+use crate::backend::holder::{GameDataHolder, HolderMapOps};
+use crate::common::{ClassId, ItemId};
+use crate::entity::henna::{Henna, HennaStats};
+use r#macro::{ReadUnreal, WriteUnreal};
+use std::thread::JoinHandle;
+
+impl GameDataHolder {
+    fn henna_as_dat_vec(&mut self, localization: Localization) -> Vec<HennaGrpDat> {
+        self.henna_holder
+            .values()
+            .filter(|v| !v._deleted)
+            .map(|v| HennaGrpDat {
+                dye_id: v.id.0,
+                dye_item_id: v.dye_item_id.0,
+                name: (&v.name[localization]).into(),
+                additional_name: (&v.additional_name[localization]).into(),
+                desc: (&v.desc[localization]).into(),
+                icon: self.game_string_table.get_index(&v.icon),
+                icon_panel: self.game_string_table.get_index(&v.icon_panel),
+                str: v.stats.str,
+                dex: v.stats.dex,
+                con: v.stats.con,
+                int: v.stats.int,
+                men: v.stats.men,
+                wit: v.stats.wit,
+                class_list: if let Some(c) = &v.allowed_classes {
+                    c.iter().map(|v| v.0).collect()
+                } else {
+                    vec![]
+                },
+            })
+            .collect()
+    }
+
+    pub fn serialize_henna_to_binary(&mut self) -> JoinHandle<Vec<Log>> {
+        let dats = self
+            .localized_dat_paths("hennagrp")
+            .into_iter()
+            .map(|(l, path)| (l, path, self.henna_as_dat_vec(l)))
+            .collect();
+
+        save_localized_dats(dats, "Hennas")
+    }
+
+    pub fn load_hennas(&mut self) -> Result<Vec<Log>, Log> {
+        if let Some(log) = self.missing_optional_dat("hennagrp") {
+            return Ok(vec![log]);
+        }
+
+        let hennas = self.load_localized_dat::<HennaGrpDat>("hennagrp")?;
+
+        let mut logs = vec![];
+
+        for v in &hennas.main {
+            let mut allowed_classes = vec![];
+
+            for c in &v.class_list {
+                if self.class_info_holder.get(&ClassId(*c)).is_none() {
+                    logs.push(Log::from_loader_e(&format!(
+                        "Henna[{}]: unknown class id {c}",
+                        v.dye_id
+                    )));
+                }
+
+                allowed_classes.push(ClassId(*c));
+            }
+
+            self.henna_holder.insert(
+                v.dye_id.into(),
+                Henna {
+                    id: v.dye_id.into(),
+                    dye_item_id: ItemId(v.dye_item_id),
+                    name: hennas.localize(v, |v| v.name.to_string(), NOT_EXIST.to_string()),
+                    additional_name: hennas.localize(
+                        v,
+                        |v| v.additional_name.to_string(),
+                        NOT_EXIST.to_string(),
+                    ),
+                    desc: hennas.localize(v, |v| v.desc.to_string(), NOT_EXIST.to_string()),
+                    icon: self.game_string_table.get_o(&v.icon),
+                    icon_panel: self.game_string_table.get_o(&v.icon_panel),
+                    stats: HennaStats {
+                        str: v.str,
+                        dex: v.dex,
+                        con: v.con,
+                        int: v.int,
+                        men: v.men,
+                        wit: v.wit,
+                    },
+                    allowed_classes: if allowed_classes.is_empty() {
+                        None
+                    } else {
+                        Some(allowed_classes)
+                    },
+
+                    _changed: false,
+                    _deleted: false,
+                },
+            );
+        }
+
+        Ok(logs)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, ReadUnreal, WriteUnreal)]
+struct HennaGrpDat {
+    dye_id: DWORD,
+    dye_item_id: DWORD,
+    name: ASCF,
+    additional_name: ASCF,
+    desc: ASCF,
+    icon: DWORD,
+    icon_panel: DWORD,
+    str: INT,
+    dex: INT,
+    con: INT,
+    int: INT,
+    men: INT,
+    wit: INT,
+    class_list: Vec<DWORD>,
+}
+
+impl GetId for HennaGrpDat {
+    fn get_id(&self) -> DWORD {
+        self.dye_id
+    }
+}
@@ -1,14 +1,14 @@
 use crate::backend::editor::WindowParams;
-use std::collections::HashMap;
 
 use l2_rw::ue2_rw::{ASCF, DWORD, INT, SHORT, USHORT};
-use l2_rw::{DatVariant, deserialize_dat, save_dat};
+use crate::backend::dat_loader::deserialize_dat;
+use l2_rw::DatVariant;
 
 use l2_rw::ue2_rw::{ReadUnreal, UnrealReader, UnrealWriter, WriteUnreal};
 
 use crate::backend::Localization;
 use crate::backend::dat_loader::protocol_166::CoordsXYZ;
-use crate::backend::dat_loader::{GetId, wrap_into_id_map};
+use crate::backend::dat_loader::{GetId, NOT_EXIST, save_localized_dats, write_dat};
 use crate::backend::holder::{GameDataHolder, HolderMapOps};
 use crate::backend::log_holder::{Log, LogLevel};
 use crate::common::QuestId;
@@ -47,10 +47,10 @@ impl GameDataHolder {
 
                 map_objects.push(MiniMapRegionDat {
                     hunting_zone_second_id: zone.second_id,
-                    icon_texture_normal: self.game_string_table_ru.get_index(&item.icon_texture),
-                    icon_texture_over: self.game_string_table_ru.get_index(&item.icon_texture_over),
+                    icon_texture_normal: self.game_string_table.get_index(&item.icon_texture),
+                    icon_texture_over: self.game_string_table.get_index(&item.icon_texture_over),
                     icon_texture_pushed: self
-                        .game_string_table_ru
+                        .game_string_table
                         .get_index(&item.icon_texture_pressed),
                     world_loc_x: item.world_pos[0],
                     world_loc_y: item.world_pos[1],
@@ -58,38 +58,27 @@ impl GameDataHolder {
                     height: item.size[1],
                     desc_offset_x: item.desc_offset[0],
                     desc_offset_y: item.desc_offset[1],
-                    desc_font_name: self.game_string_table_ru.get_index(&item.desc_font_name),
+                    desc_font_name: self.game_string_table.get_index(&item.desc_font_name),
                     unk: item.unk1.clone(),
                 })
             }
         }
 
-        let hunting_zone_ru = self
-            .hunting_zone_holder
-            .values()
-            .filter(|v| !v._deleted)
-            .map(|v| HuntingZoneDat::from_entity(v, Localization::RU))
-            .collect();
-
-        let huntingzone_path_ru = self
-            .dat_paths
-            .get(&"huntingzone-ru.dat".to_string())
-            .unwrap()
-            .clone();
-
-        let eu =
-            if let Some(huntingzone_path) = self.dat_paths.get(&"huntingzone-eu.dat".to_string()) {
-                Some((
+        let dats = self
+            .localized_dat_paths("huntingzone")
+            .into_iter()
+            .map(|(l, path)| {
+                (
+                    l,
+                    path,
                     self.hunting_zone_holder
                         .values()
                         .filter(|v| !v._deleted)
-                        .map(|v| HuntingZoneDat::from_entity(v, Localization::EU))
+                        .map(|v| HuntingZoneDat::from_entity(v, l))
                         .collect::<Vec<HuntingZoneDat>>(),
-                    huntingzone_path.clone(),
-                ))
-            } else {
-                None
-            };
+                )
+            })
+            .collect();
 
         let minimapregion_path = self
             .dat_paths
@@ -97,10 +86,12 @@ impl GameDataHolder {
             .unwrap()
             .clone();
 
+        let zones_handle = save_localized_dats(dats, "Hunting Zone");
+
         thread::spawn(move || {
             let mut logs = vec![];
 
-            if let Err(e) = save_dat(
+            if let Err(e) = write_dat(
                 minimapregion_path.path(),
                 DatVariant::<(), MiniMapRegionDat>::Array(map_objects.to_vec()),
             ) {
@@ -109,44 +100,16 @@ impl GameDataHolder {
                 logs.push(Log::from_loader_i("Mini Map Region saved"));
             }
 
-            if let Err(e) = save_dat(
-                huntingzone_path_ru.path(),
-                DatVariant::<(), HuntingZoneDat>::Array(hunting_zone_ru),
-            ) {
-                logs.push(Log::from_loader_e(e));
-            } else {
-                logs.push(Log::from_loader_i("Hunting Zone RU saved"));
-            }
-
-            if let Some((dats, dir)) = eu {
-                if let Err(e) = save_dat(dir.path(), DatVariant::<(), HuntingZoneDat>::Array(dats))
-                {
-                    logs.push(Log::from_loader_e(e));
-                } else {
-                    logs.push(Log::from_loader_i("Hunting Zone EU saved"));
-                }
-            }
+            logs.extend(zones_handle.join().unwrap());
 
             logs
         })
     }
 
-    pub fn load_hunting_zones(&mut self) -> Result<Vec<Log>, ()> {
+    pub fn load_hunting_zones(&mut self) -> Result<Vec<Log>, Log> {
         let mut warnings = vec![];
 
-        let hunting_zone_ru = deserialize_dat::<HuntingZoneDat>(
-            self.dat_paths
-                .get(&"huntingzone-ru.dat".to_string())
-                .unwrap()
-                .path(),
-        )?;
-
-        let hunting_zone_eu =
-            if let Some(eu_path) = self.dat_paths.get(&"huntingzone-eu.dat".to_string()) {
-                wrap_into_id_map(deserialize_dat::<HuntingZoneDat>(eu_path.path())?)
-            } else {
-                HashMap::new()
-            };
+        let hunting_zones = self.load_localized_dat::<HuntingZoneDat>("huntingzone")?;
 
         let map_objects = deserialize_dat::<MiniMapRegionDat>(
             self.dat_paths
@@ -155,25 +118,13 @@ impl GameDataHolder {
                 .path(),
         )?;
 
-        for v in hunting_zone_ru {
+        for v in &hunting_zones.main {
             self.hunting_zone_holder.insert(
                 v.id.into(),
                 HuntingZone {
                     id: v.id.into(),
-                    name: (
-                        v.name.to_string(),
-                        hunting_zone_eu
-                            .get(&v.id)
-                            .map_or("NOT_EXIST".to_string(), |v| v.name.to_string()),
-                    )
-                        .into(),
-                    desc: (
-                        v.desc.to_string(),
-                        hunting_zone_eu
-                            .get(&v.id)
-                            .map_or("NOT_EXIST".to_string(), |v| v.desc.to_string()),
-                    )
-                        .into(),
+                    name: hunting_zones.localize(v, |v| v.name.to_string(), NOT_EXIST.to_string()),
+                    desc: hunting_zones.localize(v, |v| v.desc.to_string(), NOT_EXIST.to_string()),
                     zone_type: HuntingZoneType::from_u32(v.zone_type)
                         .unwrap_or_else(|| panic!("unknown type: {}", v.zone_type)),
                     lvl_min: v.recommended_level_min,
@@ -197,13 +148,13 @@ impl GameDataHolder {
                 .find(|z| z.second_id == v.hunting_zone_second_id)
             {
                 c.world_map_objects.push(WindowParams::new(MapObject {
-                    icon_texture: self.game_string_table_ru.get_o(&v.icon_texture_normal),
-                    icon_texture_over: self.game_string_table_ru.get_o(&v.icon_texture_over),
-                    icon_texture_pressed: self.game_string_table_ru.get_o(&v.icon_texture_pushed),
+                    icon_texture: self.game_string_table.get_o(&v.icon_texture_normal),
+                    icon_texture_over: self.game_string_table.get_o(&v.icon_texture_over),
+                    icon_texture_pressed: self.game_string_table.get_o(&v.icon_texture_pushed),
                     world_pos: [v.world_loc_x, v.world_loc_y],
                     size: [v.width, v.height],
                     desc_offset: [v.desc_offset_x, v.desc_offset_y],
-                    desc_font_name: self.game_string_table_ru.get_o(&v.desc_font_name),
+                    desc_font_name: self.game_string_table.get_o(&v.desc_font_name),
                     unk1: v.unk,
                 }));
             } else {
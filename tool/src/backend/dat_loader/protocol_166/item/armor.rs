@@ -1,9 +1,9 @@
 use crate::backend::Localization;
-use crate::backend::dat_loader::GetId;
 use crate::backend::dat_loader::protocol_166::L2GeneralStringTable;
 use crate::backend::dat_loader::protocol_166::item::{
     AdditionalItemGrpDat, DropDatInfo, ItemBaseInfoDat, ItemNameDat, ItemStatDataDat,
 };
+use crate::backend::dat_loader::{GetId, LocalizedDat, NOT_EXIST, deserialize_dat, write_dat};
 use crate::backend::editor::WindowParams;
 use crate::backend::holder::{GameDataHolder, HolderMapOps};
 use crate::backend::log_holder::{Log, LogLevel};
@@ -16,14 +16,15 @@ use crate::entity::item::{
     ItemBaseInfo, ItemBattleStats, ItemDefaultAction, ItemDropInfo, ItemDropMeshInfo, ItemIcons,
     ItemMaterial, ItemNameColor, ItemQuality, KeepType,
 };
+use l2_rw::DatVariant;
 use l2_rw::ue2_rw::{BYTE, DWORD, MTX, MTX3, SHORT, USHORT, UVEC};
 use l2_rw::ue2_rw::{ReadUnreal, UnrealReader, UnrealWriter, WriteUnreal};
-use l2_rw::{DatVariant, deserialize_dat, save_dat};
 use r#macro::{ReadUnreal, WriteUnreal};
 use num_traits::{FromPrimitive, ToPrimitive};
 use std::collections::HashMap;
 use std::thread;
 use std::thread::JoinHandle;
+use walkdir::DirEntry;
 
 impl From<(&Armor, &mut L2GeneralStringTable, Localization)> for ItemNameDat {
     fn from(value: (&Armor, &mut L2GeneralStringTable, Localization)) -> Self {
@@ -257,7 +258,7 @@ impl GameDataHolder {
         let mut items: Vec<ArmorGrpDat> = vec![];
 
         for v in self.armor_holder.values().filter(|v| !v._deleted) {
-            items.push((v, &mut self.game_string_table_ru).into())
+            items.push((v, &mut self.game_string_table).into())
         }
 
         let armor_grp_path = self
@@ -267,7 +268,7 @@ impl GameDataHolder {
             .clone();
 
         thread::spawn(move || {
-            if let Err(e) = save_dat(
+            if let Err(e) = write_dat(
                 armor_grp_path.path(),
                 DatVariant::<(), ArmorGrpDat>::Array(items),
             ) {
@@ -283,15 +284,21 @@ impl GameDataHolder {
         additional_item_grp: &mut Vec<AdditionalItemGrpDat>,
         item_stat: &mut Vec<ItemStatDataDat>,
         item_base_info: &mut Vec<ItemBaseInfoDat>,
-        item_name_ru: &mut Vec<ItemNameDat>,
-        item_name_eu: &mut Vec<ItemNameDat>,
+        item_names: &mut [(Localization, DirEntry, Vec<ItemNameDat>)],
     ) {
         for v in self.armor_holder.values() {
-            additional_item_grp.push((v, &mut self.game_string_table_ru).into());
-            item_stat.push((v, &mut self.game_string_table_ru).into());
-            item_base_info.push((v, &mut self.game_string_table_ru).into());
-            item_name_ru.push((v, &mut self.game_string_table_ru, Localization::RU).into());
-            item_name_eu.push((v, &mut self.game_string_table_eu, Localization::EU).into());
+            additional_item_grp.push((v, &mut self.game_string_table).into());
+            item_stat.push((v, &mut self.game_string_table).into());
+            item_base_info.push((v, &mut self.game_string_table).into());
+
+            for (l, _, names) in item_names.iter_mut() {
+                let table = match self.localized_game_string_tables.get_mut(l) {
+                    Some(v) => v,
+                    None => &mut self.game_string_table,
+                };
+
+                names.push((v, table, *l).into());
+            }
         }
     }
 
@@ -300,9 +307,9 @@ impl GameDataHolder {
         additional_item_grp: &HashMap<u32, AdditionalItemGrpDat>,
         item_stat: &HashMap<u32, ItemStatDataDat>,
         item_base_info: &HashMap<u32, ItemBaseInfoDat>,
-        item_name_ru: &HashMap<u32, ItemNameDat>,
-        item_name_eu: &HashMap<u32, ItemNameDat>,
-    ) -> Result<Vec<Log>, ()> {
+        item_name: &HashMap<u32, ItemNameDat>,
+        item_names: &LocalizedDat<ItemNameDat>,
+    ) -> Result<Vec<Log>, Log> {
         let armor_grp = deserialize_dat::<ArmorGrpDat>(
             self.dat_paths
                 .get(&"armorgrp.dat".to_string())
@@ -315,10 +322,8 @@ impl GameDataHolder {
         let additional_default = AdditionalItemGrpDat::default();
         let mut warnings = vec![];
 
-        let not_existing_name_eu = ItemNameDat::not_existing();
-
         for item in armor_grp {
-            let Some(name_grp_ru) = item_name_ru.get(&item.id) else {
+            let Some(name_grp) = item_name.get(&item.id) else {
                 warnings.push(Log {
                     level: LogLevel::Error,
                     producer: "Armor Loader".to_string(),
@@ -328,8 +333,6 @@ impl GameDataHolder {
                 continue;
             };
 
-            let name_grp_eu = item_name_eu.get(&item.id).unwrap_or(&not_existing_name_eu);
-
             let base_info_grp = item_base_info.get(&item.id).unwrap_or(&base_info_default);
             let add_info_grp = additional_item_grp
                 .get(&item.id)
@@ -339,12 +342,12 @@ impl GameDataHolder {
             let mut drop_mesh_info = vec![];
             for v in &item.drop_info {
                 drop_mesh_info.push(ItemDropMeshInfo {
-                    mesh: self.game_string_table_ru.get_o(&v.mesh),
+                    mesh: self.game_string_table.get_o(&v.mesh),
                     textures: v
                         .texture
                         .inner
                         .iter()
-                        .map(|vv| self.game_string_table_ru.get_o(vv))
+                        .map(|vv| self.game_string_table.get_o(vv))
                         .collect(),
                 })
             }
@@ -356,9 +359,9 @@ impl GameDataHolder {
                 drop_height: item.drop_height,
                 drop_mesh_info,
                 complete_item_drop_sound: self
-                    .game_string_table_ru
+                    .game_string_table
                     .get_o(&item.complete_item_drop_sound),
-                drop_sound: self.game_string_table_ru.get_o(&item.drop_sound),
+                drop_sound: self.game_string_table.get_o(&item.drop_sound),
             };
 
             self.armor_holder.insert(
@@ -366,36 +369,32 @@ impl GameDataHolder {
                 Armor {
                     base_info: ItemBaseInfo {
                         id: item.id.into(),
-                        name: (
-                            self.game_string_table_ru.get_o(&name_grp_ru.name_link),
-                            self.game_string_table_eu.get_o(&name_grp_eu.name_link),
-                        )
-                            .into(),
-                        additional_name: (
-                            name_grp_ru.additional_name.to_string(),
-                            name_grp_eu.additional_name.to_string(),
-                        )
-                            .into(),
-                        popup: name_grp_ru.popup,
-                        default_action: ItemDefaultAction::from_ascf(&name_grp_ru.default_action),
-                        use_order: name_grp_ru.use_order,
-                        set_id: name_grp_ru.set_id.into(),
-                        color: ItemNameColor::from_u8(name_grp_ru.color).unwrap(),
+                        name: self.localized_item_name(item_names, name_grp),
+                        additional_name: item_names.localize(
+                            name_grp,
+                            |v| v.additional_name.to_string(),
+                            NOT_EXIST.to_string(),
+                        ),
+                        popup: name_grp.popup,
+                        default_action: ItemDefaultAction::from_ascf(&name_grp.default_action),
+                        use_order: name_grp.use_order,
+                        set_id: name_grp.set_id.into(),
+                        color: ItemNameColor::from_u8(name_grp.color).unwrap(),
                         tooltip_texture: self
-                            .game_string_table_ru
-                            .get_o(&name_grp_ru.tooltip_texture_link),
-                        is_trade: name_grp_ru.is_trade == 1,
-                        is_drop: name_grp_ru.is_drop == 1,
-                        is_destruct: name_grp_ru.is_destruct == 1,
-                        is_private_store: name_grp_ru.is_private_store == 1,
-                        is_npc_trade: name_grp_ru.is_npc_trade == 1,
-                        is_commission_store: name_grp_ru.is_commission_store == 1,
-                        keep_type: KeepType::from_u8(name_grp_ru.keep_type).unwrap(),
-                        desc: (
-                            name_grp_ru.description.to_string(),
-                            name_grp_eu.description.to_string(),
-                        )
-                            .into(),
+                            .game_string_table
+                            .get_o(&name_grp.tooltip_texture_link),
+                        is_trade: name_grp.is_trade == 1,
+                        is_drop: name_grp.is_drop == 1,
+                        is_destruct: name_grp.is_destruct == 1,
+                        is_private_store: name_grp.is_private_store == 1,
+                        is_npc_trade: name_grp.is_npc_trade == 1,
+                        is_commission_store: name_grp.is_commission_store == 1,
+                        keep_type: KeepType::from_u8(name_grp.keep_type).unwrap(),
+                        desc: item_names.localize(
+                            name_grp,
+                            |v| v.description.to_string(),
+                            NOT_EXIST.to_string(),
+                        ),
                         inventory_type: InventoryType::from_u8(item.inventory_type).unwrap(),
                         material: ItemMaterial::from_u8(item.material_type).unwrap(),
                         body_part: BodyPart::from_u8(item.body_part).unwrap(),
@@ -405,12 +404,12 @@ impl GameDataHolder {
                         durability: item.durability,
                         weight: item.weight,
                         icons: WindowParams::new(ItemIcons {
-                            icon_1: self.game_string_table_ru.get_o(&item.icon_1),
-                            icon_2: self.game_string_table_ru.get_o(&item.icon_2),
-                            icon_3: self.game_string_table_ru.get_o(&item.icon_3),
-                            icon_4: self.game_string_table_ru.get_o(&item.icon_4),
-                            icon_5: self.game_string_table_ru.get_o(&item.icon_5),
-                            icon_panel: self.game_string_table_ru.get_o(&item.icon_panel),
+                            icon_1: self.game_string_table.get_o(&item.icon_1),
+                            icon_2: self.game_string_table.get_o(&item.icon_2),
+                            icon_3: self.game_string_table.get_o(&item.icon_3),
+                            icon_4: self.game_string_table.get_o(&item.icon_4),
+                            icon_5: self.game_string_table.get_o(&item.icon_5),
+                            icon_panel: self.game_string_table.get_o(&item.icon_panel),
                         }),
                         default_price: base_info_grp.default_price,
                         is_premium: base_info_grp.is_premium == 1,
@@ -422,7 +421,7 @@ impl GameDataHolder {
                             .iter()
                             .map(|v| (*v).into())
                             .collect(),
-                        equip_sound: self.game_string_table_ru.get_o(&item.equip_sound),
+                        equip_sound: self.game_string_table.get_o(&item.equip_sound),
                         additional_info: WindowParams::new(ItemAdditionalInfo {
                             has_animation: add_info_grp.has_ani == 1,
                             include_items: add_info_grp
@@ -431,7 +430,7 @@ impl GameDataHolder {
                                 .map(|v| (*v).into())
                                 .collect(),
                             max_energy: add_info_grp.max_energy,
-                            look_change: self.game_string_table_ru.get_o(&add_info_grp.look_change),
+                            look_change: self.game_string_table.get_o(&add_info_grp.look_change),
                             hide_cloak: add_info_grp.hide_cloak == 1,
                             unk: add_info_grp.unk1 == 1,
                             hide_armor: add_info_grp.hide_armor == 1,
@@ -456,12 +455,12 @@ impl GameDataHolder {
                         }),
                     },
                     armor_type: ArmorType::from_u8(item.armor_type).unwrap(),
-                    attack_effect: self.game_string_table_ru.get_o(&item.attack_effect),
+                    attack_effect: self.game_string_table.get_o(&item.attack_effect),
                     item_sound: item
                         .item_sound
                         .inner
                         .iter()
-                        .map(|v| self.game_string_table_ru.get_o(v))
+                        .map(|v| self.game_string_table.get_o(v))
                         .collect(),
                     unk1: item.unk1,
                     unk2: item.unk2 == 1,
@@ -471,25 +470,25 @@ impl GameDataHolder {
                     underwater_body_type2: UnderwaterBodyType2::from_u8(item.unk2).unwrap(),
                     set_enchant_effect_id: item.full_armor_enchant_effect_type.into(),
                     mesh_info: WindowParams::new(ArmorMeshes {
-                        m_human_fighter: (&item.m_human_fighter, &self.game_string_table_ru).into(),
-                        f_human_fighter: (&item.f_human_fighter, &self.game_string_table_ru).into(),
-                        m_dark_elf: (&item.m_dark_elf, &self.game_string_table_ru).into(),
-                        f_dark_elf: (&item.f_dark_elf, &self.game_string_table_ru).into(),
-                        m_dwarf: (&item.m_dwarf, &self.game_string_table_ru).into(),
-                        f_dwarf: (&item.f_dwarf, &self.game_string_table_ru).into(),
-                        m_elf: (&item.m_elf, &self.game_string_table_ru).into(),
-                        f_elf: (&item.f_elf, &self.game_string_table_ru).into(),
-                        m_human_mystic: (&item.m_human_mystic, &self.game_string_table_ru).into(),
-                        f_human_mystic: (&item.f_human_mystic, &self.game_string_table_ru).into(),
-                        m_orc_fighter: (&item.m_orc_fighter, &self.game_string_table_ru).into(),
-                        f_orc_fighter: (&item.f_orc_fighter, &self.game_string_table_ru).into(),
-                        m_orc_mystic: (&item.m_orc_mystic, &self.game_string_table_ru).into(),
-                        f_orc_mystic: (&item.f_orc_mystic, &self.game_string_table_ru).into(),
-                        m_kamael: (&item.m_kamael, &self.game_string_table_ru).into(),
-                        f_kamael: (&item.f_kamael, &self.game_string_table_ru).into(),
-                        m_ertheia: (&item.m_ertheia, &self.game_string_table_ru).into(),
-                        f_ertheia: (&item.f_ertheia, &self.game_string_table_ru).into(),
-                        npc: (&item.npc, &self.game_string_table_ru).into(),
+                        m_human_fighter: (&item.m_human_fighter, &self.game_string_table).into(),
+                        f_human_fighter: (&item.f_human_fighter, &self.game_string_table).into(),
+                        m_dark_elf: (&item.m_dark_elf, &self.game_string_table).into(),
+                        f_dark_elf: (&item.f_dark_elf, &self.game_string_table).into(),
+                        m_dwarf: (&item.m_dwarf, &self.game_string_table).into(),
+                        f_dwarf: (&item.f_dwarf, &self.game_string_table).into(),
+                        m_elf: (&item.m_elf, &self.game_string_table).into(),
+                        f_elf: (&item.f_elf, &self.game_string_table).into(),
+                        m_human_mystic: (&item.m_human_mystic, &self.game_string_table).into(),
+                        f_human_mystic: (&item.f_human_mystic, &self.game_string_table).into(),
+                        m_orc_fighter: (&item.m_orc_fighter, &self.game_string_table).into(),
+                        f_orc_fighter: (&item.f_orc_fighter, &self.game_string_table).into(),
+                        m_orc_mystic: (&item.m_orc_mystic, &self.game_string_table).into(),
+                        f_orc_mystic: (&item.f_orc_mystic, &self.game_string_table).into(),
+                        m_kamael: (&item.m_kamael, &self.game_string_table).into(),
+                        f_kamael: (&item.f_kamael, &self.game_string_table).into(),
+                        m_ertheia: (&item.m_ertheia, &self.game_string_table).into(),
+                        f_ertheia: (&item.f_ertheia, &self.game_string_table).into(),
+                        npc: (&item.npc, &self.game_string_table).into(),
                     }),
                     ..Default::default()
                 },
@@ -13,12 +13,13 @@ use crate::entity::item::{
 };
 
 use l2_rw::ue2_rw::{BYTE, DWORD, SHORT, USHORT, UVEC};
-use l2_rw::{DatVariant, deserialize_dat, save_dat};
+use crate::backend::dat_loader::deserialize_dat;
+use l2_rw::DatVariant;
 
 use l2_rw::ue2_rw::{ReadUnreal, UnrealReader, UnrealWriter, WriteUnreal};
 
 use crate::backend::Localization;
-use crate::backend::dat_loader::{GetId, wrap_into_id_map};
+use crate::backend::dat_loader::{GetId, LocalizedDat, NOT_EXIST, wrap_into_id_map, write_dat};
 use crate::backend::holder::{GameDataHolder, HolderMapOps};
 use crate::backend::log_holder::{Log, LogLevel};
 use crate::common::EnsoulOptionId;
@@ -27,6 +28,7 @@ use num_traits::{FromPrimitive, ToPrimitive};
 use std::collections::HashMap;
 use std::thread;
 use std::thread::JoinHandle;
+use walkdir::DirEntry;
 
 impl From<(&EtcItem, &mut L2GeneralStringTable, Localization)> for ItemNameDat {
     fn from(value: (&EtcItem, &mut L2GeneralStringTable, Localization)) -> Self {
@@ -204,7 +206,7 @@ impl GameDataHolder {
         let mut ensoul_stones: Vec<EnsoulStoneDat> = vec![];
 
         for v in self.etc_item_holder.values().filter(|v| !v._deleted) {
-            items.push((v, &mut self.game_string_table_ru).into());
+            items.push((v, &mut self.game_string_table).into());
 
             if let Some(s) = v.into() {
                 ensoul_stones.push(s);
@@ -223,7 +225,7 @@ impl GameDataHolder {
             .clone();
 
         thread::spawn(move || {
-            let l = if let Err(e) = save_dat(
+            let l = if let Err(e) = write_dat(
                 ensoul_stone_path.path(),
                 DatVariant::<(), EnsoulStoneDat>::Array(ensoul_stones),
             ) {
@@ -232,7 +234,7 @@ impl GameDataHolder {
                 Log::from_loader_i("Ensoul Stones Saved")
             };
 
-            if let Err(e) = save_dat(
+            if let Err(e) = write_dat(
                 etc_item_grp_path.path(),
                 DatVariant::<(), EtcItemGrpDat>::Array(items),
             ) {
@@ -248,15 +250,21 @@ impl GameDataHolder {
         additional_item_grp: &mut Vec<AdditionalItemGrpDat>,
         item_stat: &mut Vec<ItemStatDataDat>,
         item_base_info: &mut Vec<ItemBaseInfoDat>,
-        item_name_ru: &mut Vec<ItemNameDat>,
-        item_name_eu: &mut Vec<ItemNameDat>,
+        item_names: &mut [(Localization, DirEntry, Vec<ItemNameDat>)],
     ) {
         for v in self.etc_item_holder.values() {
-            additional_item_grp.push((v, &mut self.game_string_table_ru).into());
-            item_stat.push((v, &mut self.game_string_table_ru).into());
-            item_base_info.push((v, &mut self.game_string_table_ru).into());
-            item_name_ru.push((v, &mut self.game_string_table_ru, Localization::RU).into());
-            item_name_eu.push((v, &mut self.game_string_table_eu, Localization::EU).into());
+            additional_item_grp.push((v, &mut self.game_string_table).into());
+            item_stat.push((v, &mut self.game_string_table).into());
+            item_base_info.push((v, &mut self.game_string_table).into());
+
+            for (l, _, names) in item_names.iter_mut() {
+                let table = match self.localized_game_string_tables.get_mut(l) {
+                    Some(v) => v,
+                    None => &mut self.game_string_table,
+                };
+
+                names.push((v, table, *l).into());
+            }
         }
     }
 
@@ -265,9 +273,9 @@ impl GameDataHolder {
         additional_item_grp: &HashMap<u32, AdditionalItemGrpDat>,
         item_stat: &HashMap<u32, ItemStatDataDat>,
         item_base_info: &HashMap<u32, ItemBaseInfoDat>,
-        item_name_ru: &HashMap<u32, ItemNameDat>,
-        item_name_eu: &HashMap<u32, ItemNameDat>,
-    ) -> Result<Vec<Log>, ()> {
+        item_name: &HashMap<u32, ItemNameDat>,
+        item_names: &LocalizedDat<ItemNameDat>,
+    ) -> Result<Vec<Log>, Log> {
         let etc_grp = deserialize_dat::<EtcItemGrpDat>(
             self.dat_paths
                 .get(&"etcitemgrp.dat".to_string())
@@ -287,10 +295,8 @@ impl GameDataHolder {
         let additional_default = AdditionalItemGrpDat::default();
         let mut warnings = vec![];
 
-        let not_existing_name_eu = ItemNameDat::not_existing();
-
         for item in etc_grp {
-            let Some(name_grp_ru) = item_name_ru.get(&item.id) else {
+            let Some(name_grp) = item_name.get(&item.id) else {
                 warnings.push(Log {
                     level: LogLevel::Error,
                     producer: "Weapon Loader".to_string(),
@@ -300,8 +306,6 @@ impl GameDataHolder {
                 continue;
             };
 
-            let name_grp_eu = item_name_eu.get(&item.id).unwrap_or(&not_existing_name_eu);
-
             let base_info_grp = item_base_info.get(&item.id).unwrap_or(&base_info_default);
             let add_info_grp = additional_item_grp
                 .get(&item.id)
@@ -313,20 +317,20 @@ impl GameDataHolder {
             for (i, v) in item.mesh.inner.iter().enumerate() {
                 let texture = item.texture.inner.get(i).unwrap();
                 mesh_info.push(EtcMeshInfo {
-                    mesh: self.game_string_table_ru.get_o(v),
-                    texture: self.game_string_table_ru.get_o(texture),
+                    mesh: self.game_string_table.get_o(v),
+                    texture: self.game_string_table.get_o(texture),
                 });
             }
 
             let mut drop_mesh_info = vec![];
             for v in &item.drop_info {
                 drop_mesh_info.push(ItemDropMeshInfo {
-                    mesh: self.game_string_table_ru.get_o(&v.mesh),
+                    mesh: self.game_string_table.get_o(&v.mesh),
                     textures: v
                         .texture
                         .inner
                         .iter()
-                        .map(|vv| self.game_string_table_ru.get_o(vv))
+                        .map(|vv| self.game_string_table.get_o(vv))
                         .collect(),
                 })
             }
@@ -338,9 +342,9 @@ impl GameDataHolder {
                 drop_height: item.drop_height,
                 drop_mesh_info,
                 complete_item_drop_sound: self
-                    .game_string_table_ru
+                    .game_string_table
                     .get_o(&item.complete_item_drop_sound),
-                drop_sound: self.game_string_table_ru.get_o(&item.drop_sound),
+                drop_sound: self.game_string_table.get_o(&item.drop_sound),
             };
 
             self.etc_item_holder.insert(
@@ -348,36 +352,32 @@ impl GameDataHolder {
                 EtcItem {
                     base_info: ItemBaseInfo {
                         id: item.id.into(),
-                        name: (
-                            self.game_string_table_ru.get_o(&name_grp_ru.name_link),
-                            self.game_string_table_eu.get_o(&name_grp_eu.name_link),
-                        )
-                            .into(),
-                        additional_name: (
-                            name_grp_ru.additional_name.to_string(),
-                            name_grp_eu.additional_name.to_string(),
-                        )
-                            .into(),
-                        popup: name_grp_ru.popup,
-                        default_action: ItemDefaultAction::from_ascf(&name_grp_ru.default_action),
-                        use_order: name_grp_ru.use_order,
-                        set_id: name_grp_ru.set_id.into(),
-                        color: ItemNameColor::from_u8(name_grp_ru.color).unwrap(),
+                        name: self.localized_item_name(item_names, name_grp),
+                        additional_name: item_names.localize(
+                            name_grp,
+                            |v| v.additional_name.to_string(),
+                            NOT_EXIST.to_string(),
+                        ),
+                        popup: name_grp.popup,
+                        default_action: ItemDefaultAction::from_ascf(&name_grp.default_action),
+                        use_order: name_grp.use_order,
+                        set_id: name_grp.set_id.into(),
+                        color: ItemNameColor::from_u8(name_grp.color).unwrap(),
                         tooltip_texture: self
-                            .game_string_table_ru
-                            .get_o(&name_grp_ru.tooltip_texture_link),
-                        is_trade: name_grp_ru.is_trade == 1,
-                        is_drop: name_grp_ru.is_drop == 1,
-                        is_destruct: name_grp_ru.is_destruct == 1,
-                        is_private_store: name_grp_ru.is_private_store == 1,
-                        is_npc_trade: name_grp_ru.is_npc_trade == 1,
-                        is_commission_store: name_grp_ru.is_commission_store == 1,
-                        keep_type: KeepType::from_u8(name_grp_ru.keep_type).unwrap(),
-                        desc: (
-                            name_grp_ru.description.to_string(),
-                            name_grp_eu.description.to_string(),
-                        )
-                            .into(),
+                            .game_string_table
+                            .get_o(&name_grp.tooltip_texture_link),
+                        is_trade: name_grp.is_trade == 1,
+                        is_drop: name_grp.is_drop == 1,
+                        is_destruct: name_grp.is_destruct == 1,
+                        is_private_store: name_grp.is_private_store == 1,
+                        is_npc_trade: name_grp.is_npc_trade == 1,
+                        is_commission_store: name_grp.is_commission_store == 1,
+                        keep_type: KeepType::from_u8(name_grp.keep_type).unwrap(),
+                        desc: item_names.localize(
+                            name_grp,
+                            |v| v.description.to_string(),
+                            NOT_EXIST.to_string(),
+                        ),
                         inventory_type: InventoryType::from_u8(item.inventory_type).unwrap(),
                         material: ItemMaterial::from_u8(item.material_type).unwrap(),
                         body_part: BodyPart::None,
@@ -387,12 +387,12 @@ impl GameDataHolder {
                         durability: item.durability,
                         weight: item.weight,
                         icons: WindowParams::new(ItemIcons {
-                            icon_1: self.game_string_table_ru.get_o(&item.icon_1),
-                            icon_2: self.game_string_table_ru.get_o(&item.icon_2),
-                            icon_3: self.game_string_table_ru.get_o(&item.icon_3),
-                            icon_4: self.game_string_table_ru.get_o(&item.icon_4),
-                            icon_5: self.game_string_table_ru.get_o(&item.icon_5),
-                            icon_panel: self.game_string_table_ru.get_o(&item.icon_panel),
+                            icon_1: self.game_string_table.get_o(&item.icon_1),
+                            icon_2: self.game_string_table.get_o(&item.icon_2),
+                            icon_3: self.game_string_table.get_o(&item.icon_3),
+                            icon_4: self.game_string_table.get_o(&item.icon_4),
+                            icon_5: self.game_string_table.get_o(&item.icon_5),
+                            icon_panel: self.game_string_table.get_o(&item.icon_panel),
                         }),
                         default_price: base_info_grp.default_price,
                         is_premium: base_info_grp.is_premium == 1,
@@ -404,7 +404,7 @@ impl GameDataHolder {
                             .iter()
                             .map(|v| (*v).into())
                             .collect(),
-                        equip_sound: self.game_string_table_ru.get_o(&item.equip_sound),
+                        equip_sound: self.game_string_table.get_o(&item.equip_sound),
                         additional_info: WindowParams::new(ItemAdditionalInfo {
                             has_animation: add_info_grp.has_ani == 1,
                             include_items: add_info_grp
@@ -413,7 +413,7 @@ impl GameDataHolder {
                                 .map(|v| (*v).into())
                                 .collect(),
                             max_energy: add_info_grp.max_energy,
-                            look_change: self.game_string_table_ru.get_o(&add_info_grp.look_change),
+                            look_change: self.game_string_table.get_o(&add_info_grp.look_change),
                             hide_cloak: add_info_grp.hide_cloak == 1,
                             unk: add_info_grp.unk1 == 1,
                             hide_armor: add_info_grp.hide_armor == 1,
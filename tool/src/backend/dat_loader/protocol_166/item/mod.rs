@@ -3,16 +3,19 @@ mod etc_item;
 mod weapon;
 
 use crate::backend::log_holder::Log;
-use std::collections::HashMap;
 use std::convert::Into;
 
 use l2_rw::ue2_rw::{ASCF, BYTE, DWORD, FLOAT, LONG, SHORT, USHORT, UVEC};
-use l2_rw::{DatVariant, deserialize_dat, save_dat};
+use crate::backend::dat_loader::deserialize_dat;
+use l2_rw::DatVariant;
 
 use l2_rw::ue2_rw::{ReadUnreal, UnrealReader, UnrealWriter, WriteUnreal};
 
-use crate::backend::dat_loader::{GetId, NOT_EXIST, wrap_into_id_map};
+use crate::backend::dat_loader::{
+    GetId, LocalizedDat, NOT_EXIST, save_localized_dats, wrap_into_id_map, write_dat,
+};
 use crate::backend::holder::{GameDataHolder, HolderOps};
+use crate::backend::util::{Localized, StringCow};
 use crate::entity::item::ItemDefaultAction;
 use r#macro::{ReadUnreal, WriteUnreal};
 use std::thread;
@@ -25,8 +28,11 @@ impl GameDataHolder {
         let mut additional_item_grp = vec![];
         let mut item_stat = vec![];
         let mut item_base_info = vec![];
-        let mut item_name_ru = vec![];
-        let mut item_name_eu = vec![];
+        let mut item_names: Vec<_> = self
+            .localized_dat_paths("itemname")
+            .into_iter()
+            .map(|(l, path)| (l, path, vec![]))
+            .collect();
 
         let weapon_handle = if self.weapon_holder.was_changed() {
             Some(self.serialize_weapons_to_binary())
@@ -50,24 +56,21 @@ impl GameDataHolder {
             &mut additional_item_grp,
             &mut item_stat,
             &mut item_base_info,
-            &mut item_name_ru,
-            &mut item_name_eu,
+            &mut item_names,
         );
 
         self.fill_items_from_armor(
             &mut additional_item_grp,
             &mut item_stat,
             &mut item_base_info,
-            &mut item_name_ru,
-            &mut item_name_eu,
+            &mut item_names,
         );
 
         self.fill_items_from_etc_items(
             &mut additional_item_grp,
             &mut item_stat,
             &mut item_base_info,
-            &mut item_name_ru,
-            &mut item_name_eu,
+            &mut item_names,
         );
 
         let additional_item_grp_path = self
@@ -88,21 +91,11 @@ impl GameDataHolder {
             .unwrap()
             .clone();
 
-        let item_name_ru_path = self
-            .dat_paths
-            .get(&"itemname-ru.dat".to_string())
-            .unwrap()
-            .clone();
-
-        let item_name_eu_path = self
-            .dat_paths
-            .get(&"itemname-eu.dat".to_string())
-            .unwrap()
-            .clone();
+        let item_names_handle = save_localized_dats(item_names, "Item Name");
 
         thread::spawn(move || {
             let additional_item_grp_handle = thread::spawn(move || {
-                if let Err(e) = save_dat(
+                if let Err(e) = write_dat(
                     additional_item_grp_path.path(),
                     DatVariant::<(), AdditionalItemGrpDat>::Array(additional_item_grp),
                 ) {
@@ -113,7 +106,7 @@ impl GameDataHolder {
             });
 
             let item_stat_handle = thread::spawn(move || {
-                if let Err(e) = save_dat(
+                if let Err(e) = write_dat(
                     item_stat_path.path(),
                     DatVariant::<(), ItemStatDataDat>::Array(item_stat),
                 ) {
@@ -124,7 +117,7 @@ impl GameDataHolder {
             });
 
             let item_base_info_handle = thread::spawn(move || {
-                if let Err(e) = save_dat(
+                if let Err(e) = write_dat(
                     item_base_info_path.path(),
                     DatVariant::<(), ItemBaseInfoDat>::Array(item_base_info),
                 ) {
@@ -134,33 +127,10 @@ impl GameDataHolder {
                 }
             });
 
-            let item_name_ru_handle = thread::spawn(move || {
-                if let Err(e) = save_dat(
-                    item_name_ru_path.path(),
-                    DatVariant::<(), ItemNameDat>::Array(item_name_ru),
-                ) {
-                    Log::from_loader_e(e)
-                } else {
-                    Log::from_loader_i("Item Name RU saved")
-                }
-            });
-
-            let item_name_eu_handle = thread::spawn(move || {
-                if let Err(e) = save_dat(
-                    item_name_eu_path.path(),
-                    DatVariant::<(), ItemNameDat>::Array(item_name_eu),
-                ) {
-                    Log::from_loader_e(e)
-                } else {
-                    Log::from_loader_i("Item Name EU saved")
-                }
-            });
-
             logs.push(additional_item_grp_handle.join().unwrap());
             logs.push(item_stat_handle.join().unwrap());
             logs.push(item_base_info_handle.join().unwrap());
-            logs.push(item_name_ru_handle.join().unwrap());
-            logs.push(item_name_eu_handle.join().unwrap());
+            logs.extend(item_names_handle.join().unwrap());
 
             if let Some(h) = weapon_handle {
                 logs.push(h.join().unwrap());
@@ -180,7 +150,7 @@ impl GameDataHolder {
         })
     }
 
-    pub fn load_items(&mut self) -> Result<Vec<Log>, ()> {
+    pub fn load_items(&mut self) -> Result<Vec<Log>, Log> {
         let additional_item_grp = wrap_into_id_map(deserialize_dat::<AdditionalItemGrpDat>(
             self.dat_paths
                 .get(&"additionalitemgrp.dat".to_string())
@@ -202,45 +172,52 @@ impl GameDataHolder {
                 .path(),
         )?);
 
-        let item_name_ru = wrap_into_id_map(deserialize_dat::<ItemNameDat>(
-            self.dat_paths
-                .get(&"itemname-ru.dat".to_string())
-                .unwrap()
-                .path(),
-        )?);
-
-        let item_name_eu = if let Some(v) = self.dat_paths.get(&"itemname-eu.dat".to_string()) {
-            wrap_into_id_map(deserialize_dat::<ItemNameDat>(v.path())?)
-        } else {
-            HashMap::new()
-        };
+        let mut item_names = self.load_localized_dat::<ItemNameDat>("itemname")?;
+        let item_name = wrap_into_id_map(std::mem::take(&mut item_names.main));
 
         let mut logs = self.load_weapons(
             &additional_item_grp,
             &item_stat,
             &item_base_info,
-            &item_name_ru,
-            &item_name_eu,
+            &item_name,
+            &item_names,
         )?;
 
         logs.extend(self.load_etc_items(
             &additional_item_grp,
             &item_stat,
             &item_base_info,
-            &item_name_ru,
-            &item_name_eu,
+            &item_name,
+            &item_names,
         )?);
 
         logs.extend(self.load_armor(
             &additional_item_grp,
             &item_stat,
             &item_base_info,
-            &item_name_ru,
-            &item_name_eu,
+            &item_name,
+            &item_names,
         )?);
 
         Ok(logs)
     }
+
+    /// Item names are stored as links into string table of the same locale
+    fn localized_item_name(
+        &self,
+        item_names: &LocalizedDat<ItemNameDat>,
+        name_grp: &ItemNameDat,
+    ) -> Localized<StringCow> {
+        Localized::from_fn_in(item_names.localizations.iter().copied(), |l| {
+            if l == item_names.localizations[0] {
+                self.game_string_table.get_o(&name_grp.name_link)
+            } else if let Some(v) = item_names.get(l, name_grp.id) {
+                self[l].get_o(&v.name_link)
+            } else {
+                NOT_EXIST.into()
+            }
+        })
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, ReadUnreal, WriteUnreal, Default)]
@@ -300,30 +277,6 @@ pub(crate) struct ItemNameDat {
     is_npc_trade: BYTE,
     is_commission_store: BYTE,
 }
-impl ItemNameDat {
-    pub fn not_existing() -> Self {
-        Self {
-            id: 0,
-            name_link: u32::MAX,
-            additional_name: NOT_EXIST.into(),
-            description: NOT_EXIST.into(),
-            popup: 0,
-            default_action: ASCF::empty(),
-            use_order: 0,
-            set_id: 0,
-            color: 0,
-            tooltip_texture_link: 0,
-            is_trade: 0,
-            is_drop: 0,
-            is_destruct: 0,
-            is_private_store: 0,
-            keep_type: 0,
-            is_npc_trade: 0,
-            is_commission_store: 0,
-        }
-    }
-}
-
 impl GetId for ItemNameDat {
     fn get_id(&self) -> u32 {
         self.id
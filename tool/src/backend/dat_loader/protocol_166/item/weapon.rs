@@ -14,12 +14,13 @@ use crate::entity::item::{
 };
 
 use l2_rw::ue2_rw::{BYTE, DVEC, DWORD, FLOAT, SHORT, USHORT, UVEC};
-use l2_rw::{DatVariant, deserialize_dat, save_dat};
+use crate::backend::dat_loader::deserialize_dat;
+use l2_rw::DatVariant;
 
 use l2_rw::ue2_rw::{ReadUnreal, UnrealReader, UnrealWriter, WriteUnreal};
 
 use crate::backend::Localization;
-use crate::backend::dat_loader::GetId;
+use crate::backend::dat_loader::{GetId, LocalizedDat, NOT_EXIST, write_dat};
 use crate::backend::holder::{GameDataHolder, HolderMapOps};
 use crate::backend::log_holder::{Log, LogLevel};
 use r#macro::{ReadUnreal, WriteUnreal};
@@ -28,6 +29,7 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use std::thread;
 use std::thread::JoinHandle;
+use walkdir::DirEntry;
 
 impl From<(&Weapon, &mut L2GeneralStringTable, Localization)> for ItemNameDat {
     fn from(value: (&Weapon, &mut L2GeneralStringTable, Localization)) -> Self {
@@ -263,7 +265,7 @@ impl GameDataHolder {
         let mut weapons: Vec<WeaponGrpDat> = vec![];
 
         for v in self.weapon_holder.values().filter(|v| !v._deleted) {
-            weapons.push((v, &mut self.game_string_table_ru).into())
+            weapons.push((v, &mut self.game_string_table).into())
         }
 
         let weapon_grp_path = self
@@ -273,7 +275,7 @@ impl GameDataHolder {
             .clone();
 
         thread::spawn(move || {
-            if let Err(e) = save_dat(
+            if let Err(e) = write_dat(
                 weapon_grp_path.path(),
                 DatVariant::<(), WeaponGrpDat>::Array(weapons),
             ) {
@@ -289,15 +291,21 @@ impl GameDataHolder {
         additional_item_grp: &mut Vec<AdditionalItemGrpDat>,
         item_stat: &mut Vec<ItemStatDataDat>,
         item_base_info: &mut Vec<ItemBaseInfoDat>,
-        item_name_ru: &mut Vec<ItemNameDat>,
-        item_name_eu: &mut Vec<ItemNameDat>,
+        item_names: &mut [(Localization, DirEntry, Vec<ItemNameDat>)],
     ) {
         for v in self.weapon_holder.values() {
-            additional_item_grp.push((v, &mut self.game_string_table_ru).into());
-            item_stat.push((v, &mut self.game_string_table_ru).into());
-            item_base_info.push((v, &mut self.game_string_table_ru).into());
-            item_name_ru.push((v, &mut self.game_string_table_ru, Localization::RU).into());
-            item_name_eu.push((v, &mut self.game_string_table_eu, Localization::EU).into());
+            additional_item_grp.push((v, &mut self.game_string_table).into());
+            item_stat.push((v, &mut self.game_string_table).into());
+            item_base_info.push((v, &mut self.game_string_table).into());
+
+            for (l, _, names) in item_names.iter_mut() {
+                let table = match self.localized_game_string_tables.get_mut(l) {
+                    Some(v) => v,
+                    None => &mut self.game_string_table,
+                };
+
+                names.push((v, table, *l).into());
+            }
         }
     }
 
@@ -306,9 +314,9 @@ impl GameDataHolder {
         additional_item_grp: &HashMap<u32, AdditionalItemGrpDat>,
         item_stat: &HashMap<u32, ItemStatDataDat>,
         item_base_info: &HashMap<u32, ItemBaseInfoDat>,
-        item_name_ru: &HashMap<u32, ItemNameDat>,
-        item_name_eu: &HashMap<u32, ItemNameDat>,
-    ) -> Result<Vec<Log>, ()> {
+        item_name: &HashMap<u32, ItemNameDat>,
+        item_names: &LocalizedDat<ItemNameDat>,
+    ) -> Result<Vec<Log>, Log> {
         let no_tex: Arc<String> = Arc::new("NO TEXT".to_string());
 
         let weapon_grp = deserialize_dat::<WeaponGrpDat>(
@@ -322,12 +330,10 @@ impl GameDataHolder {
         let base_stat_default = ItemStatDataDat::default();
         let additional_default = AdditionalItemGrpDat::default();
 
-        let not_existing_name_eu = ItemNameDat::not_existing();
-
         let mut warnings = vec![];
 
         for weapon in weapon_grp {
-            let Some(name_grp_ru) = item_name_ru.get(&weapon.id) else {
+            let Some(name_grp) = item_name.get(&weapon.id) else {
                 warnings.push(Log {
                     level: LogLevel::Error,
                     producer: "Weapon Loader".to_string(),
@@ -337,10 +343,6 @@ impl GameDataHolder {
                 continue;
             };
 
-            let name_grp_eu = item_name_eu
-                .get(&weapon.id)
-                .unwrap_or(&not_existing_name_eu);
-
             let base_info_grp = item_base_info.get(&weapon.id).unwrap_or(&base_info_default);
             let add_info_grp = additional_item_grp
                 .get(&weapon.id)
@@ -355,7 +357,7 @@ impl GameDataHolder {
                 let mut textures = vec![];
                 for _ in 0..(*texture_count).max(1) {
                     if let Some(pt) = &weapon.texture.inner.get(texture_offset) {
-                        textures.push(self.game_string_table_ru.get_o(pt));
+                        textures.push(self.game_string_table.get_o(pt));
                     } else {
                         bad_texture_array = true;
                         textures.push((&no_tex).into());
@@ -365,7 +367,7 @@ impl GameDataHolder {
                 }
 
                 mesh_info.push(WeaponMeshInfo {
-                    mesh: self.game_string_table_ru.get_o(mesh),
+                    mesh: self.game_string_table.get_o(mesh),
                     texture: textures,
                 });
             }
@@ -384,12 +386,12 @@ impl GameDataHolder {
             let mut drop_mesh_info = vec![];
             for v in &weapon.drop_info {
                 drop_mesh_info.push(ItemDropMeshInfo {
-                    mesh: self.game_string_table_ru.get_o(&v.mesh),
+                    mesh: self.game_string_table.get_o(&v.mesh),
                     textures: v
                         .texture
                         .inner
                         .iter()
-                        .map(|vv| self.game_string_table_ru.get_o(vv))
+                        .map(|vv| self.game_string_table.get_o(vv))
                         .collect(),
                 })
             }
@@ -402,9 +404,9 @@ impl GameDataHolder {
                 drop_height: weapon.drop_height,
                 drop_mesh_info,
                 complete_item_drop_sound: self
-                    .game_string_table_ru
+                    .game_string_table
                     .get_o(&weapon.complete_item_drop_sound),
-                drop_sound: self.game_string_table_ru.get_o(&weapon.drop_sound),
+                drop_sound: self.game_string_table.get_o(&weapon.drop_sound),
             };
 
             self.weapon_holder.insert(
@@ -412,36 +414,32 @@ impl GameDataHolder {
                 Weapon {
                     base_info: ItemBaseInfo {
                         id: weapon.id.into(),
-                        name: (
-                            self.game_string_table_ru.get_o(&name_grp_ru.name_link),
-                            self.game_string_table_eu.get_o(&name_grp_eu.name_link),
-                        )
-                            .into(),
-                        additional_name: (
-                            name_grp_ru.additional_name.to_string(),
-                            name_grp_eu.additional_name.to_string(),
-                        )
-                            .into(),
-                        popup: name_grp_ru.popup,
-                        default_action: ItemDefaultAction::from_ascf(&name_grp_ru.default_action),
-                        use_order: name_grp_ru.use_order,
-                        set_id: name_grp_ru.set_id.into(),
-                        color: ItemNameColor::from_u8(name_grp_ru.color).unwrap(),
+                        name: self.localized_item_name(item_names, name_grp),
+                        additional_name: item_names.localize(
+                            name_grp,
+                            |v| v.additional_name.to_string(),
+                            NOT_EXIST.to_string(),
+                        ),
+                        popup: name_grp.popup,
+                        default_action: ItemDefaultAction::from_ascf(&name_grp.default_action),
+                        use_order: name_grp.use_order,
+                        set_id: name_grp.set_id.into(),
+                        color: ItemNameColor::from_u8(name_grp.color).unwrap(),
                         tooltip_texture: self
-                            .game_string_table_ru
-                            .get_o(&name_grp_ru.tooltip_texture_link),
-                        is_trade: name_grp_ru.is_trade == 1,
-                        is_drop: name_grp_ru.is_drop == 1,
-                        is_destruct: name_grp_ru.is_destruct == 1,
-                        is_private_store: name_grp_ru.is_private_store == 1,
-                        is_npc_trade: name_grp_ru.is_npc_trade == 1,
-                        is_commission_store: name_grp_ru.is_commission_store == 1,
-                        keep_type: KeepType::from_u8(name_grp_ru.keep_type).unwrap(),
-                        desc: (
-                            name_grp_ru.description.to_string(),
-                            name_grp_eu.description.to_string(),
-                        )
-                            .into(),
+                            .game_string_table
+                            .get_o(&name_grp.tooltip_texture_link),
+                        is_trade: name_grp.is_trade == 1,
+                        is_drop: name_grp.is_drop == 1,
+                        is_destruct: name_grp.is_destruct == 1,
+                        is_private_store: name_grp.is_private_store == 1,
+                        is_npc_trade: name_grp.is_npc_trade == 1,
+                        is_commission_store: name_grp.is_commission_store == 1,
+                        keep_type: KeepType::from_u8(name_grp.keep_type).unwrap(),
+                        desc: item_names.localize(
+                            name_grp,
+                            |v| v.description.to_string(),
+                            NOT_EXIST.to_string(),
+                        ),
                         inventory_type: InventoryType::from_u8(weapon.inventory_type).unwrap(),
                         material: ItemMaterial::from_u8(weapon.material_type).unwrap(),
                         body_part: BodyPart::from_u8(weapon.body_part)
@@ -454,12 +452,12 @@ impl GameDataHolder {
                         durability: weapon.durability,
                         weight: weapon.weight,
                         icons: WindowParams::new(ItemIcons {
-                            icon_1: self.game_string_table_ru.get_o(&weapon.icon_1),
-                            icon_2: self.game_string_table_ru.get_o(&weapon.icon_2),
-                            icon_3: self.game_string_table_ru.get_o(&weapon.icon_3),
-                            icon_4: self.game_string_table_ru.get_o(&weapon.icon_4),
-                            icon_5: self.game_string_table_ru.get_o(&weapon.icon_5),
-                            icon_panel: self.game_string_table_ru.get_o(&weapon.icon_panel),
+                            icon_1: self.game_string_table.get_o(&weapon.icon_1),
+                            icon_2: self.game_string_table.get_o(&weapon.icon_2),
+                            icon_3: self.game_string_table.get_o(&weapon.icon_3),
+                            icon_4: self.game_string_table.get_o(&weapon.icon_4),
+                            icon_5: self.game_string_table.get_o(&weapon.icon_5),
+                            icon_panel: self.game_string_table.get_o(&weapon.icon_panel),
                         }),
                         default_price: base_info_grp.default_price,
                         is_premium: base_info_grp.is_premium == 1,
@@ -471,7 +469,7 @@ impl GameDataHolder {
                             .iter()
                             .map(|v| (*v).into())
                             .collect(),
-                        equip_sound: self.game_string_table_ru.get_o(&weapon.equip_sound),
+                        equip_sound: self.game_string_table.get_o(&weapon.equip_sound),
                         additional_info: WindowParams::new(ItemAdditionalInfo {
                             has_animation: add_info_grp.has_ani == 1,
                             include_items: add_info_grp
@@ -480,7 +478,7 @@ impl GameDataHolder {
                                 .map(|v| (*v).into())
                                 .collect(),
                             max_energy: add_info_grp.max_energy,
-                            look_change: self.game_string_table_ru.get_o(&add_info_grp.look_change),
+                            look_change: self.game_string_table.get_o(&add_info_grp.look_change),
                             hide_cloak: add_info_grp.hide_cloak == 1,
                             unk: add_info_grp.unk1 == 1,
                             hide_armor: add_info_grp.hide_armor == 1,
@@ -518,10 +516,10 @@ impl GameDataHolder {
                             .item_sound
                             .inner
                             .iter()
-                            .map(|v| self.game_string_table_ru.get_o(v))
+                            .map(|v| self.game_string_table.get_o(v))
                             .collect(),
                     )),
-                    effect: self.game_string_table_ru.get_o(&weapon.effect),
+                    effect: self.game_string_table.get_o(&weapon.effect),
                     mp_consume: WeaponMpConsume::from_u8(weapon.mp_consume).unwrap(),
                     soulshot_count: weapon.soulshot_count,
                     spiritshot_count: weapon.spiritshot_count,
@@ -536,7 +534,7 @@ impl GameDataHolder {
                             .inner
                             .iter()
                             .map(|v| WeaponEnchantParams {
-                                effect: self.game_string_table_ru.get_o(&v.effect),
+                                effect: self.game_string_table.get_o(&v.effect),
                                 effect_offset: v.effect_offset.into(),
                                 effect_scale: v.effect_scale,
                                 effect_velocity: v.effect_velocity,
@@ -554,7 +552,7 @@ impl GameDataHolder {
                             .variation_icon
                             .inner
                             .iter()
-                            .map(|v| self.game_string_table_ru.get_o(v))
+                            .map(|v| self.game_string_table.get_o(v))
                             .collect(),
                         effect_1: weapon.variation_effect_1,
                         effect_2: weapon.variation_effect_2,
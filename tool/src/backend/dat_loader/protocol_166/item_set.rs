@@ -1,18 +1,15 @@
 use crate::backend::log_holder::Log;
 use crate::common::ItemId;
 use crate::entity::item_set::{ItemSet, ItemSetEnchantInfo};
-use std::collections::HashMap;
 
 use l2_rw::ue2_rw::{ASCF, DWORD, UVEC};
-use l2_rw::{DatVariant, deserialize_dat, save_dat};
 
 use l2_rw::ue2_rw::{ReadUnreal, UnrealReader, UnrealWriter, WriteUnreal};
 
 use crate::backend::Localization;
-use crate::backend::dat_loader::{GetId, wrap_into_id_map};
+use crate::backend::dat_loader::{GetId, NOT_EXIST, save_localized_dats};
 use crate::backend::holder::{GameDataHolder, HolderMapOps};
 use r#macro::{ReadUnreal, WriteUnreal};
-use std::thread;
 use std::thread::JoinHandle;
 
 impl ItemSetGrpDat {
@@ -60,78 +57,29 @@ impl ItemSetGrpDat {
 
 impl GameDataHolder {
     pub fn serialize_item_sets_to_binary(&mut self) -> JoinHandle<Vec<Log>> {
-        let set_grp_ru: Vec<ItemSetGrpDat> = self
-            .item_set_holder
-            .values()
-            .filter(|v| !v._deleted)
-            .map(|v| ItemSetGrpDat::from_entity(v, Localization::RU))
-            .collect();
-
-        let set_grp_path_ru = self
-            .dat_paths
-            .get(&"setitemgrp-ru.dat".to_string())
-            .unwrap()
-            .clone();
-
-        let eu = if let Some(set_grp_path_eu) = self.dat_paths.get(&"setitemgrp-eu.dat".to_string())
-        {
-            let set_grp_eu: Vec<ItemSetGrpDat> = self
-                .item_set_holder
-                .values()
-                .filter(|v| !v._deleted)
-                .map(|v| ItemSetGrpDat::from_entity(v, Localization::EU))
-                .collect();
-
-            Some((set_grp_eu, set_grp_path_eu.clone()))
-        } else {
-            None
-        };
-
-        thread::spawn(move || {
-            let mut log = vec![if let Err(e) = save_dat(
-                set_grp_path_ru.path(),
-                DatVariant::<(), ItemSetGrpDat>::Array(set_grp_ru),
-            ) {
-                Log::from_loader_e(e)
-            } else {
-                Log::from_loader_i("Set Item Grp RU saved")
-            }];
-
-            if let Some((set_grp_eu, set_grp_path_eu)) = eu {
-                log.push(
-                    if let Err(e) = save_dat(
-                        set_grp_path_eu.path(),
-                        DatVariant::<(), ItemSetGrpDat>::Array(set_grp_eu),
-                    ) {
-                        Log::from_loader_e(e)
-                    } else {
-                        Log::from_loader_i("Set Item Grp EU saved")
-                    },
+        let dats = self
+            .localized_dat_paths("setitemgrp")
+            .into_iter()
+            .map(|(l, path)| {
+                (
+                    l,
+                    path,
+                    self.item_set_holder
+                        .values()
+                        .filter(|v| !v._deleted)
+                        .map(|v| ItemSetGrpDat::from_entity(v, l))
+                        .collect::<Vec<ItemSetGrpDat>>(),
                 )
-            }
+            })
+            .collect();
 
-            log
-        })
+        save_localized_dats(dats, "Set Item Grp")
     }
 
-    pub fn load_item_sets(&mut self) -> Result<Vec<Log>, ()> {
-        let set_grp_ru = deserialize_dat::<ItemSetGrpDat>(
-            self.dat_paths
-                .get(&"setitemgrp-ru.dat".to_string())
-                .unwrap()
-                .path(),
-        )?;
-
-        let set_grp_eu = if let Some(eu_path) = self.dat_paths.get(&"setitemgrp-eu.dat".to_string())
-        {
-            wrap_into_id_map(deserialize_dat::<ItemSetGrpDat>(eu_path.path())?)
-        } else {
-            HashMap::new()
-        };
-
-        for v in set_grp_ru {
-            let eu = set_grp_eu.get(&v.id);
+    pub fn load_item_sets(&mut self) -> Result<Vec<Log>, Log> {
+        let set_grp = self.load_localized_dat::<ItemSetGrpDat>("setitemgrp")?;
 
+        for v in &set_grp.main {
             self.item_set_holder.insert(
                 v.id.into(),
                 ItemSet {
@@ -142,22 +90,18 @@ impl GameDataHolder {
                         .iter()
                         .map(|v| v.inner.iter().map(|vv| ItemId(*vv)).collect())
                         .collect(),
-                    base_descriptions: v
-                        .base_descriptions
-                        .inner
-                        .iter()
-                        .enumerate()
-                        .map(|(i, v)| {
-                            (
-                                v.to_string(),
-                                eu.map_or("NOT_EXITS".to_string(), |vv| {
+                    base_descriptions: (0..v.base_descriptions.inner.len())
+                        .map(|i| {
+                            set_grp.localize(
+                                v,
+                                |vv| {
                                     vv.base_descriptions
                                         .inner
                                         .get(i)
-                                        .map_or("NOT_EXITS".to_string(), |v| v.to_string())
-                                }),
+                                        .map_or(NOT_EXIST.to_string(), |v| v.to_string())
+                                },
+                                NOT_EXIST.to_string(),
                             )
-                                .into()
                         })
                         .collect(),
                     additional_items: v
@@ -166,22 +110,18 @@ impl GameDataHolder {
                         .iter()
                         .map(|v| v.inner.iter().map(|vv| ItemId(*vv)).collect())
                         .collect(),
-                    additional_descriptions: v
-                        .additional_descriptions
-                        .inner
-                        .iter()
-                        .enumerate()
-                        .map(|(i, v)| {
-                            (
-                                v.to_string(),
-                                eu.map_or("NOT_EXITS".to_string(), |vv| {
+                    additional_descriptions: (0..v.additional_descriptions.inner.len())
+                        .map(|i| {
+                            set_grp.localize(
+                                v,
+                                |vv| {
                                     vv.additional_descriptions
                                         .inner
                                         .get(i)
-                                        .map_or("NOT_EXITS".to_string(), |v| v.to_string())
-                                }),
+                                        .map_or(NOT_EXIST.to_string(), |v| v.to_string())
+                                },
+                                NOT_EXIST.to_string(),
                             )
-                                .into()
                         })
                         .collect(),
                     unk1: v.unk1,
@@ -191,20 +131,20 @@ impl GameDataHolder {
                         .inner
                         .iter()
                         .enumerate()
-                        .map(|(i, v)| ItemSetEnchantInfo {
-                            enchant_level: v.enchant_level,
-                            enchant_description: (
-                                v.description.to_string(),
-                                eu.map_or("NOT_EXITS".to_string(), |vv| {
+                        .map(|(i, b)| ItemSetEnchantInfo {
+                            enchant_level: b.enchant_level,
+                            enchant_description: set_grp.localize(
+                                v,
+                                |vv| {
                                     vv.enchant_bonuses
                                         .inner
                                         .get(i)
-                                        .map_or("NOT_EXITS".to_string(), |v| {
+                                        .map_or(NOT_EXIST.to_string(), |v| {
                                             v.description.to_string()
                                         })
-                                }),
-                            )
-                                .into(),
+                                },
+                                NOT_EXIST.to_string(),
+                            ),
                         })
                         .collect(),
                     ..Default::default()
@@ -3,12 +3,16 @@
     clippy::unnecessary_to_owned
 )]
 mod animation_combo;
+mod class_info;
 mod daily_mission;
 mod ensoul_option;
+mod game_tip;
+mod henna;
 mod hunting_zone;
 mod item;
 mod item_set;
 mod npc;
+mod product;
 mod quest;
 mod raid_data;
 mod recipe;
@@ -16,18 +20,25 @@ mod region;
 mod residence;
 mod skill;
 mod system_msg;
+mod user_action;
 
 use crate::backend::holder::{
-    DictItem, GameDataHolder, HolderMapOps, HolderOps, L2GeneralStringTable,
+    DictItem, DumpFormat, GameDataHolder, HolderMapOps, HolderOps, L2GeneralStringTable, RonLayout,
+    load_from_ron, load_from_ron_limited,
 };
 use crate::common::{Location, Position};
 use crate::frontend::IS_SAVING;
 
-use crate::backend::dat_loader::{DatLoader, GetId, wrap_into_id_map};
+use crate::backend::Localization;
+use crate::backend::backup::finish_backup_batch;
+use crate::backend::dat_loader::{
+    DatLoader, DatOutput, GetId, begin_write_batch, commit_write_batch, deserialize_dat,
+    save_localized_dats, write_dat,
+};
 use crate::backend::log_holder::Log;
 use crate::entity::{CommonEntity, Dictionary, GameEntity};
+use l2_rw::DatVariant;
 use l2_rw::ue2_rw::{ASCF, BYTE, DWORD, FLOAT, STR};
-use l2_rw::{DatVariant, deserialize_dat, save_dat};
 use r#macro::{ReadUnreal, WriteUnreal};
 use std::collections::HashMap;
 use std::collections::hash_map::Keys;
@@ -41,7 +52,7 @@ use std::time::Instant;
 use strum::IntoEnumIterator;
 use walkdir::DirEntry;
 
-use crate::backend::util::{Localized, StringCow};
+use crate::backend::util::StringCow;
 use crate::log_multiple;
 use l2_rw::ue2_rw::{ReadUnreal, UnrealReader, UnrealWriter, WriteUnreal};
 
@@ -140,28 +151,33 @@ impl Index<u32> for L2SkillStringTable {
 }
 
 impl DatLoader for GameDataHolder {
-    fn load_from_binary(&mut self, dat_paths: HashMap<String, DirEntry>) -> Result<Vec<Log>, ()> {
+    fn load_from_binary(&mut self, dat_paths: HashMap<String, DirEntry>) -> Result<Vec<Log>, Log> {
         let start = Instant::now();
 
-        let Some(l2gdn_ru) = dat_paths.get(&"l2gamedataname.dat".to_string()) else {
-            return Err(());
+        let Some(l2gdn) = dat_paths.get(&"l2gamedataname.dat".to_string()) else {
+            return Err(Log::from_loader_fatal(
+                "l2gamedataname.dat not found".to_string(),
+            ));
         };
 
-        self.game_string_table_ru = Self::load_game_data_name(l2gdn_ru.path())?;
-        self.game_string_table_eu =
-            if let Some(l2gdn_en) = dat_paths.get(&"l2gamedataname-eu.dat".to_string()) {
-                Self::load_game_data_name(l2gdn_en.path())?
-            } else {
-                L2GeneralStringTable::default()
-            };
+        self.game_string_table = Self::load_game_data_name(l2gdn.path())?;
+
+        for l in self.localizations() {
+            if let Some(path) = dat_paths.get(&format!("l2gamedataname-{}.dat", l.code())) {
+                self.localized_game_string_tables
+                    .insert(l, Self::load_game_data_name(path.path())?);
+            }
+        }
 
         self.dat_paths = dat_paths;
 
         let mut logs = vec![];
 
+        logs.extend(self.load_class_infos()?);
         logs.extend(self.load_npcs()?);
         logs.extend(self.load_npc_strings()?);
         logs.extend(self.load_sys_strings()?);
+        logs.extend(self.load_game_tips()?);
 
         logs.extend(self.load_items()?);
         self.refill_all_items();
@@ -178,6 +194,9 @@ impl DatLoader for GameDataHolder {
         logs.extend(self.load_animation_combo()?);
         logs.extend(self.load_residences()?);
         logs.extend(self.load_ensoul_options()?);
+        logs.extend(self.load_hennas()?);
+        logs.extend(self.load_user_actions()?);
+        logs.extend(self.load_products()?);
 
         let duration = Instant::now() - start;
 
@@ -214,13 +233,21 @@ impl DatLoader for GameDataHolder {
             "\nEnsoul Options: {}",
             self.ensoul_option_holder.len()
         ));
+        log.push_str(&format!("\nHennas: {}", self.henna_holder.len()));
+        log.push_str(&format!(
+            "\nUser Actions: {}",
+            self.user_action_holder.len()
+        ));
+        log.push_str(&format!("\nProducts: {}", self.product_holder.len()));
+        log.push_str(&format!("\nClasses: {}", self.class_info_holder.len()));
 
         log.push_str("\n\n------------Dictionaries--------------");
-        log.push_str(&format!("\nNpc Strings RU: {}", self.npc_strings.len()));
+        log.push_str(&format!("\nNpc Strings: {}", self.npc_strings.len()));
         log.push_str(&format!("\nSystem Strings: {}", self.system_strings.len()));
+        log.push_str(&format!("\nGame Tips: {}", self.game_tips.len()));
         log.push_str(&format!(
             "\n\nL2GameDataName size: {}",
-            self.game_string_table_ru.keys().len()
+            self.game_string_table.keys().len()
         ));
         log.push_str("\n======================================");
 
@@ -229,15 +256,24 @@ impl DatLoader for GameDataHolder {
         Ok(logs)
     }
 
-    fn save_to_binary(&mut self, ron_path: &Option<String>) -> std::io::Result<()> {
+    fn save_to_binary(
+        &mut self,
+        ron_path: &Option<String>,
+        ron_layout: RonLayout,
+        dump_format: DumpFormat,
+        output: Option<DatOutput>,
+    ) -> std::io::Result<()> {
         let mut res = vec![];
 
         IS_SAVING.store(true, Ordering::Relaxed);
 
+        begin_write_batch(output);
+
         if let Some(path) = ron_path
-            && let Err(e) = self.save_to_ron(path, false) {
-                res.push(Log::from_loader_e(e.to_string()));
-            }
+            && let Err(e) = self.save_to_ron(path, false, ron_layout, dump_format)
+        {
+            res.push(Log::from_loader_e(e.to_string()));
+        }
 
         //------------------------------------------------------------------------------------------
         // Entity
@@ -323,6 +359,30 @@ impl DatLoader for GameDataHolder {
             None
         };
 
+        let henna_handle = if self.henna_holder.was_changed() {
+            Some(self.serialize_henna_to_binary())
+        } else {
+            None
+        };
+
+        let user_action_handle = if self.user_action_holder.was_changed() {
+            Some(self.serialize_user_action_to_binary())
+        } else {
+            None
+        };
+
+        let product_handle = if self.product_holder.was_changed() {
+            Some(self.serialize_product_to_binary())
+        } else {
+            None
+        };
+
+        let class_info_handle = if self.class_info_holder.was_changed() {
+            Some(self.serialize_class_info_to_binary())
+        } else {
+            None
+        };
+
         //------------------------------------------------------------------------------------------
         // Dictionary
         //------------------------------------------------------------------------------------------
@@ -339,70 +399,70 @@ impl DatLoader for GameDataHolder {
             None
         };
 
-        let gdn_ru_changed = self.game_string_table_ru.was_changed;
-        let gdn_ru_values = if gdn_ru_changed {
-            self.game_string_table_ru.to_vec()
+        let game_tips_handle = if self.game_tips.was_changed() {
+            Some(self.serialize_game_tips_to_binary())
         } else {
-            vec![]
+            None
         };
-        let gdn_ru_path = self
-            .dat_paths
-            .get(&"l2gamedataname.dat".to_string())
-            .unwrap()
-            .clone();
 
-        let gdn_eu_changed = self.game_string_table_eu.was_changed;
-        let gdn_eu_values = if gdn_eu_changed {
-            self.game_string_table_eu.to_vec()
+        let gdn_changed = self.game_string_table.was_changed;
+        let gdn_values = if gdn_changed {
+            self.game_string_table.to_vec()
         } else {
             vec![]
         };
-        let gdn_eu_path = self
+        let gdn_path = self
             .dat_paths
-            .get(&"l2gamedataname-eu.dat".to_string())
+            .get(&"l2gamedataname.dat".to_string())
             .unwrap()
             .clone();
 
+        let gdn_localized: Vec<_> = self
+            .localized_game_string_tables
+            .iter()
+            .filter(|(_, v)| v.was_changed)
+            .map(|(l, v)| {
+                (
+                    *l,
+                    self.dat_paths
+                        .get(&format!("l2gamedataname-{}.dat", l.code()))
+                        .unwrap()
+                        .clone(),
+                    v.to_vec(),
+                )
+            })
+            .collect();
+
         //------------------------------------------------------------------------------------------
         //------------------------------------------------------------------------------------------
 
         thread::spawn(move || {
-            let gdn_ru_handel = if gdn_ru_changed {
+            let gdn_handel = if gdn_changed {
                 Some(thread::spawn(move || {
-                    if let Err(e) = save_dat(
-                        gdn_ru_path.path(),
-                        DatVariant::<(), String>::Array(gdn_ru_values),
-                    ) {
+                    if let Err(e) =
+                        write_dat(gdn_path.path(), DatVariant::<(), String>::Array(gdn_values))
+                    {
                         Log::from_loader_e(&format!("{e:?}"))
                     } else {
-                        Log::from_loader_i("Game Data Name RU saved")
+                        Log::from_loader_i("Game Data Name saved")
                     }
                 }))
             } else {
                 None
             };
 
-            let gdn_eu_handel = if gdn_eu_changed {
-                Some(thread::spawn(move || {
-                    if let Err(e) = save_dat(
-                        gdn_eu_path.path(),
-                        DatVariant::<(), String>::Array(gdn_eu_values),
-                    ) {
-                        Log::from_loader_e(&format!("{e:?}"))
-                    } else {
-                        Log::from_loader_i("Game Data Name EU saved")
-                    }
-                }))
-            } else {
+            let gdn_localized_handle = if gdn_localized.is_empty() {
                 None
+            } else {
+                Some(save_localized_dats(gdn_localized, "Game Data Name"))
             };
 
-            if let Some(v) = gdn_ru_handel {
+            if let Some(v) = gdn_handel {
                 res.push(v.join().unwrap());
             }
 
-            if let Some(v) = gdn_eu_handel {
-                res.push(v.join().unwrap());
+            if let Some(v) = gdn_localized_handle {
+                res.extend(v.join().unwrap());
             }
 
             if let Some(v) = skills_handle {
@@ -454,34 +514,64 @@ impl DatLoader for GameDataHolder {
             }
 
             if let Some(v) = npc_strings_handle {
-                let c = v.join().unwrap();
-
-                res.push(c.0);
-                res.push(c.1);
+                res.extend(v.join().unwrap());
             }
 
             if let Some(v) = sys_strings_handle {
-                let c = v.join().unwrap();
+                res.extend(v.join().unwrap());
+            }
 
-                res.push(c.0);
-                res.push(c.1);
+            if let Some(v) = game_tips_handle {
+                res.extend(v.join().unwrap());
             }
 
             if let Some(v) = ensoul_option_handle {
                 res.extend(v.join().unwrap());
             }
 
-            res.push(Log::from_loader_i("Binaries Saved"));
+            if let Some(v) = henna_handle {
+                res.extend(v.join().unwrap());
+            }
+
+            if let Some(v) = user_action_handle {
+                res.extend(v.join().unwrap());
+            }
+
+            if let Some(v) = product_handle {
+                res.extend(v.join().unwrap());
+            }
+
+            if let Some(v) = class_info_handle {
+                res.extend(v.join().unwrap());
+            }
+
+            match commit_write_batch() {
+                Ok((count, warning)) => {
+                    res.push(Log::from_loader_i(&format!(
+                        "Binaries Saved: {count} dat(s)"
+                    )));
+                    res.extend(warning.map(|v| Log::from_loader_w(&v)));
+                }
+                Err(e) => res.push(Log::from_loader_e(format!("Save aborted: {e}"))),
+            }
 
             log_multiple(res);
 
+            finish_backup_batch();
+
             IS_SAVING.store(false, Ordering::Relaxed);
         });
 
         Ok(())
     }
 
-    fn save_to_ron(&self, folder_path: &str, all: bool) -> std::io::Result<()> {
+    fn save_to_ron(
+        &self,
+        folder_path: &str,
+        all: bool,
+        layout: RonLayout,
+        format: DumpFormat,
+    ) -> anyhow::Result<()> {
         impl GameEntity {
             fn get_file_limit(&self) -> u32 {
                 match self {
@@ -500,212 +590,246 @@ impl DatLoader for GameDataHolder {
                     GameEntity::AnimationCombo => 10_000,
                     GameEntity::Residence => 10_000,
                     GameEntity::EnsoulOption => 10_000,
+                    GameEntity::Henna => 10_000,
+                    GameEntity::UserAction => 10_000,
+                    GameEntity::Product => 10_000,
+                    GameEntity::ClassInfo => 10_000,
                 }
             }
         }
 
         for e in GameEntity::iter() {
             if all || self[e].was_changed() {
-                let _ =
-                    self[e].save_to_ron_limited(folder_path, &e.to_string(), e.get_file_limit());
+                match layout {
+                    RonLayout::Chunked => self[e].save_to_ron_limited(
+                        folder_path,
+                        &e.to_string(),
+                        e.get_file_limit(),
+                        format,
+                    )?,
+                    RonLayout::PerEntity => {
+                        self[e].save_to_ron_per_entity(folder_path, &e.to_string(), format)?;
+                    }
+                }
             }
         }
 
         for e in Dictionary::iter() {
             if all || self[e].was_changed() {
-                let _ = self[e].save_to_ron(folder_path, &e.to_string());
+                self[e].save_to_ron(folder_path, &e.to_string(), format)?;
             }
         }
 
-        if all || self.game_string_table_ru.was_changed {
-            let _ = self
-                .game_string_table_ru
-                .save_to_ron(Path::new(folder_path).join("L2GameDataName-ru.ron"));
+        if all || self.game_string_table.was_changed {
+            self.game_string_table.save_to_ron(
+                Path::new(folder_path).join(format!(
+                    "L2GameDataName-{}.{}",
+                    self.main_localization().code(),
+                    format.extension()
+                )),
+                format,
+            )?;
         }
 
-        if all || self.game_string_table_eu.was_changed {
-            let _ = self
-                .game_string_table_eu
-                .save_to_ron(Path::new(folder_path).join("L2GameDataName-eu.ron"));
+        for (l, table) in &self.localized_game_string_tables {
+            if all || table.was_changed {
+                table.save_to_ron(
+                    Path::new(folder_path).join(format!(
+                        "L2GameDataName-{}.{}",
+                        l.code(),
+                        format.extension()
+                    )),
+                    format,
+                )?;
+            }
         }
 
         Ok(())
     }
-}
 
-impl GameDataHolder {
-    fn serialize_npc_strings_to_binary(&mut self) -> JoinHandle<(Log, Log)> {
-        let path_ru = self
-            .dat_paths
-            .get(&"npcstring-ru.dat".to_string())
-            .unwrap()
-            .clone();
-        let data_ru: Vec<_> = self
-            .npc_strings
-            .values()
-            .map(|v| NpcStringDat {
-                id: v.id,
-                value: (&v.item.ru).into(),
-            })
-            .collect();
+    fn load_from_ron(&mut self, folder_path: &str) -> anyhow::Result<Vec<Log>> {
+        let folder = Path::new(folder_path);
+        let mut logs = vec![];
 
-        let path_eu = self
-            .dat_paths
-            .get(&"npcstring-eu.dat".to_string())
-            .unwrap()
-            .clone();
-        let data_eu: Vec<_> = self
-            .npc_strings
-            .values()
-            .map(|v| NpcStringDat {
-                id: v.id,
-                value: (&v.item.eu).into(),
-            })
-            .collect();
+        for e in GameEntity::iter() {
+            let path = folder.join(e.to_string());
 
-        thread::spawn(move || {
-            let log_ru = if let Err(e) = save_dat(
-                path_ru.path(),
-                DatVariant::<(), NpcStringDat>::Array(data_ru),
-            ) {
-                Log::from_loader_e(e)
-            } else {
-                Log::from_loader_i("Npc Strings RU saved")
+            if !path.is_dir() {
+                anyhow::bail!("No {e} folder in {folder_path}");
+            }
+
+            let count = match e {
+                GameEntity::Npc => load_from_ron_limited(&mut self.npc_holder, &path)?,
+                GameEntity::Quest => load_from_ron_limited(&mut self.quest_holder, &path)?,
+                GameEntity::Skill => load_from_ron_limited(&mut self.skill_holder, &path)?,
+                GameEntity::Weapon => load_from_ron_limited(&mut self.weapon_holder, &path)?,
+                GameEntity::Armor => load_from_ron_limited(&mut self.armor_holder, &path)?,
+                GameEntity::EtcItem => load_from_ron_limited(&mut self.etc_item_holder, &path)?,
+                GameEntity::ItemSet => load_from_ron_limited(&mut self.item_set_holder, &path)?,
+                GameEntity::Recipe => load_from_ron_limited(&mut self.recipe_holder, &path)?,
+                GameEntity::HuntingZone => {
+                    load_from_ron_limited(&mut self.hunting_zone_holder, &path)?
+                }
+                GameEntity::Region => load_from_ron_limited(&mut self.region_holder, &path)?,
+                GameEntity::RaidInfo => load_from_ron_limited(&mut self.raid_info_holder, &path)?,
+                GameEntity::DailyMission => {
+                    load_from_ron_limited(&mut self.daily_mission_holder, &path)?
+                }
+                GameEntity::AnimationCombo => {
+                    load_from_ron_limited(&mut self.animation_combo_holder, &path)?
+                }
+                GameEntity::Residence => load_from_ron_limited(&mut self.residence_holder, &path)?,
+                GameEntity::EnsoulOption => {
+                    load_from_ron_limited(&mut self.ensoul_option_holder, &path)?
+                }
+                GameEntity::Henna => load_from_ron_limited(&mut self.henna_holder, &path)?,
+                GameEntity::UserAction => {
+                    load_from_ron_limited(&mut self.user_action_holder, &path)?
+                }
+                GameEntity::Product => load_from_ron_limited(&mut self.product_holder, &path)?,
+                GameEntity::ClassInfo => load_from_ron_limited(&mut self.class_info_holder, &path)?,
             };
 
-            let log_eu = if let Err(e) = save_dat(
-                path_eu.path(),
-                DatVariant::<(), NpcStringDat>::Array(data_eu),
-            ) {
-                Log::from_loader_e(e)
-            } else {
-                Log::from_loader_i("Npc Strings EU saved")
+            logs.push(Log::from_loader_i(&format!("{e}: {count} loaded")));
+        }
+
+        for e in Dictionary::iter() {
+            let path = DumpFormat::find(folder, &e.to_string());
+
+            let count = match e {
+                Dictionary::SystemStrings => {
+                    load_from_ron(&mut self.system_strings, &path, |v| v.id)?
+                }
+                Dictionary::NpcStrings => load_from_ron(&mut self.npc_strings, &path, |v| v.id)?,
+                Dictionary::GameTips => load_from_ron(&mut self.game_tips, &path, |v| v.id)?,
             };
 
-            (log_ru, log_eu)
-        })
-    }
+            logs.push(Log::from_loader_i(&format!("{e}: {count} loaded")));
+        }
 
-    fn load_npc_strings(&mut self) -> Result<Vec<Log>, ()> {
-        let mut warn = vec![];
+        for entry in std::fs::read_dir(folder)? {
+            let path = entry?.path();
 
-        let vals_ru = wrap_into_id_map(deserialize_dat::<NpcStringDat>(
-            self.dat_paths
-                .get(&"npcstring-ru.dat".to_string())
-                .unwrap()
-                .path(),
-        )?);
-
-        let vals_eu = wrap_into_id_map(deserialize_dat::<NpcStringDat>(
-            self.dat_paths
-                .get(&"npcstring-eu.dat".to_string())
-                .unwrap()
-                .path(),
-        )?);
-
-        for (key, v) in vals_ru {
-            let eu = if let Some(v) = vals_eu.get(&key) {
-                v.value.to_string()
+            let Some(code) = path
+                .file_stem()
+                .and_then(|v| v.to_str())
+                .and_then(|v| v.strip_prefix("L2GameDataName-"))
+                .filter(|_| DumpFormat::from_path(&path).is_some())
+            else {
+                continue;
+            };
+
+            let l = Localization::new(code);
+            let table = L2GeneralStringTable::load_from_ron(&path)?;
+
+            if l == self.main_localization() {
+                self.game_string_table = table;
+            } else if self.localizations().contains(&l) {
+                self.localized_game_string_tables.insert(l, table);
             } else {
-                warn.push(Log::from_loader_e(format!(
-                    "No value [{}] with id {key} in eu",
-                    v.value
+                logs.push(Log::from_loader_e(format!(
+                    "{path:?} skipped: no {l} dats in system folder"
                 )));
+            }
+        }
 
-                "NOT EXISTS".to_string()
-            };
+        self.refill_all_items();
+
+        Ok(logs)
+    }
+}
+
+impl GameDataHolder {
+    fn serialize_npc_strings_to_binary(&mut self) -> JoinHandle<Vec<Log>> {
+        let dats = self
+            .localized_dat_paths("npcstring")
+            .into_iter()
+            .map(|(l, path)| {
+                let data = self
+                    .npc_strings
+                    .values()
+                    .map(|v| NpcStringDat {
+                        id: v.id,
+                        value: (&v.item[l]).into(),
+                    })
+                    .collect();
+
+                (l, path, data)
+            })
+            .collect();
+
+        save_localized_dats(dats, "Npc Strings")
+    }
+
+    fn load_npc_strings(&mut self) -> Result<Vec<Log>, Log> {
+        let mut warn = vec![];
+
+        let vals = self.load_localized_dat::<NpcStringDat>("npcstring")?;
+
+        for v in &vals.main {
+            for (l, others) in &vals.others {
+                if !others.contains_key(&v.id) {
+                    warn.push(Log::from_loader_e(format!(
+                        "No value [{}] with id {} in {l}",
+                        v.value, v.id
+                    )));
+                }
+            }
 
             self.npc_strings.insert(
                 v.id,
-                DictItem::new(v.id, Localized::from((v.value.to_string(), eu))),
+                DictItem::new(
+                    v.id,
+                    vals.localize(v, |v| v.value.to_string(), "NOT EXISTS".to_string()),
+                ),
             );
         }
 
         Ok(warn)
     }
 
-    fn serialize_sys_strings_to_binary(&mut self) -> JoinHandle<(Log, Log)> {
-        let path_ru = self
-            .dat_paths
-            .get(&"sysstring-ru.dat".to_string())
-            .unwrap()
-            .clone();
-        let data_ru: Vec<_> = self
-            .system_strings
-            .values()
-            .map(|v| SysStringDat {
-                id: v.id,
-                value: (&v.item.ru).into(),
-            })
-            .collect();
-
-        let path_eu = self
-            .dat_paths
-            .get(&"sysstring-eu.dat".to_string())
-            .unwrap()
-            .clone();
-        let data_eu: Vec<_> = self
-            .system_strings
-            .values()
-            .map(|v| SysStringDat {
-                id: v.id,
-                value: (&v.item.eu).into(),
+    fn serialize_sys_strings_to_binary(&mut self) -> JoinHandle<Vec<Log>> {
+        let dats = self
+            .localized_dat_paths("sysstring")
+            .into_iter()
+            .map(|(l, path)| {
+                let data = self
+                    .system_strings
+                    .values()
+                    .map(|v| SysStringDat {
+                        id: v.id,
+                        value: (&v.item[l]).into(),
+                    })
+                    .collect();
+
+                (l, path, data)
             })
             .collect();
 
-        thread::spawn(move || {
-            let ru = if let Err(e) = save_dat(
-                path_ru.path(),
-                DatVariant::<(), SysStringDat>::Array(data_ru),
-            ) {
-                Log::from_loader_e(e)
-            } else {
-                Log::from_loader_i("Sys Strings RU saved")
-            };
-
-            let eu = if let Err(e) = save_dat(
-                path_eu.path(),
-                DatVariant::<(), SysStringDat>::Array(data_eu),
-            ) {
-                Log::from_loader_e(e)
-            } else {
-                Log::from_loader_i("Sys Strings EU saved")
-            };
-
-            (ru, eu)
-        })
+        save_localized_dats(dats, "Sys Strings")
     }
-    fn load_sys_strings(&mut self) -> Result<Vec<Log>, ()> {
+
+    fn load_sys_strings(&mut self) -> Result<Vec<Log>, Log> {
         let mut warn = vec![];
 
-        let vals_ru = wrap_into_id_map(deserialize_dat::<SysStringDat>(
-            self.dat_paths
-                .get(&"sysstring-ru.dat".to_string())
-                .unwrap()
-                .path(),
-        )?);
-        let vals_eu = wrap_into_id_map(deserialize_dat::<SysStringDat>(
-            self.dat_paths
-                .get(&"sysstring-eu.dat".to_string())
-                .unwrap()
-                .path(),
-        )?);
-
-        for (key, v) in vals_ru {
-            let eu = if let Some(v) = vals_eu.get(&key) {
-                v.value.to_string()
-            } else {
-                warn.push(Log::from_loader_e(format!(
-                    "No value [{}] with id {key} in eu",
-                    v.value
-                )));
+        let vals = self.load_localized_dat::<SysStringDat>("sysstring")?;
 
-                "NOT EXISTS".to_string()
-            };
+        for v in &vals.main {
+            for (l, others) in &vals.others {
+                if !others.contains_key(&v.id) {
+                    warn.push(Log::from_loader_e(format!(
+                        "No value [{}] with id {} in {l}",
+                        v.value, v.id
+                    )));
+                }
+            }
 
             self.system_strings.insert(
                 v.id,
-                DictItem::new(v.id, Localized::from((v.value.to_string(), eu))),
+                DictItem::new(
+                    v.id,
+                    vals.localize(v, |v| v.value.to_string(), "NOT EXISTS".to_string()),
+                ),
             );
         }
 
@@ -714,14 +838,14 @@ impl GameDataHolder {
 }
 
 impl GameDataHolder {
-    fn load_game_data_name(path: &Path) -> Result<L2GeneralStringTable, ()> {
+    fn load_game_data_name(path: &Path) -> Result<L2GeneralStringTable, Log> {
         match deserialize_dat(path) {
             Ok(r) => Ok(L2GeneralStringTable::from_vec(r)),
             Err(e) => Err(e),
         }
     }
 
-    fn refill_all_items(&mut self) {
+    pub(crate) fn refill_all_items(&mut self) {
         self.item_holder.clear();
 
         self.item_holder
@@ -7,14 +7,18 @@ use crate::entity::npc::{
 };
 
 use l2_rw::ue2_rw::{ASCF, BYTE, DOUBLE, DWORD, FLOAT, USHORT, UVEC};
-use l2_rw::{DatVariant, deserialize_dat, save_dat};
+use crate::backend::dat_loader::deserialize_dat;
+use l2_rw::DatVariant;
 
 use l2_rw::ue2_rw::{ReadUnreal, UnrealReader, UnrealWriter, WriteUnreal};
 
 use crate::backend::Localization;
-use crate::backend::dat_loader::{GetId, wrap_into_id_map, wrap_into_id_vec_map};
+use crate::backend::dat_loader::{
+    GetId, NOT_EXIST, save_localized_dats, wrap_into_id_map, wrap_into_id_vec_map, write_dat,
+};
 use crate::backend::holder::{GameDataHolder, HolderMapOps};
 use crate::backend::log_holder::{Log, LogLevel};
+use crate::backend::util::Localized;
 use eframe::egui::Color32;
 use r#macro::{ReadUnreal, WriteUnreal};
 use num_traits::{FromPrimitive, ToPrimitive};
@@ -195,7 +199,6 @@ impl GameDataHolder {
 
         let mut npc_grp: Vec<NpcGrpDat> = vec![];
         let mut additional_npc_parts_grp: Vec<AdditionalNpcGrpPartsDat> = vec![];
-        let mut npc_name_ru: Vec<NpcNameDat> = vec![];
         let mut mob_skill_anim: Vec<MobSkillAnimGrpDat> = vec![];
 
         let npc_grp_path = self
@@ -210,12 +213,6 @@ impl GameDataHolder {
             .unwrap()
             .clone();
 
-        let npc_name_path_ru = self
-            .dat_paths
-            .get(&"npcname-ru.dat".to_string())
-            .unwrap()
-            .clone();
-
         let mob_skill_anim_path = self
             .dat_paths
             .get(&"mobskillanimgrp.dat".to_string())
@@ -223,36 +220,36 @@ impl GameDataHolder {
             .clone();
 
         for npc in self.npc_holder.values().filter(|v| !v._deleted) {
-            npc_grp.push((npc, &mut self.game_string_table_ru).into());
+            npc_grp.push((npc, &mut self.game_string_table).into());
 
-            if let Some(v) = AdditionalNpcGrpPartsDat::from((npc, &mut self.game_string_table_ru)) {
+            if let Some(v) = AdditionalNpcGrpPartsDat::from((npc, &mut self.game_string_table)) {
                 additional_npc_parts_grp.push(v);
             }
 
-            npc_name_ru.push(NpcNameDat::from_entity(npc, Localization::RU));
-
-            mob_skill_anim.extend(MobSkillAnimGrpDat::from((
-                npc,
-                &mut self.game_string_table_ru,
-            )));
+            mob_skill_anim.extend(MobSkillAnimGrpDat::from((npc, &mut self.game_string_table)));
         }
 
-        let eu = if let Some(path) = self.dat_paths.get(&"npcname-eu.dat".to_string()) {
-            Some((
-                path.clone(),
-                self.npc_holder
-                    .values()
-                    .filter(|v| !v._deleted)
-                    .map(|v| NpcNameDat::from_entity(v, Localization::EU))
-                    .collect::<Vec<_>>(),
-            ))
-        } else {
-            None
-        };
+        let npc_names = self
+            .localized_dat_paths("npcname")
+            .into_iter()
+            .map(|(l, path)| {
+                (
+                    l,
+                    path,
+                    self.npc_holder
+                        .values()
+                        .filter(|v| !v._deleted)
+                        .map(|v| NpcNameDat::from_entity(v, l))
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .collect();
+
+        let npc_name_handle = save_localized_dats(npc_names, "NpcName");
 
         thread::spawn(move || {
             let npc_grp_handle = thread::spawn(move || {
-                if let Err(e) = save_dat(
+                if let Err(e) = write_dat(
                     npc_grp_path.path(),
                     DatVariant::<(), NpcGrpDat>::Array(npc_grp),
                 ) {
@@ -262,7 +259,7 @@ impl GameDataHolder {
                 }
             });
             let additional_npc_parts_handle = thread::spawn(move || {
-                if let Err(e) = save_dat(
+                if let Err(e) = write_dat(
                     additional_npc_parts_path.path(),
                     DatVariant::<(), AdditionalNpcGrpPartsDat>::Array(additional_npc_parts_grp),
                 ) {
@@ -271,32 +268,8 @@ impl GameDataHolder {
                     Log::from_loader_i("AdditionalNpcPartsGrp saved")
                 }
             });
-            let npc_name_handle = thread::spawn(move || {
-                let mut log = vec![if let Err(e) = save_dat(
-                    npc_name_path_ru.path(),
-                    DatVariant::<(), NpcNameDat>::Array(npc_name_ru),
-                ) {
-                    Log::from_loader_e(e)
-                } else {
-                    Log::from_loader_i("NpcName saved")
-                }];
-
-                if let Some((dir, dats)) = eu {
-                    log.push(
-                        if let Err(e) =
-                            save_dat(dir.path(), DatVariant::<(), NpcNameDat>::Array(dats))
-                        {
-                            Log::from_loader_e(e)
-                        } else {
-                            Log::from_loader_i("NpcName saved")
-                        },
-                    )
-                }
-
-                log
-            });
             let mob_skill_anim_handle = thread::spawn(move || {
-                if let Err(e) = save_dat(
+                if let Err(e) = write_dat(
                     mob_skill_anim_path.path(),
                     DatVariant::<(), MobSkillAnimGrpDat>::Array(mob_skill_anim),
                 ) {
@@ -315,7 +288,7 @@ impl GameDataHolder {
         })
     }
 
-    pub fn load_npcs(&mut self) -> Result<Vec<Log>, ()> {
+    pub fn load_npcs(&mut self) -> Result<Vec<Log>, Log> {
         let npc_grp = deserialize_dat::<NpcGrpDat>(
             self.dat_paths
                 .get(&"npcgrp.dat".to_string())
@@ -331,18 +304,8 @@ impl GameDataHolder {
                     .path(),
             )?);
 
-        let npc_name_ru = wrap_into_id_map(deserialize_dat::<NpcNameDat>(
-            self.dat_paths
-                .get(&"npcname-ru.dat".to_string())
-                .unwrap()
-                .path(),
-        )?);
-
-        let npc_name_eu = if let Some(path) = self.dat_paths.get(&"npcname-eu.dat".to_string()) {
-            wrap_into_id_map(deserialize_dat::<NpcNameDat>(path.path())?)
-        } else {
-            HashMap::new()
-        };
+        let mut npc_names = self.load_localized_dat::<NpcNameDat>("npcname")?;
+        let npc_name = wrap_into_id_map(std::mem::take(&mut npc_names.main));
 
         let mut mob_skill_anim = wrap_into_id_vec_map(deserialize_dat::<MobSkillAnimGrpDat>(
             self.dat_paths
@@ -351,11 +314,7 @@ impl GameDataHolder {
                 .path(),
         )?);
 
-        let default_npc_name_ru = NpcNameDat::default();
-
-        let mut default_npc_name_eu = NpcNameDat::default();
-        default_npc_name_eu.name = "NOT_EXIST".into();
-        default_npc_name_eu.title = "NOT_EXIST".into();
+        let default_npc_name = NpcNameDat::default();
 
         {
             let mut types = HashMap::new();
@@ -371,7 +330,7 @@ impl GameDataHolder {
         for npc in npc_grp {
             let id = npc.id as u32;
 
-            let npc_name_record_ru = if let Some(v) = npc_name_ru.get(&id) {
+            let npc_name_record = if let Some(v) = npc_name.get(&id) {
                 v
             } else {
                 warnings.push(Log {
@@ -383,37 +342,41 @@ impl GameDataHolder {
                     ),
                 });
 
-                &default_npc_name_ru
+                &default_npc_name
             };
 
-            let npc_name_record_eu = if let Some(v) = npc_name_eu.get(&id) {
-                v
-            } else {
-                &default_npc_name_eu
+            let localize = |f: fn(&NpcNameDat) -> String| {
+                Localized::from_fn_in(npc_names.localizations.iter().copied(), |l| {
+                    if l == npc_names.localizations[0] {
+                        f(npc_name_record)
+                    } else {
+                        npc_names.get(l, id).map_or(NOT_EXIST.to_string(), f)
+                    }
+                })
             };
 
             let mesh_params = WindowParams::new(NpcMeshParams {
-                mesh: self.game_string_table_ru.get_o(&npc.mesh),
+                mesh: self.game_string_table.get_o(&npc.mesh),
                 textures: npc
                     .texture_1
                     .iter()
-                    .map(|v| self.game_string_table_ru.get_o(v))
+                    .map(|v| self.game_string_table.get_o(v))
                     .collect(),
                 additional_textures: npc
                     .texture_2
                     .inner
                     .iter()
-                    .map(|v| self.game_string_table_ru.get_o(v))
+                    .map(|v| self.game_string_table.get_o(v))
                     .collect(),
                 decorations: npc
                     .deco_effect
                     .iter()
                     .map(|v| NpcDecorationEffect {
-                        effect: self.game_string_table_ru.get_o(&v.effect),
+                        effect: self.game_string_table.get_o(&v.effect),
                         scale: v.scale,
                     })
                     .collect(),
-                attack_effect: self.game_string_table_ru.get_o(&npc.attack_effect),
+                attack_effect: self.game_string_table.get_o(&npc.attack_effect),
                 speed: npc.npc_speed,
                 draw_scale: npc.draw_scale,
                 use_zoomincam: npc.use_zoom_in_cam,
@@ -429,23 +392,23 @@ impl GameDataHolder {
                 attack_sound: npc
                     .attack_sound
                     .iter()
-                    .map(|v| self.game_string_table_ru.get_o(v))
+                    .map(|v| self.game_string_table.get_o(v))
                     .collect(),
                 defence_sound: npc
                     .defence_sound
                     .iter()
-                    .map(|v| self.game_string_table_ru.get_o(v))
+                    .map(|v| self.game_string_table.get_o(v))
                     .collect(),
                 damage_sound: npc
                     .damage_sound
                     .iter()
-                    .map(|v| self.game_string_table_ru.get_o(v))
+                    .map(|v| self.game_string_table.get_o(v))
                     .collect(),
                 dialog_sound: npc
                     .dialog_sounds
                     .inner
                     .iter()
-                    .map(|v| self.game_string_table_ru.get_o(v))
+                    .map(|v| self.game_string_table.get_o(v))
                     .collect(),
                 vol: npc.sound_vol,
                 rad: npc.sound_radius,
@@ -472,7 +435,7 @@ impl GameDataHolder {
                         .iter()
                         .map(|v| NpcSkillAnimation {
                             id: SkillId(v.skill_id),
-                            animation: self.game_string_table_ru.get_o(&v.animation),
+                            animation: self.game_string_table.get_o(&v.animation),
                         })
                         .collect()
                 } else {
@@ -482,7 +445,7 @@ impl GameDataHolder {
             let additional_parts =
                 WindowParams::new(npc_additional_parts_grp.remove(&id).map(|parts| {
                     NpcAdditionalParts {
-                        class: self.game_string_table_ru.get_o(&parts.class),
+                        class: self.game_string_table.get_o(&parts.class),
                         chest: ItemId(parts.chest),
                         legs: ItemId(parts.legs),
                         gloves: ItemId(parts.gloves),
@@ -522,24 +485,16 @@ impl GameDataHolder {
 
             let npc = Npc {
                 id: NpcId(id),
-                name: (
-                    npc_name_record_ru.name.to_string(),
-                    npc_name_record_eu.name.to_string(),
-                )
-                    .into(),
-                title: (
-                    npc_name_record_ru.title.to_string(),
-                    npc_name_record_eu.title.to_string(),
-                )
-                    .into(),
+                name: localize(|v| v.name.to_string()),
+                title: localize(|v| v.title.to_string()),
                 title_color: Color32::from_rgba_unmultiplied(
-                    npc_name_record_ru.title_color.r,
-                    npc_name_record_ru.title_color.g,
-                    npc_name_record_ru.title_color.b,
-                    npc_name_record_ru.title_color.a,
+                    npc_name_record.title_color.r,
+                    npc_name_record.title_color.g,
+                    npc_name_record.title_color.b,
+                    npc_name_record.title_color.a,
                 ),
                 npc_type: npc.npc_type,
-                unreal_script_class: self.game_string_table_ru.get_o(&npc.unreal_class),
+                unreal_script_class: self.game_string_table.get_o(&npc.unreal_class),
                 mesh_params,
                 sound_params,
                 summon_params,
@@ -550,7 +505,7 @@ impl GameDataHolder {
                 show_hp: npc.show_hp == 1,
                 org_hp: npc.hp,
                 org_mp: npc.mp,
-                icon: self.game_string_table_ru.get_o(&npc.npc_icon),
+                icon: self.game_string_table.get_o(&npc.npc_icon),
                 additional_parts,
                 quest_infos,
                 ..Default::default()
@@ -0,0 +1,114 @@
+use crate::backend::log_holder::Log;
+
+use l2_rw::ue2_rw::{ASCF, DWORD};
+use l2_rw::ue2_rw::{ReadUnreal, UnrealReader, UnrealWriter, WriteUnreal};
+
+use crate::backend::Localization;
+use crate::backend::dat_loader::{GetId, NOT_EXIST, save_localized_dats};
+use crate::backend::holder::{GameDataHolder, HolderMapOps};
+use crate::entity::product::{Product, ProductItem};
+use r#macro::{ReadUnreal, WriteUnreal};
+use std::thread;
+use std::thread::JoinHandle;
+
+impl GameDataHolder {
+    fn product_as_dat_vec(&self, localization: Localization) -> Vec<ProductNameDat> {
+        self.product_holder
+            .values()
+            .filter(|v| !v._deleted)
+            .map(|v| ProductNameDat {
+                id: v.id.0,
+                name: (&v.name[localization]).into(),
+                icon: (&v.icon).into(),
+                category: v.category,
+                items: v
+                    .items
+                    .iter()
+                    .map(|i| ProductItemDat {
+                        item_id: i.item_id.0,
+                        count: i.count,
+                    })
+                    .collect(),
+            })
+            .collect()
+    }
+
+    pub fn serialize_product_to_binary(&mut self) -> JoinHandle<Vec<Log>> {
+        let mut logs = vec![];
+
+        for v in self.product_holder.values().filter(|v| !v._deleted) {
+            logs.extend(self.validate_product(v));
+        }
+
+        let dats = self
+            .localized_dat_paths("productname")
+            .into_iter()
+            .map(|(l, path)| (l, path, self.product_as_dat_vec(l)))
+            .collect();
+
+        let handle = save_localized_dats(dats, "Products");
+
+        thread::spawn(move || {
+            logs.extend(handle.join().unwrap());
+
+            logs
+        })
+    }
+
+    pub fn load_products(&mut self) -> Result<Vec<Log>, Log> {
+        if let Some(log) = self.missing_optional_dat("productname") {
+            return Ok(vec![log]);
+        }
+
+        let products = self.load_localized_dat::<ProductNameDat>("productname")?;
+
+        let mut logs = vec![];
+
+        for v in &products.main {
+            let product = Product {
+                id: v.id.into(),
+                name: products.localize(v, |v| v.name.to_string(), NOT_EXIST.to_string()),
+                icon: v.icon.to_string(),
+                category: v.category,
+                items: v
+                    .items
+                    .iter()
+                    .map(|i| ProductItem {
+                        item_id: i.item_id.into(),
+                        count: i.count,
+                    })
+                    .collect(),
+
+                _changed: false,
+                _deleted: false,
+            };
+
+            logs.extend(self.validate_product(&product));
+
+            self.product_holder.insert(product.id, product);
+        }
+
+        Ok(logs)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, ReadUnreal, WriteUnreal)]
+struct ProductItemDat {
+    item_id: DWORD,
+    count: DWORD,
+}
+
+#[derive(Debug, Clone, PartialEq, ReadUnreal, WriteUnreal)]
+struct ProductNameDat {
+    id: DWORD,
+    name: ASCF,
+    icon: ASCF,
+    category: DWORD,
+    items: Vec<ProductItemDat>,
+}
+
+impl GetId for ProductNameDat {
+    fn get_id(&self) -> DWORD {
+        self.id
+    }
+}
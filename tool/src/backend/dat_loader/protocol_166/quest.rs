@@ -1,154 +1,135 @@
 use crate::backend::dat_loader::protocol_166::CoordsXYZ;
-use crate::common::{HuntingZoneId, ItemId, NpcId, PlayerClass, QuestId};
+use crate::common::{ClassId, HuntingZoneId, ItemId, NpcId, QuestId};
 use crate::entity::quest::{
     GoalType, MarkType, Quest, QuestCategory, QuestReward, QuestStep, QuestType, StepGoal, Unk1,
     Unk2, UnkQLevel,
 };
 
+use crate::backend::dat_loader::deserialize_dat;
 use l2_rw::ue2_rw::{ASCF, DWORD, LONG};
-use l2_rw::{DatVariant, deserialize_dat, save_dat};
 
 use l2_rw::ue2_rw::{ReadUnreal, UnrealReader, UnrealWriter, WriteUnreal};
 
 use crate::backend::Localization;
+use crate::backend::dat_loader::{NOT_EXIST, save_localized_dats};
 use crate::backend::holder::{GameDataHolder, HolderMapOps};
 use crate::backend::log_holder::{Log, LogLevel};
+use crate::backend::util::Localized;
 use eframe::egui::Pos2;
 use r#macro::{ReadUnreal, WriteUnreal};
 use num_traits::{FromPrimitive, ToPrimitive};
-use std::thread;
 use std::thread::JoinHandle;
 
 impl GameDataHolder {
     pub fn serialize_quests_to_binary(&mut self) -> JoinHandle<Vec<Log>> {
-        let mut res = Vec::new();
-
         let mut vals: Vec<_> = self.quest_holder.values().filter(|v| !v._deleted).collect();
         vals.sort_by(|a, b| a.id.cmp(&b.id));
 
-        let eu = self
-            .dat_paths
-            .get(&"questname-eu.dat".to_string())
-            .map(|dir| {
+        let dats = self
+            .localized_dat_paths("questname")
+            .into_iter()
+            .map(|(l, path)| {
                 (
-                    dir.clone(),
+                    l,
+                    path,
                     vals.iter()
-                        .flat_map(|v| QuestNameDat::from_entity(v, Localization::EU))
+                        .flat_map(|v| QuestNameDat::from_entity(v, l))
                         .collect::<Vec<QuestNameDat>>(),
                 )
-            });
+            })
+            .collect();
 
-        for quest in vals {
-            for step in QuestNameDat::from_entity(quest, Localization::RU) {
-                res.push(step);
-            }
-        }
+        save_localized_dats(dats, "Quest Name")
+    }
+
+    pub fn load_quests(&mut self) -> Result<Vec<Log>, Log> {
+        let mut warnings = vec![];
 
-        let quest_path = self
+        let main = self.main_localization();
+
+        let Some(main_path) = self
             .dat_paths
-            .get(&"questname-ru.dat".to_string())
-            .unwrap()
-            .clone();
-
-        thread::spawn(move || {
-            let mut log = if let Err(e) = save_dat(
-                quest_path.path(),
-                DatVariant::<(), QuestNameDat>::Array(res),
-            ) {
-                vec![Log::from_loader_e(e)]
-            } else {
-                vec![Log::from_loader_i("Quest Name RU saved")]
-            };
+            .get(&format!("questname-{}.dat", main.code()))
+        else {
+            return Err(self.missing_dat("questname"));
+        };
 
-            if let Some((dir, dats)) = eu {
-                log.push(
-                    if let Err(e) =
-                        save_dat(dir.path(), DatVariant::<(), QuestNameDat>::Array(dats))
-                    {
-                        Log::from_loader_e(e)
-                    } else {
-                        Log::from_loader_i("Quest Name EU saved")
-                    },
-                );
-            }
+        let quest_name = deserialize_dat::<QuestNameDat>(main_path.path())?;
 
-            log
-        })
-    }
-    pub fn load_quests(&mut self) -> Result<Vec<Log>, ()> {
-        let mut warnings = vec![];
+        let mut quest_name_localized = vec![];
 
-        let quest_name_ru = deserialize_dat::<QuestNameDat>(
-            self.dat_paths
-                .get(&"questname-ru.dat".to_string())
-                .unwrap()
-                .path(),
-        )?;
+        for (l, path) in self.localized_dat_paths("questname") {
+            if l == main {
+                continue;
+            }
 
-        let quest_name_eu =
-            if let Some(eu_dir) = self.dat_paths.get(&"questname-eu.dat".to_string()) {
-                let dats = deserialize_dat::<QuestNameDat>(eu_dir.path())?;
+            let dats = deserialize_dat::<QuestNameDat>(path.path())?;
 
-                if dats.len() != quest_name_ru.len() {
-                    warnings.push(Log {
+            if dats.len() != quest_name.len() {
+                warnings.push(Log {
                     level: LogLevel::Error,
                     producer: "Quest Loader".to_string(),
                     log: format!(
-                        "Quest Name EU rows count({}) doesn't match Quest Name RU rows count({})",
+                        "Quest Name {l} rows count({}) doesn't match Quest Name {main} rows count({})",
                         dats.len(),
-                        quest_name_ru.len()
+                        quest_name.len()
                     ),
                 });
-                    None
-                } else {
-                    Some(dats)
-                }
             } else {
-                None
-            };
+                quest_name_localized.push((l, dats));
+            }
+        }
 
-        let mut current_id = if let Some(v) = quest_name_ru.first() {
+        let mut current_id = if let Some(v) = quest_name.first() {
             v.id
         } else {
             0u32
         };
 
-        let mut current_steps_ru = Vec::new();
-        let mut current_steps_eu = Vec::new();
+        let mut current_steps = Vec::new();
+        let mut current_steps_localized: Vec<_> = quest_name_localized
+            .iter()
+            .map(|(l, _)| (*l, Vec::new()))
+            .collect();
 
-        for (i, v) in quest_name_ru.iter().enumerate() {
+        for (i, v) in quest_name.iter().enumerate() {
             if v.id != current_id {
-                warnings.extend(self.construct_quest(&current_steps_ru, &current_steps_eu));
-                current_steps_ru.clear();
-                current_steps_eu.clear();
+                warnings.extend(self.construct_quest(&current_steps, &current_steps_localized));
+                current_steps.clear();
+
+                for (_, steps) in &mut current_steps_localized {
+                    steps.clear();
+                }
 
                 current_id = v.id;
             }
 
-            current_steps_ru.push(v);
+            current_steps.push(v);
 
-            if let Some(eu) = &quest_name_eu {
-                current_steps_eu.push(&eu[i])
+            for (j, (_, dats)) in quest_name_localized.iter().enumerate() {
+                current_steps_localized[j].1.push(&dats[i]);
             }
         }
 
-        warnings.extend(self.construct_quest(&current_steps_ru, &current_steps_eu));
+        warnings.extend(self.construct_quest(&current_steps, &current_steps_localized));
 
         Ok(warnings)
     }
 
     pub fn construct_quest(
         &mut self,
-        current_steps_ru: &[&QuestNameDat],
-        current_steps_eu: &[&QuestNameDat],
+        current_steps: &[&QuestNameDat],
+        current_steps_localized: &[(Localization, Vec<&QuestNameDat>)],
     ) -> Vec<Log> {
         let mut warnings = vec![];
 
-        if current_steps_ru.is_empty() {
+        if current_steps.is_empty() {
             return warnings;
         }
 
-        let steps = current_steps_ru
+        let localizations = self.localizations();
+
+        let steps = current_steps
             .iter()
             .enumerate()
             .map(|(i, v)| {
@@ -169,7 +150,7 @@ impl GameDataHolder {
                 let mut prev_steps = vec![];
 
                 for i in &v.pre_level {
-                    if let Some((idx, _)) = current_steps_ru
+                    if let Some((idx, _)) = current_steps
                         .iter()
                         .enumerate()
                         .find(|(_, v)| v.level == *i)
@@ -179,27 +160,27 @@ impl GameDataHolder {
                 }
 
                 QuestStep {
-                    title: (
-                        v.sub_name.to_string(),
-                        current_steps_eu
-                            .get(i)
-                            .map_or("NOT_EXIST".to_string(), |v| v.sub_name.to_string()),
-                    )
-                        .into(),
-                    label: (
-                        v.entity_name.to_string(),
-                        current_steps_eu
-                            .get(i)
-                            .map_or("NOT_EXIST".to_string(), |v| v.entity_name.to_string()),
-                    )
-                        .into(),
-                    desc: (
-                        v.desc.to_string(),
-                        current_steps_eu
-                            .get(i)
-                            .map_or("NOT_EXIST".to_string(), |v| v.desc.to_string()),
-                    )
-                        .into(),
+                    title: localize_step(
+                        &localizations,
+                        current_steps,
+                        current_steps_localized,
+                        i,
+                        |v| v.sub_name.to_string(),
+                    ),
+                    label: localize_step(
+                        &localizations,
+                        current_steps,
+                        current_steps_localized,
+                        i,
+                        |v| v.entity_name.to_string(),
+                    ),
+                    desc: localize_step(
+                        &localizations,
+                        current_steps,
+                        current_steps_localized,
+                        i,
+                        |v| v.desc.to_string(),
+                    ),
                     goals,
                     location: v.target_loc.into(),
                     additional_locations: v
@@ -223,16 +204,15 @@ impl GameDataHolder {
             })
             .collect();
 
-        let first_ru = current_steps_ru[0];
-        let first_eu = current_steps_eu.first();
+        let first = current_steps[0];
 
-        let rewards = first_ru
+        let rewards = first
             .reward_ids
             .iter()
             .enumerate()
             .map(|(i, v)| QuestReward {
                 reward_id: ItemId(*v),
-                count: if let Some(v) = first_ru.reward_nums.get(i) {
+                count: if let Some(v) = first.reward_nums.get(i) {
                     *v
                 } else {
                     warnings.push(Log {
@@ -240,7 +220,7 @@ impl GameDataHolder {
                         producer: "Quest Loader".to_string(),
                         log: format!(
                             "Quest[{}]: no reward count for item[{}]. Set to 0",
-                            first_ru.id, v
+                            first.id, v
                         ),
                     });
                     0
@@ -249,52 +229,50 @@ impl GameDataHolder {
             .collect();
 
         let x = Quest {
-            id: QuestId(first_ru.id),
-            title: (
-                first_ru.title.to_string(),
-                first_eu.map_or("NOT_EXIST".to_string(), |v| v.title.to_string()),
-            )
-                .into(),
-            intro: (
-                first_ru.intro.to_string(),
-                first_eu.map_or("NOT_EXIST".to_string(), |v| v.intro.to_string()),
-            )
-                .into(),
-            requirements: (
-                first_ru.requirements.to_string(),
-                first_eu.map_or("NOT_EXIST".to_string(), |v| v.requirements.to_string()),
-            )
-                .into(),
+            id: QuestId(first.id),
+            title: localize_step(
+                &localizations,
+                current_steps,
+                current_steps_localized,
+                0,
+                |v| v.title.to_string(),
+            ),
+            intro: localize_step(
+                &localizations,
+                current_steps,
+                current_steps_localized,
+                0,
+                |v| v.intro.to_string(),
+            ),
+            requirements: localize_step(
+                &localizations,
+                current_steps,
+                current_steps_localized,
+                0,
+                |v| v.requirements.to_string(),
+            ),
             steps,
-            quest_type: QuestType::from_u32(first_ru.quest_type).unwrap(),
-            category: QuestCategory::from_u32(first_ru.category).unwrap(),
-            mark_type: MarkType::from_u32(first_ru.mark_type)
-                .unwrap_or_else(|| panic!("unknown mark type {}", first_ru.mark_type)),
-            min_lvl: first_ru.lvl_min,
-            max_lvl: first_ru.lvl_max,
-            allowed_classes: if first_ru.class_limit.is_empty() {
+            quest_type: QuestType::from_u32(first.quest_type).unwrap(),
+            category: QuestCategory::from_u32(first.category).unwrap(),
+            mark_type: MarkType::from_u32(first.mark_type)
+                .unwrap_or_else(|| panic!("unknown mark type {}", first.mark_type)),
+            min_lvl: first.lvl_min,
+            max_lvl: first.lvl_max,
+            allowed_classes: if first.class_limit.is_empty() {
                 None
             } else {
-                Some(
-                    first_ru
-                        .class_limit
-                        .iter()
-                        .map(|v| {
-                            PlayerClass::from_u32(*v).unwrap_or_else(|| panic!("Unk type: {v}"))
-                        })
-                        .collect(),
-                )
+                Some(first.class_limit.iter().map(|v| ClassId(*v)).collect())
             },
-            required_completed_quest_id: QuestId(first_ru.cleared_quest),
-            search_zone_id: HuntingZoneId(first_ru.search_zone_id),
-            _is_clan_pet_quest: first_ru.clan_pet_quest == 1,
-            start_npc_loc: first_ru.start_npc_loc.into(),
-            start_npc_ids: first_ru.start_npc_ids.iter().map(|v| NpcId(*v)).collect(),
+            required_completed_quest_id: QuestId(first.cleared_quest),
+            search_zone_id: HuntingZoneId(first.search_zone_id),
+            _is_clan_pet_quest: first.clan_pet_quest == 1,
+            start_npc_loc: first.start_npc_loc.into(),
+            start_npc_ids: first.start_npc_ids.iter().map(|v| NpcId(*v)).collect(),
             rewards,
-            quest_items: first_ru.quest_items.iter().map(|v| ItemId(*v)).collect(),
-            _faction_id: first_ru.faction_id,
-            _faction_level_min: first_ru.faction_level_min,
-            _faction_level_max: first_ru.faction_level_max,
+            quest_items: first.quest_items.iter().map(|v| ItemId(*v)).collect(),
+            _faction_id: first.faction_id,
+            _faction_level_min: first.faction_level_min,
+            _faction_level_max: first.faction_level_max,
 
             java_class: None,
             ..Default::default()
@@ -306,6 +284,26 @@ impl GameDataHolder {
     }
 }
 
+fn localize_step(
+    localizations: &[Localization],
+    steps: &[&QuestNameDat],
+    localized_steps: &[(Localization, Vec<&QuestNameDat>)],
+    i: usize,
+    f: impl Fn(&QuestNameDat) -> String,
+) -> Localized<String> {
+    Localized::from_fn_in(localizations.iter().copied(), |l| {
+        if l == localizations[0] {
+            return f(steps[i]);
+        }
+
+        localized_steps
+            .iter()
+            .find(|(v, _)| *v == l)
+            .and_then(|(_, v)| v.get(i))
+            .map_or(NOT_EXIST.to_string(), |v| f(v))
+    })
+}
+
 #[derive(Debug, Clone, PartialEq, ReadUnreal, WriteUnreal, Default)]
 pub struct QuestNameDat {
     tag: DWORD,
@@ -406,7 +404,7 @@ impl QuestNameDat {
                 requirements: (&quest.requirements[localization]).into(),
                 intro: (&quest.intro[localization]).into(),
                 class_limit: if let Some(c) = &quest.allowed_classes {
-                    c.iter().map(|v| v.to_u32().unwrap()).collect()
+                    c.iter().map(|v| v.0).collect()
                 } else {
                     vec![]
                 },
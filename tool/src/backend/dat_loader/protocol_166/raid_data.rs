@@ -1,18 +1,15 @@
 use crate::backend::log_holder::Log;
-use std::collections::HashMap;
 
 use l2_rw::ue2_rw::{ASCF, BYTE, DWORD, FLOAT};
-use l2_rw::{DatVariant, deserialize_dat, save_dat};
 
 use l2_rw::ue2_rw::{ReadUnreal, UnrealReader, UnrealWriter, WriteUnreal};
 
 use crate::backend::Localization;
-use crate::backend::dat_loader::{GetId, wrap_into_id_map};
+use crate::backend::dat_loader::{GetId, NOT_EXIST, save_localized_dats};
 use crate::backend::holder::{GameDataHolder, HolderMapOps};
 use crate::common::Position;
 use crate::entity::raid_info::RaidInfo;
 use r#macro::{ReadUnreal, WriteUnreal};
-use std::thread;
 use std::thread::JoinHandle;
 
 impl GameDataHolder {
@@ -36,60 +33,19 @@ impl GameDataHolder {
     }
 
     pub fn serialize_raid_data_to_binary(&mut self) -> JoinHandle<Vec<Log>> {
-        let raid_grp = self.as_dat(Localization::RU);
+        let dats = self
+            .localized_dat_paths("raiddata")
+            .into_iter()
+            .map(|(l, path)| (l, path, self.as_dat(l)))
+            .collect();
 
-        let dat_path_ru = self
-            .dat_paths
-            .get(&"raiddata-ru.dat".to_string())
-            .unwrap()
-            .clone();
-
-        let eu = self
-            .dat_paths
-            .get(&"raiddata-eu.dat".to_string())
-            .cloned()
-            .map(|dir| (self.as_dat(Localization::EU), dir));
-
-        thread::spawn(move || {
-            let mut log = vec![if let Err(e) = save_dat(
-                dat_path_ru.path(),
-                DatVariant::<(), RaidDataDat>::Array(raid_grp),
-            ) {
-                Log::from_loader_e(e)
-            } else {
-                Log::from_loader_i("RaidData RU saved")
-            }];
-
-            if let Some((dats, dir)) = eu {
-                log.push(
-                    if let Err(e) = save_dat(dir.path(), DatVariant::<(), RaidDataDat>::Array(dats))
-                    {
-                        Log::from_loader_e(e)
-                    } else {
-                        Log::from_loader_i("RaidData EU saved")
-                    },
-                )
-            }
-
-            log
-        })
+        save_localized_dats(dats, "RaidData")
     }
 
-    pub fn load_raid_data(&mut self) -> Result<Vec<Log>, ()> {
-        let raid_grp_ru = deserialize_dat::<RaidDataDat>(
-            self.dat_paths
-                .get(&"raiddata-ru.dat".to_string())
-                .unwrap()
-                .path(),
-        )?;
-
-        let raid_grp_eu = if let Some(dir) = self.dat_paths.get(&"raiddata-eu.dat".to_string()) {
-            wrap_into_id_map(deserialize_dat::<RaidDataDat>(dir.path())?)
-        } else {
-            HashMap::new()
-        };
+    pub fn load_raid_data(&mut self) -> Result<Vec<Log>, Log> {
+        let raid_grp = self.load_localized_dat::<RaidDataDat>("raiddata")?;
 
-        for v in raid_grp_ru {
+        for v in &raid_grp.main {
             self.raid_info_holder.insert(
                 v.id.into(),
                 RaidInfo {
@@ -102,13 +58,7 @@ impl GameDataHolder {
                         y: v.y,
                         z: v.z,
                     },
-                    desc: (
-                        v.desc.to_string(),
-                        raid_grp_eu
-                            .get(&v.id)
-                            .map_or("NOT_EXIST".to_string(), |v| v.desc.to_string()),
-                    )
-                        .into(),
+                    desc: raid_grp.localize(v, |v| v.desc.to_string(), NOT_EXIST.to_string()),
                     recommended_level_min: v.recommended_level_min,
                     recommended_level_max: v.recommended_level_max,
 
@@ -3,11 +3,12 @@ use crate::backend::log_holder::Log;
 use crate::entity::recipe::{Recipe, RecipeMaterial};
 
 use l2_rw::ue2_rw::{ASCF, DWORD, UVEC};
-use l2_rw::{DatVariant, deserialize_dat, save_dat};
+use crate::backend::dat_loader::deserialize_dat;
+use l2_rw::DatVariant;
 
 use l2_rw::ue2_rw::{ReadUnreal, UnrealReader, UnrealWriter, WriteUnreal};
 
-use crate::backend::dat_loader::GetId;
+use crate::backend::dat_loader::{GetId, write_dat};
 use crate::backend::holder::{GameDataHolder, HolderMapOps};
 use r#macro::{ReadUnreal, WriteUnreal};
 use std::thread;
@@ -47,7 +48,7 @@ impl GameDataHolder {
         let mut set_grp: Vec<RecipeDat> = vec![];
 
         for set in self.recipe_holder.values().filter(|v| !v._deleted) {
-            set_grp.push((set, &mut self.game_string_table_ru).into());
+            set_grp.push((set, &mut self.game_string_table).into());
         }
 
         let set_grp_path = self
@@ -57,7 +58,7 @@ impl GameDataHolder {
             .clone();
 
         thread::spawn(move || {
-            if let Err(e) = save_dat(
+            if let Err(e) = write_dat(
                 set_grp_path.path(),
                 DatVariant::<(), RecipeDat>::Array(set_grp.to_vec()),
             ) {
@@ -68,7 +69,7 @@ impl GameDataHolder {
         })
     }
 
-    pub fn load_recipes(&mut self) -> Result<Vec<Log>, ()> {
+    pub fn load_recipes(&mut self) -> Result<Vec<Log>, Log> {
         let set_grp = deserialize_dat::<RecipeDat>(
             self.dat_paths
                 .get(&"recipe.dat".to_string())
@@ -1,18 +1,15 @@
 use crate::backend::log_holder::Log;
-use std::collections::HashMap;
 
 use l2_rw::ue2_rw::{ASCF, DWORD, FLOAT, INT, SHORT, USHORT};
-use l2_rw::{DatVariant, deserialize_dat, save_dat};
 
 use l2_rw::ue2_rw::{ReadUnreal, UnrealReader, UnrealWriter, WriteUnreal};
 
 use crate::backend::Localization;
-use crate::backend::dat_loader::{GetId, wrap_into_id_map};
+use crate::backend::dat_loader::{GetId, NOT_EXIST, save_localized_dats};
 use crate::backend::holder::{GameDataHolder, HolderMapOps, L2GeneralStringTable};
 use crate::entity::region::{Continent, MapInfo, Region};
 use r#macro::{ReadUnreal, WriteUnreal};
 use num_traits::{FromPrimitive, ToPrimitive};
-use std::thread;
 use std::thread::JoinHandle;
 
 impl From<(&Region, &mut L2GeneralStringTable, &MapInfo, Localization)> for ZoneNameDat {
@@ -75,90 +72,32 @@ impl GameDataHolder {
             texture: "None".into(),
         };
 
-        let zonenames_ru = zonenames
-            .iter()
-            .map(|v| {
-                (
-                    *v,
-                    &mut self.game_string_table_ru,
-                    &zone_map_info,
-                    Localization::RU,
-                )
-                    .into()
-            })
-            .collect();
+        let mut dats = vec![];
 
-        let zonename_path_ru = self
-            .dat_paths
-            .get(&"zonename-ru.dat".to_string())
-            .unwrap()
-            .clone();
-
-        let eu = self
-            .dat_paths
-            .get(&"zonename-eu.dat".to_string())
-            .cloned()
-            .map(|dir| {
-                (
-                    zonenames
-                        .iter()
-                        .map(|v| {
-                            (
-                                *v,
-                                &mut self.game_string_table_eu,
-                                &zone_map_info,
-                                Localization::EU,
-                            )
-                                .into()
-                        })
-                        .collect::<Vec<ZoneNameDat>>(),
-                    dir,
-                )
-            });
-
-        thread::spawn(move || {
-            let mut log = vec![if let Err(e) = save_dat(
-                zonename_path_ru.path(),
-                DatVariant::<(), ZoneNameDat>::Array(zonenames_ru),
-            ) {
-                Log::from_loader_e(e)
-            } else {
-                Log::from_loader_i("Mini Map Region RU saved")
-            }];
-
-            if let Some((dats, dir)) = eu {
-                log.push(
-                    if let Err(e) = save_dat(dir.path(), DatVariant::<(), ZoneNameDat>::Array(dats))
-                    {
-                        Log::from_loader_e(e)
-                    } else {
-                        Log::from_loader_i("Mini Map Region EU saved")
-                    },
-                );
-            }
+        for (l, path) in self.localized_dat_paths("zonename") {
+            let table = match self.localized_game_string_tables.get_mut(&l) {
+                Some(v) => v,
+                None => &mut self.game_string_table,
+            };
+
+            let zonenames_dat: Vec<ZoneNameDat> = zonenames
+                .iter()
+                .map(|v| (*v, &mut *table, &zone_map_info, l).into())
+                .collect();
 
-            log
-        })
+            dats.push((l, path, zonenames_dat));
+        }
+
+        save_localized_dats(dats, "Zone Name")
     }
 
-    pub fn load_regions(&mut self) -> Result<Vec<Log>, ()> {
+    pub fn load_regions(&mut self) -> Result<Vec<Log>, Log> {
         let warnings = vec![];
 
-        let zonename_ru = deserialize_dat::<ZoneNameDat>(
-            self.dat_paths
-                .get(&"zonename-ru.dat".to_string())
-                .unwrap()
-                .path(),
-        )?;
-
-        let zonename_eu = if let Some(dir) = self.dat_paths.get(&"zonename-eu.dat".to_string()) {
-            wrap_into_id_map(deserialize_dat::<ZoneNameDat>(dir.path())?)
-        } else {
-            HashMap::new()
-        };
+        let zonenames = self.load_localized_dat::<ZoneNameDat>("zonename")?;
 
-        for v in zonename_ru {
-            let map_texture = self.game_string_table_ru.get_o(&v.town_map_texture);
+        for v in &zonenames.main {
+            let map_texture = self.game_string_table.get_o(&v.town_map_texture);
 
             let map_info = if map_texture.to_lowercase() == "none" {
                 None
@@ -181,13 +120,7 @@ impl GameDataHolder {
                 v.id.into(),
                 Region {
                     id: v.id.into(),
-                    name: (
-                        v.name.to_string(),
-                        zonename_eu
-                            .get(&(v.id as u32))
-                            .map_or("NOT_EXIST".to_string(), |v| v.name.to_string()),
-                    )
-                        .into(),
+                    name: zonenames.localize(v, |v| v.name.to_string(), NOT_EXIST.to_string()),
                     world_map_square: [v.map_square_x, v.map_square_y],
                     z_range: [v.z_max, v.z_min],
                     map_info,
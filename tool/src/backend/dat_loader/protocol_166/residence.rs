@@ -1,16 +1,13 @@
 use l2_rw::ue2_rw::{ASCF, DWORD, USHORT};
-use l2_rw::{DatVariant, deserialize_dat, save_dat};
-use std::collections::HashMap;
 
 use l2_rw::ue2_rw::{ReadUnreal, UnrealReader, UnrealWriter, WriteUnreal};
 
 use crate::backend::Localization;
-use crate::backend::dat_loader::{GetId, wrap_into_id_map};
+use crate::backend::dat_loader::{GetId, NOT_EXIST, save_localized_dats};
 use crate::backend::holder::{GameDataHolder, HolderMapOps, L2GeneralStringTable};
 use crate::backend::log_holder::Log;
 use crate::entity::residence::Residence;
 use r#macro::{ReadUnreal, WriteUnreal};
-use std::thread;
 use std::thread::JoinHandle;
 
 impl From<(&Residence, &mut L2GeneralStringTable, Localization)> for CastleNameDat {
@@ -35,106 +32,42 @@ impl From<(&Residence, &mut L2GeneralStringTable, Localization)> for CastleNameD
 
 impl GameDataHolder {
     pub fn serialize_residence_to_binary(&mut self) -> JoinHandle<Vec<Log>> {
-        let residences_ru = self
-            .residence_holder
-            .values()
-            .map(|v| (v, &mut self.game_string_table_ru, Localization::RU).into())
-            .collect();
+        let mut dats = vec![];
 
-        let residence_path = self
-            .dat_paths
-            .get(&"castlename-ru.dat".to_string())
-            .unwrap()
-            .clone();
+        for (l, path) in self.localized_dat_paths("castlename") {
+            let table = match self.localized_game_string_tables.get_mut(&l) {
+                Some(v) => v,
+                None => &mut self.game_string_table,
+            };
 
-        let eu = if let Some(dir) = self
-            .dat_paths
-            .get(&"castlename-eu.dat".to_string())
-            .cloned()
-        {
-            Some((
-                self.residence_holder
-                    .values()
-                    .map(|v| (v, &mut self.game_string_table_eu, Localization::EU).into())
-                    .collect::<Vec<CastleNameDat>>(),
-                dir,
-            ))
-        } else {
-            None
-        };
+            let residences: Vec<CastleNameDat> = self
+                .residence_holder
+                .values()
+                .map(|v| (v, &mut *table, l).into())
+                .collect();
 
-        thread::spawn(move || {
-            let mut log = vec![if let Err(e) = save_dat(
-                residence_path.path(),
-                DatVariant::<(), CastleNameDat>::Array(residences_ru),
-            ) {
-                Log::from_loader_e(e)
-            } else {
-                Log::from_loader_i("Residences EU saved")
-            }];
-
-            if let Some((dats, dir)) = eu {
-                log.push(
-                    if let Err(e) =
-                        save_dat(dir.path(), DatVariant::<(), CastleNameDat>::Array(dats))
-                    {
-                        Log::from_loader_e(e)
-                    } else {
-                        Log::from_loader_i("Residences EU saved")
-                    },
-                )
-            }
+            dats.push((l, path, residences));
+        }
 
-            log
-        })
+        save_localized_dats(dats, "Residences")
     }
 
-    pub fn load_residences(&mut self) -> Result<Vec<Log>, ()> {
+    pub fn load_residences(&mut self) -> Result<Vec<Log>, Log> {
         let warnings = vec![];
 
-        let residences_ru = deserialize_dat::<CastleNameDat>(
-            self.dat_paths
-                .get(&"castlename-ru.dat".to_string())
-                .unwrap()
-                .path(),
-        )?;
-
-        let residences_eu = if let Some(dir) = self.dat_paths.get(&"castlename-eu.dat".to_string())
-        {
-            wrap_into_id_map(deserialize_dat::<CastleNameDat>(dir.path())?)
-        } else {
-            HashMap::new()
-        };
+        let residences = self.load_localized_dat::<CastleNameDat>("castlename")?;
 
-        for v in residences_ru {
+        for v in &residences.main {
             self.residence_holder.insert(
                 v.id.into(),
                 Residence {
                     id: v.id.into(),
-                    name: (
-                        v.name.to_string(),
-                        residences_eu
-                            .get(&{ v.id })
-                            .map_or("NOT_EXIST".to_string(), |v| v.name.to_string()),
-                    )
-                        .into(),
-                    desc: (
-                        v.desc.to_string(),
-                        residences_eu
-                            .get(&{ v.id })
-                            .map_or("NOT_EXIST".to_string(), |v| v.desc.to_string()),
-                    )
-                        .into(),
-                    territory: (
-                        v.loc.to_string(),
-                        residences_eu
-                            .get(&{ v.id })
-                            .map_or("NOT_EXIST".to_string(), |v| v.loc.to_string()),
-                    )
-                        .into(),
-                    mark: self.game_string_table_ru.get_o(&v.mark),
-                    mark_grey: self.game_string_table_ru.get_o(&v.mark_grey),
-                    flag_icon: self.game_string_table_ru.get_o(&v.flag_icon),
+                    name: residences.localize(v, |v| v.name.to_string(), NOT_EXIST.to_string()),
+                    desc: residences.localize(v, |v| v.desc.to_string(), NOT_EXIST.to_string()),
+                    territory: residences.localize(v, |v| v.loc.to_string(), NOT_EXIST.to_string()),
+                    mark: self.game_string_table.get_o(&v.mark),
+                    mark_grey: self.game_string_table.get_o(&v.mark_grey),
+                    flag_icon: self.game_string_table.get_o(&v.flag_icon),
                     merc_name: v.merc_name.to_string(),
                     region_id: v.region_id,
                     ..Default::default()
@@ -9,15 +9,16 @@ use crate::entity::skill::{
 };
 
 use l2_rw::ue2_rw::{ASCF, BYTE, DWORD, FLOAT, INT, SHORT, USHORT, UVEC};
-use l2_rw::{DatVariant, deserialize_dat, deserialize_dat_with_string_dict, save_dat};
+use crate::backend::dat_loader::{deserialize_dat, deserialize_dat_with_string_dict};
+use l2_rw::DatVariant;
 
 use l2_rw::ue2_rw::{ReadUnreal, UnrealReader, UnrealWriter, WriteUnreal};
 
 use crate::backend::Localization;
-use crate::backend::dat_loader::NOT_EXIST;
+use crate::backend::dat_loader::{NOT_EXIST, write_dat};
 use crate::backend::holder::{GameDataHolder, HolderMapOps};
 use crate::backend::log_holder::{Log, LogLevel};
-use crate::backend::util::StringCow;
+use crate::backend::util::{Localized, StringCow};
 use r#macro::{ReadUnreal, WriteUnreal};
 use num_traits::{FromPrimitive, ToPrimitive};
 use std::collections::HashMap;
@@ -103,21 +104,16 @@ impl GameDataHolder {
 
         let mut skill_grp = vec![];
 
-        let mut skill_string_table_ru = L2SkillStringTable::from_vec(vec![]);
-        let mut skill_name_ru = vec![];
-
-        let mut skill_string_table_eu = L2SkillStringTable::from_vec(vec![]);
-        let mut skill_name_eu = vec![];
+        let mut skill_names: Vec<_> = self
+            .localized_dat_paths("skillname")
+            .into_iter()
+            .map(|(l, path)| (l, path, L2SkillStringTable::from_vec(vec![]), vec![]))
+            .collect();
 
         let mut skill_sound = vec![];
         let mut skill_sound_src = vec![];
         let mut ms_condition = vec![];
 
-        let serialize_eu = self
-            .dat_paths
-            .get(&"skillname-eu.dat".to_string())
-            .is_some();
-
         let mut vals: Vec<_> = self.skill_holder.values().filter(|v| !v._deleted).collect();
         vals.sort_by(|a, b| a.id.cmp(&b.id));
 
@@ -128,30 +124,23 @@ impl GameDataHolder {
 
             let cond = MSConditionDataDat::from_skill(skill);
 
-            skill_sound.push(skill.sound_data(&mut self.game_string_table_ru));
+            skill_sound.push(skill.sound_data(&mut self.game_string_table));
             skill_sound_src.push(skill.sound_source_data());
 
             let mut base_skill_grp = SkillGrpDat::default();
-            let mut base_skill_name_ru = SkillNameDat::default();
-            let mut base_skill_name_eu = SkillNameDat::default();
-
-            base_skill_grp.fill_from_skill(skill, &mut self.game_string_table_ru);
-
-            base_skill_name_ru.fill_from_skill(skill, &mut skill_string_table_ru, Localization::RU);
-            if serialize_eu {
-                base_skill_name_eu.fill_from_skill(
-                    skill,
-                    &mut skill_string_table_eu,
-                    Localization::EU,
-                );
+            let mut base_skill_names = vec![SkillNameDat::default(); skill_names.len()];
+
+            base_skill_grp.fill_from_skill(skill, &mut self.game_string_table);
+
+            for ((l, _, table, _), name) in skill_names.iter_mut().zip(&mut base_skill_names) {
+                name.fill_from_skill(skill, table, *l);
             }
 
             let mut first = true;
             for level in &skill.skill_levels {
                 let mut base_skill_grp = base_skill_grp.clone();
 
-                let mut base_skill_name_ru = base_skill_name_ru;
-                let mut base_skill_name_eu = base_skill_name_eu;
+                let mut base_skill_names = base_skill_names.clone();
 
                 let cond = if let Some(c) = &cond {
                     let cc = c.fill_from_level(level);
@@ -162,28 +151,16 @@ impl GameDataHolder {
                     None
                 };
 
-                base_skill_grp.fill_from_level(level, &mut self.game_string_table_ru, first);
-
-                base_skill_name_ru.fill_from_level(
-                    level,
-                    &mut skill_string_table_ru,
-                    first,
-                    Localization::RU,
-                );
-                if serialize_eu {
-                    base_skill_name_eu.fill_from_level(
-                        level,
-                        &mut skill_string_table_eu,
-                        first,
-                        Localization::EU,
-                    );
+                base_skill_grp.fill_from_level(level, &mut self.game_string_table, first);
+
+                for ((l, _, table, _), name) in skill_names.iter_mut().zip(&mut base_skill_names) {
+                    name.fill_from_level(level, table, first, *l);
                 }
 
                 skill_grp.push(base_skill_grp.clone());
 
-                skill_name_ru.push(base_skill_name_ru);
-                if serialize_eu {
-                    skill_name_eu.push(base_skill_name_eu);
+                for ((_, _, _, names), name) in skill_names.iter_mut().zip(&base_skill_names) {
+                    names.push(*name);
                 }
 
                 first = false;
@@ -191,29 +168,18 @@ impl GameDataHolder {
                 for enchant in &level.available_enchants {
                     let enchant = &enchant.inner;
                     let mut base_skill_grp = base_skill_grp.clone();
-                    let mut base_skill_name_ru = base_skill_name_ru;
-                    let mut base_skill_name_eu = base_skill_name_eu;
+                    let mut base_skill_names = base_skill_names.clone();
 
                     base_skill_grp.fill_from_enchant(
                         enchant,
-                        &mut self.game_string_table_ru,
-                        level.level,
-                    );
-
-                    base_skill_name_ru.fill_from_enchant(
-                        enchant,
-                        &mut skill_string_table_ru,
+                        &mut self.game_string_table,
                         level.level,
-                        Localization::RU,
                     );
 
-                    if serialize_eu {
-                        base_skill_name_eu.fill_from_enchant(
-                            enchant,
-                            &mut skill_string_table_eu,
-                            level.level,
-                            Localization::EU,
-                        );
+                    for ((l, _, table, _), name) in
+                        skill_names.iter_mut().zip(&mut base_skill_names)
+                    {
+                        name.fill_from_enchant(enchant, table, level.level, *l);
                     }
 
                     for enchant_level in &enchant.enchant_levels {
@@ -225,28 +191,25 @@ impl GameDataHolder {
 
                         base_skill_grp.fill_from_enchant_level(
                             enchant_level,
-                            &mut self.game_string_table_ru,
-                            enchant.enchant_type,
-                        );
-                        base_skill_name_ru.fill_from_enchant_level(
-                            enchant_level,
-                            &mut skill_string_table_ru,
+                            &mut self.game_string_table,
                             enchant.enchant_type,
                         );
-
-                        if serialize_eu {
-                            base_skill_name_eu.fill_from_enchant_level(
+                        for ((_, _, table, _), name) in
+                            skill_names.iter_mut().zip(&mut base_skill_names)
+                        {
+                            name.fill_from_enchant_level(
                                 enchant_level,
-                                &mut skill_string_table_eu,
+                                table,
                                 enchant.enchant_type,
                             );
                         }
 
                         skill_grp.push(base_skill_grp.clone());
 
-                        skill_name_ru.push(base_skill_name_ru);
-                        if serialize_eu {
-                            skill_name_eu.push(base_skill_name_eu);
+                        for ((_, _, _, names), name) in
+                            skill_names.iter_mut().zip(&base_skill_names)
+                        {
+                            names.push(*name);
                         }
                     }
                 }
@@ -263,25 +226,6 @@ impl GameDataHolder {
             .get(&"msconditiondata.dat".to_string())
             .unwrap()
             .clone();
-        let skill_name_ru_path = self
-            .dat_paths
-            .get(&"skillname-ru.dat".to_string())
-            .unwrap()
-            .clone();
-
-        let eu = if serialize_eu {
-            Some((
-                skill_string_table_eu,
-                skill_name_eu,
-                self.dat_paths
-                    .get(&"skillname-ru.dat".to_string())
-                    .unwrap()
-                    .clone(),
-            ))
-        } else {
-            None
-        };
-
         let skill_grp_path = self
             .dat_paths
             .get(&"skillgrp.dat".to_string())
@@ -295,7 +239,7 @@ impl GameDataHolder {
 
         thread::spawn(move || {
             let ms_condition_handle = thread::spawn(move || {
-                if let Err(e) = save_dat(
+                if let Err(e) = write_dat(
                     ms_condition_path.path(),
                     DatVariant::<(), MSConditionDataDat>::Array(ms_condition),
                 ) {
@@ -305,27 +249,17 @@ impl GameDataHolder {
                 }
             });
             let skill_name_handel = thread::spawn(move || {
-                let mut log = vec![if let Err(e) = save_dat(
-                    skill_name_ru_path.path(),
-                    DatVariant::DoubleArray(
-                        SkillNameTableRecord::from_table(skill_string_table_ru),
-                        skill_name_ru,
-                    ),
-                ) {
-                    Log::from_loader_e(e)
-                } else {
-                    Log::from_loader_i("Skill Name RU saved")
-                }];
+                let mut log = vec![];
 
-                if let Some((table, dat, dir)) = eu {
+                for (l, path, table, dats) in skill_names {
                     log.push(
-                        if let Err(e) = save_dat(
-                            dir.path(),
-                            DatVariant::DoubleArray(SkillNameTableRecord::from_table(table), dat),
+                        if let Err(e) = write_dat(
+                            path.path(),
+                            DatVariant::DoubleArray(SkillNameTableRecord::from_table(table), dats),
                         ) {
                             Log::from_loader_e(e)
                         } else {
-                            Log::from_loader_i("Skill Name EU saved")
+                            Log::from_loader_i(&format!("Skill Name {l} saved"))
                         },
                     );
                 }
@@ -334,7 +268,7 @@ impl GameDataHolder {
             });
 
             let skill_grp_handel = thread::spawn(move || {
-                if let Err(e) = save_dat(
+                if let Err(e) = write_dat(
                     skill_grp_path.path(),
                     DatVariant::<(), SkillGrpDat>::Array(skill_grp),
                 ) {
@@ -344,7 +278,7 @@ impl GameDataHolder {
                 }
             });
             let skill_sound_handel = thread::spawn(move || {
-                if let Err(e) = save_dat(
+                if let Err(e) = write_dat(
                     skill_sound_path.path(),
                     DatVariant::<(), SkillSoundDat>::Array(skill_sound),
                 ) {
@@ -354,7 +288,7 @@ impl GameDataHolder {
                 }
             });
             let skill_sound_src_handel = thread::spawn(move || {
-                if let Err(e) = save_dat(
+                if let Err(e) = write_dat(
                     skill_sound_src_path.path(),
                     DatVariant::<(), SkillSoundSourceDat>::Array(skill_sound_src),
                 ) {
@@ -374,16 +308,7 @@ impl GameDataHolder {
         })
     }
 
-    fn build_name_tree(
-        path: &Path,
-    ) -> Result<
-        (
-            HashMap<u16, HashMap<u8, HashMap<u16, SkillNameDat>>>,
-            HashMap<DWORD, Arc<String>>,
-            u32,
-        ),
-        (),
-    > {
+    fn build_name_tree(path: &Path) -> Result<SkillNames, Log> {
         let (skill_name_table, skill_name) =
             deserialize_dat_with_string_dict::<SkillNameTableRecord, SkillNameDat>(path)?;
 
@@ -423,10 +348,14 @@ impl GameDataHolder {
             }
         }
 
-        Ok((treed_names, string_dict, empty_line))
+        Ok(SkillNames {
+            tree: treed_names,
+            dict: string_dict,
+            empty_line,
+        })
     }
 
-    pub fn load_skills(&mut self) -> Result<Vec<Log>, ()> {
+    pub fn load_skills(&mut self) -> Result<Vec<Log>, Log> {
         let mut warnings = vec![];
 
         let skill_grp = deserialize_dat::<SkillGrpDat>(
@@ -462,23 +391,22 @@ impl GameDataHolder {
             sound_source_map.insert(s.id, s);
         }
 
-        let (treed_names_ru, string_dict_ru, empty_line_ru) = Self::build_name_tree(
-            self.dat_paths
-                .get(&"skillname-ru.dat".to_string())
-                .unwrap()
-                .path(),
-        )?;
+        let main = self.main_localization();
 
-        let (treed_names_eu, string_dict_eu, ..) =
-            if let Some(dir) = self.dat_paths.get(&"skillname-eu.dat".to_string()) {
-                Self::build_name_tree(dir.path())?
-            } else {
-                let mut v = (HashMap::new(), HashMap::new(), u32::MAX);
+        let Some(main_path) = self
+            .dat_paths
+            .get(&format!("skillname-{}.dat", main.code()))
+        else {
+            return Err(self.missing_dat("skillname"));
+        };
 
-                v.1.insert(u32::MAX, Arc::new(NOT_EXIST.to_string()));
+        let mut skill_names = vec![(main, Self::build_name_tree(main_path.path())?)];
 
-                v
-            };
+        for (l, path) in self.localized_dat_paths("skillname") {
+            if l != main {
+                skill_names.push((l, Self::build_name_tree(path.path())?));
+            }
+        }
 
         let skill_condition_dat = deserialize_dat::<MSConditionDataDat>(
             self.dat_paths
@@ -518,14 +446,10 @@ impl GameDataHolder {
             if record.id != current_id {
                 if let Some(l) = self.build_skill(
                     &current_grps,
-                    &treed_names_ru,
-                    &string_dict_ru,
-                    &treed_names_eu,
-                    &string_dict_eu,
+                    &skill_names,
                     &sound_map,
                     &sound_source_map,
                     &treed_conditions,
-                    empty_line_ru,
                 ) {
                     warnings.push(l);
                 }
@@ -540,14 +464,10 @@ impl GameDataHolder {
         if !current_grps.is_empty()
             && let Some(l) = self.build_skill(
                 &current_grps,
-                &treed_names_ru,
-                &string_dict_ru,
-                &treed_names_eu,
-                &string_dict_eu,
+                &skill_names,
                 &sound_map,
                 &sound_source_map,
                 &treed_conditions,
-                empty_line_ru,
             ) {
                 warnings.push(l);
             }
@@ -555,13 +475,12 @@ impl GameDataHolder {
         Ok(warnings)
     }
 
-    fn get_name_record_or_default<'a>(
-        &self,
+    fn get_name_record_or_default(
         id: u16,
         level: u8,
         sub_level: u16,
-        skill_names: &'a HashMap<u16, HashMap<u8, HashMap<u16, SkillNameDat>>>,
-    ) -> &'a SkillNameDat {
+        skill_names: &HashMap<u16, HashMap<u8, HashMap<u16, SkillNameDat>>>,
+    ) -> &SkillNameDat {
         const DEFAULT_SKILL_NAME_DAT: SkillNameDat = SkillNameDat {
             id: 0,
             level: 0,
@@ -587,27 +506,40 @@ impl GameDataHolder {
     fn build_skill(
         &mut self,
         skill_grps: &[SkillGrpDat],
-        skill_names_ru: &HashMap<u16, HashMap<u8, HashMap<u16, SkillNameDat>>>,
-        string_dict_ru: &HashMap<u32, Arc<String>>,
-        skill_names_eu: &HashMap<u16, HashMap<u8, HashMap<u16, SkillNameDat>>>,
-        string_dict_eu: &HashMap<u32, Arc<String>>,
+        skill_names: &[(Localization, SkillNames)],
         sound_map: &HashMap<u32, SkillSoundDat>,
         sound_source_map: &HashMap<u32, SkillSoundSourceDat>,
         treed_condition: &HashMap<u16, HashMap<u8, HashMap<u16, MSConditionDataDat>>>,
-        empty_line_ru: u32,
     ) -> Option<Log> {
+        let (_, main_names) = &skill_names[0];
+        let string_dict = &main_names.dict;
+        let empty_line = main_names.empty_line;
+
+        let localize = |grp: &SkillGrpDat, f: fn(&SkillNameDat) -> u32| {
+            Localized::from_fn_in(skill_names.iter().map(|(v, _)| *v), |l| {
+                let Some((_, names)) = skill_names.iter().find(|(v, _)| *v == l) else {
+                    return StringCow::Owned(NOT_EXIST.to_string());
+                };
+
+                let record =
+                    Self::get_name_record_or_default(grp.id, grp.level, grp.sub_level, &names.tree);
+
+                StringCow::Borrowed(
+                    names
+                        .dict
+                        .get(&f(record))
+                        .unwrap_or_else(|| names.dict.get(&u32::MAX).unwrap())
+                        .clone(),
+                )
+            })
+        };
+
         let first_grp = skill_grps.first().unwrap();
-        let first_name_ru = self.get_name_record_or_default(
+        let first_name = Self::get_name_record_or_default(
             first_grp.id,
             first_grp.level,
             first_grp.sub_level,
-            skill_names_ru,
-        );
-        let first_name_eu = self.get_name_record_or_default(
-            first_grp.id,
-            first_grp.level,
-            first_grp.sub_level,
-            skill_names_eu,
+            &main_names.tree,
         );
 
         let first_condition = if let Some(v) = treed_condition.get(&first_grp.id) {
@@ -679,28 +611,8 @@ impl GameDataHolder {
 
         let mut skill = Skill {
             id: SkillId(first_grp.id as u32),
-            name: (
-                StringCow::Borrowed(string_dict_ru.get(&first_name_ru.name).unwrap().clone()),
-                StringCow::Borrowed(
-                    string_dict_eu
-                        .get(&first_name_eu.name)
-                        .map_or(string_dict_eu.get(&u32::MAX).unwrap().clone(), |v| {
-                            v.clone()
-                        }),
-                ),
-            )
-                .into(),
-            description: (
-                StringCow::Borrowed(string_dict_ru.get(&first_name_ru.desc).unwrap().clone()),
-                StringCow::Borrowed(
-                    string_dict_eu
-                        .get(&first_name_eu.desc)
-                        .map_or(string_dict_eu.get(&u32::MAX).unwrap().clone(), |v| {
-                            v.clone()
-                        }),
-                ),
-            )
-                .into(),
+            name: localize(first_grp, |n| n.name),
+            description: localize(first_grp, |n| n.desc),
             skill_type: SkillType::from_u8(first_grp.operate_type).unwrap(),
             resist_cast: first_grp.resist_cast,
             magic_type: first_grp.magic_type,
@@ -712,13 +624,11 @@ impl GameDataHolder {
                 .animation
                 .inner
                 .iter()
-                .map(|v| self.game_string_table_ru.get_o(v))
+                .map(|v| self.game_string_table.get_o(v))
                 .collect(),
-            visual_effect: self
-                .game_string_table_ru
-                .get_o(&first_grp.skill_visual_effect),
-            icon: self.game_string_table_ru.get_o(&first_grp.icon),
-            icon_panel: self.game_string_table_ru.get_o(&first_grp.icon_panel),
+            visual_effect: self.game_string_table.get_o(&first_grp.skill_visual_effect),
+            icon: self.game_string_table.get_o(&first_grp.icon),
+            icon_panel: self.game_string_table.get_o(&first_grp.icon_panel),
             cast_bar_text_is_red: first_grp.icon_type == 1,
             rumble_self: first_grp.rumble_self,
             rumble_target: first_grp.rumble_target,
@@ -726,110 +636,110 @@ impl GameDataHolder {
             is_debuff: first_grp.debuff == 1,
             sound_info: WindowParams::new(SkillSoundInfo {
                 spell_effect_1: SoundInfo {
-                    sound: self.game_string_table_ru.get_o(&sound.spell_1_effect),
+                    sound: self.game_string_table.get_o(&sound.spell_1_effect),
                     vol: sound.spell_1_vol,
                     rad: sound.spell_1_rad,
                     delay: sound.spell_1_delay,
                     source: sound_source.spell_1_effect,
                 },
                 spell_effect_2: SoundInfo {
-                    sound: self.game_string_table_ru.get_o(&sound.spell_2_effect),
+                    sound: self.game_string_table.get_o(&sound.spell_2_effect),
                     vol: sound.spell_2_vol,
                     rad: sound.spell_2_rad,
                     delay: sound.spell_2_delay,
                     source: sound_source.spell_2_effect,
                 },
                 spell_effect_3: SoundInfo {
-                    sound: self.game_string_table_ru.get_o(&sound.spell_3_effect),
+                    sound: self.game_string_table.get_o(&sound.spell_3_effect),
                     vol: sound.spell_3_vol,
                     rad: sound.spell_3_rad,
                     delay: sound.spell_3_delay,
                     source: sound_source.spell_3_effect,
                 },
                 shot_effect_1: SoundInfo {
-                    sound: self.game_string_table_ru.get_o(&sound.shot_1_effect),
+                    sound: self.game_string_table.get_o(&sound.shot_1_effect),
                     vol: sound.shot_1_vol,
                     rad: sound.shot_1_rad,
                     delay: sound.shot_1_delay,
                     source: sound_source.shot_1_effect,
                 },
                 shot_effect_2: SoundInfo {
-                    sound: self.game_string_table_ru.get_o(&sound.shot_2_effect),
+                    sound: self.game_string_table.get_o(&sound.shot_2_effect),
                     vol: sound.shot_2_vol,
                     rad: sound.shot_2_rad,
                     delay: sound.shot_2_delay,
                     source: sound_source.shot_2_effect,
                 },
                 shot_effect_3: SoundInfo {
-                    sound: self.game_string_table_ru.get_o(&sound.shot_3_effect),
+                    sound: self.game_string_table.get_o(&sound.shot_3_effect),
                     vol: sound.shot_3_vol,
                     rad: sound.shot_3_rad,
                     delay: sound.shot_3_delay,
                     source: sound_source.shot_3_effect,
                 },
                 exp_effect_1: SoundInfo {
-                    sound: self.game_string_table_ru.get_o(&sound.exp_1_effect),
+                    sound: self.game_string_table.get_o(&sound.exp_1_effect),
                     vol: sound.exp_1_vol,
                     rad: sound.exp_1_rad,
                     delay: sound.exp_1_delay,
                     source: sound_source.exp_1_effect,
                 },
                 exp_effect_2: SoundInfo {
-                    sound: self.game_string_table_ru.get_o(&sound.exp_2_effect),
+                    sound: self.game_string_table.get_o(&sound.exp_2_effect),
                     vol: sound.exp_2_vol,
                     rad: sound.exp_2_rad,
                     delay: sound.exp_2_delay,
                     source: sound_source.exp_2_effect,
                 },
                 exp_effect_3: SoundInfo {
-                    sound: self.game_string_table_ru.get_o(&sound.exp_3_effect),
+                    sound: self.game_string_table.get_o(&sound.exp_3_effect),
                     vol: sound.exp_3_vol,
                     rad: sound.exp_3_rad,
                     delay: sound.exp_3_delay,
                     source: sound_source.exp_3_effect,
                 },
                 sound_before_cast: RacesSkillSoundInfo {
-                    mfighter: self.game_string_table_ru.get_o(&sound.mfighter_cast),
-                    ffighter: self.game_string_table_ru.get_o(&sound.ffighter_cast),
-                    mmagic: self.game_string_table_ru.get_o(&sound.mmagic_cast),
-                    fmagic: self.game_string_table_ru.get_o(&sound.fmagic_cast),
-                    melf: self.game_string_table_ru.get_o(&sound.melf_cast),
-                    felf: self.game_string_table_ru.get_o(&sound.felf_cast),
-                    mdark_elf: self.game_string_table_ru.get_o(&sound.mdark_elf_cast),
-                    fdark_elf: self.game_string_table_ru.get_o(&sound.fdark_elf_cast),
-                    mdwarf: self.game_string_table_ru.get_o(&sound.mdwarf_cast),
-                    fdwarf: self.game_string_table_ru.get_o(&sound.fdwarf_cast),
-                    morc: self.game_string_table_ru.get_o(&sound.morc_cast),
-                    forc: self.game_string_table_ru.get_o(&sound.forc_cast),
-                    mshaman: self.game_string_table_ru.get_o(&sound.mshaman_cast),
-                    fshaman: self.game_string_table_ru.get_o(&sound.fshaman_cast),
-                    mkamael: self.game_string_table_ru.get_o(&sound.mkamael_cast),
-                    fkamael: self.game_string_table_ru.get_o(&sound.fkamael_cast),
-                    mertheia: self.game_string_table_ru.get_o(&sound.mertheia_cast),
-                    fertheia: self.game_string_table_ru.get_o(&sound.fertheia_cast),
+                    mfighter: self.game_string_table.get_o(&sound.mfighter_cast),
+                    ffighter: self.game_string_table.get_o(&sound.ffighter_cast),
+                    mmagic: self.game_string_table.get_o(&sound.mmagic_cast),
+                    fmagic: self.game_string_table.get_o(&sound.fmagic_cast),
+                    melf: self.game_string_table.get_o(&sound.melf_cast),
+                    felf: self.game_string_table.get_o(&sound.felf_cast),
+                    mdark_elf: self.game_string_table.get_o(&sound.mdark_elf_cast),
+                    fdark_elf: self.game_string_table.get_o(&sound.fdark_elf_cast),
+                    mdwarf: self.game_string_table.get_o(&sound.mdwarf_cast),
+                    fdwarf: self.game_string_table.get_o(&sound.fdwarf_cast),
+                    morc: self.game_string_table.get_o(&sound.morc_cast),
+                    forc: self.game_string_table.get_o(&sound.forc_cast),
+                    mshaman: self.game_string_table.get_o(&sound.mshaman_cast),
+                    fshaman: self.game_string_table.get_o(&sound.fshaman_cast),
+                    mkamael: self.game_string_table.get_o(&sound.mkamael_cast),
+                    fkamael: self.game_string_table.get_o(&sound.fkamael_cast),
+                    mertheia: self.game_string_table.get_o(&sound.mertheia_cast),
+                    fertheia: self.game_string_table.get_o(&sound.fertheia_cast),
                 },
                 sound_after_cast: RacesSkillSoundInfo {
-                    mfighter: self.game_string_table_ru.get_o(&sound.mfighter_magic),
-                    ffighter: self.game_string_table_ru.get_o(&sound.ffighter_magic),
-                    mmagic: self.game_string_table_ru.get_o(&sound.mmagic_magic),
-                    fmagic: self.game_string_table_ru.get_o(&sound.fmagic_magic),
-                    melf: self.game_string_table_ru.get_o(&sound.melf_magic),
-                    felf: self.game_string_table_ru.get_o(&sound.felf_magic),
-                    mdark_elf: self.game_string_table_ru.get_o(&sound.mdark_elf_magic),
-                    fdark_elf: self.game_string_table_ru.get_o(&sound.fdark_elf_magic),
-                    mdwarf: self.game_string_table_ru.get_o(&sound.mdwarf_magic),
-                    fdwarf: self.game_string_table_ru.get_o(&sound.fdwarf_magic),
-                    morc: self.game_string_table_ru.get_o(&sound.morc_magic),
-                    forc: self.game_string_table_ru.get_o(&sound.forc_magic),
-                    mshaman: self.game_string_table_ru.get_o(&sound.mshaman_magic),
-                    fshaman: self.game_string_table_ru.get_o(&sound.fshaman_magic),
-                    mkamael: self.game_string_table_ru.get_o(&sound.mkamael_magic),
-                    fkamael: self.game_string_table_ru.get_o(&sound.fkamael_magic),
-                    mertheia: self.game_string_table_ru.get_o(&sound.mertheia_magic),
-                    fertheia: self.game_string_table_ru.get_o(&sound.fertheia_magic),
+                    mfighter: self.game_string_table.get_o(&sound.mfighter_magic),
+                    ffighter: self.game_string_table.get_o(&sound.ffighter_magic),
+                    mmagic: self.game_string_table.get_o(&sound.mmagic_magic),
+                    fmagic: self.game_string_table.get_o(&sound.fmagic_magic),
+                    melf: self.game_string_table.get_o(&sound.melf_magic),
+                    felf: self.game_string_table.get_o(&sound.felf_magic),
+                    mdark_elf: self.game_string_table.get_o(&sound.mdark_elf_magic),
+                    fdark_elf: self.game_string_table.get_o(&sound.fdark_elf_magic),
+                    mdwarf: self.game_string_table.get_o(&sound.mdwarf_magic),
+                    fdwarf: self.game_string_table.get_o(&sound.fdwarf_magic),
+                    morc: self.game_string_table.get_o(&sound.morc_magic),
+                    forc: self.game_string_table.get_o(&sound.forc_magic),
+                    mshaman: self.game_string_table.get_o(&sound.mshaman_magic),
+                    fshaman: self.game_string_table.get_o(&sound.fshaman_magic),
+                    mkamael: self.game_string_table.get_o(&sound.mkamael_magic),
+                    fkamael: self.game_string_table.get_o(&sound.fkamael_magic),
+                    mertheia: self.game_string_table.get_o(&sound.mertheia_magic),
+                    fertheia: self.game_string_table.get_o(&sound.fertheia_magic),
                 },
-                mextra_throw: self.game_string_table_ru.get_o(&sound.mextra_throw),
-                fextra_throw: self.game_string_table_ru.get_o(&sound.fextra_throw),
+                mextra_throw: self.game_string_table.get_o(&sound.mextra_throw),
+                fextra_throw: self.game_string_table.get_o(&sound.fextra_throw),
                 vol: sound.cast_volume,
                 rad: sound.cast_rad,
             }),
@@ -841,63 +751,28 @@ impl GameDataHolder {
         let mut enchants: HashMap<u8, HashMap<u16, EnchantInfo>> = HashMap::new();
 
         for v in skill_grps.iter() {
-            let skill_name_ru =
-                self.get_name_record_or_default(v.id, v.level, v.sub_level, skill_names_ru);
-            let skill_name_eu =
-                self.get_name_record_or_default(v.id, v.level, v.sub_level, skill_names_eu);
+            let skill_name =
+                Self::get_name_record_or_default(v.id, v.level, v.sub_level, &main_names.tree);
 
             if v.sub_level == 0 {
-                let descriptions = if skill_name_ru.desc == first_name_ru.desc
-                    || skill_name_ru.desc == empty_line_ru
-                {
-                    None
-                } else {
-                    Some(
-                        (
-                            StringCow::Borrowed(
-                                string_dict_ru.get(&skill_name_ru.desc).unwrap().clone(),
-                            ),
-                            StringCow::Borrowed(
-                                string_dict_eu
-                                    .get(&skill_name_eu.desc)
-                                    .map_or(string_dict_eu.get(&u32::MAX).unwrap().clone(), |v| {
-                                        v.clone()
-                                    }),
-                            ),
-                        )
-                            .into(),
-                    )
-                };
+                let descriptions =
+                    if skill_name.desc == first_name.desc || skill_name.desc == empty_line {
+                        None
+                    } else {
+                        Some(localize(v, |n| n.desc))
+                    };
 
-                let level_names = if skill_name_ru.name == first_name_ru.name
-                    || skill_name_ru.name == empty_line_ru
-                {
-                    None
-                } else {
-                    Some(
-                        (
-                            StringCow::Borrowed(
-                                string_dict_ru.get(&skill_name_ru.name).unwrap().clone(),
-                            ),
-                            StringCow::Borrowed(
-                                string_dict_eu
-                                    .get(&skill_name_eu.name)
-                                    .map_or(string_dict_eu.get(&u32::MAX).unwrap().clone(), |v| {
-                                        v.clone()
-                                    }),
-                            ),
-                        )
-                            .into(),
-                    )
-                };
+                let level_names =
+                    if skill_name.name == first_name.name || skill_name.name == empty_line {
+                        None
+                    } else {
+                        Some(localize(v, |n| n.name))
+                    };
 
                 levels.push(SkillLevelInfo {
                     level: v.level as u32,
                     description_params: StringCow::Borrowed(
-                        string_dict_ru
-                            .get(&skill_name_ru.desc_params)
-                            .unwrap()
-                            .clone(),
+                        string_dict.get(&skill_name.desc_params).unwrap().clone(),
                     ),
                     mp_cost: v.mp_consume,
                     hp_cost: v.hp_consume,
@@ -909,12 +784,12 @@ impl GameDataHolder {
                     icon: if v.icon == first_grp.icon {
                         None
                     } else {
-                        Some(self.game_string_table_ru.get_o(&v.icon))
+                        Some(self.game_string_table.get_o(&v.icon))
                     },
                     icon_panel: if v.icon_panel == first_grp.icon_panel {
                         None
                     } else {
-                        Some(self.game_string_table_ru.get_o(&v.icon_panel))
+                        Some(self.game_string_table.get_o(&v.icon_panel))
                     },
                     name: level_names,
                     description: descriptions,
@@ -926,20 +801,17 @@ impl GameDataHolder {
                 let enchant_level = EnchantLevelInfo {
                     level: v.sub_level as u32 - variant as u32 * 1000,
                     skill_description_params: StringCow::Borrowed(
-                        string_dict_ru
-                            .get(&skill_name_ru.desc_params)
-                            .unwrap()
-                            .clone(),
+                        string_dict.get(&skill_name.desc_params).unwrap().clone(),
                     ),
                     enchant_name_params: StringCow::Borrowed(
-                        string_dict_ru
-                            .get(&skill_name_ru.enchant_name_params)
+                        string_dict
+                            .get(&skill_name.enchant_name_params)
                             .unwrap()
                             .clone(),
                     ),
                     enchant_description_params: StringCow::Borrowed(
-                        string_dict_ru
-                            .get(&skill_name_ru.enchant_desc_params)
+                        string_dict
+                            .get(&skill_name.enchant_desc_params)
                             .unwrap()
                             .clone(),
                     ),
@@ -953,12 +825,12 @@ impl GameDataHolder {
                     icon: if v.icon == first_grp.icon {
                         None
                     } else {
-                        Some(self.game_string_table_ru.get_o(&v.icon))
+                        Some(self.game_string_table.get_o(&v.icon))
                     },
                     icon_panel: if v.icon_panel == first_grp.icon_panel {
                         None
                     } else {
-                        Some(self.game_string_table_ru.get_o(&v.icon_panel))
+                        Some(self.game_string_table.get_o(&v.icon_panel))
                     },
                 };
 
@@ -966,26 +838,15 @@ impl GameDataHolder {
                     if let Some(ei) = curr_level_enchants.get_mut(&variant) {
                         ei.enchant_levels.push(enchant_level);
                     } else {
-                        let descriptions = if skill_name_ru.desc == first_name_ru.desc {
+                        let descriptions = if skill_name.desc == first_name.desc {
                             None
                         } else {
-                            let c = string_dict_ru.get(&skill_name_ru.desc).unwrap();
+                            let c = string_dict.get(&skill_name.desc).unwrap();
 
                             if c.is_empty() {
                                 None
                             } else {
-                                Some(
-                                    (
-                                        StringCow::Borrowed(c.clone()),
-                                        StringCow::Borrowed(
-                                            string_dict_eu.get(&skill_name_eu.desc).map_or(
-                                                string_dict_eu.get(&u32::MAX).unwrap().clone(),
-                                                |v| v.clone(),
-                                            ),
-                                        ),
-                                    )
-                                        .into(),
-                                )
+                                Some(localize(v, |n| n.desc))
                             }
                         };
 
@@ -994,37 +855,9 @@ impl GameDataHolder {
                             EnchantInfo {
                                 enchant_type: variant as u32,
                                 skill_description: descriptions,
-                                enchant_name: (
-                                    StringCow::Borrowed(
-                                        string_dict_ru
-                                            .get(&skill_name_ru.enchant_name)
-                                            .unwrap()
-                                            .clone(),
-                                    ),
-                                    StringCow::Borrowed(
-                                        string_dict_eu.get(&skill_name_eu.enchant_name).map_or(
-                                            string_dict_eu.get(&u32::MAX).unwrap().clone(),
-                                            |v| v.clone(),
-                                        ),
-                                    ),
-                                )
-                                    .into(),
-                                enchant_icon: self.game_string_table_ru.get_o(&v.enchant_icon),
-                                enchant_description: (
-                                    StringCow::Borrowed(
-                                        string_dict_ru
-                                            .get(&skill_name_ru.enchant_desc)
-                                            .unwrap()
-                                            .clone(),
-                                    ),
-                                    StringCow::Borrowed(
-                                        string_dict_eu.get(&skill_name_eu.enchant_desc).map_or(
-                                            string_dict_eu.get(&u32::MAX).unwrap().clone(),
-                                            |v| v.clone(),
-                                        ),
-                                    ),
-                                )
-                                    .into(),
+                                enchant_name: localize(v, |n| n.enchant_name),
+                                enchant_icon: self.game_string_table.get_o(&v.enchant_icon),
+                                enchant_description: localize(v, |n| n.enchant_desc),
                                 is_debuff: v.debuff == 1,
                                 enchant_levels: vec![enchant_level],
                             },
@@ -1033,67 +866,27 @@ impl GameDataHolder {
                 } else {
                     let mut infos = HashMap::new();
 
-                    let descriptions = if skill_name_ru.desc == first_name_ru.desc
-                        || skill_name_ru.desc == empty_line_ru
-                    {
-                        None
-                    } else {
-                        let c = string_dict_ru.get(&skill_name_ru.desc).unwrap();
-
-                        if c.is_empty() {
+                    let descriptions =
+                        if skill_name.desc == first_name.desc || skill_name.desc == empty_line {
                             None
                         } else {
-                            Some(
-                                (
-                                    StringCow::Borrowed(c.clone()),
-                                    StringCow::Borrowed(
-                                        string_dict_eu.get(&skill_name_eu.desc).map_or(
-                                            string_dict_eu.get(&u32::MAX).unwrap().clone(),
-                                            |v| v.clone(),
-                                        ),
-                                    ),
-                                )
-                                    .into(),
-                            )
-                        }
-                    };
+                            let c = string_dict.get(&skill_name.desc).unwrap();
+
+                            if c.is_empty() {
+                                None
+                            } else {
+                                Some(localize(v, |n| n.desc))
+                            }
+                        };
 
                     infos.insert(
                         variant,
                         EnchantInfo {
                             enchant_type: variant as u32,
                             skill_description: descriptions,
-                            enchant_name: (
-                                StringCow::Borrowed(
-                                    string_dict_ru
-                                        .get(&skill_name_ru.enchant_name)
-                                        .unwrap()
-                                        .clone(),
-                                ),
-                                StringCow::Borrowed(
-                                    string_dict_eu.get(&skill_name_eu.enchant_name).map_or(
-                                        string_dict_eu.get(&u32::MAX).unwrap().clone(),
-                                        |v| v.clone(),
-                                    ),
-                                ),
-                            )
-                                .into(),
-                            enchant_icon: self.game_string_table_ru.get_o(&v.enchant_icon),
-                            enchant_description: (
-                                StringCow::Borrowed(
-                                    string_dict_ru
-                                        .get(&skill_name_ru.enchant_desc)
-                                        .unwrap()
-                                        .clone(),
-                                ),
-                                StringCow::Borrowed(
-                                    string_dict_eu.get(&skill_name_eu.enchant_desc).map_or(
-                                        string_dict_eu.get(&u32::MAX).unwrap().clone(),
-                                        |v| v.clone(),
-                                    ),
-                                ),
-                            )
-                                .into(),
+                            enchant_name: localize(v, |n| n.enchant_name),
+                            enchant_icon: self.game_string_table.get_o(&v.enchant_icon),
+                            enchant_description: localize(v, |n| n.enchant_desc),
                             is_debuff: v.debuff == 1,
                             enchant_levels: vec![enchant_level],
                         },
@@ -1406,6 +1199,13 @@ impl SkillNameTableRecord {
     }
 }
 
+/// Skill name records of one locale and string dictionary they point into
+struct SkillNames {
+    tree: HashMap<u16, HashMap<u8, HashMap<u16, SkillNameDat>>>,
+    dict: HashMap<u32, Arc<String>>,
+    empty_line: u32,
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, ReadUnreal, WriteUnreal, Default)]
 pub struct SkillNameDat {
     id: USHORT,
@@ -0,0 +1,90 @@
+use crate::backend::log_holder::Log;
+
+use l2_rw::ue2_rw::{ASCF, DWORD};
+use l2_rw::ue2_rw::{ReadUnreal, UnrealReader, UnrealWriter, WriteUnreal};
+
+use crate::backend::Localization;
+use crate::backend::dat_loader::{GetId, NOT_EXIST, save_localized_dats};
+use crate::backend::holder::{GameDataHolder, HolderMapOps};
+use crate::entity::user_action::UserAction;
+use r#macro::{ReadUnreal, WriteUnreal};
+use std::thread::JoinHandle;
+
+impl GameDataHolder {
+    fn user_action_as_dat_vec(&mut self, localization: Localization) -> Vec<ActionNameDat> {
+        self.user_action_holder
+            .values()
+            .filter(|v| !v._deleted)
+            .map(|v| ActionNameDat {
+                tag: v.tag,
+                id: v.id.0,
+                action_type: v.action_type,
+                category: v.category,
+                command: (&v.command).into(),
+                icon: self.game_string_table.get_index(&v.icon),
+                name: (&v.name[localization]).into(),
+                toggle_group_id: v.toggle_group_id,
+                desc: (&v.desc[localization]).into(),
+            })
+            .collect()
+    }
+
+    pub fn serialize_user_action_to_binary(&mut self) -> JoinHandle<Vec<Log>> {
+        let dats = self
+            .localized_dat_paths("actionname")
+            .into_iter()
+            .map(|(l, path)| (l, path, self.user_action_as_dat_vec(l)))
+            .collect();
+
+        save_localized_dats(dats, "Action Names")
+    }
+
+    pub fn load_user_actions(&mut self) -> Result<Vec<Log>, Log> {
+        if let Some(log) = self.missing_optional_dat("actionname") {
+            return Ok(vec![log]);
+        }
+
+        let actions = self.load_localized_dat::<ActionNameDat>("actionname")?;
+
+        for v in &actions.main {
+            self.user_action_holder.insert(
+                v.id.into(),
+                UserAction {
+                    id: v.id.into(),
+                    tag: v.tag,
+                    action_type: v.action_type,
+                    category: v.category,
+                    toggle_group_id: v.toggle_group_id,
+                    command: v.command.to_string(),
+                    icon: self.game_string_table.get_o(&v.icon),
+                    name: actions.localize(v, |v| v.name.to_string(), NOT_EXIST.to_string()),
+                    desc: actions.localize(v, |v| v.desc.to_string(), NOT_EXIST.to_string()),
+
+                    _changed: false,
+                    _deleted: false,
+                },
+            );
+        }
+
+        Ok(vec![])
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, ReadUnreal, WriteUnreal)]
+struct ActionNameDat {
+    tag: DWORD,
+    id: DWORD,
+    action_type: DWORD,
+    category: DWORD,
+    command: ASCF,
+    icon: DWORD,
+    name: ASCF,
+    toggle_group_id: DWORD,
+    desc: ASCF,
+}
+
+impl GetId for ActionNameDat {
+    fn get_id(&self) -> DWORD {
+        self.id
+    }
+}
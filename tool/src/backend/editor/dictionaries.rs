@@ -1,10 +1,12 @@
-use crate::backend::Backend;
 use crate::backend::holder::{DictEditItem, DictItem, HolderMapOps};
 use crate::backend::util::is_in_range;
+use crate::backend::{Backend, Localization};
 use crate::entity::Dictionary;
+use crate::entity::game_tip::GameTip;
 use serde::Serialize;
 use std::hash::Hash;
 use std::ops::{Index, IndexMut};
+use strum::IntoEnumIterator;
 
 #[derive(Default)]
 pub struct DictEditor<ID: Hash + Eq + Ord + Copy, T: Clone + Ord + Eq + Serialize + Default> {
@@ -14,7 +16,35 @@ pub struct DictEditor<ID: Hash + Eq + Ord + Copy, T: Clone + Ord + Eq + Serializ
     pub search: String,
 }
 
-impl DictEditor<u32, String> {
+pub trait DictValue: Clone + Ord + Eq + Serialize + Default {
+    fn new_placeholder() -> Self;
+    fn lowered_contains(&self, s: &str) -> bool;
+}
+
+impl DictValue for String {
+    fn new_placeholder() -> Self {
+        "-- NEW --".to_string()
+    }
+
+    fn lowered_contains(&self, s: &str) -> bool {
+        self.to_lowercase().contains(s)
+    }
+}
+
+impl DictValue for GameTip {
+    fn new_placeholder() -> Self {
+        GameTip {
+            text: "-- NEW --".to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn lowered_contains(&self, s: &str) -> bool {
+        self.text.to_lowercase().contains(s)
+    }
+}
+
+impl<T: DictValue> DictEditor<u32, T> {
     pub fn add_new(&mut self) {
         let id = if let Some(v) = self.items.last() {
             v.id + 1
@@ -28,7 +58,7 @@ impl DictEditor<u32, String> {
 
         self.items.push(DictEditItem::new(
             id,
-            ("-- NEW --".to_string(), "-- NEW --".to_string()).into(),
+            (T::new_placeholder(), T::new_placeholder()).into(),
         ));
 
         self.apply_search()
@@ -62,29 +92,14 @@ impl<ID: Hash + Eq + Ord + Copy, T: Clone + Ord + Eq + Serialize + Default> Dict
 pub struct DictEditors {
     pub system_strings: DictEditor<u32, String>,
     pub npc_strings: DictEditor<u32, String>,
+    pub game_tips: DictEditor<u32, GameTip>,
 }
 
-pub trait DictEditorOps<K: Hash + Eq + Ord + Copy, V: Clone + Ord + Eq + Serialize + Default> {
-    #[allow(unused)]
-    fn items(&self) -> &Vec<DictEditItem<K, V>>;
-    fn items_mut(&mut self) -> &mut Vec<DictEditItem<K, V>>;
-
-    fn set_changed_count(&mut self, changed_count: usize);
+pub trait DictEditorOps {
     fn apply_search(&mut self);
 }
 
-impl DictEditorOps<u32, String> for DictEditor<u32, String> {
-    fn items(&self) -> &Vec<DictEditItem<u32, String>> {
-        &self.items
-    }
-    fn items_mut(&mut self) -> &mut Vec<DictEditItem<u32, String>> {
-        &mut self.items
-    }
-
-    fn set_changed_count(&mut self, changed_count: usize) {
-        self.changed_count = changed_count;
-    }
-
+impl<T: DictValue> DictEditorOps for DictEditor<u32, T> {
     fn apply_search(&mut self) {
         if let Some(range) = self.search.strip_prefix("r:") {
             self.filtered_indexes = self
@@ -100,7 +115,7 @@ impl DictEditorOps<u32, String> for DictEditor<u32, String> {
                 .items
                 .iter()
                 .enumerate()
-                .filter(|(_, v)| v.item.lowered_contains(&search))
+                .filter(|(_, v)| Localization::iter().any(|l| v.item[l].lowered_contains(&search)))
                 .map(|(i, _)| i)
                 .collect();
         }
@@ -108,12 +123,13 @@ impl DictEditorOps<u32, String> for DictEditor<u32, String> {
 }
 
 impl Index<Dictionary> for DictEditors {
-    type Output = dyn DictEditorOps<u32, String>;
+    type Output = dyn DictEditorOps;
 
     fn index(&self, entity: Dictionary) -> &Self::Output {
         match entity {
             Dictionary::SystemStrings => &self.system_strings,
             Dictionary::NpcStrings => &self.npc_strings,
+            Dictionary::GameTips => &self.game_tips,
         }
     }
 }
@@ -123,6 +139,7 @@ impl IndexMut<Dictionary> for DictEditors {
         match entity {
             Dictionary::SystemStrings => &mut self.system_strings,
             Dictionary::NpcStrings => &mut self.npc_strings,
+            Dictionary::GameTips => &mut self.game_tips,
         }
     }
 }
@@ -155,4 +172,18 @@ impl Backend {
 
         self.editors.dictionaries.npc_strings = DictEditor::new(strings);
     }
+
+    pub fn fill_game_tips_editor(&mut self) {
+        let mut tips: Vec<_> = self
+            .holders
+            .game_data_holder
+            .game_tips
+            .values()
+            .cloned()
+            .collect();
+
+        tips.sort_by_key(|v| v.id);
+
+        self.editors.dictionaries.game_tips = DictEditor::new(tips);
+    }
 }
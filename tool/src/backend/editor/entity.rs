@@ -1,8 +1,8 @@
 use super::*;
 use crate::backend::HandleAction;
-use crate::backend::holder::{GameDataHolder, HolderMapOps};
+use crate::backend::editor::history::EditHistory;
+use crate::backend::holder::{DumpFormat, GameDataHolder, HolderMapOps};
 use crate::entity::{CommonEntity, GameEntity, GameEntityT, GetEditParams};
-use ron::error::SpannedError;
 use ron::ser::PrettyConfig;
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
@@ -22,10 +22,27 @@ pub struct ChangeTrackedParams<
     pub initial: Entity,
     changed: bool,
     pub is_new: bool,
+    /// Missing in saves of older versions, starts empty then
+    #[serde(default = "EditHistory::default")]
+    pub history: EditHistory<Entity>,
 }
 
 impl<
-    Entity: PartialEq + Clone + Serialize + DeserializeOwned,
+    Entity: GetEditParams<EditParams> + PartialEq + Clone + Serialize,
+    EntityId: Default + Serialize + DeserializeOwned,
+    EditAction: Default + Serialize + DeserializeOwned,
+    EditParams: Default + Serialize + DeserializeOwned,
+> ChangeTrackedParams<Entity, EntityId, EditAction, EditParams>
+{
+    fn restore(&mut self, entity: Entity) {
+        self.inner.params = entity.edit_params();
+        self.inner.inner = entity;
+        self.changed = self.inner.inner != self.initial;
+    }
+}
+
+impl<
+    Entity: GetEditParams<EditParams> + PartialEq + Clone + Serialize + DeserializeOwned,
     EntityId: From<u32> + Default + Serialize + DeserializeOwned,
     EditAction: Default + Serialize + DeserializeOwned,
     EditParams: Default + Serialize + DeserializeOwned,
@@ -46,18 +63,19 @@ where
 
     fn check_change(&mut self) {
         self.changed = self.inner.inner != self.initial;
+        self.history.record(&self.inner.inner);
     }
 
     fn handle_actions(&mut self) {
         self.inner.handle_action()
     }
 
-    fn get_wrapped_entity_as_ron_string(&self) -> String {
-        ron::ser::to_string_pretty(&self.inner, PrettyConfig::default().struct_names(true)).unwrap()
+    fn get_wrapped_entity_as_string(&self, format: DumpFormat) -> anyhow::Result<String> {
+        format.encode(&self.inner, PrettyConfig::default().struct_names(true))
     }
 
-    fn set_wrapped_entity_from_ron_string(&mut self, val: &str) -> Result<(), SpannedError> {
-        let r = ron::from_str(val);
+    fn set_wrapped_entity_from_string(&mut self, val: &str) -> anyhow::Result<()> {
+        let r = DumpFormat::detect(val).decode(val);
 
         match r {
             Ok(r) => {
@@ -70,6 +88,46 @@ where
             Err(e) => Err(e),
         }
     }
+
+    fn undo(&mut self) {
+        self.history.record(&self.inner.inner);
+
+        if let Some(v) = self.history.undo().cloned() {
+            self.restore(v);
+        }
+    }
+
+    fn redo(&mut self) {
+        self.history.record(&self.inner.inner);
+
+        if let Some(v) = self.history.redo().cloned() {
+            self.restore(v);
+        }
+    }
+
+    fn go_to_history_record(&mut self, index: usize) {
+        self.history.record(&self.inner.inner);
+
+        if let Some(v) = self.history.go_to(index).cloned() {
+            self.restore(v);
+        }
+    }
+
+    fn can_undo(&self) -> bool {
+        self.history.can_undo() || self.history.is_unrecorded(&self.inner.inner)
+    }
+
+    fn can_redo(&self) -> bool {
+        self.history.can_redo()
+    }
+
+    fn history_labels(&self) -> Vec<String> {
+        self.history.labels()
+    }
+
+    fn history_position(&self) -> usize {
+        self.history.current()
+    }
 }
 
 fn params_serializer<S, Entity, EntityId, EditAction, EditParams>(
@@ -197,6 +255,7 @@ impl<
     fn add(&mut self, e: Entity, original_id: EntityId, is_new: bool) -> usize {
         self.opened.push(ChangeTrackedParams {
             initial: e.clone(),
+            history: EditHistory::new(e.clone()),
             inner: WindowParams {
                 params: e.edit_params(),
                 inner: e,
@@ -241,7 +300,81 @@ impl<
     }
 }
 
+fn has_unsaved<
+    Entity: Serialize,
+    EntityId: PartialEq + Default + Serialize + DeserializeOwned,
+    Action: Default + Serialize + DeserializeOwned,
+    Params: Default + Serialize + DeserializeOwned,
+>(
+    editor: &EntityEditParams<Entity, EntityId, Action, Params>,
+    id: EntityId,
+) -> bool {
+    editor
+        .opened
+        .iter()
+        .any(|v| v.inner.initial_id == id && (v.changed || v.is_new))
+}
+
 impl Editors {
+    /// Any opened entity is new or edited
+    pub fn has_any_unsaved_changes(&self) -> bool {
+        fn unsaved<
+            E: Serialize,
+            I: Default + Serialize + DeserializeOwned,
+            A: Default + Serialize + DeserializeOwned,
+            P: Default + Serialize + DeserializeOwned,
+        >(
+            v: &ChangeTrackedParams<E, I, A, P>,
+        ) -> bool {
+            v.changed || v.is_new
+        }
+
+        self.npcs.opened.iter().any(unsaved)
+            || self.quests.opened.iter().any(unsaved)
+            || self.skills.opened.iter().any(unsaved)
+            || self.weapons.opened.iter().any(unsaved)
+            || self.armor.opened.iter().any(unsaved)
+            || self.etc_items.opened.iter().any(unsaved)
+            || self.item_sets.opened.iter().any(unsaved)
+            || self.recipes.opened.iter().any(unsaved)
+            || self.hunting_zones.opened.iter().any(unsaved)
+            || self.regions.opened.iter().any(unsaved)
+            || self.raid_info.opened.iter().any(unsaved)
+            || self.daily_mission.opened.iter().any(unsaved)
+            || self.animation_combo.opened.iter().any(unsaved)
+            || self.residences.opened.iter().any(unsaved)
+            || self.ensoul_options.opened.iter().any(unsaved)
+            || self.hennas.opened.iter().any(unsaved)
+            || self.user_actions.opened.iter().any(unsaved)
+            || self.products.opened.iter().any(unsaved)
+            || self.class_infos.opened.iter().any(unsaved)
+    }
+
+    /// Drops every opened entity, unsaved edits included
+    pub fn close_all(&mut self) {
+        self.npcs.opened.clear();
+        self.quests.opened.clear();
+        self.skills.opened.clear();
+        self.weapons.opened.clear();
+        self.armor.opened.clear();
+        self.etc_items.opened.clear();
+        self.item_sets.opened.clear();
+        self.recipes.opened.clear();
+        self.hunting_zones.opened.clear();
+        self.regions.opened.clear();
+        self.raid_info.opened.clear();
+        self.daily_mission.opened.clear();
+        self.animation_combo.opened.clear();
+        self.residences.opened.clear();
+        self.ensoul_options.opened.clear();
+        self.hennas.opened.clear();
+        self.user_actions.opened.clear();
+        self.products.opened.clear();
+        self.class_infos.opened.clear();
+
+        self.current_entity = CurrentEntity::None;
+    }
+
     pub fn close_if_opened(&mut self, entity: GameEntityT) {
         match entity {
             GameEntityT::Quest(id) => {
@@ -409,10 +542,169 @@ impl Editors {
                     self.ensoul_options.opened.remove(i);
                 }
             }
+            GameEntityT::Henna(id) => {
+                if let Some((i, _)) = self
+                    .hennas
+                    .opened
+                    .iter()
+                    .enumerate()
+                    .find(|(_, v)| v.inner.initial_id == id)
+                {
+                    self.hennas.opened.remove(i);
+                }
+            }
+            GameEntityT::UserAction(id) => {
+                if let Some((i, _)) = self
+                    .user_actions
+                    .opened
+                    .iter()
+                    .enumerate()
+                    .find(|(_, v)| v.inner.initial_id == id)
+                {
+                    self.user_actions.opened.remove(i);
+                }
+            }
+            GameEntityT::Product(id) => {
+                if let Some((i, _)) = self
+                    .products
+                    .opened
+                    .iter()
+                    .enumerate()
+                    .find(|(_, v)| v.inner.initial_id == id)
+                {
+                    self.products.opened.remove(i);
+                }
+            }
+            GameEntityT::ClassInfo(id) => {
+                if let Some((i, _)) = self
+                    .class_infos
+                    .opened
+                    .iter()
+                    .enumerate()
+                    .find(|(_, v)| v.inner.initial_id == id)
+                {
+                    self.class_infos.opened.remove(i);
+                }
+            }
         }
 
         self.find_opened_entity();
     }
+
+    /// Entity is opened in a tab with changes that are not saved to holder
+    pub fn has_unsaved_changes(&self, entity: GameEntityT) -> bool {
+        match entity {
+            GameEntityT::Quest(id) => has_unsaved(&self.quests, id),
+            GameEntityT::Skill(id) => has_unsaved(&self.skills, id),
+            GameEntityT::Npc(id) => has_unsaved(&self.npcs, id),
+            GameEntityT::Weapon(id) => has_unsaved(&self.weapons, id),
+            GameEntityT::Armor(id) => has_unsaved(&self.armor, id),
+            GameEntityT::EtcItem(id) => has_unsaved(&self.etc_items, id),
+            GameEntityT::ItemSet(id) => has_unsaved(&self.item_sets, id),
+            GameEntityT::Recipe(id) => has_unsaved(&self.recipes, id),
+            GameEntityT::HuntingZone(id) => has_unsaved(&self.hunting_zones, id),
+            GameEntityT::Region(id) => has_unsaved(&self.regions, id),
+            GameEntityT::RaidInfo(id) => has_unsaved(&self.raid_info, id),
+            GameEntityT::DailyMission(id) => has_unsaved(&self.daily_mission, id),
+            GameEntityT::AnimationCombo(id) => has_unsaved(&self.animation_combo, id),
+            GameEntityT::Residence(id) => has_unsaved(&self.residences, id),
+            GameEntityT::EnsoulOption(id) => has_unsaved(&self.ensoul_options, id),
+            GameEntityT::Henna(id) => has_unsaved(&self.hennas, id),
+            GameEntityT::UserAction(id) => has_unsaved(&self.user_actions, id),
+            GameEntityT::Product(id) => has_unsaved(&self.products, id),
+            GameEntityT::ClassInfo(id) => has_unsaved(&self.class_infos, id),
+        }
+    }
+
+    pub fn open_entity(&mut self, entity: GameEntityT, holders: &mut GameDataHolder) {
+        match entity {
+            GameEntityT::Quest(id) => self.open_quest(id, &mut holders.quest_holder),
+            GameEntityT::Skill(id) => self.open_skill(id, &mut holders.skill_holder),
+            GameEntityT::Npc(id) => self.open_npc(id, &mut holders.npc_holder),
+            GameEntityT::Weapon(id) => self.open_weapon(id, &mut holders.weapon_holder),
+            GameEntityT::Armor(id) => self.open_armor(id, &mut holders.armor_holder),
+            GameEntityT::EtcItem(id) => self.open_etc_item(id, &mut holders.etc_item_holder),
+            GameEntityT::ItemSet(id) => self.open_item_set(id, &mut holders.item_set_holder),
+            GameEntityT::Recipe(id) => self.open_recipe(id, &mut holders.recipe_holder),
+            GameEntityT::HuntingZone(id) => {
+                self.open_hunting_zone(id, &mut holders.hunting_zone_holder)
+            }
+            GameEntityT::Region(id) => self.open_region(id, &mut holders.region_holder),
+            GameEntityT::RaidInfo(id) => self.open_raid_info(id, &mut holders.raid_info_holder),
+            GameEntityT::DailyMission(id) => {
+                self.open_daily_mission(id, &mut holders.daily_mission_holder)
+            }
+            GameEntityT::AnimationCombo(id) => {
+                self.open_animation_combo(id, &mut holders.animation_combo_holder)
+            }
+            GameEntityT::Residence(id) => self.open_residence(id, &mut holders.residence_holder),
+            GameEntityT::EnsoulOption(id) => {
+                self.open_ensoul_option(id, &mut holders.ensoul_option_holder)
+            }
+            GameEntityT::Henna(id) => self.open_henna(id, &mut holders.henna_holder),
+            GameEntityT::UserAction(id) => {
+                self.open_user_action(id, &mut holders.user_action_holder)
+            }
+            GameEntityT::Product(id) => self.open_product(id, &mut holders.product_holder),
+            GameEntityT::ClassInfo(id) => self.open_class_info(id, &mut holders.class_info_holder),
+        }
+    }
+
+    /// Entity behind the current tab as it is stored in holder
+    pub fn current_game_entity(&self) -> Option<GameEntityT> {
+        Some(match self.current_entity {
+            CurrentEntity::Quest(i) => GameEntityT::Quest(self.quests.opened[i].inner.initial_id),
+            CurrentEntity::Skill(i) => GameEntityT::Skill(self.skills.opened[i].inner.initial_id),
+            CurrentEntity::Npc(i) => GameEntityT::Npc(self.npcs.opened[i].inner.initial_id),
+            CurrentEntity::Weapon(i) => {
+                GameEntityT::Weapon(self.weapons.opened[i].inner.initial_id)
+            }
+            CurrentEntity::EtcItem(i) => {
+                GameEntityT::EtcItem(self.etc_items.opened[i].inner.initial_id)
+            }
+            CurrentEntity::Armor(i) => GameEntityT::Armor(self.armor.opened[i].inner.initial_id),
+            CurrentEntity::ItemSet(i) => {
+                GameEntityT::ItemSet(self.item_sets.opened[i].inner.initial_id)
+            }
+            CurrentEntity::Recipe(i) => {
+                GameEntityT::Recipe(self.recipes.opened[i].inner.initial_id)
+            }
+            CurrentEntity::HuntingZone(i) => {
+                GameEntityT::HuntingZone(self.hunting_zones.opened[i].inner.initial_id)
+            }
+            CurrentEntity::Region(i) => {
+                GameEntityT::Region(self.regions.opened[i].inner.initial_id)
+            }
+            CurrentEntity::RaidInfo(i) => {
+                GameEntityT::RaidInfo(self.raid_info.opened[i].inner.initial_id)
+            }
+            CurrentEntity::DailyMission(i) => {
+                GameEntityT::DailyMission(self.daily_mission.opened[i].inner.initial_id)
+            }
+            CurrentEntity::AnimationCombo(i) => {
+                GameEntityT::AnimationCombo(self.animation_combo.opened[i].inner.initial_id)
+            }
+            CurrentEntity::Residence(i) => {
+                GameEntityT::Residence(self.residences.opened[i].inner.initial_id)
+            }
+            CurrentEntity::EnsoulOption(i) => {
+                GameEntityT::EnsoulOption(self.ensoul_options.opened[i].inner.initial_id)
+            }
+            CurrentEntity::Henna(i) => GameEntityT::Henna(self.hennas.opened[i].inner.initial_id),
+            CurrentEntity::UserAction(i) => {
+                GameEntityT::UserAction(self.user_actions.opened[i].inner.initial_id)
+            }
+            CurrentEntity::Product(i) => {
+                GameEntityT::Product(self.products.opened[i].inner.initial_id)
+            }
+            CurrentEntity::ClassInfo(i) => {
+                GameEntityT::ClassInfo(self.class_infos.opened[i].inner.initial_id)
+            }
+
+            CurrentEntity::None => return None,
+        })
+    }
+
     pub fn reset_initial(&mut self, entity: GameEntity, holders: &GameDataHolder) {
         match entity {
             GameEntity::Quest => self.quests.reset_initial(&holders.quest_holder),
@@ -438,6 +730,10 @@ impl Editors {
             GameEntity::EnsoulOption => self
                 .ensoul_options
                 .reset_initial(&holders.ensoul_option_holder),
+            GameEntity::Henna => self.hennas.reset_initial(&holders.henna_holder),
+            GameEntity::UserAction => self.user_actions.reset_initial(&holders.user_action_holder),
+            GameEntity::Product => self.products.reset_initial(&holders.product_holder),
+            GameEntity::ClassInfo => self.class_infos.reset_initial(&holders.class_info_holder),
         }
     }
     pub(crate) fn find_opened_entity(&mut self) {
@@ -558,6 +854,37 @@ impl Editors {
                     return;
                 }
             }
+            CurrentEntity::Henna(i) => {
+                if !self.hennas.opened.is_empty() {
+                    self.current_entity = CurrentEntity::Henna(i.min(self.hennas.opened.len() - 1));
+
+                    return;
+                }
+            }
+            CurrentEntity::UserAction(i) => {
+                if !self.user_actions.opened.is_empty() {
+                    self.current_entity =
+                        CurrentEntity::UserAction(i.min(self.user_actions.opened.len() - 1));
+
+                    return;
+                }
+            }
+            CurrentEntity::Product(i) => {
+                if !self.products.opened.is_empty() {
+                    self.current_entity =
+                        CurrentEntity::Product(i.min(self.products.opened.len() - 1));
+
+                    return;
+                }
+            }
+            CurrentEntity::ClassInfo(i) => {
+                if !self.class_infos.opened.is_empty() {
+                    self.current_entity =
+                        CurrentEntity::ClassInfo(i.min(self.class_infos.opened.len() - 1));
+
+                    return;
+                }
+            }
 
             CurrentEntity::None => {}
         }
@@ -592,6 +919,14 @@ impl Editors {
             self.current_entity = CurrentEntity::Residence(self.residences.len() - 1);
         } else if !self.ensoul_options.is_empty() {
             self.current_entity = CurrentEntity::EnsoulOption(self.ensoul_options.len() - 1);
+        } else if !self.hennas.is_empty() {
+            self.current_entity = CurrentEntity::Henna(self.hennas.len() - 1);
+        } else if !self.user_actions.is_empty() {
+            self.current_entity = CurrentEntity::UserAction(self.user_actions.len() - 1);
+        } else if !self.products.is_empty() {
+            self.current_entity = CurrentEntity::Product(self.products.len() - 1);
+        } else if !self.class_infos.is_empty() {
+            self.current_entity = CurrentEntity::ClassInfo(self.class_infos.len() - 1);
         } else {
             self.current_entity = CurrentEntity::None;
         }
@@ -0,0 +1,143 @@
+use serde::{Deserialize, Serialize};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const HISTORY_LIMIT: usize = 64;
+
+#[derive(Serialize, Deserialize, Clone)]
+struct HistoryRecord<Entity> {
+    entity: Entity,
+    timestamp: u64,
+}
+
+impl<Entity> HistoryRecord<Entity> {
+    fn new(entity: Entity) -> Self {
+        Self {
+            entity,
+            timestamp: now(),
+        }
+    }
+}
+
+/// Snapshots of editor tab state. Records are taken on change checks, so fast sequential
+/// edits (typing) are collapsed into one step
+#[derive(Serialize, Deserialize, Clone)]
+pub struct EditHistory<Entity> {
+    records: Vec<HistoryRecord<Entity>>,
+    current: usize,
+}
+
+impl<Entity> EditHistory<Entity> {
+    pub fn new(initial: Entity) -> Self {
+        Self {
+            records: vec![HistoryRecord::new(initial)],
+            current: 0,
+        }
+    }
+}
+
+/// Empty history, first [`EditHistory::record`] makes the initial step
+impl<Entity> Default for EditHistory<Entity> {
+    fn default() -> Self {
+        Self {
+            records: vec![],
+            current: 0,
+        }
+    }
+}
+
+impl<Entity: PartialEq + Clone> EditHistory<Entity> {
+    /// Pushes `entity` as a new step if it differs from the current one. Drops redo steps
+    pub fn record(&mut self, entity: &Entity) {
+        if !self.is_unrecorded(entity) {
+            return;
+        }
+
+        self.records.truncate(self.current + 1);
+        self.records.push(HistoryRecord::new(entity.clone()));
+
+        if self.records.len() > HISTORY_LIMIT {
+            self.records.remove(0);
+        }
+
+        self.current = self.records.len() - 1;
+    }
+
+    pub fn is_unrecorded(&self, entity: &Entity) -> bool {
+        self.records
+            .get(self.current)
+            .is_none_or(|v| v.entity != *entity)
+    }
+
+    pub fn can_undo(&self) -> bool {
+        self.current > 0
+    }
+
+    pub fn can_redo(&self) -> bool {
+        self.current + 1 < self.records.len()
+    }
+
+    pub fn undo(&mut self) -> Option<&Entity> {
+        if !self.can_undo() {
+            return None;
+        }
+
+        self.go_to(self.current - 1)
+    }
+
+    pub fn redo(&mut self) -> Option<&Entity> {
+        if !self.can_redo() {
+            return None;
+        }
+
+        self.go_to(self.current + 1)
+    }
+
+    pub fn go_to(&mut self, index: usize) -> Option<&Entity> {
+        if index >= self.records.len() || index == self.current {
+            return None;
+        }
+
+        self.current = index;
+
+        Some(&self.records[index].entity)
+    }
+
+    pub fn current(&self) -> usize {
+        self.current
+    }
+
+    /// Human-readable step names, oldest first
+    pub fn labels(&self) -> Vec<String> {
+        let now = now();
+
+        self.records
+            .iter()
+            .enumerate()
+            .map(|(i, v)| {
+                let age = now.saturating_sub(v.timestamp);
+
+                let age = if age < 60 {
+                    format!("{age}s ago")
+                } else if age < 60 * 60 {
+                    format!("{}m ago", age / 60)
+                } else if age < 60 * 60 * 24 {
+                    format!("{}h ago", age / (60 * 60))
+                } else {
+                    format!("{}d ago", age / (60 * 60 * 24))
+                };
+
+                if i == 0 {
+                    format!("Opened ({age})")
+                } else {
+                    format!("Edit #{i} ({age})")
+                }
+            })
+            .collect()
+    }
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |v| v.as_secs())
+}
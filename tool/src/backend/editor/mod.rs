@@ -1,21 +1,25 @@
 use crate::backend::editor::dictionaries::DictEditors;
 use crate::backend::entity_impl::animation_combo::AnimationComboEditor;
+use crate::backend::entity_impl::class_info::ClassInfoEditor;
 use crate::backend::entity_impl::daily_missions::DailyMissionEditor;
 use crate::backend::entity_impl::ensoul_option::EnsoulOptionEditor;
+use crate::backend::entity_impl::henna::HennaEditor;
 use crate::backend::entity_impl::hunting_zone::HuntingZoneEditor;
 use crate::backend::entity_impl::item::armor::ArmorEditor;
 use crate::backend::entity_impl::item::etc_item::EtcItemEditor;
 use crate::backend::entity_impl::item::weapon::WeaponEditor;
 use crate::backend::entity_impl::item_set::ItemSetEditor;
 use crate::backend::entity_impl::npc::NpcEditor;
+use crate::backend::entity_impl::product::ProductEditor;
 use crate::backend::entity_impl::quest::QuestEditor;
 use crate::backend::entity_impl::raid_info::RaidInfoEditor;
 use crate::backend::entity_impl::recipe::RecipeEditor;
 use crate::backend::entity_impl::region::RegionEditor;
 use crate::backend::entity_impl::residence::ResidenceEditor;
 use crate::backend::entity_impl::skill::SkillEditor;
+use crate::backend::entity_impl::user_action::UserActionEditor;
+use crate::backend::holder::DumpFormat;
 use crate::entity::GameEntity;
-use ron::de::SpannedError;
 use serde::de::{MapAccess, SeqAccess, Visitor};
 use serde::ser::SerializeStruct;
 use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
@@ -27,14 +31,23 @@ use strum_macros::Display;
 
 pub mod dictionaries;
 pub mod entity;
+pub mod history;
 
 pub trait EditParamsCommonOps {
     fn is_changed(&self) -> bool;
     fn on_save(&mut self);
     fn check_change(&mut self);
     fn handle_actions(&mut self);
-    fn get_wrapped_entity_as_ron_string(&self) -> String;
-    fn set_wrapped_entity_from_ron_string(&mut self, val: &str) -> Result<(), SpannedError>;
+    fn get_wrapped_entity_as_string(&self, format: DumpFormat) -> anyhow::Result<String>;
+    /// Format is detected from `val`
+    fn set_wrapped_entity_from_string(&mut self, val: &str) -> anyhow::Result<()>;
+    fn undo(&mut self);
+    fn redo(&mut self);
+    fn go_to_history_record(&mut self, index: usize);
+    fn can_undo(&self) -> bool;
+    fn can_redo(&self) -> bool;
+    fn history_labels(&self) -> Vec<String>;
+    fn history_position(&self) -> usize;
 }
 
 #[derive(Serialize, Deserialize, Default, Eq, PartialEq, Copy, Clone)]
@@ -56,6 +69,10 @@ pub enum CurrentEntity {
     AnimationCombo(usize),
     Residence(usize),
     EnsoulOption(usize),
+    Henna(usize),
+    UserAction(usize),
+    Product(usize),
+    ClassInfo(usize),
 }
 
 impl CurrentEntity {
@@ -81,6 +98,10 @@ pub struct Editors {
     pub animation_combo: AnimationComboEditor,
     pub residences: ResidenceEditor,
     pub ensoul_options: EnsoulOptionEditor,
+    pub hennas: HennaEditor,
+    pub user_actions: UserActionEditor,
+    pub products: ProductEditor,
+    pub class_infos: ClassInfoEditor,
 
     pub current_entity: CurrentEntity,
 
@@ -108,6 +129,10 @@ impl Index<GameEntity> for Editors {
             GameEntity::AnimationCombo => &self.animation_combo,
             GameEntity::Residence => &self.residences,
             GameEntity::EnsoulOption => &self.ensoul_options,
+            GameEntity::Henna => &self.hennas,
+            GameEntity::UserAction => &self.user_actions,
+            GameEntity::Product => &self.products,
+            GameEntity::ClassInfo => &self.class_infos,
         }
     }
 }
@@ -1,24 +1,29 @@
 use crate::backend::holder::HolderMapOps;
 use crate::backend::util::is_in_range;
 use crate::common::{
-    AnimationComboId, DailyMissionId, EnsoulOptionId, HuntingZoneId, ItemId, ItemSetId, NpcId,
-    QuestId, RaidInfoId, RecipeId, RegionId, ResidenceId, SkillId,
+    AnimationComboId, ClassId, DailyMissionId, EnsoulOptionId, HennaId, HuntingZoneId, ItemId,
+    ItemSetId, NpcId, ProductId, QuestId, RaidInfoId, RecipeId, RegionId, ResidenceId, SkillId,
+    UserActionId,
 };
 use crate::entity::animation_combo::AnimationCombo;
+use crate::entity::class_info::ClassInfo;
 use crate::entity::daily_mission::DailyMission;
 use crate::entity::ensoul_option::EnsoulOption;
+use crate::entity::henna::Henna;
 use crate::entity::hunting_zone::HuntingZone;
 use crate::entity::item::armor::Armor;
 use crate::entity::item::etc_item::EtcItem;
 use crate::entity::item::weapon::Weapon;
 use crate::entity::item_set::ItemSet;
 use crate::entity::npc::Npc;
+use crate::entity::product::Product;
 use crate::entity::quest::Quest;
 use crate::entity::raid_info::RaidInfo;
 use crate::entity::recipe::Recipe;
 use crate::entity::region::Region;
 use crate::entity::residence::Residence;
 use crate::entity::skill::Skill;
+use crate::entity::user_action::UserAction;
 use crate::entity::{CommonEntity, GameEntity};
 use std::cmp::Ordering;
 use std::hash::Hash;
@@ -157,6 +162,10 @@ pub struct EntityCatalogsHolder {
     pub animation_combo: EntityCatalog<AnimationCombo, AnimationComboId>,
     pub residence: EntityCatalog<Residence, ResidenceId>,
     pub ensoul_option: EntityCatalog<EnsoulOption, EnsoulOptionId>,
+    pub henna: EntityCatalog<Henna, HennaId>,
+    pub user_action: EntityCatalog<UserAction, UserActionId>,
+    pub product: EntityCatalog<Product, ProductId>,
+    pub class_info: EntityCatalog<ClassInfo, ClassId>,
 }
 
 impl EntityCatalogsHolder {
@@ -483,6 +492,98 @@ impl EntityCatalogsHolder {
                     }
                 }),
             },
+            henna: EntityCatalog {
+                filter: "".to_string(),
+                search_tooltip: "\
+Фильтр по диапазону Id - id:start-end или id:start
+Фильтр по Id предмета краски - item:id"
+                    .to_string(),
+                history: vec![],
+                catalog: vec![],
+                filter_fn: Box::new(|v, s| {
+                    if s.is_empty() {
+                        true
+                    } else if let Ok(id) = u32::from_str(s) {
+                        v.id.0 == id
+                    } else if let Some(range) = s.strip_prefix("id:") {
+                        is_in_range(range, v.id.0)
+                    } else if let Some(item) = s.strip_prefix("item:") {
+                        u32::from_str(item).is_ok_and(|id| v.dye_item_id.0 == id)
+                    } else {
+                        v.name.lowered_contains(s) || v.additional_name.lowered_contains(s)
+                    }
+                }),
+            },
+            user_action: EntityCatalog {
+                filter: "".to_string(),
+                search_tooltip: "\
+Фильтр по диапазону Id - id:start-end или id:start
+Фильтр по команде - cmd:command"
+                    .to_string(),
+                history: vec![],
+                catalog: vec![],
+                filter_fn: Box::new(|v, s| {
+                    if s.is_empty() {
+                        true
+                    } else if let Ok(id) = u32::from_str(s) {
+                        v.id.0 == id
+                    } else if let Some(range) = s.strip_prefix("id:") {
+                        is_in_range(range, v.id.0)
+                    } else if let Some(cmd) = s.strip_prefix("cmd:") {
+                        v.command.to_lowercase().contains(cmd)
+                    } else {
+                        v.name.lowered_contains(s)
+                    }
+                }),
+            },
+            product: EntityCatalog {
+                filter: "".to_string(),
+                search_tooltip: "\
+Фильтр по диапазону Id - id:start-end или id:start
+Фильтр по содержащемуся предмету - item:id
+Фильтр по категории - category:id"
+                    .to_string(),
+                history: vec![],
+                catalog: vec![],
+                filter_fn: Box::new(|v, s| {
+                    if s.is_empty() {
+                        true
+                    } else if let Ok(id) = u32::from_str(s) {
+                        v.id.0 == id
+                    } else if let Some(range) = s.strip_prefix("id:") {
+                        is_in_range(range, v.id.0)
+                    } else if let Some(item) = s.strip_prefix("item:") {
+                        u32::from_str(item)
+                            .is_ok_and(|id| v.items.iter().any(|i| i.item_id.0 == id))
+                    } else if let Some(category) = s.strip_prefix("category:") {
+                        u32::from_str(category).is_ok_and(|c| v.category == c)
+                    } else {
+                        v.name.lowered_contains(s)
+                    }
+                }),
+            },
+            class_info: EntityCatalog {
+                filter: "".to_string(),
+                search_tooltip: "\
+Фильтр по диапазону Id - id:start-end или id:start
+Фильтр по родительскому классу - parent:id"
+                    .to_string(),
+                history: vec![],
+                catalog: vec![],
+                filter_fn: Box::new(|v, s| {
+                    if s.is_empty() {
+                        true
+                    } else if let Ok(id) = u32::from_str(s) {
+                        v.id.0 == id
+                    } else if let Some(range) = s.strip_prefix("id:") {
+                        is_in_range(range, v.id.0)
+                    } else if let Some(parent) = s.strip_prefix("parent:") {
+                        u32::from_str(parent).is_ok_and(|id| v.parent.is_some_and(|p| p.0 == id))
+                    } else {
+                        v.name.lowered_contains(s)
+                    }
+                }),
+            },
         }
     }
 }
@@ -520,6 +621,10 @@ impl Index<GameEntity> for EntityCatalogsHolder {
             GameEntity::AnimationCombo => &self.animation_combo,
             GameEntity::Residence => &self.residence,
             GameEntity::EnsoulOption => &self.ensoul_option,
+            GameEntity::Henna => &self.henna,
+            GameEntity::UserAction => &self.user_action,
+            GameEntity::Product => &self.product,
+            GameEntity::ClassInfo => &self.class_info,
         }
     }
 }
@@ -0,0 +1,118 @@
+use crate::backend::editor::entity::{CommonEditorOps, EntityEditParams};
+use crate::backend::editor::{CurrentEntity, EditParamsCommonOps, Editors, WindowParams};
+use crate::backend::entity_catalog::EntityInfo;
+use crate::backend::holder::{FHashMap, HolderMapOps};
+use crate::backend::{Backend, HandleAction};
+use crate::common::ClassId;
+use crate::entity::class_info::ClassInfo;
+use crate::entity::{CommonEntity, GameEntityT};
+use serde::{Deserialize, Serialize};
+
+pub type ClassInfoEditor = EntityEditParams<ClassInfo, ClassId, ClassInfoAction, ()>;
+
+impl HandleAction for WindowParams<ClassInfo, ClassId, ClassInfoAction, ()> {
+    fn handle_action(&mut self) {
+        let item = self;
+
+        let mut action = item.action.write().unwrap();
+
+        match *action {
+            ClassInfoAction::None => {}
+        }
+
+        *action = ClassInfoAction::None;
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, Default)]
+pub enum ClassInfoAction {
+    #[default]
+    None,
+}
+
+impl Editors {
+    pub fn get_opened_class_info_info(&self) -> Vec<(String, ClassId, bool)> {
+        self.class_infos.get_opened_info()
+    }
+
+    pub fn open_class_info(&mut self, id: ClassId, holder: &mut FHashMap<ClassId, ClassInfo>) {
+        for (i, q) in self.class_infos.opened.iter().enumerate() {
+            if q.inner.initial_id == id {
+                self.current_entity = CurrentEntity::ClassInfo(i);
+
+                return;
+            }
+        }
+
+        if let Some(q) = holder.get(&id) {
+            self.current_entity =
+                CurrentEntity::ClassInfo(self.class_infos.add(q.clone(), q.id(), false));
+        }
+    }
+
+    pub fn set_current_class_info(&mut self, index: usize) {
+        if index < self.class_infos.opened.len() {
+            self.current_entity = CurrentEntity::ClassInfo(index);
+        }
+    }
+
+    pub fn create_new_class_info(&mut self) {
+        self.current_entity = CurrentEntity::ClassInfo(self.class_infos.add_new());
+    }
+}
+
+impl Backend {
+    pub fn filter_class_info(&mut self) {
+        self.entity_catalogs.class_info.filter(
+            &self.holders.game_data_holder.class_info_holder,
+            self.entity_catalogs.filter_mode,
+        );
+    }
+
+    pub fn save_class_info_from_dlg(&mut self, id: ClassId) {
+        if let CurrentEntity::ClassInfo(index) = self.editors.current_entity {
+            let new_entity = self.editors.class_infos.opened.get_mut(index).unwrap();
+
+            if new_entity.inner.inner.id() != id {
+                return;
+            }
+
+            new_entity.inner.initial_id = new_entity.inner.inner.id;
+
+            let entity = new_entity.inner.inner.clone();
+
+            new_entity.on_save();
+
+            self.save_class_info_force(entity);
+        }
+    }
+
+    pub fn save_class_info_force(&mut self, mut v: ClassInfo) {
+        if let Some(vv) = self.holders.game_data_holder.class_info_holder.get(&v.id)
+            && *vv == v
+        {
+            return;
+        }
+
+        v._changed = true;
+
+        self.holders
+            .game_data_holder
+            .reference_index
+            .update(GameEntityT::ClassInfo(v.id), &v);
+
+        self.holders
+            .game_data_holder
+            .class_info_holder
+            .insert(v.id, v);
+
+        self.filter_class_info();
+        self.check_for_unwrote_changed();
+    }
+}
+
+impl From<&ClassInfo> for EntityInfo<ClassInfo, ClassId> {
+    fn from(value: &ClassInfo) -> Self {
+        EntityInfo::new(&format!("ID: {}\n{}", value.id.0, value.name.main()), value)
+    }
+}
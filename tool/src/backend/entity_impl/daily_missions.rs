@@ -4,8 +4,8 @@ use crate::backend::entity_catalog::EntityInfo;
 use crate::backend::holder::{FHashMap, HolderMapOps};
 use crate::backend::{Backend, HandleAction};
 use crate::common::DailyMissionId;
-use crate::entity::CommonEntity;
 use crate::entity::daily_mission::DailyMission;
+use crate::entity::{CommonEntity, GameEntityT};
 use serde::{Deserialize, Serialize};
 
 pub type DailyMissionEditor =
@@ -110,6 +110,11 @@ impl Backend {
             }
         v._changed = true;
 
+        self.holders
+            .game_data_holder
+            .reference_index
+            .update(GameEntityT::DailyMission(v.id), &v);
+
         self.holders
             .game_data_holder
             .daily_mission_holder
@@ -122,6 +127,6 @@ impl Backend {
 
 impl From<&DailyMission> for EntityInfo<DailyMission, DailyMissionId> {
     fn from(value: &DailyMission) -> Self {
-        EntityInfo::new(&format!("ID: {}\n{}", value.id.0, value.name.ru), value)
+        EntityInfo::new(&format!("ID: {}\n{}", value.id.0, value.name.main()), value)
     }
 }
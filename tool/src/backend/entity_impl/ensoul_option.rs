@@ -117,6 +117,11 @@ impl Backend {
 
         v._changed = true;
 
+        self.holders
+            .game_data_holder
+            .reference_index
+            .update(GameEntityT::EnsoulOption(v.id), &v);
+
         self.holders
             .game_data_holder
             .ensoul_option_holder
@@ -132,7 +137,10 @@ impl From<&EnsoulOption> for EntityInfo<EnsoulOption, EnsoulOptionId> {
         EntityInfo::new(
             &format!(
                 "ID: {}\n{} {} {}",
-                value.id.0, value.name.ru, value.option_type, value.step
+                value.id.0,
+                value.name.main(),
+                value.option_type,
+                value.step
             ),
             value,
         )
@@ -0,0 +1,135 @@
+use crate::backend::editor::entity::{CommonEditorOps, EntityEditParams};
+use crate::backend::editor::{CurrentEntity, EditParamsCommonOps, Editors, WindowParams};
+use crate::backend::entity_catalog::EntityInfo;
+use crate::backend::holder::{FHashMap, HolderMapOps};
+use crate::backend::{Backend, HandleAction};
+use crate::common::HennaId;
+use crate::entity::henna::Henna;
+use crate::entity::{CommonEntity, GameEntityT};
+use serde::{Deserialize, Serialize};
+
+pub type HennaEditor = EntityEditParams<Henna, HennaId, HennaAction, ()>;
+
+impl HandleAction for WindowParams<Henna, HennaId, HennaAction, ()> {
+    fn handle_action(&mut self) {
+        let item = self;
+
+        let mut action = item.action.write().unwrap();
+
+        match *action {
+            HennaAction::None => {}
+        }
+
+        *action = HennaAction::None;
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, Default)]
+pub enum HennaAction {
+    #[default]
+    None,
+}
+
+impl Editors {
+    pub fn get_opened_henna_info(&self) -> Vec<(String, HennaId, bool)> {
+        self.hennas.get_opened_info()
+    }
+
+    pub fn force_update_henna(&mut self, item: &Henna) {
+        if item._deleted {
+            self.close_if_opened(GameEntityT::Henna(item.id));
+        } else if let Some(v) = self
+            .hennas
+            .opened
+            .iter_mut()
+            .find(|v| v.inner.inner.id() == item.id())
+        {
+            v.inner.inner = item.clone();
+        }
+    }
+
+    pub fn open_henna(&mut self, id: HennaId, holder: &mut FHashMap<HennaId, Henna>) {
+        for (i, q) in self.hennas.opened.iter().enumerate() {
+            if q.inner.initial_id == id {
+                self.current_entity = CurrentEntity::Henna(i);
+
+                return;
+            }
+        }
+
+        if let Some(q) = holder.get(&id) {
+            self.current_entity = CurrentEntity::Henna(self.hennas.add(q.clone(), q.id(), false));
+        }
+    }
+
+    pub fn set_current_henna(&mut self, index: usize) {
+        if index < self.hennas.opened.len() {
+            self.current_entity = CurrentEntity::Henna(index);
+        }
+    }
+
+    pub fn create_new_henna(&mut self) {
+        self.current_entity = CurrentEntity::Henna(self.hennas.add_new());
+    }
+}
+
+impl Backend {
+    pub fn filter_henna(&mut self) {
+        self.entity_catalogs.henna.filter(
+            &self.holders.game_data_holder.henna_holder,
+            self.entity_catalogs.filter_mode,
+        );
+    }
+
+    pub fn save_henna_from_dlg(&mut self, id: HennaId) {
+        if let CurrentEntity::Henna(index) = self.editors.current_entity {
+            let new_entity = self.editors.hennas.opened.get_mut(index).unwrap();
+
+            if new_entity.inner.inner.id() != id {
+                return;
+            }
+
+            new_entity.inner.initial_id = new_entity.inner.inner.id;
+
+            let entity = new_entity.inner.inner.clone();
+
+            new_entity.on_save();
+
+            self.save_henna_force(entity);
+        }
+    }
+
+    pub fn save_henna_force(&mut self, mut v: Henna) {
+        if let Some(vv) = self.holders.game_data_holder.henna_holder.get(&v.id)
+            && *vv == v
+        {
+            return;
+        }
+
+        v._changed = true;
+
+        self.holders
+            .game_data_holder
+            .reference_index
+            .update(GameEntityT::Henna(v.id), &v);
+
+        self.holders.game_data_holder.henna_holder.insert(v.id, v);
+
+        self.filter_henna();
+        self.check_for_unwrote_changed();
+    }
+}
+
+impl From<&Henna> for EntityInfo<Henna, HennaId> {
+    fn from(value: &Henna) -> Self {
+        EntityInfo::new(
+            &format!(
+                "ID: {}\n{} {}",
+                value.id.0,
+                value.name.main(),
+                value.additional_name.main()
+            ),
+            value,
+        )
+    }
+}
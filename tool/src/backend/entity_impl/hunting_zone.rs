@@ -4,8 +4,8 @@ use crate::backend::entity_catalog::EntityInfo;
 use crate::backend::holder::{FHashMap, HolderMapOps};
 use crate::backend::{Backend, HandleAction};
 use crate::common::HuntingZoneId;
-use crate::entity::CommonEntity;
 use crate::entity::hunting_zone::HuntingZone;
+use crate::entity::{CommonEntity, GameEntityT};
 use serde::{Deserialize, Serialize};
 
 pub type HuntingZoneEditor = EntityEditParams<HuntingZone, HuntingZoneId, HuntingZoneAction, ()>;
@@ -128,6 +128,11 @@ impl Backend {
             }
         v._changed = true;
 
+        self.holders
+            .game_data_holder
+            .reference_index
+            .update(GameEntityT::HuntingZone(v.id), &v);
+
         self.holders
             .game_data_holder
             .hunting_zone_holder
@@ -140,6 +145,6 @@ impl Backend {
 
 impl From<&HuntingZone> for EntityInfo<HuntingZone, HuntingZoneId> {
     fn from(value: &HuntingZone) -> Self {
-        EntityInfo::new(&format!("ID: {}\n{}", value.id.0, value.name.ru), value)
+        EntityInfo::new(&format!("ID: {}\n{}", value.id.0, value.name.main()), value)
     }
 }
@@ -157,6 +157,10 @@ impl Backend {
         {
             self.editors
                 .close_if_opened(GameEntityT::Weapon(v.base_info.id));
+            self.holders
+                .game_data_holder
+                .reference_index
+                .remove(GameEntityT::Weapon(v.base_info.id));
             self.filter_weapons();
         }
 
@@ -169,9 +173,17 @@ impl Backend {
         {
             self.editors
                 .close_if_opened(GameEntityT::EtcItem(v.base_info.id));
+            self.holders
+                .game_data_holder
+                .reference_index
+                .remove(GameEntityT::EtcItem(v.base_info.id));
             self.filter_etc_items();
         }
 
+        self.holders
+            .game_data_holder
+            .reference_index
+            .update(GameEntityT::Armor(v.base_info.id), &v);
         self.holders
             .game_data_holder
             .item_holder
@@ -191,7 +203,9 @@ impl From<&Armor> for EntityInfo<Armor, ItemId> {
         EntityInfo::new(
             &format!(
                 "ID: {}\n{} {}",
-                value.base_info.id.0, value.base_info.name.ru, value.base_info.additional_name.ru
+                value.base_info.id.0,
+                value.base_info.name.main(),
+                value.base_info.additional_name.main()
             ),
             value,
         )
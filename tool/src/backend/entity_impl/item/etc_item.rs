@@ -164,6 +164,10 @@ impl Backend {
         {
             self.editors
                 .close_if_opened(GameEntityT::Armor(v.base_info.id));
+            self.holders
+                .game_data_holder
+                .reference_index
+                .remove(GameEntityT::Armor(v.base_info.id));
             self.filter_armor();
         }
         if self
@@ -174,10 +178,18 @@ impl Backend {
             .is_some()
         {
             self.editors
-                .close_if_opened(GameEntityT::EtcItem(v.base_info.id));
+                .close_if_opened(GameEntityT::Weapon(v.base_info.id));
+            self.holders
+                .game_data_holder
+                .reference_index
+                .remove(GameEntityT::Weapon(v.base_info.id));
             self.filter_weapons();
         }
 
+        self.holders
+            .game_data_holder
+            .reference_index
+            .update(GameEntityT::EtcItem(v.base_info.id), &v);
         self.holders
             .game_data_holder
             .item_holder
@@ -197,7 +209,9 @@ impl From<&EtcItem> for EntityInfo<EtcItem, ItemId> {
         EntityInfo::new(
             &format!(
                 "ID: {}\n{} {}",
-                value.base_info.id.0, value.base_info.name.ru, value.base_info.additional_name.ru
+                value.base_info.id.0,
+                value.base_info.name.main(),
+                value.base_info.additional_name.main()
             ),
             value,
         )
@@ -225,6 +225,10 @@ impl Backend {
         {
             self.editors
                 .close_if_opened(GameEntityT::Armor(v.base_info.id));
+            self.holders
+                .game_data_holder
+                .reference_index
+                .remove(GameEntityT::Armor(v.base_info.id));
             self.filter_armor();
         }
         if self
@@ -236,9 +240,17 @@ impl Backend {
         {
             self.editors
                 .close_if_opened(GameEntityT::EtcItem(v.base_info.id));
+            self.holders
+                .game_data_holder
+                .reference_index
+                .remove(GameEntityT::EtcItem(v.base_info.id));
             self.filter_etc_items();
         }
 
+        self.holders
+            .game_data_holder
+            .reference_index
+            .update(GameEntityT::Weapon(v.base_info.id), &v);
         self.holders
             .game_data_holder
             .item_holder
@@ -258,7 +270,9 @@ impl From<&Weapon> for EntityInfo<Weapon, ItemId> {
         EntityInfo::new(
             &format!(
                 "ID: {}\n{} {}",
-                value.base_info.id.0, value.base_info.name.ru, value.base_info.additional_name.ru
+                value.base_info.id.0,
+                value.base_info.name.main(),
+                value.base_info.additional_name.main()
             ),
             value,
         )
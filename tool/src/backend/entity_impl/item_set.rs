@@ -4,8 +4,8 @@ use crate::backend::entity_catalog::EntityInfo;
 use crate::backend::holder::{FHashMap, HolderMapOps};
 use crate::backend::{Backend, HandleAction};
 use crate::common::{ItemId, ItemSetId};
-use crate::entity::CommonEntity;
 use crate::entity::item_set::ItemSet;
+use crate::entity::{CommonEntity, GameEntityT};
 use serde::{Deserialize, Serialize};
 
 pub type ItemSetEditor = EntityEditParams<ItemSet, ItemSetId, ItemSetAction, ()>;
@@ -164,6 +164,11 @@ impl Backend {
             }
         v._changed = true;
 
+        self.holders
+            .game_data_holder
+            .reference_index
+            .update(GameEntityT::ItemSet(v.id), &v);
+
         self.holders
             .game_data_holder
             .item_set_holder
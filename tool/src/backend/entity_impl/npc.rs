@@ -4,6 +4,7 @@ use crate::backend::entity_catalog::EntityInfo;
 use crate::backend::holder::{FHashMap, HolderMapOps};
 use crate::backend::{Backend, HandleAction};
 use crate::common::NpcId;
+use crate::entity::GameEntityT;
 use crate::entity::npc::Npc;
 use serde::{Deserialize, Serialize};
 
@@ -186,6 +187,11 @@ impl Backend {
 
         v._changed = true;
 
+        self.holders
+            .game_data_holder
+            .reference_index
+            .update(GameEntityT::Npc(v.id), &v);
+
         self.holders.game_data_holder.npc_holder.insert(v.id, v);
 
         self.filter_npcs();
@@ -195,6 +201,6 @@ impl Backend {
 
 impl From<&Npc> for EntityInfo<Npc, NpcId> {
     fn from(value: &Npc) -> Self {
-        EntityInfo::new(&format!("ID: {}\n{}", value.id.0, value.name.ru), value)
+        EntityInfo::new(&format!("ID: {}\n{}", value.id.0, value.name.main()), value)
     }
 }
@@ -0,0 +1,144 @@
+use crate::backend::editor::entity::{CommonEditorOps, EntityEditParams};
+use crate::backend::editor::{CurrentEntity, EditParamsCommonOps, Editors, WindowParams};
+use crate::backend::entity_catalog::EntityInfo;
+use crate::backend::holder::{FHashMap, GameDataHolder, HolderMapOps};
+use crate::backend::log_holder::Log;
+use crate::backend::{Backend, HandleAction};
+use crate::common::ProductId;
+use crate::entity::product::Product;
+use crate::entity::{CommonEntity, GameEntityT};
+use crate::logs_mut;
+use serde::{Deserialize, Serialize};
+
+pub type ProductEditor = EntityEditParams<Product, ProductId, ProductAction, ()>;
+
+impl HandleAction for WindowParams<Product, ProductId, ProductAction, ()> {
+    fn handle_action(&mut self) {
+        let item = self;
+
+        let mut action = item.action.write().unwrap();
+
+        match *action {
+            ProductAction::RemoveItem(i) => {
+                item.inner.items.remove(i);
+            }
+
+            ProductAction::None => {}
+        }
+
+        *action = ProductAction::None;
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, Default)]
+pub enum ProductAction {
+    #[default]
+    None,
+    RemoveItem(usize),
+}
+
+impl Editors {
+    pub fn get_opened_product_info(&self) -> Vec<(String, ProductId, bool)> {
+        self.products.get_opened_info()
+    }
+
+    pub fn open_product(&mut self, id: ProductId, holder: &mut FHashMap<ProductId, Product>) {
+        for (i, q) in self.products.opened.iter().enumerate() {
+            if q.inner.initial_id == id {
+                self.current_entity = CurrentEntity::Product(i);
+
+                return;
+            }
+        }
+
+        if let Some(q) = holder.get(&id) {
+            self.current_entity =
+                CurrentEntity::Product(self.products.add(q.clone(), q.id(), false));
+        }
+    }
+
+    pub fn set_current_product(&mut self, index: usize) {
+        if index < self.products.opened.len() {
+            self.current_entity = CurrentEntity::Product(index);
+        }
+    }
+
+    pub fn create_new_product(&mut self) {
+        self.current_entity = CurrentEntity::Product(self.products.add_new());
+    }
+}
+
+impl Backend {
+    pub fn filter_product(&mut self) {
+        self.entity_catalogs.product.filter(
+            &self.holders.game_data_holder.product_holder,
+            self.entity_catalogs.filter_mode,
+        );
+    }
+
+    pub fn save_product_from_dlg(&mut self, id: ProductId) {
+        if let CurrentEntity::Product(index) = self.editors.current_entity {
+            let new_entity = self.editors.products.opened.get_mut(index).unwrap();
+
+            if new_entity.inner.inner.id() != id {
+                return;
+            }
+
+            new_entity.inner.initial_id = new_entity.inner.inner.id;
+
+            let entity = new_entity.inner.inner.clone();
+
+            new_entity.on_save();
+
+            self.save_product_force(entity);
+        }
+    }
+
+    pub fn save_product_force(&mut self, mut v: Product) {
+        if let Some(vv) = self.holders.game_data_holder.product_holder.get(&v.id)
+            && *vv == v
+        {
+            return;
+        }
+
+        for log in self.holders.game_data_holder.validate_product(&v) {
+            logs_mut().add(log);
+        }
+
+        v._changed = true;
+
+        self.holders
+            .game_data_holder
+            .reference_index
+            .update(GameEntityT::Product(v.id), &v);
+
+        self.holders.game_data_holder.product_holder.insert(v.id, v);
+
+        self.filter_product();
+        self.check_for_unwrote_changed();
+    }
+}
+
+impl GameDataHolder {
+    pub fn validate_product(&self, product: &Product) -> Vec<Log> {
+        product
+            .items
+            .iter()
+            .filter(|v| !self.item_holder.contains_key(&v.item_id))
+            .map(|v| {
+                Log::from_validator_e(&format!(
+                    "Product [{}] {}: item {} doesn't exist!",
+                    product.id.0,
+                    product.name.main(),
+                    v.item_id.0
+                ))
+            })
+            .collect()
+    }
+}
+
+impl From<&Product> for EntityInfo<Product, ProductId> {
+    fn from(value: &Product) -> Self {
+        EntityInfo::new(&format!("ID: {}\n{}", value.id.0, value.name.main()), value)
+    }
+}
@@ -4,6 +4,7 @@ use crate::backend::entity_catalog::EntityInfo;
 use crate::backend::holder::{FHashMap, HolderMapOps};
 use crate::backend::{Backend, HandleAction};
 use crate::common::QuestId;
+use crate::entity::GameEntityT;
 use crate::entity::quest::Quest;
 use crate::frontend::entity_impl::quest::steps_editor::QuestStepsEditorParams;
 use serde::{Deserialize, Serialize};
@@ -164,7 +165,7 @@ impl Backend {
         if let Some(java_class) = v.java_class {
             self.holders.server_data_holder.save_java_class(
                 v.id,
-                &v.title.ru,
+                v.title.main(),
                 java_class.inner,
                 &self.config.server_quests_java_classes_path,
             )
@@ -174,9 +175,15 @@ impl Backend {
         v._changed = true;
 
         if let Some(vv) = self.holders.game_data_holder.quest_holder.get(&v.id)
-            && *vv == v {
-                return;
-            }
+            && *vv == v
+        {
+            return;
+        }
+
+        self.holders
+            .game_data_holder
+            .reference_index
+            .update(GameEntityT::Quest(v.id), &v);
 
         self.holders.game_data_holder.quest_holder.insert(v.id, v);
 
@@ -193,6 +200,9 @@ pub struct QuestInfo {
 
 impl From<&Quest> for EntityInfo<Quest, QuestId> {
     fn from(value: &Quest) -> Self {
-        EntityInfo::new(&format!("ID: {}\n{}", value.id.0, value.title.ru), value)
+        EntityInfo::new(
+            &format!("ID: {}\n{}", value.id.0, value.title.main()),
+            value,
+        )
     }
 }
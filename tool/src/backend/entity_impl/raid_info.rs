@@ -4,8 +4,8 @@ use crate::backend::entity_catalog::EntityInfo;
 use crate::backend::holder::{FHashMap, HolderMapOps};
 use crate::backend::{Backend, HandleAction};
 use crate::common::RaidInfoId;
-use crate::entity::CommonEntity;
 use crate::entity::raid_info::RaidInfo;
+use crate::entity::{CommonEntity, GameEntityT};
 
 pub type RaidInfoEditor = EntityEditParams<RaidInfo, RaidInfoId, (), ()>;
 
@@ -77,6 +77,11 @@ impl Backend {
             }
         v._changed = true;
 
+        self.holders
+            .game_data_holder
+            .reference_index
+            .update(GameEntityT::RaidInfo(v.id), &v);
+
         self.holders
             .game_data_holder
             .raid_info_holder
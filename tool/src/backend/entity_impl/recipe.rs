@@ -4,8 +4,8 @@ use crate::backend::entity_catalog::EntityInfo;
 use crate::backend::holder::{FHashMap, HolderMapOps};
 use crate::backend::{Backend, HandleAction};
 use crate::common::RecipeId;
-use crate::entity::CommonEntity;
 use crate::entity::recipe::Recipe;
+use crate::entity::{CommonEntity, GameEntityT};
 use serde::{Deserialize, Serialize};
 
 pub type RecipeEditor = EntityEditParams<Recipe, RecipeId, RecipeAction, ()>;
@@ -98,6 +98,11 @@ impl Backend {
             }
         v._changed = true;
 
+        self.holders
+            .game_data_holder
+            .reference_index
+            .update(GameEntityT::Recipe(v.id), &v);
+
         self.holders.game_data_holder.recipe_holder.insert(v.id, v);
 
         self.filter_recipes();
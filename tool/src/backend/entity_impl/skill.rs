@@ -289,6 +289,11 @@ impl Backend {
             }
         v._changed = true;
 
+        self.holders
+            .game_data_holder
+            .reference_index
+            .update(GameEntityT::Skill(v.id), &v);
+
         self.holders.game_data_holder.skill_holder.insert(v.id, v);
 
         self.filter_skills();
@@ -298,6 +303,6 @@ impl Backend {
 
 impl From<&Skill> for EntityInfo<Skill, SkillId> {
     fn from(value: &Skill) -> Self {
-        EntityInfo::new(&format!("ID: {}\n{}", value.id.0, value.name.ru), value)
+        EntityInfo::new(&format!("ID: {}\n{}", value.id.0, value.name.main()), value)
     }
 }
@@ -0,0 +1,125 @@
+use crate::backend::editor::entity::{CommonEditorOps, EntityEditParams};
+use crate::backend::editor::{CurrentEntity, EditParamsCommonOps, Editors, WindowParams};
+use crate::backend::entity_catalog::EntityInfo;
+use crate::backend::holder::{FHashMap, HolderMapOps};
+use crate::backend::{Backend, HandleAction};
+use crate::common::UserActionId;
+use crate::entity::CommonEntity;
+use crate::entity::user_action::UserAction;
+use serde::{Deserialize, Serialize};
+
+pub type UserActionEditor = EntityEditParams<UserAction, UserActionId, UserActionAction, ()>;
+
+impl HandleAction for WindowParams<UserAction, UserActionId, UserActionAction, ()> {
+    fn handle_action(&mut self) {
+        let item = self;
+
+        let mut action = item.action.write().unwrap();
+
+        match *action {
+            UserActionAction::None => {}
+        }
+
+        *action = UserActionAction::None;
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, Default)]
+pub enum UserActionAction {
+    #[default]
+    None,
+}
+
+impl Editors {
+    pub fn get_opened_user_action_info(&self) -> Vec<(String, UserActionId, bool)> {
+        self.user_actions.get_opened_info()
+    }
+
+    pub fn open_user_action(
+        &mut self,
+        id: UserActionId,
+        holder: &mut FHashMap<UserActionId, UserAction>,
+    ) {
+        for (i, q) in self.user_actions.opened.iter().enumerate() {
+            if q.inner.initial_id == id {
+                self.current_entity = CurrentEntity::UserAction(i);
+
+                return;
+            }
+        }
+
+        if let Some(q) = holder.get(&id) {
+            self.current_entity =
+                CurrentEntity::UserAction(self.user_actions.add(q.clone(), q.id(), false));
+        }
+    }
+
+    pub fn set_current_user_action(&mut self, index: usize) {
+        if index < self.user_actions.opened.len() {
+            self.current_entity = CurrentEntity::UserAction(index);
+        }
+    }
+
+    pub fn create_new_user_action(&mut self) {
+        self.current_entity = CurrentEntity::UserAction(self.user_actions.add_new());
+    }
+}
+
+impl Backend {
+    pub fn filter_user_action(&mut self) {
+        self.entity_catalogs.user_action.filter(
+            &self.holders.game_data_holder.user_action_holder,
+            self.entity_catalogs.filter_mode,
+        );
+    }
+
+    pub fn save_user_action_from_dlg(&mut self, id: UserActionId) {
+        if let CurrentEntity::UserAction(index) = self.editors.current_entity {
+            let new_entity = self.editors.user_actions.opened.get_mut(index).unwrap();
+
+            if new_entity.inner.inner.id() != id {
+                return;
+            }
+
+            new_entity.inner.initial_id = new_entity.inner.inner.id;
+
+            let entity = new_entity.inner.inner.clone();
+
+            new_entity.on_save();
+
+            self.save_user_action_force(entity);
+        }
+    }
+
+    pub fn save_user_action_force(&mut self, mut v: UserAction) {
+        if let Some(vv) = self.holders.game_data_holder.user_action_holder.get(&v.id)
+            && *vv == v
+        {
+            return;
+        }
+
+        v._changed = true;
+
+        self.holders
+            .game_data_holder
+            .user_action_holder
+            .insert(v.id, v);
+
+        self.filter_user_action();
+        self.check_for_unwrote_changed();
+    }
+}
+
+impl From<&UserAction> for EntityInfo<UserAction, UserActionId> {
+    fn from(value: &UserAction) -> Self {
+        EntityInfo::new(
+            &format!(
+                "ID: {}\n{} {}",
+                value.id.0,
+                value.name.main(),
+                value.command
+            ),
+            value,
+        )
+    }
+}
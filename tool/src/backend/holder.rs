@@ -1,15 +1,21 @@
-use crate::backend::dat_loader::NOT_EXIST;
+use crate::backend::dat_loader::{DatOutput, NOT_EXIST};
 use crate::backend::editor::WindowParams;
+use crate::backend::editor::dictionaries::DictEditor;
+use crate::backend::reference_index::ReferenceIndex;
 use crate::backend::server_side::ServerDataHolder;
 use crate::backend::util::{Localized, StringCow};
 use crate::backend::{Backend, Config, Localization};
 use crate::common::{
-    AnimationComboId, DailyMissionId, EnsoulOptionId, HuntingZoneId, ItemId, ItemSetId, NpcId,
-    QuestId, RaidInfoId, RecipeId, RegionId, ResidenceId, SkillId,
+    AnimationComboId, ClassId, DailyMissionId, EnsoulOptionId, HennaId, HuntingZoneId, ItemId,
+    ItemSetId, NpcId, ProductId, QuestId, RaidInfoId, RecipeId, RegionId, ResidenceId, SkillId,
+    UserActionId,
 };
 use crate::entity::animation_combo::AnimationCombo;
+use crate::entity::class_info::ClassInfo;
 use crate::entity::daily_mission::DailyMission;
 use crate::entity::ensoul_option::EnsoulOption;
+use crate::entity::game_tip::GameTip;
+use crate::entity::henna::Henna;
 use crate::entity::hunting_zone::HuntingZone;
 use crate::entity::item::Item;
 use crate::entity::item::armor::Armor;
@@ -17,17 +23,21 @@ use crate::entity::item::etc_item::EtcItem;
 use crate::entity::item::weapon::Weapon;
 use crate::entity::item_set::ItemSet;
 use crate::entity::npc::Npc;
+use crate::entity::product::Product;
 use crate::entity::quest::Quest;
 use crate::entity::raid_info::RaidInfo;
 use crate::entity::recipe::Recipe;
 use crate::entity::region::Region;
 use crate::entity::residence::Residence;
 use crate::entity::skill::Skill;
+use crate::entity::user_action::UserAction;
 use crate::entity::{CommonEntity, Dictionary, Entity, GameEntity};
+use anyhow::anyhow;
 use ron::ser::PrettyConfig;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
 use std::collections::hash_map::{Keys, Values, ValuesMut};
+use std::collections::{HashMap, HashSet};
 use std::fs::File;
 use std::hash::Hash;
 use std::io::{Read, Write};
@@ -35,6 +45,7 @@ use std::ops::{Index, IndexMut};
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, RwLock};
 use strum::IntoEnumIterator;
+use strum_macros::{Display, EnumIter};
 use walkdir::DirEntry;
 
 #[derive(Clone, Serialize, Deserialize)]
@@ -130,20 +141,14 @@ impl<ID: Hash + Eq + Ord + Copy, T: Clone + Ord + Eq + Serialize + Default> Dict
     }
 }
 
-pub trait DictOps<K: Hash + Eq + Copy + Clone, V: Clone + CommonEntity<K>>:
-    HolderOps + HolderMapOps<K, V>
-{
-}
-
-impl DictOps<u32, DictItem<u32, String>> for FHashMap<u32, DictItem<u32, String>> {}
-
 impl Index<Dictionary> for GameDataHolder {
-    type Output = dyn DictOps<u32, DictItem<u32, String>>;
+    type Output = dyn HolderOps;
 
     fn index(&self, entity: Dictionary) -> &Self::Output {
         match entity {
             Dictionary::SystemStrings => &self.system_strings,
             Dictionary::NpcStrings => &self.npc_strings,
+            Dictionary::GameTips => &self.game_tips,
         }
     }
 }
@@ -153,53 +158,78 @@ impl IndexMut<Dictionary> for GameDataHolder {
         match entity {
             Dictionary::SystemStrings => &mut self.system_strings,
             Dictionary::NpcStrings => &mut self.npc_strings,
+            Dictionary::GameTips => &mut self.game_tips,
         }
     }
 }
 
+fn store_dict_items<T: Clone + Ord + Eq + Serialize + Default>(
+    dict_editor: &mut DictEditor<u32, T>,
+    dict: &mut FHashMap<u32, DictItem<u32, T>>,
+) {
+    for v in dict_editor.items.iter_mut() {
+        if !v.changed {
+            continue;
+        }
+
+        v.previous.clone_from(&v.item);
+        v.changed = false;
+
+        if let Some(vv) = dict.get_mut(&v.id) {
+            vv.item.clone_from(&v.item);
+            vv.changed = v.item != vv.initial;
+
+            if vv.changed {
+                dict.set_changed(true);
+            }
+        } else {
+            dict.insert(
+                v.id,
+                DictItem {
+                    id: v.id,
+                    item: v.item.clone(),
+                    initial: v.initial.clone(),
+                    changed: true,
+                },
+            );
+        }
+    }
+
+    dict_editor.changed_count = 0;
+}
+
 impl Backend {
     pub fn apply_search(&mut self, dict: Dictionary) {
         self.editors.dictionaries[dict].apply_search()
     }
 
     pub fn store_dict(&mut self, dict: Dictionary) {
-        let dict_editor = &mut self.editors.dictionaries[dict];
-        let dict = &mut self.holders.game_data_holder[dict];
+        let editors = &mut self.editors.dictionaries;
+        let holder = &mut self.holders.game_data_holder;
 
-        for v in dict_editor.items_mut() {
-            if !v.changed {
-                continue;
+        match dict {
+            Dictionary::SystemStrings => {
+                store_dict_items(&mut editors.system_strings, &mut holder.system_strings)
             }
-
-            v.previous.clone_from(&v.item);
-            v.changed = false;
-
-            if let Some(vv) = dict.get_mut(&v.id) {
-                vv.item.clone_from(&v.item);
-                vv.changed = v.item != vv.initial;
-
-                if vv.changed {
-                    dict.set_changed(true);
-                }
-            } else {
-                dict.insert(
-                    v.id,
-                    DictItem {
-                        id: v.id,
-                        item: v.item.clone(),
-                        initial: v.initial.clone(),
-                        changed: true,
-                    },
-                );
+            Dictionary::NpcStrings => {
+                store_dict_items(&mut editors.npc_strings, &mut holder.npc_strings)
             }
+            Dictionary::GameTips => store_dict_items(&mut editors.game_tips, &mut holder.game_tips),
         }
 
-        dict_editor.set_changed_count(0);
-
         self.check_for_unwrote_changed();
     }
 }
 
+/// [`HolderOps::revision`] of each holder at some point
+#[derive(PartialEq)]
+pub struct HolderRevisions {
+    entities: Vec<(GameEntity, u32)>,
+    dictionaries: Vec<(Dictionary, u32)>,
+    string_table: u32,
+    localized_string_tables: Vec<(Localization, u32)>,
+}
+
 #[derive(Default)]
 pub struct GameDataHolder {
     pub dat_paths: HashMap<String, DirEntry>,
@@ -219,6 +249,10 @@ pub struct GameDataHolder {
     pub animation_combo_holder: FDHashMap<AnimationComboId, AnimationCombo>,
     pub residence_holder: FHashMap<ResidenceId, Residence>,
     pub ensoul_option_holder: FHashMap<EnsoulOptionId, EnsoulOption>,
+    pub henna_holder: FHashMap<HennaId, Henna>,
+    pub user_action_holder: FHashMap<UserActionId, UserAction>,
+    pub product_holder: FHashMap<ProductId, Product>,
+    pub class_info_holder: FHashMap<ClassId, ClassInfo>,
 
     pub item_holder: HashMap<ItemId, Item>,
 
@@ -226,26 +260,33 @@ pub struct GameDataHolder {
 
     pub system_strings: FHashMap<u32, DictItem<u32, String>>,
 
-    pub game_string_table_ru: L2GeneralStringTable,
-    pub game_string_table_eu: L2GeneralStringTable,
+    pub game_tips: FHashMap<u32, DictItem<u32, GameTip>>,
+
+    pub game_string_table: L2GeneralStringTable,
+    /// `l2gamedataname-<code>.dat`. Locales without own table use the main one
+    pub localized_game_string_tables: HashMap<Localization, L2GeneralStringTable>,
+    /// Locales found in system folder this holder was loaded from, main one first
+    pub localizations: Vec<Localization>,
+
+    pub reference_index: ReferenceIndex,
 }
 
 impl Index<Localization> for GameDataHolder {
     type Output = L2GeneralStringTable;
 
     fn index(&self, index: Localization) -> &Self::Output {
-        match index {
-            Localization::RU => &self.game_string_table_ru,
-            Localization::EU => &self.game_string_table_eu,
-        }
+        self.localized_game_string_tables
+            .get(&index)
+            .unwrap_or(&self.game_string_table)
     }
 }
 
 impl IndexMut<Localization> for GameDataHolder {
     fn index_mut(&mut self, index: Localization) -> &mut Self::Output {
-        match index {
-            Localization::RU => &mut self.game_string_table_ru,
-            Localization::EU => &mut self.game_string_table_eu,
+        if self.localized_game_string_tables.contains_key(&index) {
+            self.localized_game_string_tables.get_mut(&index).unwrap()
+        } else {
+            &mut self.game_string_table
         }
     }
 }
@@ -270,6 +311,10 @@ impl Index<GameEntity> for GameDataHolder {
             GameEntity::AnimationCombo => &self.animation_combo_holder,
             GameEntity::Residence => &self.residence_holder,
             GameEntity::EnsoulOption => &self.ensoul_option_holder,
+            GameEntity::Henna => &self.henna_holder,
+            GameEntity::UserAction => &self.user_action_holder,
+            GameEntity::Product => &self.product_holder,
+            GameEntity::ClassInfo => &self.class_info_holder,
         }
     }
 }
@@ -292,11 +337,32 @@ impl IndexMut<GameEntity> for GameDataHolder {
             GameEntity::AnimationCombo => &mut self.animation_combo_holder,
             GameEntity::Residence => &mut self.residence_holder,
             GameEntity::EnsoulOption => &mut self.ensoul_option_holder,
+            GameEntity::Henna => &mut self.henna_holder,
+            GameEntity::UserAction => &mut self.user_action_holder,
+            GameEntity::Product => &mut self.product_holder,
+            GameEntity::ClassInfo => &mut self.class_info_holder,
         }
     }
 }
 
 impl GameDataHolder {
+    /// Locales dats are read from and written to, main one first. Holders not loaded from a
+    /// system folder use the known ones
+    pub fn localizations(&self) -> Vec<Localization> {
+        if self.localizations.is_empty() {
+            Localization::iter().collect()
+        } else {
+            self.localizations.clone()
+        }
+    }
+
+    pub fn main_localization(&self) -> Localization {
+        self.localizations
+            .first()
+            .copied()
+            .unwrap_or_else(Localization::main)
+    }
+
     pub fn set_all_holders_unchanged(&mut self) {
         for e in GameEntity::iter() {
             self[e].set_changed(false)
@@ -306,7 +372,74 @@ impl GameDataHolder {
             self[e].set_changed(false)
         }
 
-        self.game_string_table_ru.set_changed(false);
+        self.game_string_table.set_changed(false);
+
+        for table in self.localized_game_string_tables.values_mut() {
+            table.set_changed(false);
+        }
+    }
+
+    /// Revisions of every holder, taken when their dats are written
+    pub fn revisions(&self) -> HolderRevisions {
+        HolderRevisions {
+            entities: GameEntity::iter()
+                .map(|e| (e, self[e].revision()))
+                .collect(),
+            dictionaries: Dictionary::iter()
+                .map(|e| (e, self[e].revision()))
+                .collect(),
+            string_table: self.game_string_table.revision(),
+            localized_string_tables: self
+                .localized_game_string_tables
+                .iter()
+                .map(|(l, v)| (*l, v.revision()))
+                .collect(),
+        }
+    }
+
+    /// Same as [`GameDataHolder::set_all_holders_unchanged`], but holders changed after
+    /// `written` was taken are kept as changed
+    pub fn set_written_holders_unchanged(&mut self, written: &HolderRevisions) {
+        for (e, revision) in &written.entities {
+            if self[*e].revision() == *revision {
+                self[*e].set_changed(false)
+            }
+        }
+
+        for (e, revision) in &written.dictionaries {
+            if self[*e].revision() == *revision {
+                self[*e].set_changed(false)
+            }
+        }
+
+        if self.game_string_table.revision() == written.string_table {
+            self.game_string_table.set_changed(false);
+        }
+
+        for (l, revision) in &written.localized_string_tables {
+            if let Some(table) = self.localized_game_string_tables.get_mut(l)
+                && table.revision() == *revision
+            {
+                table.set_changed(false);
+            }
+        }
+    }
+
+    /// Makes next save write every dat
+    pub fn set_all_holders_changed(&mut self) {
+        for e in GameEntity::iter() {
+            self[e].set_changed(true)
+        }
+
+        for e in Dictionary::iter() {
+            self[e].set_changed(true)
+        }
+
+        self.game_string_table.set_changed(true);
+
+        for table in self.localized_game_string_tables.values_mut() {
+            table.set_changed(true);
+        }
     }
 
     pub fn changed_entities(&self) -> Vec<Entity> {
@@ -332,11 +465,29 @@ impl GameDataHolder {
             && !Path::new(path).is_dir() {
                 config.system_folder_path = None
             }
+
+        if let Some(path) = &config.dat_output_folder_path
+            && (!Path::new(path).is_dir()
+                || config
+                    .system_folder_path
+                    .as_ref()
+                    .is_some_and(|v| DatOutput::folders_overlap(Path::new(v), Path::new(path))))
+        {
+            config.dat_output_folder_path = None
+        }
     }
 
     pub fn get_npc_name(&self, id: &NpcId) -> String {
         if let Some(npc) = self.npc_holder.get(id) {
-            npc.name.ru.clone()
+            npc.name.main().clone()
+        } else {
+            format!("{id:?} Not Exist!")
+        }
+    }
+
+    pub fn get_class_name(&self, id: &ClassId) -> String {
+        if let Some(class) = self.class_info_holder.get(id) {
+            class.name.main().clone()
         } else {
             format!("{id:?} Not Exist!")
         }
@@ -358,6 +509,8 @@ impl GameDataHolder {
 #[derive(Clone)]
 pub struct FHashMap<K: Hash + Eq, V> {
     was_changed: bool,
+    /// Bumped on every change, see [`HolderOps::revision`]
+    revision: u32,
     deleted_count: u32,
     inner: HashMap<K, V>,
 }
@@ -366,6 +519,9 @@ pub struct FHashMap<K: Hash + Eq, V> {
 pub trait HolderOps {
     fn set_changed(&mut self, val: bool);
     fn was_changed(&self) -> bool;
+    /// Grows with every change, so a holder edited after some point can be told from the one
+    /// that wasn't
+    fn revision(&self) -> u32;
     fn inc_deleted(&mut self);
     fn dec_deleted(&mut self);
     fn new() -> Self
@@ -377,8 +533,20 @@ pub trait HolderOps {
         folder_path: &str,
         entity_name: &str,
         file_limit: u32,
+        format: DumpFormat,
+    ) -> anyhow::Result<()>;
+    fn save_to_ron(
+        &self,
+        folder_path: &str,
+        entity_name: &str,
+        format: DumpFormat,
     ) -> anyhow::Result<()>;
-    fn save_to_ron(&self, folder_path: &str, entity_name: &str) -> anyhow::Result<()>;
+    fn save_to_ron_per_entity(
+        &self,
+        folder_path: &str,
+        entity_name: &str,
+        format: DumpFormat,
+    ) -> anyhow::Result<usize>;
 }
 #[allow(unused)]
 pub trait HolderMapOps<K: Hash + Eq + Copy + Clone, V: Clone + CommonEntity<K>> {
@@ -396,6 +564,10 @@ impl<K: Hash + Eq + Copy + Clone + Ord + Into<u32>, V: Clone + CommonEntity<K> +
     HolderOps for FHashMap<K, V>
 {
     fn set_changed(&mut self, val: bool) {
+        if val {
+            self.revision = self.revision.wrapping_add(1);
+        }
+
         self.was_changed = val;
     }
 
@@ -403,7 +575,12 @@ impl<K: Hash + Eq + Copy + Clone + Ord + Into<u32>, V: Clone + CommonEntity<K> +
         self.was_changed || self.deleted_count != 0
     }
 
+    fn revision(&self) -> u32 {
+        self.revision
+    }
+
     fn inc_deleted(&mut self) {
+        self.revision = self.revision.wrapping_add(1);
         self.deleted_count += 1;
     }
 
@@ -412,12 +589,14 @@ impl<K: Hash + Eq + Copy + Clone + Ord + Into<u32>, V: Clone + CommonEntity<K> +
             return;
         }
 
+        self.revision = self.revision.wrapping_add(1);
         self.deleted_count -= 1;
     }
 
     fn new() -> FHashMap<K, V> {
         Self {
             was_changed: false,
+            revision: 0,
             deleted_count: 0,
             inner: HashMap::new(),
         }
@@ -432,12 +611,27 @@ impl<K: Hash + Eq + Copy + Clone + Ord + Into<u32>, V: Clone + CommonEntity<K> +
         folder_path: &str,
         entity_name: &str,
         file_limit: u32,
+        format: DumpFormat,
+    ) -> anyhow::Result<()> {
+        save_to_ron_limited(self, folder_path, entity_name, file_limit, format)
+    }
+
+    fn save_to_ron(
+        &self,
+        folder_path: &str,
+        entity_name: &str,
+        format: DumpFormat,
     ) -> anyhow::Result<()> {
-        save_to_ron_limited(self, folder_path, entity_name, file_limit)
+        save_to_ron(self, folder_path, entity_name, format)
     }
 
-    fn save_to_ron(&self, folder_path: &str, entity_name: &str) -> anyhow::Result<()> {
-        save_to_ron(self, folder_path, entity_name)
+    fn save_to_ron_per_entity(
+        &self,
+        folder_path: &str,
+        entity_name: &str,
+        format: DumpFormat,
+    ) -> anyhow::Result<usize> {
+        save_to_ron_per_entity(self, folder_path, entity_name, format)
     }
 }
 
@@ -469,6 +663,7 @@ impl<K: Hash + Eq + Copy + Clone + Ord, V: Clone + CommonEntity<K>> HolderMapOps
 
     fn insert(&mut self, key: K, val: V) -> Option<V> {
         self.was_changed = true;
+        self.revision = self.revision.wrapping_add(1);
         self.inner.insert(key, val)
     }
 
@@ -481,6 +676,7 @@ impl<K: Hash + Eq, V> Default for FHashMap<K, V> {
     fn default() -> Self {
         Self {
             was_changed: false,
+            revision: 0,
             deleted_count: 0,
             inner: HashMap::new(),
         }
@@ -490,6 +686,8 @@ impl<K: Hash + Eq, V> Default for FHashMap<K, V> {
 #[derive(Clone)]
 pub struct FDHashMap<K: Hash + Eq, V> {
     was_changed: bool,
+    /// Bumped on every change, see [`HolderOps::revision`]
+    revision: u32,
     deleted_count: u32,
     inner: HashMap<K, V>,
     inner_double: HashMap<String, K>,
@@ -503,12 +701,41 @@ impl<K: Hash + Eq + Copy + Clone, V: Clone + CommonEntity<K>> FDHashMap<K, V> {
             None
         }
     }
+
+    /// Secondary keys shared by more than one not deleted entity, with their owners sorted
+    pub fn duplicated_secondary_keys(&self) -> Vec<(String, Vec<K>)>
+    where
+        K: Ord,
+    {
+        let mut keys: HashMap<String, Vec<K>> = HashMap::new();
+
+        for v in self.inner.values().filter(|v| !v.deleted()) {
+            keys.entry(v.name()).or_default().push(v.id());
+        }
+
+        let mut res: Vec<_> = keys
+            .into_iter()
+            .filter(|v| v.1.len() > 1)
+            .map(|(k, mut ids)| {
+                ids.sort();
+                (k, ids)
+            })
+            .collect();
+
+        res.sort_by(|a, b| a.0.cmp(&b.0));
+
+        res
+    }
 }
 
 impl<K: Hash + Eq + Copy + Clone + Ord + Into<u32>, V: Clone + CommonEntity<K> + Serialize>
     HolderOps for FDHashMap<K, V>
 {
     fn set_changed(&mut self, val: bool) {
+        if val {
+            self.revision = self.revision.wrapping_add(1);
+        }
+
         self.was_changed = val;
     }
 
@@ -516,7 +743,12 @@ impl<K: Hash + Eq + Copy + Clone + Ord + Into<u32>, V: Clone + CommonEntity<K> +
         self.was_changed || self.deleted_count != 0
     }
 
+    fn revision(&self) -> u32 {
+        self.revision
+    }
+
     fn inc_deleted(&mut self) {
+        self.revision = self.revision.wrapping_add(1);
         self.deleted_count += 1;
     }
 
@@ -525,12 +757,14 @@ impl<K: Hash + Eq + Copy + Clone + Ord + Into<u32>, V: Clone + CommonEntity<K> +
             return;
         }
 
+        self.revision = self.revision.wrapping_add(1);
         self.deleted_count -= 1;
     }
 
     fn new() -> FDHashMap<K, V> {
         Self {
             was_changed: false,
+            revision: 0,
             deleted_count: 0,
             inner: HashMap::new(),
             inner_double: HashMap::new(),
@@ -546,12 +780,121 @@ impl<K: Hash + Eq + Copy + Clone + Ord + Into<u32>, V: Clone + CommonEntity<K> +
         folder_path: &str,
         entity_name: &str,
         file_limit: u32,
+        format: DumpFormat,
     ) -> anyhow::Result<()> {
-        save_to_ron_limited(self, folder_path, entity_name, file_limit)
+        save_to_ron_limited(self, folder_path, entity_name, file_limit, format)
+    }
+
+    fn save_to_ron(
+        &self,
+        folder_path: &str,
+        entity_name: &str,
+        format: DumpFormat,
+    ) -> anyhow::Result<()> {
+        save_to_ron(self, folder_path, entity_name, format)
+    }
+
+    fn save_to_ron_per_entity(
+        &self,
+        folder_path: &str,
+        entity_name: &str,
+        format: DumpFormat,
+    ) -> anyhow::Result<usize> {
+        save_to_ron_per_entity(self, folder_path, entity_name, format)
+    }
+}
+
+/// How entities are laid out in a .ron dump folder
+#[derive(Serialize, Deserialize, Copy, Clone, Default, PartialEq, Eq, Debug, EnumIter, Display)]
+pub enum RonLayout {
+    /// `<Entity>/<first id>-<last id>.ron`, chunk size depends on entity
+    #[default]
+    #[strum(to_string = "Chunks")]
+    Chunked,
+    /// `<Entity>/<id>.ron`
+    #[strum(to_string = "File per entity")]
+    PerEntity,
+}
+
+/// Text format of dumps, patches and entities exported to copy buffer
+#[derive(Serialize, Deserialize, Copy, Clone, Default, PartialEq, Eq, Debug, EnumIter, Display)]
+pub enum DumpFormat {
+    #[default]
+    #[strum(to_string = "RON")]
+    Ron,
+    #[strum(to_string = "JSON")]
+    Json,
+}
+
+impl DumpFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            DumpFormat::Ron => "ron",
+            DumpFormat::Json => "json",
+        }
+    }
+
+    /// Format of a dump file, `None` for files of other kinds
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let ext = path.extension()?;
+
+        DumpFormat::iter().find(|v| ext == v.extension())
+    }
+
+    /// JSON value always starts with an object or an array, RON ones written by the tool start
+    /// with a struct name
+    pub fn detect(data: &str) -> Self {
+        if data.trim_start().starts_with(['{', '[']) {
+            DumpFormat::Json
+        } else {
+            DumpFormat::Ron
+        }
+    }
+
+    /// `config` is used only by RON, JSON is always indented with four spaces and `\n`
+    pub fn encode<T: Serialize>(self, val: &T, config: PrettyConfig) -> anyhow::Result<String> {
+        Ok(match self {
+            DumpFormat::Ron => ron::ser::to_string_pretty(val, config)?,
+            DumpFormat::Json => {
+                let mut res = vec![];
+                let mut ser = serde_json::Serializer::with_formatter(
+                    &mut res,
+                    serde_json::ser::PrettyFormatter::with_indent(b"    "),
+                );
+
+                val.serialize(&mut ser)?;
+
+                String::from_utf8(res)?
+            }
+        })
     }
 
-    fn save_to_ron(&self, folder_path: &str, entity_name: &str) -> anyhow::Result<()> {
-        save_to_ron(self, folder_path, entity_name)
+    pub fn decode<T: DeserializeOwned>(self, data: &str) -> anyhow::Result<T> {
+        Ok(match self {
+            DumpFormat::Ron => ron::from_str(data)?,
+            DumpFormat::Json => serde_json::from_str(data)?,
+        })
+    }
+
+    /// Removes `path` written in other formats, so it can't shadow the one just written
+    fn remove_other(&self, path: &Path) -> std::io::Result<()> {
+        for f in DumpFormat::iter().filter(|v| v != self) {
+            let other = path.with_extension(f.extension());
+
+            if other.is_file() {
+                std::fs::remove_file(other)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Existing file of `folder` named `stem` in any format, RON path if there are none
+    pub fn find(folder: &Path, stem: &str) -> PathBuf {
+        DumpFormat::iter()
+            .map(|v| folder.join(format!("{stem}.{}", v.extension())))
+            .find(|v| v.is_file())
+            .unwrap_or_else(|| folder.join(format!("{stem}.ron")))
     }
 }
 
@@ -563,25 +906,34 @@ fn save_to_ron<
     holder: &T,
     folder_path: &str,
     entity_name: &str,
+    format: DumpFormat,
 ) -> anyhow::Result<()> {
-    let mut keys: Vec<_> = holder.keys().collect();
+    let mut keys: Vec<_> = holder
+        .values()
+        .filter(|v| !v.deleted())
+        .map(|v| v.id())
+        .collect();
 
     keys.sort();
 
-    let mut file = File::create(format!("{folder_path}/{entity_name}.ron"))?;
+    let path = Path::new(folder_path).join(format!("{entity_name}.{}", format.extension()));
+
+    let mut file = File::create(&path)?;
 
     for key in keys {
         file.write_all(
-            &ron::ser::to_string_pretty(
-                holder.get(key).unwrap(),
-                PrettyConfig::default().struct_names(true),
-            )
-            .unwrap()
-            .into_bytes(),
+            &format
+                .encode(
+                    holder.get(&key).unwrap(),
+                    PrettyConfig::default().struct_names(true),
+                )?
+                .into_bytes(),
         )?;
         file.write_all(b"\n")?;
     }
 
+    format.remove_other(&path)?;
+
     Ok(())
 }
 
@@ -594,8 +946,13 @@ fn save_to_ron_limited<
     folder_path: &str,
     entity_name: &str,
     file_limit: u32,
+    format: DumpFormat,
 ) -> anyhow::Result<()> {
-    let mut keys: Vec<_> = holder.keys().collect();
+    let mut keys: Vec<_> = holder
+        .values()
+        .filter(|v| !v.deleted())
+        .map(|v| v.id())
+        .collect();
 
     keys.sort();
 
@@ -604,14 +961,31 @@ fn save_to_ron_limited<
     let folder = Path::new(folder_path).join(entity_name);
     std::fs::create_dir_all(&folder)?;
 
-    let mut file = File::create(folder.join(format!("0-{max_id_in_file}.ron")))?;
+    let ext = format.extension();
+
+    // RON allows trailing comma, JSON doesn't
+    let (separator, trailing) = match format {
+        DumpFormat::Ron => (&b""[..], &b",\n"[..]),
+        DumpFormat::Json => (&b",\n"[..], &b""[..]),
+    };
+
+    let mut names = HashSet::new();
+    let mut create = |name: String| {
+        let file = File::create(folder.join(&name));
+        names.insert(name);
 
-    if holder.keys().len() != 0 {
+        file
+    };
+
+    let mut file = create(format!("0-{max_id_in_file}.{ext}"))?;
+    let mut first_in_file = true;
+
+    if !keys.is_empty() {
         file.write_all(b"[")?;
     }
 
     for key in keys {
-        let v = (*key).into();
+        let v = key.into();
 
         if v > max_id_in_file {
             file.write_all(b"]")?;
@@ -619,32 +993,192 @@ fn save_to_ron_limited<
 
             max_id_in_file = v - (v % file_limit) + file_limit - 1;
 
-            file = File::create(folder.join(format!(
-                "{}-{}.ron",
+            file = create(format!(
+                "{}-{}.{ext}",
                 max_id_in_file - file_limit + 1,
                 max_id_in_file
-            )))?;
+            ))?;
 
             file.write_all(b"[")?;
+            first_in_file = true;
+        }
+
+        if !first_in_file {
+            file.write_all(separator)?;
         }
 
         file.write_all(
-            &ron::ser::to_string_pretty(
-                holder.get(key).unwrap(),
-                PrettyConfig::default().struct_names(true),
-            )
-            .unwrap()
-            .into_bytes(),
+            &format
+                .encode(
+                    holder.get(&key).unwrap(),
+                    PrettyConfig::default().struct_names(true),
+                )?
+                .into_bytes(),
         )?;
 
-        file.write_all(b",\n")?;
+        file.write_all(trailing)?;
+        first_in_file = false;
     }
 
     file.write_all(b"]")?;
 
+    // Chunks of a previous run, entity files of [`save_to_ron_per_entity`] and the ones in other
+    // format would be loaded along with the written ones
+    for entry in std::fs::read_dir(&folder)? {
+        let path = entry?.path();
+
+        if DumpFormat::from_path(&path).is_some()
+            && !path
+                .file_name()
+                .is_some_and(|v| names.contains(v.to_string_lossy().as_ref()))
+        {
+            std::fs::remove_file(path)?;
+        }
+    }
+
     Ok(())
 }
 
+/// Writes every entity into its own `<id>.<ext>`. Formatting doesn't depend on platform and files
+/// that would stay the same are not touched. Other dump files of the folder are removed, so
+/// chunks left from [`save_to_ron_limited`] don't shadow entities. Returns amount of written files
+fn save_to_ron_per_entity<
+    K: Hash + Eq + Copy + Clone + Ord + Into<u32>,
+    V: Clone + CommonEntity<K> + Serialize,
+    T: HolderMapOps<K, V>,
+>(
+    holder: &T,
+    folder_path: &str,
+    entity_name: &str,
+    format: DumpFormat,
+) -> anyhow::Result<usize> {
+    let folder = Path::new(folder_path).join(entity_name);
+    std::fs::create_dir_all(&folder)?;
+
+    let config = PrettyConfig::default()
+        .struct_names(true)
+        .new_line("\n".to_string())
+        .indentor("    ".to_string());
+
+    let mut names = HashSet::new();
+    let mut written = 0;
+
+    for v in holder.values().filter(|v| !v.deleted()) {
+        let name = format!("{}.{}", v.id().into(), format.extension());
+        let path = folder.join(&name);
+
+        let mut data = format.encode(v, config.clone())?;
+        data.push('\n');
+
+        if std::fs::read_to_string(&path).ok().as_ref() != Some(&data) {
+            std::fs::write(&path, data)?;
+            written += 1;
+        }
+
+        names.insert(name);
+    }
+
+    for entry in std::fs::read_dir(&folder)? {
+        let path = entry?.path();
+
+        if DumpFormat::from_path(&path).is_some()
+            && !path
+                .file_name()
+                .is_some_and(|v| names.contains(v.to_string_lossy().as_ref()))
+        {
+            std::fs::remove_file(path)?;
+        }
+    }
+
+    Ok(written)
+}
+
+/// Reads back folder written by [`save_to_ron_limited`] or [`save_to_ron_per_entity`] in any
+/// [`DumpFormat`]. Returns amount of loaded entities
+pub fn load_from_ron_limited<
+    K: Hash + Eq + Copy + Clone + Ord,
+    V: Clone + CommonEntity<K> + DeserializeOwned,
+    T: HolderMapOps<K, V>,
+>(
+    holder: &mut T,
+    folder: &Path,
+) -> anyhow::Result<usize> {
+    let mut count = 0;
+
+    for entry in std::fs::read_dir(folder)? {
+        let path = entry?.path();
+
+        let Some(format) = DumpFormat::from_path(&path) else {
+            continue;
+        };
+
+        let data = std::fs::read_to_string(&path)?;
+
+        // Empty holder is written as a lone closing bracket
+        if matches!(data.trim(), "" | "]") {
+            continue;
+        }
+
+        let values: Vec<V> = if data.trim_start().starts_with('[') {
+            format.decode(&data)
+        } else {
+            format.decode(&data).map(|v| vec![v])
+        }
+        .map_err(|e| anyhow!("{path:?}: {e}"))?;
+
+        for v in values {
+            holder.insert(v.id(), v);
+            count += 1;
+        }
+    }
+
+    Ok(count)
+}
+
+/// Reads back file written by [`save_to_ron`], where values simply follow each other. Format is
+/// taken from extension. Returns amount of loaded entities
+pub fn load_from_ron<
+    K: Hash + Eq + Copy + Clone + Ord,
+    V: Clone + CommonEntity<K> + DeserializeOwned,
+    T: HolderMapOps<K, V>,
+>(
+    holder: &mut T,
+    path: &Path,
+    key: impl Fn(&V) -> K,
+) -> anyhow::Result<usize> {
+    let data = std::fs::read_to_string(path)?;
+    let mut rest = data.as_str();
+
+    let mut count = 0;
+
+    if DumpFormat::from_path(path) == Some(DumpFormat::Json) {
+        for v in serde_json::Deserializer::from_str(&data).into_iter::<V>() {
+            let v = v.map_err(|e| anyhow!("{path:?}, value {count}: {e}"))?;
+
+            holder.insert(key(&v), v);
+            count += 1;
+        }
+
+        return Ok(count);
+    }
+
+    // Deserializer can't go past the first top level value, so each one gets its own
+    while !rest.trim().is_empty() {
+        let mut de = ron::Deserializer::from_str(rest)
+            .map_err(|e| anyhow!("{path:?}, value {count}: {e}"))?;
+
+        let v = V::deserialize(&mut de)
+            .map_err(|e| anyhow!("{path:?}, value {count}: {}", de.span_error(e)))?;
+
+        rest = &rest[rest.len() - de.remainder().len()..];
+
+        holder.insert(key(&v), v);
+        count += 1;
+    }
+
+    Ok(count)
+}
+
 impl<K: Hash + Eq + Copy + Clone, V: Clone + CommonEntity<K>> HolderMapOps<K, V>
     for FDHashMap<K, V>
 {
@@ -689,6 +1223,7 @@ impl<K: Hash + Eq + Copy + Clone, V: Clone + CommonEntity<K>> HolderMapOps<K, V>
 
     fn insert(&mut self, key: K, val: V) -> Option<V> {
         self.was_changed = true;
+        self.revision = self.revision.wrapping_add(1);
         self.inner_double.insert(val.name(), key);
         self.inner.insert(key, val)
     }
@@ -702,6 +1237,7 @@ impl<K: Hash + Eq, V> Default for FDHashMap<K, V> {
     fn default() -> Self {
         Self {
             was_changed: false,
+            revision: 0,
             deleted_count: 0,
             inner: HashMap::new(),
             inner_double: HashMap::new(),
@@ -712,6 +1248,8 @@ impl<K: Hash + Eq, V> Default for FDHashMap<K, V> {
 #[derive(Clone)]
 pub struct L2GeneralStringTable {
     pub(crate) was_changed: bool,
+    /// Bumped on every change, see [`HolderOps::revision`]
+    revision: u32,
     next_index: u32,
     inner: HashMap<u32, Arc<String>>,
     reverse_map: HashMap<String, u32>,
@@ -721,6 +1259,7 @@ impl Default for L2GeneralStringTable {
     fn default() -> Self {
         let mut s = Self {
             was_changed: false,
+            revision: 0,
             next_index: 0,
             inner: Default::default(),
             reverse_map: Default::default(),
@@ -734,26 +1273,45 @@ impl Default for L2GeneralStringTable {
 }
 
 impl L2GeneralStringTable {
-    pub fn save_to_ron(&self, path: PathBuf) -> anyhow::Result<()> {
-        let mut keys: Vec<_> = self.inner.keys().collect();
-
-        keys.sort();
+    /// Written as a list of strings in both formats, so strings may hold line breaks
+    pub fn save_to_ron(&self, path: PathBuf, format: DumpFormat) -> anyhow::Result<()> {
+        std::fs::write(
+            &path,
+            format.encode(&self.to_vec(), PrettyConfig::default())?,
+        )?;
 
-        let mut file = File::create(path)?;
+        format.remove_other(&path)?;
 
-        for key in keys[..keys.len() - 1].iter() {
-            file.write_all(self.inner.get(key).unwrap().as_bytes())?;
+        Ok(())
+    }
 
-            file.write_all(b"\n")?;
-        }
+    /// Reads back file written by [`L2GeneralStringTable::save_to_ron`], format is taken from
+    /// extension. RON files of older versions with one string per line are accepted too
+    pub fn load_from_ron(path: &Path) -> anyhow::Result<Self> {
+        let data = std::fs::read_to_string(path)?;
+        let format = DumpFormat::from_path(path).unwrap_or_default();
 
-        Ok(())
+        Ok(Self::from_vec(
+            if format == DumpFormat::Ron && !data.trim_start().starts_with('[') {
+                data.lines().map(|v| v.to_string()).collect()
+            } else {
+                format.decode(&data)?
+            },
+        ))
     }
 
     pub fn set_changed(&mut self, val: bool) {
+        if val {
+            self.revision = self.revision.wrapping_add(1);
+        }
+
         self.was_changed = val;
     }
 
+    pub(crate) fn revision(&self) -> u32 {
+        self.revision
+    }
+
     pub(crate) fn to_vec(&self) -> Vec<String> {
         let mut k: Vec<_> = self.keys().collect();
         k.sort();
@@ -802,7 +1360,7 @@ impl L2GeneralStringTable {
         if let Some(i) = self.reverse_map.get(&lower) {
             *i
         } else {
-            self.was_changed = true;
+            self.set_changed(true);
             self.add_cow(value, lower)
         }
     }
@@ -899,3 +1457,147 @@ impl DataHolder {
         }
     }
 }
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::{DumpFormat, GameDataHolder, HolderMapOps, HolderOps};
+    use crate::backend::editor::WindowParams;
+    use crate::common::{ItemId, RecipeId, SkillId};
+    use crate::entity::item::ItemBaseInfo;
+    use crate::entity::item::etc_item::EtcItem;
+    use crate::entity::recipe::{Recipe, RecipeMaterial};
+    use crate::entity::skill::{EnchantInfo, Skill, SkillLevelInfo, SkillUseCondition};
+    use ron::ser::PrettyConfig;
+    use std::sync::Arc;
+
+    /// Etc items 10, 11, 12 and 30, recipe 5 makes 11 out of 10
+    pub(crate) fn items_with_recipe() -> GameDataHolder {
+        let mut holder = GameDataHolder::default();
+
+        for id in [10, 11, 12, 30] {
+            holder.etc_item_holder.insert(
+                ItemId(id),
+                EtcItem {
+                    base_info: ItemBaseInfo {
+                        id: ItemId(id),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+            );
+        }
+
+        holder.recipe_holder.insert(
+            RecipeId(5),
+            Recipe {
+                id: RecipeId(5),
+                product: ItemId(11),
+                materials: vec![RecipeMaterial {
+                    id: ItemId(10),
+                    count: 1,
+                    recipe_id: RecipeId(5),
+                }],
+                ..Default::default()
+            },
+        );
+
+        holder.rebuild_reference_index();
+
+        holder
+    }
+
+    #[test]
+    fn test_set_written_holders_unchanged() {
+        let mut holder = GameDataHolder::default();
+        holder.recipe_holder.insert(RecipeId(1), Recipe::default());
+        holder.item_set_holder.set_changed(true);
+
+        let written = holder.revisions();
+
+        // Edited while the save was running
+        holder.recipe_holder.insert(
+            RecipeId(1),
+            Recipe {
+                product: ItemId(10),
+                ..Default::default()
+            },
+        );
+
+        assert!(holder.revisions() != written);
+
+        holder.set_written_holders_unchanged(&written);
+
+        assert!(holder.recipe_holder.was_changed());
+        assert!(!holder.item_set_holder.was_changed());
+    }
+
+    #[test]
+    fn test_save_to_ron_limited_removes_stale_files() {
+        let mut holder = GameDataHolder::default();
+        holder.recipe_holder.insert(
+            RecipeId(5),
+            Recipe {
+                id: RecipeId(5),
+                ..Default::default()
+            },
+        );
+
+        let root = std::env::temp_dir().join(format!("l2w_chunks_{}", std::process::id()));
+        let folder = root.join("recipes");
+        std::fs::create_dir_all(&folder).unwrap();
+
+        for name in ["100-199.ron", "0-99.json", "7.ron", "notes.txt"] {
+            std::fs::write(folder.join(name), "[]").unwrap();
+        }
+
+        holder
+            .recipe_holder
+            .save_to_ron_limited(root.to_str().unwrap(), "recipes", 100, DumpFormat::Ron)
+            .unwrap();
+
+        let mut names: Vec<_> = std::fs::read_dir(&folder)
+            .unwrap()
+            .map(|v| v.unwrap().file_name().to_string_lossy().to_string())
+            .collect();
+        names.sort();
+
+        std::fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(names, ["0-99.ron", "notes.txt"]);
+    }
+
+    #[test]
+    fn test_json_matches_ron() {
+        let mut skill = Skill {
+            id: SkillId(3),
+            name: ("Удар".into(), "Strike".into()).into(),
+            description: ("".into(), "Hits \"hard\"\n\\o/".into()).into(),
+            animations: vec![Arc::new("Attack".to_string()).into(), "Cast".into()],
+            icon: Arc::new("icon.skill0003".to_string()).into(),
+            use_condition: Some(WindowParams::new(SkillUseCondition::default())),
+            skill_levels: vec![SkillLevelInfo {
+                name: Some(("".into(), "Strike II".into()).into()),
+                available_enchants: vec![WindowParams::new(EnchantInfo::default())],
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        skill.sound_info.inner.spell_effect_1.sound = "Sound.strike".into();
+
+        let ron = DumpFormat::Ron
+            .encode(&skill, PrettyConfig::default().struct_names(true))
+            .unwrap();
+        let json = DumpFormat::Json
+            .encode(&skill, PrettyConfig::default())
+            .unwrap();
+
+        assert_eq!(DumpFormat::detect(&ron), DumpFormat::Ron);
+        assert_eq!(DumpFormat::detect(&json), DumpFormat::Json);
+
+        let from_ron: Skill = DumpFormat::Ron.decode(&ron).unwrap();
+        let from_json: Skill = DumpFormat::Json.decode(&json).unwrap();
+
+        assert_eq!(from_ron, skill);
+        assert_eq!(from_json, from_ron);
+    }
+}
@@ -0,0 +1,186 @@
+use crate::backend::holder::{GameDataHolder, HolderMapOps};
+use crate::backend::log_holder::LogLevel;
+use crate::backend::reference_index::RefTarget;
+use crate::entity::{CommonEntity, GameEntityT};
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+
+#[derive(Clone, Debug)]
+pub struct IntegrityProblem {
+    pub level: LogLevel,
+    pub entity: GameEntityT,
+    pub message: String,
+}
+
+#[derive(Default)]
+pub struct IntegrityReport {
+    pub opened: bool,
+    pub problems: Vec<IntegrityProblem>,
+}
+
+impl IntegrityReport {
+    pub fn has_errors(&self) -> bool {
+        self.problems.iter().any(|v| v.level == LogLevel::Error)
+    }
+
+    pub fn count(&self, level: LogLevel) -> usize {
+        self.problems.iter().filter(|v| v.level == level).count()
+    }
+}
+
+enum TargetStatus {
+    Missing,
+    Deleted,
+    Exists,
+}
+
+fn status<Id, T: CommonEntity<Id>>(v: Option<&T>) -> TargetStatus {
+    match v {
+        None => TargetStatus::Missing,
+        Some(v) if v.deleted() => TargetStatus::Deleted,
+        Some(_) => TargetStatus::Exists,
+    }
+}
+
+impl Display for RefTarget {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RefTarget::Item(id) => write!(f, "Item [{}]", id.0),
+            RefTarget::Npc(id) => write!(f, "Npc [{}]", id.0),
+            RefTarget::Skill(id) => write!(f, "Skill [{}]", id.0),
+            RefTarget::Quest(id) => write!(f, "Quest [{}]", id.0),
+            RefTarget::HuntingZone(id) => write!(f, "Hunting Zone [{}]", id.0),
+            RefTarget::Region(id) => write!(f, "Region [{}]", id.0),
+            RefTarget::ClassInfo(id) => write!(f, "Class [{}]", id.0),
+            RefTarget::ItemSet(id) => write!(f, "Item Set [{}]", id.0),
+            RefTarget::EnsoulOption(id) => write!(f, "Ensoul Option [{}]", id.0),
+        }
+    }
+}
+
+impl GameDataHolder {
+    /// Walks the reference index and holders looking for broken links. Referrers that are
+    /// deleted themselves are ignored
+    pub fn check_integrity(&self) -> Vec<IntegrityProblem> {
+        let mut res = vec![];
+
+        let mut targets: Vec<_> = self.reference_index.targets().collect();
+        targets.sort_by_key(|v| v.to_string());
+
+        for target in targets {
+            let level = match self.target_status(target) {
+                TargetStatus::Exists => continue,
+                TargetStatus::Missing => LogLevel::Error,
+                TargetStatus::Deleted => LogLevel::Warning,
+            };
+
+            for (referrer, field) in self.reference_index.used_by(target) {
+                if self.get_entity_name(*referrer).is_none() {
+                    continue;
+                }
+
+                res.push(IntegrityProblem {
+                    level,
+                    entity: *referrer,
+                    message: if level == LogLevel::Error {
+                        format!("{field}: {target} does not exist")
+                    } else {
+                        format!("{field}: {target} is marked as deleted")
+                    },
+                });
+            }
+        }
+
+        self.check_item_ids(&mut res);
+
+        for (name, ids) in self.animation_combo_holder.duplicated_secondary_keys() {
+            res.push(IntegrityProblem {
+                level: LogLevel::Warning,
+                entity: GameEntityT::AnimationCombo(ids[0]),
+                message: format!(
+                    "Animation name {name} is shared by combos {:?}",
+                    ids.iter().map(|v| v.0).collect::<Vec<_>>()
+                ),
+            });
+        }
+
+        res
+    }
+
+    fn target_status(&self, target: RefTarget) -> TargetStatus {
+        match target {
+            RefTarget::Item(id) => {
+                let statuses = [
+                    status(self.weapon_holder.get(&id)),
+                    status(self.armor_holder.get(&id)),
+                    status(self.etc_item_holder.get(&id)),
+                ];
+
+                if statuses.iter().any(|v| matches!(v, TargetStatus::Exists)) {
+                    TargetStatus::Exists
+                } else if statuses.iter().any(|v| matches!(v, TargetStatus::Deleted)) {
+                    TargetStatus::Deleted
+                } else {
+                    TargetStatus::Missing
+                }
+            }
+            RefTarget::Npc(id) => status(self.npc_holder.get(&id)),
+            RefTarget::Skill(id) => status(self.skill_holder.get(&id)),
+            RefTarget::Quest(id) => status(self.quest_holder.get(&id)),
+            RefTarget::HuntingZone(id) => status(self.hunting_zone_holder.get(&id)),
+            RefTarget::Region(id) => status(self.region_holder.get(&id)),
+            RefTarget::ClassInfo(id) => {
+                //Class info dat is optional, without it there is nothing to check against
+                if self.class_info_holder.len() == 0 {
+                    TargetStatus::Exists
+                } else {
+                    status(self.class_info_holder.get(&id))
+                }
+            }
+            RefTarget::ItemSet(id) => status(self.item_set_holder.get(&id)),
+            RefTarget::EnsoulOption(id) => status(self.ensoul_option_holder.get(&id)),
+        }
+    }
+
+    /// Weapons, armor and etc items share one id space
+    fn check_item_ids(&self, res: &mut Vec<IntegrityProblem>) {
+        let mut owners: HashMap<u32, Vec<GameEntityT>> = HashMap::new();
+
+        for v in self.weapon_holder.values().filter(|v| !v.deleted()) {
+            owners
+                .entry(v.id().0)
+                .or_default()
+                .push(GameEntityT::Weapon(v.id()));
+        }
+        for v in self.armor_holder.values().filter(|v| !v.deleted()) {
+            owners
+                .entry(v.id().0)
+                .or_default()
+                .push(GameEntityT::Armor(v.id()));
+        }
+        for v in self.etc_item_holder.values().filter(|v| !v.deleted()) {
+            owners
+                .entry(v.id().0)
+                .or_default()
+                .push(GameEntityT::EtcItem(v.id()));
+        }
+
+        let mut duplicates: Vec<_> = owners.into_iter().filter(|v| v.1.len() > 1).collect();
+        duplicates.sort_by_key(|v| v.0);
+
+        for (id, entities) in duplicates {
+            res.push(IntegrityProblem {
+                level: LogLevel::Error,
+                entity: entities[0],
+                message: format!(
+                    "Item id {id} is used by {}",
+                    entities
+                        .iter()
+                        .map(|v| v.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            });
+        }
+    }
+}
@@ -0,0 +1,360 @@
+use crate::VERSION;
+use crate::backend::holder::{DictItem, FHashMap, GameDataHolder, HolderMapOps, HolderOps};
+use crate::backend::log_holder::{Log, LogLevel};
+use crate::backend::patch::PatchValue;
+use crate::backend::util::Localized;
+use crate::backend::{Backend, Dialog, Localization};
+use crate::entity::{CommonEntity, GameEntity, GameEntityT};
+use crate::frontend::IS_SAVING;
+use crate::logs_mut;
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::Write;
+use std::sync::atomic::Ordering;
+use strum::IntoEnumIterator;
+
+fn journal_path() -> String {
+    format!("./v{VERSION}.journal")
+}
+
+/// Changes stored in holders but not written to .dat yet. Written along with autosave and
+/// offered for replay on the next start with the same system folder
+#[derive(Serialize, Deserialize, Default)]
+pub struct Journal {
+    pub system_folder: String,
+    /// Changed and created entities and dictionary items
+    pub stored: Vec<PatchValue>,
+    pub deleted: Vec<(GameEntity, u32)>,
+    /// Locales whose string tables are marked as changed
+    pub string_tables: Vec<Localization>,
+}
+
+macro_rules! collect_entities {
+    ($holder:ident, $journal:ident, $($variant:ident => $field:ident,)*) => {
+        $(
+            if $holder.$field.was_changed() {
+                for v in $holder.$field.values() {
+                    if v.deleted() {
+                        $journal.deleted.push((GameEntity::$variant, v.id().into()));
+                    } else if v.changed() {
+                        $journal.stored.push(PatchValue::$variant(Box::new(v.clone())));
+                    }
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! collect_dict_items {
+    ($holder:ident, $journal:ident, $($variant:ident => $field:ident,)*) => {
+        $(
+            if $holder.$field.was_changed() {
+                for v in $holder.$field.values() {
+                    if v.changed {
+                        $journal.stored.push(PatchValue::$variant(Box::new(v.clone())));
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl Journal {
+    pub fn collect(holder: &GameDataHolder, system_folder: &str) -> Self {
+        let mut journal = Journal {
+            system_folder: system_folder.to_string(),
+            ..Default::default()
+        };
+
+        collect_entities!(holder, journal,
+            Npc => npc_holder,
+            Quest => quest_holder,
+            Skill => skill_holder,
+            Weapon => weapon_holder,
+            Armor => armor_holder,
+            EtcItem => etc_item_holder,
+            ItemSet => item_set_holder,
+            Recipe => recipe_holder,
+            HuntingZone => hunting_zone_holder,
+            Region => region_holder,
+            RaidInfo => raid_info_holder,
+            DailyMission => daily_mission_holder,
+            AnimationCombo => animation_combo_holder,
+            Residence => residence_holder,
+            EnsoulOption => ensoul_option_holder,
+            Henna => henna_holder,
+            UserAction => user_action_holder,
+            Product => product_holder,
+            ClassInfo => class_info_holder,
+        );
+
+        collect_dict_items!(holder, journal,
+            SystemString => system_strings,
+            NpcString => npc_strings,
+            GameTip => game_tips,
+        );
+
+        for loc in holder.localizations() {
+            if holder[loc].was_changed && !journal.string_tables.contains(&loc) {
+                journal.string_tables.push(loc);
+            }
+        }
+
+        journal
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.stored.is_empty() && self.deleted.is_empty() && self.string_tables.is_empty()
+    }
+
+    /// Journal left by previous session for `system_folder`
+    fn load(system_folder: &Option<String>) -> Option<Self> {
+        let f = File::open(journal_path()).ok()?;
+
+        let Ok(journal) = bincode::deserialize_from::<File, Journal>(f) else {
+            logs_mut().add(Log::from_journal_w(
+                "Journal is unreadable, skipped".to_string(),
+            ));
+
+            return None;
+        };
+
+        if journal.is_empty() {
+            return None;
+        }
+
+        if system_folder.as_ref() != Some(&journal.system_folder) {
+            logs_mut().add(Log::from_journal_w(format!(
+                "Journal was made for {}, skipped",
+                journal.system_folder
+            )));
+
+            return None;
+        }
+
+        Some(journal)
+    }
+}
+
+fn replay_dict_item<T: Clone + Ord + Eq + Serialize + Default>(
+    dict: &mut FHashMap<u32, DictItem<u32, T>>,
+    v: DictItem<u32, T>,
+) {
+    if let Some(vv) = dict.get_mut(&v.id) {
+        vv.item = v.item;
+        vv.changed = vv.item != vv.initial;
+    } else {
+        dict.insert(
+            v.id,
+            DictItem {
+                id: v.id,
+                item: v.item,
+                initial: Localized::default(),
+                changed: true,
+            },
+        );
+    }
+
+    dict.set_changed(true);
+}
+
+impl Log {
+    fn from_journal_i(val: String) -> Self {
+        Log {
+            level: LogLevel::Info,
+            producer: "Journal".to_string(),
+            log: val,
+        }
+    }
+
+    fn from_journal_w(val: String) -> Self {
+        Log {
+            level: LogLevel::Warning,
+            producer: "Journal".to_string(),
+            log: val,
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct JournalParams {
+    /// Left by previous session, waiting for user answer
+    pub pending: Option<Journal>,
+}
+
+impl Backend {
+    /// Shows replay dialog if previous session left unwritten changes
+    pub(crate) fn offer_journal_replay(&mut self) {
+        self.journal.pending = Journal::load(&self.config.system_folder_path);
+
+        if let Some(v) = &self.journal.pending {
+            let message = format!(
+                "Previous session has {} change(s) and {} deletion(s) not written to .dat\nRestore them?",
+                v.stored.len(),
+                v.deleted.len()
+            );
+
+            self.show_dialog(Dialog::ConfirmJournalReplay { message });
+        }
+    }
+
+    /// Current pending changes replace the journal file. File is removed if there are none
+    pub(crate) fn write_journal(&self) {
+        // Not answered replay would be overwritten, running save would be journaled twice
+        if self.journal.pending.is_some() || IS_SAVING.load(Ordering::Relaxed) {
+            return;
+        }
+
+        let Some(folder) = &self.config.system_folder_path else {
+            return;
+        };
+
+        let journal = Journal::collect(&self.holders.game_data_holder, folder);
+
+        if journal.is_empty() {
+            let _ = std::fs::remove_file(journal_path());
+
+            return;
+        }
+
+        if let Ok(data) = bincode::serialize(&journal)
+            && let Ok(mut out) = File::create(journal_path())
+        {
+            let _ = out.write_all(&data);
+        }
+    }
+
+    pub(crate) fn discard_journal(&mut self) {
+        self.journal.pending = None;
+
+        let _ = std::fs::remove_file(journal_path());
+    }
+
+    /// Stores journaled values the way editors save them
+    pub(crate) fn replay_journal(&mut self) {
+        let Some(journal) = self.journal.pending.take() else {
+            return;
+        };
+
+        let count = journal.stored.len() + journal.deleted.len();
+
+        for v in journal.stored {
+            match v {
+                PatchValue::Npc(v) => self.save_npc_force(*v),
+                PatchValue::Quest(v) => self.save_quest_force(*v),
+                PatchValue::Skill(v) => self.save_skill_force(*v),
+                PatchValue::Weapon(v) => self.save_weapon_force(*v),
+                PatchValue::Armor(v) => self.save_armor_force(*v),
+                PatchValue::EtcItem(v) => self.save_etc_item_force(*v),
+                PatchValue::ItemSet(v) => self.save_item_set_force(*v),
+                PatchValue::Recipe(v) => self.save_recipe_force(*v),
+                PatchValue::HuntingZone(v) => self.save_hunting_zone_object_force(*v),
+                PatchValue::Region(v) => self.save_region_object_force(*v),
+                PatchValue::RaidInfo(v) => self.save_raid_info_object_force(*v),
+                PatchValue::DailyMission(v) => self.save_daily_mission_object_force(*v),
+                PatchValue::AnimationCombo(v) => self.save_animation_combo_object_force(*v),
+                PatchValue::Residence(v) => self.save_residence_force(*v),
+                PatchValue::EnsoulOption(v) => self.save_ensoul_option_force(*v),
+                PatchValue::Henna(v) => self.save_henna_force(*v),
+                PatchValue::UserAction(v) => self.save_user_action_force(*v),
+                PatchValue::Product(v) => self.save_product_force(*v),
+                PatchValue::ClassInfo(v) => self.save_class_info_force(*v),
+                PatchValue::SystemString(v) => {
+                    replay_dict_item(&mut self.holders.game_data_holder.system_strings, *v)
+                }
+                PatchValue::NpcString(v) => {
+                    replay_dict_item(&mut self.holders.game_data_holder.npc_strings, *v)
+                }
+                PatchValue::GameTip(v) => {
+                    replay_dict_item(&mut self.holders.game_data_holder.game_tips, *v)
+                }
+            }
+        }
+
+        for (entity, id) in journal.deleted {
+            if PatchValue::find(&self.holders.game_data_holder, entity.into(), id).is_some() {
+                self.toggle_deleted_force(GameEntityT::from_raw(entity, id));
+            }
+        }
+
+        for loc in journal.string_tables {
+            self.holders.game_data_holder[loc].set_changed(true);
+        }
+
+        for e in GameEntity::iter() {
+            self.editors
+                .reset_initial(e, &self.holders.game_data_holder);
+        }
+
+        self.check_for_unwrote_changed();
+
+        logs_mut().add(Log::from_journal_i(format!(
+            "{count} change(s) restored from journal"
+        )));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Journal;
+    use crate::backend::holder::{HolderMapOps, HolderOps};
+    use crate::backend::log_holder::LogHolder;
+    use crate::backend::{Backend, Config};
+    use crate::common::{ItemId, RecipeId};
+    use crate::entity::GameEntityT;
+    use crate::entity::recipe::Recipe;
+    use std::sync::RwLock;
+
+    fn recipe(id: u32, product: u32) -> Recipe {
+        Recipe {
+            id: RecipeId(id),
+            product: ItemId(product),
+            ..Default::default()
+        }
+    }
+
+    fn backend() -> Backend {
+        crate::APP_LOGS.set(RwLock::new(LogHolder::new())).ok();
+
+        let mut backend = Backend::from_config(Config::default(), false);
+
+        for id in [1, 2] {
+            backend
+                .holders
+                .game_data_holder
+                .recipe_holder
+                .insert(RecipeId(id), recipe(id, 10));
+        }
+
+        backend
+    }
+
+    #[test]
+    fn test_journal_replay() {
+        let mut session = backend();
+        assert!(Journal::collect(&session.holders.game_data_holder, "system").is_empty());
+
+        session.save_recipe_force(recipe(1, 11));
+        session.save_recipe_force(recipe(3, 12));
+        session.toggle_deleted_force(GameEntityT::Recipe(RecipeId(2)));
+
+        let journal = Journal::collect(&session.holders.game_data_holder, "system");
+        assert_eq!(2, journal.stored.len());
+        assert_eq!(1, journal.deleted.len());
+
+        let journal: Journal =
+            bincode::deserialize(&bincode::serialize(&journal).unwrap()).unwrap();
+
+        let mut next = backend();
+        next.journal.pending = Some(journal);
+        next.replay_journal();
+
+        let holder = &next.holders.game_data_holder.recipe_holder;
+        assert!(holder.was_changed());
+        assert_eq!(ItemId(11), holder.get(&RecipeId(1)).unwrap().product);
+        assert!(holder.get(&RecipeId(1)).unwrap()._changed);
+        assert!(holder.get(&RecipeId(2)).unwrap()._deleted);
+        assert_eq!(ItemId(12), holder.get(&RecipeId(3)).unwrap().product);
+        assert!(next.journal.pending.is_none());
+    }
+}
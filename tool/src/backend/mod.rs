@@ -1,45 +1,176 @@
+pub mod backup;
+pub mod batch;
+pub mod cherry_pick;
+pub mod client_diff;
+pub mod clone_range;
+pub mod csv_table;
 pub mod dat_loader;
 pub mod editor;
 pub mod entity_catalog;
 pub mod entity_impl;
 pub mod holder;
+pub mod integrity;
+pub mod journal;
 pub mod log_holder;
+pub mod patch;
+pub mod re_id;
+pub mod reference_index;
+pub mod safe_delete;
 mod script_ext;
 pub mod server_side;
+pub mod sqlite_export;
 pub mod util;
 
 use crate::VERSION;
-use crate::backend::holder::{DataHolder, GameDataHolder, HolderMapOps};
+use crate::backend::backup::BackupParams;
+use crate::backend::client_diff::ClientDiffParams;
+use crate::backend::clone_range::CloneRangeParams;
+use crate::backend::csv_table::CsvParams;
+use crate::backend::holder::{
+    DataHolder, DumpFormat, GameDataHolder, HolderMapOps, HolderRevisions, RonLayout,
+};
+use crate::backend::integrity::IntegrityReport;
+use crate::backend::journal::JournalParams;
 use crate::backend::log_holder::{Log, LogLevel};
+use crate::backend::patch::PatchParams;
+use crate::backend::re_id::{ReIdParams, ReIdPreview, ReIdRecord};
 use crate::backend::server_side::ServerDataHolder;
 use crate::common::{
-    AnimationComboId, DailyMissionId, EnsoulOptionId, HuntingZoneId, ItemId, ItemSetId, NpcId,
-    QuestId, RaidInfoId, RecipeId, RegionId, ResidenceId, SkillId,
+    AnimationComboId, ClassId, DailyMissionId, EnsoulOptionId, HennaId, HuntingZoneId, ItemId,
+    ItemSetId, NpcId, ProductId, QuestId, RaidInfoId, RecipeId, RegionId, ResidenceId, SkillId,
+    UserActionId,
 };
-use crate::entity::{CommonEntity, GameEntity};
+use crate::entity::{CommonEntity, GameEntity, GameEntityT};
+use crate::frontend::IS_SAVING;
 use crate::logs_mut;
-use dat_loader::DatLoader;
-use dat_loader::load_game_data_holder;
+use dat_loader::{DatLoader, DatOutput};
+use dat_loader::{last_commit_succeeded, load_game_data_holder};
 use editor::{CurrentEntity, EditParamsCommonOps, Editors, WindowParams};
 use entity_catalog::EntityCatalogsHolder;
 use log_holder::LogHolderParams;
-use serde::{Deserialize, Serialize};
+use serde::de::Visitor;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt::{Display, Formatter};
 use std::fs::File;
 use std::io::{Read, Write};
 use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+use std::sync::atomic::Ordering;
 use std::time::{Duration, SystemTime};
 use strum::IntoEnumIterator;
-use strum_macros::{Display, EnumIter};
 
 const AUTO_SAVE_INTERVAL: Duration = Duration::from_secs(30);
 const CHANGE_CHECK_INTERVAL: Duration = Duration::from_millis(500);
 const CONFIG_FILE_NAME: &str = "./config.ron";
 
-#[derive(Copy, Clone, EnumIter, PartialEq, Eq, Display, Default)]
-pub enum Localization {
-    #[default]
-    RU,
-    EU,
+pub const DEFAULT_LOCALIZATION: &str = "ru";
+
+static LOCALIZATION_CODES: RwLock<Vec<&'static str>> = RwLock::new(vec![]);
+static KNOWN_LOCALIZATIONS: RwLock<Vec<Localization>> = RwLock::new(vec![]);
+
+/// Locale code taken from dat file suffix (`itemname-ru.dat` -> `ru`)
+#[derive(Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+pub struct Localization(&'static str);
+
+impl Localization {
+    pub fn new(code: &str) -> Self {
+        let code = code.to_lowercase();
+
+        if let Some(v) = LOCALIZATION_CODES
+            .read()
+            .unwrap()
+            .iter()
+            .find(|v| **v == code)
+        {
+            return Self(v);
+        }
+
+        let mut codes = LOCALIZATION_CODES.write().unwrap();
+
+        if let Some(v) = codes.iter().find(|v| **v == code) {
+            return Self(v);
+        }
+
+        let v: &'static str = Box::leak(code.into_boxed_str());
+        codes.push(v);
+
+        Self(v)
+    }
+
+    pub fn code(&self) -> &'static str {
+        self.0
+    }
+
+    /// Locale whose dats define the set of ids. Always goes first in [`Localization::iter`]
+    pub fn main() -> Self {
+        KNOWN_LOCALIZATIONS
+            .read()
+            .unwrap()
+            .first()
+            .copied()
+            .unwrap_or_else(|| Self::new(DEFAULT_LOCALIZATION))
+    }
+
+    pub fn is_main(&self) -> bool {
+        *self == Self::main()
+    }
+
+    pub fn set_known(localizations: Vec<Localization>) {
+        *KNOWN_LOCALIZATIONS.write().unwrap() = localizations;
+    }
+}
+
+impl IntoEnumIterator for Localization {
+    type Iterator = std::vec::IntoIter<Localization>;
+
+    fn iter() -> Self::Iterator {
+        let known = KNOWN_LOCALIZATIONS.read().unwrap();
+
+        if known.is_empty() {
+            vec![Self::main()].into_iter()
+        } else {
+            known.clone().into_iter()
+        }
+    }
+}
+
+impl Default for Localization {
+    fn default() -> Self {
+        Self::main()
+    }
+}
+
+impl Display for Localization {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0.to_uppercase())
+    }
+}
+
+impl Serialize for Localization {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+struct LocalizationVisitor;
+
+impl Visitor<'_> for LocalizationVisitor {
+    type Value = Localization;
+
+    fn expecting(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "locale code")
+    }
+
+    fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        Ok(Localization::new(v))
+    }
+}
+
+impl<'de> Deserialize<'de> for Localization {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Str rather than String, so field names of old `(ru: .., eu: ..)` structs are accepted
+        deserializer.deserialize_str(LocalizationVisitor)
+    }
 }
 
 pub struct Backend {
@@ -53,16 +184,39 @@ pub struct Backend {
     has_unwrote_changes: bool,
 
     pub logs: WindowParams<LogHolderParams, (), (), ()>,
+    pub integrity_report: IntegrityReport,
+    pub re_id: ReIdParams,
+    pub clone_range: CloneRangeParams,
+    pub client_diff: ClientDiffParams,
+    pub patch: PatchParams,
+    pub backups: BackupParams,
+    pub csv: CsvParams,
+    pub journal: JournalParams,
 
     tasks: Tasks,
 }
 
 impl Backend {
     pub fn init() -> Self {
-        let config = Self::load_config();
+        Self::from_config(Self::load_config(), true)
+    }
 
+    /// Opened tabs are restored from autosave only if `restore_editors` is set
+    pub fn from_config(config: Config, restore_editors: bool) -> Self {
         let (game_data_holder, warnings) = if let Some(path) = &config.system_folder_path {
-            load_game_data_holder(path).unwrap()
+            load_game_data_holder(path).unwrap_or_else(|e| {
+                (
+                    GameDataHolder::default(),
+                    vec![
+                        Log {
+                            level: LogLevel::Error,
+                            producer: "Dat Loader".to_string(),
+                            log: format!("Failed to load {path}"),
+                        },
+                        e,
+                    ],
+                )
+            })
         } else {
             (GameDataHolder::default(), vec![])
         };
@@ -73,7 +227,9 @@ impl Backend {
             ServerDataHolder::default()
         };
 
-        let edit_params = if let Ok(f) = File::open(format!("./v{VERSION}.asave")) {
+        let edit_params = if !restore_editors {
+            Editors::default()
+        } else if let Ok(f) = File::open(format!("./v{VERSION}.asave")) {
             if let Ok(mut d) = bincode::deserialize_from::<File, Editors>(f) {
                 for v in GameEntity::iter() {
                     d.reset_initial(v, &game_data_holder)
@@ -93,7 +249,7 @@ impl Backend {
             config,
 
             holders: DataHolder {
-                localization: Localization::RU,
+                localization: Localization::main(),
                 game_data_holder,
                 server_data_holder,
             },
@@ -106,27 +262,157 @@ impl Backend {
             tasks: Tasks::init(),
             editors: edit_params,
             logs: WindowParams::default(),
+            integrity_report: IntegrityReport::default(),
+            re_id: ReIdParams::default(),
+            clone_range: CloneRangeParams::default(),
+            client_diff: ClientDiffParams::default(),
+            patch: PatchParams::default(),
+            backups: BackupParams::default(),
+            csv: CsvParams::default(),
+            journal: JournalParams::default(),
         };
 
         r.update_last_ids();
 
+        if restore_editors {
+            r.offer_journal_replay();
+        }
+
         r
     }
 
+    /// Runs integrity check first. Errors have to be confirmed before anything is written
     pub fn save_to_dat(&mut self) {
-        self.holders
-            .game_data_holder
-            .save_to_binary(&self.config.ron_dumps_folder_path)
-            .unwrap();
+        self.integrity_report.problems = self.holders.game_data_holder.check_integrity();
 
-        self.set_unchanged();
+        if self.integrity_report.has_errors() {
+            self.integrity_report.opened = true;
+            self.show_dialog(Dialog::ConfirmDatSave {
+                message: format!(
+                    "Integrity check found {} error(s)!\nWrite to .dat anyway?",
+                    self.integrity_report.count(LogLevel::Error)
+                ),
+            });
+
+            return;
+        }
+
+        self.save_to_dat_force();
     }
 
-    pub fn save_to_ron(&self, folder_path: &str) {
+    fn save_to_dat_force(&mut self) {
+        if IS_SAVING.load(Ordering::Relaxed) {
+            self.show_dialog(Dialog::ShowWarning(
+                "Previous save is still in progress".to_string(),
+            ));
+
+            return;
+        }
+
+        let output = self.dat_output();
+
+        let target = output
+            .as_ref()
+            .map(|v| v.output_folder.to_string_lossy().to_string())
+            .or(self.config.system_folder_path.clone());
+
+        if let Some(folder) = &target {
+            self.begin_dat_backup(folder, &self.holders.game_data_holder.changed_entities());
+        }
+
         self.holders
             .game_data_holder
-            .save_to_ron(folder_path, true)
+            .save_to_binary(
+                &self.config.ron_dumps_folder_path,
+                self.config.ron_dump_layout,
+                self.config.dump_format,
+                output,
+            )
             .unwrap();
+
+        self.tasks.dat_save_pending = Some(self.holders.game_data_holder.revisions());
+    }
+
+    /// Holders are marked as written only after the whole batch replaced its dats, so a failed
+    /// save can be retried. Ones edited while the save was running stay changed
+    fn check_dat_save(&mut self) {
+        if IS_SAVING.load(Ordering::Relaxed) {
+            return;
+        }
+
+        let Some(written) = self.tasks.dat_save_pending.take() else {
+            return;
+        };
+
+        if last_commit_succeeded() {
+            let holder = &mut self.holders.game_data_holder;
+
+            self.has_unwrote_changes = holder.revisions() != written;
+            holder.set_written_holders_unchanged(&written);
+        }
+    }
+
+    pub fn save_to_ron(&mut self, folder_path: &str) {
+        if let Err(e) = self.holders.game_data_holder.save_to_ron(
+            folder_path,
+            true,
+            self.config.ron_dump_layout,
+            self.config.dump_format,
+        ) {
+            self.show_dialog(Dialog::ShowWarning(format!("Dump failed: {e:?}")));
+        }
+    }
+
+    /// Replaces all data with a [`DatLoader::save_to_ron`] dump. Dat paths of current system
+    /// folder are kept, so next save rebuilds every dat from the dump
+    pub fn load_from_ron_dump(&mut self, folder_path: &str) {
+        if self.holders.game_data_holder.dat_paths.is_empty() {
+            self.show_dialog(Dialog::ShowWarning(
+                "Select L2 system folder first, dats are built in place of its ones".to_string(),
+            ));
+
+            return;
+        }
+
+        if self.is_changed() || self.editors.has_any_unsaved_changes() {
+            self.show_dialog(Dialog::ShowWarning(
+                "Write current changes and save opened entities before loading a dump".to_string(),
+            ));
+
+            return;
+        }
+
+        let mut holder = GameDataHolder {
+            dat_paths: self.holders.game_data_holder.dat_paths.clone(),
+            ..Default::default()
+        };
+
+        match holder.load_from_ron(folder_path) {
+            Ok(logs) => {
+                holder.set_all_holders_changed();
+                holder.rebuild_reference_index();
+
+                self.holders.game_data_holder = holder;
+                // Opened entities belong to the replaced data, saving them would bring it back
+                self.editors.close_all();
+
+                logs_mut().reset(logs);
+                logs_mut().add(Log {
+                    level: LogLevel::Info,
+                    producer: "Ron Dump".to_string(),
+                    log: format!("{folder_path} loaded, write changes to build dats"),
+                });
+
+                self.update_last_ids();
+                self.check_for_unwrote_changed();
+            }
+
+            Err(e) => logs_mut().add(Log {
+                level: LogLevel::Error,
+                producer: "Ron Dump".to_string(),
+                log: format!("Can't load {folder_path}: {e}"),
+            }),
+        }
     }
 
     fn load_config() -> Config {
@@ -167,6 +453,10 @@ impl Backend {
             CurrentEntity::AnimationCombo(i) => Some(&self.editors.animation_combo.opened[i]),
             CurrentEntity::Residence(i) => Some(&self.editors.residences.opened[i]),
             CurrentEntity::EnsoulOption(i) => Some(&self.editors.ensoul_options.opened[i]),
+            CurrentEntity::Henna(i) => Some(&self.editors.hennas.opened[i]),
+            CurrentEntity::UserAction(i) => Some(&self.editors.user_actions.opened[i]),
+            CurrentEntity::Product(i) => Some(&self.editors.products.opened[i]),
+            CurrentEntity::ClassInfo(i) => Some(&self.editors.class_infos.opened[i]),
 
             CurrentEntity::None => None,
         }
@@ -188,6 +478,10 @@ impl Backend {
             CurrentEntity::AnimationCombo(i) => Some(&mut self.editors.animation_combo.opened[i]),
             CurrentEntity::Residence(i) => Some(&mut self.editors.residences.opened[i]),
             CurrentEntity::EnsoulOption(i) => Some(&mut self.editors.ensoul_options.opened[i]),
+            CurrentEntity::Henna(i) => Some(&mut self.editors.hennas.opened[i]),
+            CurrentEntity::UserAction(i) => Some(&mut self.editors.user_actions.opened[i]),
+            CurrentEntity::Product(i) => Some(&mut self.editors.products.opened[i]),
+            CurrentEntity::ClassInfo(i) => Some(&mut self.editors.class_infos.opened[i]),
 
             CurrentEntity::None => None,
         }
@@ -239,6 +533,18 @@ impl Backend {
         self.entity_catalogs.ensoul_option.filter = "".to_string();
         self.filter_ensoul_option();
 
+        self.entity_catalogs.henna.filter = "".to_string();
+        self.filter_henna();
+
+        self.entity_catalogs.user_action.filter = "".to_string();
+        self.filter_user_action();
+
+        self.entity_catalogs.product.filter = "".to_string();
+        self.filter_product();
+
+        self.entity_catalogs.class_info.filter = "".to_string();
+        self.filter_class_info();
+
         self.editors.quests.next_id = if let Some(last) = self.entity_catalogs.quest.catalog.last()
         {
             last.id.0 + 1
@@ -346,6 +652,34 @@ impl Backend {
                 0
             };
 
+        self.editors.hennas.next_id = if let Some(last) = self.entity_catalogs.henna.catalog.last()
+        {
+            last.id.0 + 1
+        } else {
+            0
+        };
+
+        self.editors.user_actions.next_id =
+            if let Some(last) = self.entity_catalogs.user_action.catalog.last() {
+                last.id.0 + 1
+            } else {
+                0
+            };
+
+        self.editors.products.next_id =
+            if let Some(last) = self.entity_catalogs.product.catalog.last() {
+                last.id.0 + 1
+            } else {
+                0
+            };
+
+        self.editors.class_infos.next_id =
+            if let Some(last) = self.entity_catalogs.class_info.catalog.last() {
+                last.id.0 + 1
+            } else {
+                0
+            };
+
         for e in GameEntity::iter() {
             if self.editors[e].next_id() == 0 && !self.entity_catalogs[e].is_empty() {
                 logs_mut().add(Log::from_validator_e(&format!(
@@ -370,6 +704,8 @@ impl Backend {
                 .unwrap();
         }
 
+        self.write_journal();
+
         self.tasks.last_auto_save = SystemTime::now();
     }
 
@@ -398,6 +734,7 @@ impl Backend {
     pub fn on_update(&mut self) {
         self.proceed_actions();
         self.logs.inner.sync();
+        self.check_dat_save();
         self.auto_save(false);
         self.check_change();
     }
@@ -406,17 +743,37 @@ impl Backend {
         if path.is_dir() {
             let path = path.to_str().unwrap().to_string();
 
-            if let Ok((h, w)) = load_game_data_holder(&path) {
-                self.holders.game_data_holder = h;
+            match load_game_data_holder(&path) {
+                Ok((h, w)) => {
+                    self.holders.game_data_holder = h;
+                    self.holders.localization = Localization::main();
 
-                self.editors.current_entity = CurrentEntity::None;
+                    self.editors.current_entity = CurrentEntity::None;
 
-                logs_mut().reset(w);
+                    logs_mut().reset(w);
 
-                self.update_last_ids();
+                    self.update_last_ids();
+
+                    if let Some(output) = &self.config.dat_output_folder_path
+                        && DatOutput::folders_overlap(Path::new(&path), Path::new(output))
+                    {
+                        self.config.dat_output_folder_path = None;
+                        self.show_dialog(Dialog::ShowWarning(
+                            "Output folder overlaps the new system folder and was reset"
+                                .to_string(),
+                        ));
+                    }
+
+                    self.config.system_folder_path = Some(path);
+                    self.config.dump();
+                }
 
-                self.config.system_folder_path = Some(path);
-                self.config.dump();
+                Err(e) => {
+                    self.show_dialog(Dialog::ShowWarning(format!(
+                        "Failed to load {path}\n{}",
+                        e.log
+                    )));
+                }
             }
         }
     }
@@ -430,6 +787,51 @@ impl Backend {
         }
     }
 
+    pub fn update_ron_dump_layout(&mut self, layout: RonLayout) {
+        self.config.ron_dump_layout = layout;
+        self.config.dump();
+    }
+
+    pub fn update_dump_format(&mut self, format: DumpFormat) {
+        self.config.dump_format = format;
+        self.config.dump();
+    }
+
+    /// `None` switches back to overwriting the system folder
+    pub fn update_dat_output_path(&mut self, path: Option<PathBuf>) {
+        if let Some(path) = &path
+            && (!path.is_dir()
+                || self
+                    .config
+                    .system_folder_path
+                    .as_ref()
+                    .is_some_and(|v| DatOutput::folders_overlap(Path::new(v), path)))
+        {
+            self.show_dialog(Dialog::ShowWarning(
+                "Output folder must be an existing folder outside the system one and not holding it"
+                    .to_string(),
+            ));
+
+            return;
+        }
+
+        self.config.dat_output_folder_path = path.map(|v| v.to_str().unwrap().to_string());
+        self.config.dump();
+    }
+
+    pub fn update_copy_unchanged_dats(&mut self, val: bool) {
+        self.config.copy_unchanged_dats = val;
+        self.config.dump();
+    }
+
+    fn dat_output(&self) -> Option<DatOutput> {
+        Some(DatOutput {
+            system_folder: self.config.system_folder_path.as_ref()?.into(),
+            output_folder: self.config.dat_output_folder_path.as_ref()?.into(),
+            copy_unchanged: self.config.copy_unchanged_dats,
+        })
+    }
+
     pub fn update_textures_path(&mut self, path: PathBuf) {
         if path.is_dir() {
             let path = path.to_str().unwrap().to_string();
@@ -469,16 +871,206 @@ impl Backend {
         }
     }
 
-    pub fn import_entity_from_ron_string(&mut self, val: &str) {
-        if let Some(v) = self.get_current_entity_mut()
-            && let Err(e) = v.set_wrapped_entity_from_ron_string(val) {
-                self.show_dialog(Dialog::ShowWarning(format!("{e:?}")));
+    /// Not deleted entities referencing the current one
+    pub fn current_entity_used_by(&self) -> Vec<(GameEntityT, &'static str, String)> {
+        self.editors
+            .current_game_entity()
+            .map_or(vec![], |v| self.holders.game_data_holder.get_used_by(v))
+    }
+
+    pub fn check_integrity(&mut self) {
+        self.integrity_report.problems = self.holders.game_data_holder.check_integrity();
+        self.integrity_report.opened = true;
+    }
+
+    pub fn open_re_id_for_current_entity(&mut self) {
+        if let Some(v) = self.editors.current_game_entity() {
+            self.re_id.entity = Some(v);
+            self.re_id.new_id = v.raw_id();
+            self.re_id.preview = None;
+            self.re_id.opened = true;
+        }
+    }
+
+    pub fn preview_re_id(&mut self) {
+        if let Some(v) = self.re_id.entity {
+            self.re_id.preview = Some(self.check_re_id(v, self.re_id.new_id));
+        }
+    }
+
+    fn check_re_id(&self, from: GameEntityT, new_id: u32) -> Result<ReIdPreview, String> {
+        let preview = self.holders.game_data_holder.re_id_preview(from, new_id)?;
+
+        for v in [from]
+            .into_iter()
+            .chain(preview.affected.iter().map(|v| v.0))
+        {
+            if self.editors.has_unsaved_changes(v) {
+                return Err(format!(
+                    "{v} [{}] has unsaved changes, save or close it first",
+                    v.raw_id()
+                ));
+            }
+        }
+
+        Ok(preview)
+    }
+
+    /// Moves entity to new id and rewrites all references to it. Whole operation is one step
+    /// of [`Backend::undo_re_id`]
+    pub fn apply_re_id(&mut self) {
+        let Some(entity) = self.re_id.entity else {
+            return;
+        };
+
+        match self.check_re_id(entity, self.re_id.new_id) {
+            Ok(preview) => {
+                let referrers: Vec<_> = preview.affected.iter().map(|v| v.0).collect();
+
+                self.perform_re_id(preview.from, preview.to, &referrers);
+
+                self.re_id.history.push(ReIdRecord {
+                    from: preview.from,
+                    to: preview.to,
+                    referrers,
+                });
+                self.re_id.entity = Some(preview.to);
+                self.re_id.preview = None;
+            }
+
+            Err(e) => self.re_id.preview = Some(Err(e)),
+        }
+    }
+
+    pub fn undo_re_id(&mut self) {
+        let Some(record) = self.re_id.history.last().cloned() else {
+            return;
+        };
+
+        match self.check_re_id(record.to, record.from.raw_id()) {
+            Ok(_) => {
+                self.perform_re_id(record.to, record.from, &record.referrers);
+
+                self.re_id.history.pop();
+                self.re_id.entity = Some(record.from);
+                self.re_id.new_id = record.from.raw_id();
+                self.re_id.preview = None;
+            }
+
+            Err(e) => self.re_id.preview = Some(Err(format!("Can't undo: {e}"))),
+        }
+    }
+
+    fn perform_re_id(&mut self, from: GameEntityT, to: GameEntityT, referrers: &[GameEntityT]) {
+        for v in [from].iter().chain(referrers) {
+            self.editors.close_if_opened(*v);
+        }
+
+        self.holders.game_data_holder.re_id(from, to, referrers);
+
+        self.filter_entity_catalog(from.into());
+        self.update_last_ids();
+        self.check_for_unwrote_changed();
+    }
+
+    pub fn preview_clone_range(&mut self) {
+        self.clone_range.plan = Some(
+            self.holders
+                .game_data_holder
+                .clone_range_plan(&self.clone_range),
+        );
+    }
+
+    pub fn apply_clone_range(&mut self) {
+        match self
+            .holders
+            .game_data_holder
+            .clone_range_plan(&self.clone_range)
+        {
+            Ok(plan) => {
+                self.holders.game_data_holder.clone_range(&plan);
+                self.clone_range.plan = None;
+
+                let mut entities: Vec<GameEntity> = vec![];
+
+                for (_, to) in &plan {
+                    let entity = (*to).into();
+
+                    if !entities.contains(&entity) {
+                        entities.push(entity);
+                    }
+                }
+
+                for e in entities {
+                    self.filter_entity_catalog(e);
+                }
+
+                self.update_last_ids();
+                self.check_for_unwrote_changed();
             }
+
+            Err(e) => self.clone_range.plan = Some(Err(e)),
+        }
+    }
+
+    pub fn open_entity(&mut self, entity: GameEntityT) {
+        self.editors
+            .open_entity(entity, &mut self.holders.game_data_holder);
+    }
+
+    pub fn undo_current_entity(&mut self) {
+        if let Some(v) = self.get_current_entity_mut() {
+            v.undo();
+        }
+    }
+
+    pub fn redo_current_entity(&mut self) {
+        if let Some(v) = self.get_current_entity_mut() {
+            v.redo();
+        }
+    }
+
+    pub fn go_to_current_entity_history_record(&mut self, index: usize) {
+        if let Some(v) = self.get_current_entity_mut() {
+            v.go_to_history_record(index);
+        }
     }
 
-    pub fn export_entity_as_ron_string(&self) -> Option<String> {
-        self.get_current_entity()
-            .map(|v| v.get_wrapped_entity_as_ron_string())
+    /// (can undo, can redo, step labels, current step)
+    pub fn current_entity_history(&self) -> Option<(bool, bool, Vec<String>, usize)> {
+        self.get_current_entity().map(|v| {
+            (
+                v.can_undo(),
+                v.can_redo(),
+                v.history_labels(),
+                v.history_position(),
+            )
+        })
+    }
+
+    pub fn import_entity_from_string(&mut self, val: &str) {
+        if let Some(v) = self.get_current_entity_mut()
+            && let Err(e) = v.set_wrapped_entity_from_string(val)
+        {
+            self.show_dialog(Dialog::ShowWarning(format!("{e:?}")));
+        }
+    }
+
+    /// Encoding errors are shown in a dialog
+    pub fn export_entity_as_string(&mut self, format: DumpFormat) -> Option<String> {
+        match self
+            .get_current_entity()?
+            .get_wrapped_entity_as_string(format)
+        {
+            Ok(v) => Some(v),
+            Err(e) => {
+                self.show_dialog(Dialog::ShowWarning(format!(
+                    "Export to {format} failed: {e}"
+                )));
+
+                None
+            }
+        }
     }
 
     pub fn save_current_entity(&mut self) {
@@ -928,6 +1520,114 @@ impl Backend {
                 }
             }
 
+            CurrentEntity::Henna(index) => {
+                let new_entity = self.editors.hennas.opened.get(index).unwrap();
+
+                if let Some(old_entity) = self
+                    .holders
+                    .game_data_holder
+                    .henna_holder
+                    .get(&new_entity.inner.inner.id)
+                {
+                    if new_entity.inner.initial_id == old_entity.id() || old_entity.deleted() {
+                        self.save_henna_force(new_entity.inner.inner.clone());
+                    } else {
+                        self.show_dialog(Dialog::ConfirmHennaSave {
+                            message: format!(
+                                "Henna with Id {} already exists.\nOverwrite?",
+                                old_entity.id.0
+                            ),
+                            henna_id: new_entity.inner.inner.id,
+                        });
+
+                        return;
+                    }
+                } else {
+                    self.save_henna_force(new_entity.inner.inner.clone());
+                }
+            }
+
+            CurrentEntity::UserAction(index) => {
+                let new_entity = self.editors.user_actions.opened.get(index).unwrap();
+
+                if let Some(old_entity) = self
+                    .holders
+                    .game_data_holder
+                    .user_action_holder
+                    .get(&new_entity.inner.inner.id)
+                {
+                    if new_entity.inner.initial_id == old_entity.id() || old_entity.deleted() {
+                        self.save_user_action_force(new_entity.inner.inner.clone());
+                    } else {
+                        self.show_dialog(Dialog::ConfirmUserActionSave {
+                            message: format!(
+                                "User Action with Id {} already exists.\nOverwrite?",
+                                old_entity.id.0
+                            ),
+                            user_action_id: new_entity.inner.inner.id,
+                        });
+
+                        return;
+                    }
+                } else {
+                    self.save_user_action_force(new_entity.inner.inner.clone());
+                }
+            }
+
+            CurrentEntity::Product(index) => {
+                let new_entity = self.editors.products.opened.get(index).unwrap();
+
+                if let Some(old_entity) = self
+                    .holders
+                    .game_data_holder
+                    .product_holder
+                    .get(&new_entity.inner.inner.id)
+                {
+                    if new_entity.inner.initial_id == old_entity.id() || old_entity.deleted() {
+                        self.save_product_force(new_entity.inner.inner.clone());
+                    } else {
+                        self.show_dialog(Dialog::ConfirmProductSave {
+                            message: format!(
+                                "Product with Id {} already exists.\nOverwrite?",
+                                old_entity.id.0
+                            ),
+                            product_id: new_entity.inner.inner.id,
+                        });
+
+                        return;
+                    }
+                } else {
+                    self.save_product_force(new_entity.inner.inner.clone());
+                }
+            }
+
+            CurrentEntity::ClassInfo(index) => {
+                let new_entity = self.editors.class_infos.opened.get(index).unwrap();
+
+                if let Some(old_entity) = self
+                    .holders
+                    .game_data_holder
+                    .class_info_holder
+                    .get(&new_entity.inner.inner.id)
+                {
+                    if new_entity.inner.initial_id == old_entity.id() || old_entity.deleted() {
+                        self.save_class_info_force(new_entity.inner.inner.clone());
+                    } else {
+                        self.show_dialog(Dialog::ConfirmClassInfoSave {
+                            message: format!(
+                                "Class Info with Id {} already exists.\nOverwrite?",
+                                old_entity.id.0
+                            ),
+                            class_info_id: new_entity.inner.inner.id,
+                        });
+
+                        return;
+                    }
+                } else {
+                    self.save_class_info_force(new_entity.inner.inner.clone());
+                }
+            }
+
             CurrentEntity::None => {
                 return;
             }
@@ -942,11 +1642,6 @@ impl Backend {
         self.has_unwrote_changes = !self.holders.game_data_holder.changed_entities().is_empty();
     }
 
-    fn set_unchanged(&mut self) {
-        self.has_unwrote_changes = false;
-        self.holders.game_data_holder.set_all_holders_unchanged();
-    }
-
     pub fn is_changed(&self) -> bool {
         self.has_unwrote_changes
     }
@@ -1051,6 +1746,58 @@ impl Backend {
                 }
             }
 
+            Dialog::ConfirmHennaSave { henna_id, .. } => {
+                if answer == DialogAnswer::Confirm {
+                    self.save_henna_from_dlg(henna_id);
+                }
+            }
+
+            Dialog::ConfirmUserActionSave { user_action_id, .. } => {
+                if answer == DialogAnswer::Confirm {
+                    self.save_user_action_from_dlg(user_action_id);
+                }
+            }
+
+            Dialog::ConfirmProductSave { product_id, .. } => {
+                if answer == DialogAnswer::Confirm {
+                    self.save_product_from_dlg(product_id);
+                }
+            }
+
+            Dialog::ConfirmClassInfoSave { class_info_id, .. } => {
+                if answer == DialogAnswer::Confirm {
+                    self.save_class_info_from_dlg(class_info_id);
+                }
+            }
+
+            Dialog::ConfirmDatSave { .. } => {
+                if answer == DialogAnswer::Confirm {
+                    self.save_to_dat_force();
+                }
+            }
+
+            Dialog::ConfirmJournalReplay { .. } => {
+                if answer == DialogAnswer::Confirm {
+                    self.replay_journal();
+                } else {
+                    self.discard_journal();
+                }
+            }
+
+            Dialog::ConfirmCherryPick { .. } => {
+                if answer == DialogAnswer::Confirm {
+                    self.cherry_pick_selected_force();
+                }
+            }
+
+            Dialog::ConfirmDelete {
+                entity, substitute, ..
+            } => {
+                self.dialog = self.answer_delete(entity, substitute, answer);
+
+                return;
+            }
+
             Dialog::ShowWarning(_) => {}
 
             Dialog::ConfirmClose(index) => {
@@ -1259,6 +2006,54 @@ impl Backend {
 
                 self.editors.find_opened_entity();
             }
+            CurrentEntity::Henna(index) => {
+                if !force && self.editors.hennas.opened[index].is_changed() {
+                    self.editors.current_entity = ind;
+                    self.show_dialog(Dialog::ConfirmClose(CurrentEntity::Henna(index)));
+
+                    return;
+                }
+
+                self.editors.hennas.opened.remove(index);
+
+                self.editors.find_opened_entity();
+            }
+            CurrentEntity::UserAction(index) => {
+                if !force && self.editors.user_actions.opened[index].is_changed() {
+                    self.editors.current_entity = ind;
+                    self.show_dialog(Dialog::ConfirmClose(CurrentEntity::UserAction(index)));
+
+                    return;
+                }
+
+                self.editors.user_actions.opened.remove(index);
+
+                self.editors.find_opened_entity();
+            }
+            CurrentEntity::Product(index) => {
+                if !force && self.editors.products.opened[index].is_changed() {
+                    self.editors.current_entity = ind;
+                    self.show_dialog(Dialog::ConfirmClose(CurrentEntity::Product(index)));
+
+                    return;
+                }
+
+                self.editors.products.opened.remove(index);
+
+                self.editors.find_opened_entity();
+            }
+            CurrentEntity::ClassInfo(index) => {
+                if !force && self.editors.class_infos.opened[index].is_changed() {
+                    self.editors.current_entity = ind;
+                    self.show_dialog(Dialog::ConfirmClose(CurrentEntity::ClassInfo(index)));
+
+                    return;
+                }
+
+                self.editors.class_infos.opened.remove(index);
+
+                self.editors.find_opened_entity();
+            }
 
             CurrentEntity::None => {}
         }
@@ -1277,6 +2072,18 @@ pub struct Config {
     pub server_quests_java_classes_path: Option<String>,
     pub server_spawn_root_folder_path: Option<String>,
     pub ron_dumps_folder_path: Option<String>,
+    #[serde(default)]
+    pub ron_dump_layout: RonLayout,
+    /// Used by dumps and patch export, files of any format are read back
+    #[serde(default)]
+    pub dump_format: DumpFormat,
+    /// Save batches kept in backups folder, zero turns backups off
+    pub dat_backups_to_keep: Option<u32>,
+    /// Changed dats are written here, mirroring system folder layout. System folder is
+    /// overwritten if not set
+    pub dat_output_folder_path: Option<String>,
+    #[serde(default)]
+    pub copy_unchanged_dats: bool,
 }
 
 impl Config {
@@ -1299,6 +2106,8 @@ impl Config {
 struct Tasks {
     last_auto_save: SystemTime,
     last_change_check: SystemTime,
+    /// Dat save was started and its result is not checked yet. Holds revisions of written holders
+    dat_save_pending: Option<HolderRevisions>,
 }
 
 impl Tasks {
@@ -1306,6 +2115,7 @@ impl Tasks {
         Self {
             last_auto_save: SystemTime::now(),
             last_change_check: SystemTime::now(),
+            dat_save_pending: None,
         }
     }
 }
@@ -1319,6 +2129,7 @@ impl Tasks {
 pub enum DialogAnswer {
     Confirm,
     Abort,
+    Cascade,
 }
 
 pub enum Dialog {
@@ -1383,6 +2194,36 @@ pub enum Dialog {
         message: String,
         ensoul_stone_id: EnsoulOptionId,
     },
+    ConfirmHennaSave {
+        message: String,
+        henna_id: HennaId,
+    },
+    ConfirmUserActionSave {
+        message: String,
+        user_action_id: UserActionId,
+    },
+    ConfirmProductSave {
+        message: String,
+        product_id: ProductId,
+    },
+    ConfirmClassInfoSave {
+        message: String,
+        class_info_id: ClassId,
+    },
+    ConfirmDatSave {
+        message: String,
+    },
+    ConfirmJournalReplay {
+        message: String,
+    },
+    ConfirmCherryPick {
+        message: String,
+    },
+    ConfirmDelete {
+        entity: GameEntityT,
+        used_by: Vec<(GameEntityT, &'static str, String)>,
+        substitute: u32,
+    },
 
     ShowWarning(String),
     ConfirmClose(CurrentEntity),
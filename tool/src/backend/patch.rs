@@ -0,0 +1,561 @@
+use crate::backend::Backend;
+use crate::backend::Dialog;
+use crate::backend::dat_loader::{DatLoader, load_detached_game_data_holder};
+use crate::backend::holder::{
+    DictItem, DumpFormat, GameDataHolder, HolderMapOps, HolderOps, RonLayout,
+};
+use crate::backend::log_holder::{Log, LogLevel};
+use crate::entity::animation_combo::AnimationCombo;
+use crate::entity::class_info::ClassInfo;
+use crate::entity::daily_mission::DailyMission;
+use crate::entity::ensoul_option::EnsoulOption;
+use crate::entity::game_tip::GameTip;
+use crate::entity::henna::Henna;
+use crate::entity::hunting_zone::HuntingZone;
+use crate::entity::item::armor::Armor;
+use crate::entity::item::etc_item::EtcItem;
+use crate::entity::item::weapon::Weapon;
+use crate::entity::item_set::ItemSet;
+use crate::entity::npc::Npc;
+use crate::entity::product::Product;
+use crate::entity::quest::Quest;
+use crate::entity::raid_info::RaidInfo;
+use crate::entity::recipe::Recipe;
+use crate::entity::region::Region;
+use crate::entity::residence::Residence;
+use crate::entity::skill::Skill;
+use crate::entity::user_action::UserAction;
+use crate::entity::{CommonEntity, Dictionary, Entity, GameEntity};
+use crate::frontend::IS_SAVING;
+use crate::logs_mut;
+use ron::ser::PrettyConfig;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::Ordering;
+
+/// Values are compared the way they are written to patch
+fn same<T: Serialize>(a: &T, b: &T) -> bool {
+    serde_json::to_value(a).ok() == serde_json::to_value(b).ok()
+}
+
+macro_rules! patch_values {
+    ($($variant:ident($ty:ty) => $holder:ident, $entity:expr, |$v:ident| $id:expr, |$l:ident| $live:expr, |$c:ident| $mark:expr;)*) => {
+        #[derive(Serialize, Deserialize, Clone)]
+        pub enum PatchValue {
+            $($variant(Box<$ty>),)*
+        }
+
+        impl PatchValue {
+            pub fn entity(&self) -> Entity {
+                match self {
+                    $(PatchValue::$variant(_) => $entity.into(),)*
+                }
+            }
+
+            pub fn id(&self) -> u32 {
+                match self {
+                    $(PatchValue::$variant($v) => $id.into(),)*
+                }
+            }
+
+            /// Value of the same entity in `holder`. Deleted ones are treated as missing
+            fn find_in(&self, holder: &GameDataHolder) -> Option<PatchValue> {
+                match self {
+                    $(PatchValue::$variant($v) => holder
+                        .$holder
+                        .get(&$id)
+                        .filter(|$l| $live)
+                        .map(|v| PatchValue::$variant(Box::new(v.clone()))),)*
+                }
+            }
+
+            fn write_to(&self, holder: &mut GameDataHolder) {
+                match self {
+                    $(PatchValue::$variant($v) => {
+                        holder.$holder.insert($id, $v.as_ref().clone());
+                    })*
+                }
+            }
+
+            /// Same as [`PatchValue::write_to`], but entity is marked as changed, the way editors
+            /// save it
+            pub(crate) fn write_changed_to(&self, holder: &mut GameDataHolder) {
+                match self {
+                    $(PatchValue::$variant($v) => {
+                        let mut value = $v.as_ref().clone();
+
+                        {
+                            let $c = &mut value;
+                            $mark;
+                        }
+
+                        holder.$holder.insert($id, value);
+                    })*
+                }
+            }
+
+            /// Not deleted value of `entity` with `id` in `holder`
+            pub(crate) fn find(holder: &GameDataHolder, entity: Entity, id: u32) -> Option<Self> {
+                $(
+                    if entity == $entity.into() {
+                        return holder
+                            .$holder
+                            .get(&id.into())
+                            .filter(|$l| $live)
+                            .map(|v| PatchValue::$variant(Box::new(v.clone())));
+                    }
+                )*
+
+                None
+            }
+
+            /// Every not deleted value of `entity` in `holder`, sorted by id
+            pub(crate) fn all_of(holder: &GameDataHolder, entity: Entity) -> Vec<Self> {
+                $(
+                    if entity == $entity.into() {
+                        let mut keys: Vec<_> = holder.$holder.keys().copied().collect();
+                        keys.sort();
+
+                        return keys
+                            .into_iter()
+                            .filter_map(|k| holder.$holder.get(&k))
+                            .filter(|$l| $live)
+                            .map(|v| PatchValue::$variant(Box::new(v.clone())))
+                            .collect();
+                    }
+                )*
+
+                vec![]
+            }
+
+            fn remove_from(&self, holder: &mut GameDataHolder) {
+                match self {
+                    $(PatchValue::$variant($v) => {
+                        holder.$holder.remove(&$id);
+                        holder.$holder.set_changed(true);
+                    })*
+                }
+            }
+        }
+
+        impl Patch {
+            /// Everything that differs between `base` and `current`. Only holders marked as
+            /// changed are looked through
+            pub fn collect(base: &GameDataHolder, current: &GameDataHolder) -> Self {
+                let mut entries = vec![];
+
+                $(
+                    if current.$holder.was_changed() {
+                        let keys: BTreeSet<_> = base
+                            .$holder
+                            .keys()
+                            .chain(current.$holder.keys())
+                            .copied()
+                            .collect();
+
+                        for key in keys {
+                            let old = base
+                                .$holder
+                                .get(&key)
+                                .filter(|$l| $live)
+                                .map(|v| PatchValue::$variant(Box::new(v.clone())));
+                            let new = current
+                                .$holder
+                                .get(&key)
+                                .filter(|$l| $live)
+                                .map(|v| PatchValue::$variant(Box::new(v.clone())));
+
+                            match (old, new) {
+                                (Some(old), Some(new)) => {
+                                    if !same(&old, &new) {
+                                        entries.push(PatchEntry::Modified { old, new });
+                                    }
+                                }
+                                (Some(old), None) => entries.push(PatchEntry::Deleted { old }),
+                                (None, Some(new)) => entries.push(PatchEntry::Created { new }),
+                                (None, None) => {}
+                            }
+                        }
+                    }
+                )*
+
+                Self { entries }
+            }
+        }
+    };
+}
+
+patch_values! {
+    Npc(Npc) => npc_holder, GameEntity::Npc, |v| v.id, |v| !v.deleted(), |v| v._changed = true;
+    Quest(Quest) => quest_holder, GameEntity::Quest, |v| v.id, |v| !v.deleted(), |v| v._changed = true;
+    Skill(Skill) => skill_holder, GameEntity::Skill, |v| v.id, |v| !v.deleted(), |v| v._changed = true;
+    Weapon(Weapon) => weapon_holder, GameEntity::Weapon, |v| v.base_info.id, |v| !v.deleted(), |v| v._changed = true;
+    Armor(Armor) => armor_holder, GameEntity::Armor, |v| v.base_info.id, |v| !v.deleted(), |v| v._changed = true;
+    EtcItem(EtcItem) => etc_item_holder, GameEntity::EtcItem, |v| v.base_info.id, |v| !v.deleted(), |v| v._changed = true;
+    ItemSet(ItemSet) => item_set_holder, GameEntity::ItemSet, |v| v.id, |v| !v.deleted(), |v| v._changed = true;
+    Recipe(Recipe) => recipe_holder, GameEntity::Recipe, |v| v.id, |v| !v.deleted(), |v| v._changed = true;
+    HuntingZone(HuntingZone) => hunting_zone_holder, GameEntity::HuntingZone, |v| v.id, |v| !v.deleted(), |v| v._changed = true;
+    Region(Region) => region_holder, GameEntity::Region, |v| v.id, |v| !v.deleted(), |v| v._changed = true;
+    RaidInfo(RaidInfo) => raid_info_holder, GameEntity::RaidInfo, |v| v.id, |v| !v.deleted(), |v| v._changed = true;
+    DailyMission(DailyMission) => daily_mission_holder, GameEntity::DailyMission, |v| v.id, |v| !v.deleted(), |v| v._changed = true;
+    AnimationCombo(AnimationCombo) => animation_combo_holder, GameEntity::AnimationCombo, |v| v.id, |v| !v.deleted(), |v| v._changed = true;
+    Residence(Residence) => residence_holder, GameEntity::Residence, |v| v.id, |v| !v.deleted(), |v| v._changed = true;
+    EnsoulOption(EnsoulOption) => ensoul_option_holder, GameEntity::EnsoulOption, |v| v.id, |v| !v.deleted(), |v| v._changed = true;
+    Henna(Henna) => henna_holder, GameEntity::Henna, |v| v.id, |v| !v.deleted(), |v| v._changed = true;
+    UserAction(UserAction) => user_action_holder, GameEntity::UserAction, |v| v.id, |v| !v.deleted(), |v| v._changed = true;
+    Product(Product) => product_holder, GameEntity::Product, |v| v.id, |v| !v.deleted(), |v| v._changed = true;
+    ClassInfo(ClassInfo) => class_info_holder, GameEntity::ClassInfo, |v| v.id, |v| !v.deleted(), |v| v._changed = true;
+    SystemString(DictItem<u32, String>) => system_strings, Dictionary::SystemStrings, |v| v.id, |_v| true, |_v| {};
+    NpcString(DictItem<u32, String>) => npc_strings, Dictionary::NpcStrings, |v| v.id, |_v| true, |_v| {};
+    GameTip(DictItem<u32, GameTip>) => game_tips, Dictionary::GameTips, |v| v.id, |_v| true, |_v| {};
+}
+
+/// `old` is the value patch was made against, used to find conflicts
+#[derive(Serialize, Deserialize, Clone)]
+pub enum PatchEntry {
+    Created { new: PatchValue },
+    Modified { old: PatchValue, new: PatchValue },
+    Deleted { old: PatchValue },
+}
+
+impl PatchEntry {
+    fn value(&self) -> &PatchValue {
+        match self {
+            PatchEntry::Created { new } | PatchEntry::Modified { new, .. } => new,
+            PatchEntry::Deleted { old } => old,
+        }
+    }
+
+    pub fn entity(&self) -> Entity {
+        self.value().entity()
+    }
+
+    pub fn id(&self) -> u32 {
+        self.value().id()
+    }
+
+    pub fn kind(&self) -> &'static str {
+        match self {
+            PatchEntry::Created { .. } => "Created",
+            PatchEntry::Modified { .. } => "Modified",
+            PatchEntry::Deleted { .. } => "Deleted",
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Default)]
+pub struct Patch {
+    pub entries: Vec<PatchEntry>,
+}
+
+pub enum PatchCheck {
+    /// Target is already in the state patch leads to
+    Applied,
+    Clean,
+    Conflict(String),
+}
+
+impl Patch {
+    /// How entry relates to the state of `target`
+    pub fn check(entry: &PatchEntry, target: &GameDataHolder) -> PatchCheck {
+        let current = entry.value().find_in(target);
+
+        match (entry, current) {
+            (PatchEntry::Created { new }, Some(v)) => {
+                if same(new, &v) {
+                    PatchCheck::Applied
+                } else {
+                    PatchCheck::Conflict("Already exists in target".to_string())
+                }
+            }
+            (PatchEntry::Created { .. }, None) => PatchCheck::Clean,
+
+            (PatchEntry::Modified { old, new }, Some(v)) => {
+                if same(new, &v) {
+                    PatchCheck::Applied
+                } else if same(old, &v) {
+                    PatchCheck::Clean
+                } else {
+                    PatchCheck::Conflict("Target differs from patch base".to_string())
+                }
+            }
+            (PatchEntry::Modified { .. }, None) => {
+                PatchCheck::Conflict("Missing in target".to_string())
+            }
+
+            (PatchEntry::Deleted { old }, Some(v)) => {
+                if same(old, &v) {
+                    PatchCheck::Clean
+                } else {
+                    PatchCheck::Conflict("Target differs from patch base".to_string())
+                }
+            }
+            (PatchEntry::Deleted { .. }, None) => PatchCheck::Applied,
+        }
+    }
+
+    fn apply(entry: &PatchEntry, target: &mut GameDataHolder) {
+        match entry {
+            PatchEntry::Created { new } | PatchEntry::Modified { new, .. } => new.write_to(target),
+            PatchEntry::Deleted { old } => old.remove_from(target),
+        }
+    }
+}
+
+/// Patch checked against target folder and waiting for confirmation
+pub struct PendingPatch {
+    pub patch: Patch,
+    pub folder: String,
+    pub target: GameDataHolder,
+    /// Entry index -> reason
+    pub conflicts: Vec<(usize, String)>,
+    pub applied: usize,
+}
+
+#[derive(Default)]
+pub struct PatchParams {
+    pub opened: bool,
+    pub pending: Option<PendingPatch>,
+}
+
+impl Log {
+    fn from_patch_i(val: String) -> Self {
+        Log {
+            level: LogLevel::Info,
+            producer: "Patch".to_string(),
+            log: val,
+        }
+    }
+
+    fn from_patch_e(val: String) -> Self {
+        Log {
+            level: LogLevel::Error,
+            producer: "Patch".to_string(),
+            log: val,
+        }
+    }
+}
+
+impl Backend {
+    /// Changes stored in holders but not written to .dat yet. Old values are read from dats
+    /// of the current system folder
+    pub fn export_patch(&self, path: &Path, format: DumpFormat) {
+        let Some(folder) = &self.config.system_folder_path else {
+            return;
+        };
+
+        let (base, _) = match load_detached_game_data_holder(folder) {
+            Ok(v) => v,
+            Err(e) => {
+                logs_mut().add(Log::from_patch_e(format!(
+                    "Failed to load {folder}: {}",
+                    e.log
+                )));
+
+                return;
+            }
+        };
+
+        let patch = Patch::collect(&base, &self.holders.game_data_holder);
+
+        let res = format
+            .encode(&patch, PrettyConfig::default().struct_names(true))
+            .map_err(|e| e.to_string())
+            .and_then(|v| std::fs::write(path, v).map_err(|e| e.to_string()));
+
+        logs_mut().add(match res {
+            Ok(_) => Log::from_patch_i(format!(
+                "{} change(s) exported to {path:?}",
+                patch.entries.len()
+            )),
+            Err(e) => Log::from_patch_e(format!("Patch export failed: {e}")),
+        });
+    }
+
+    /// Reads patch and checks it against `folder`. Nothing is written until
+    /// [`Backend::apply_pending_patch`]
+    pub fn prepare_patch(&mut self, patch_path: PathBuf, folder: PathBuf) {
+        let folder = folder.to_str().unwrap().to_string();
+
+        if self.config.system_folder_path.as_ref() == Some(&folder) {
+            self.show_dialog(Dialog::ShowWarning(
+                "Patch can't be applied to the current system folder".to_string(),
+            ));
+
+            return;
+        }
+
+        let patch: Patch = match std::fs::read_to_string(&patch_path)
+            .map_err(|e| e.to_string())
+            .and_then(|v| DumpFormat::detect(&v).decode(&v).map_err(|e| e.to_string()))
+        {
+            Ok(v) => v,
+            Err(e) => {
+                self.show_dialog(Dialog::ShowWarning(format!("Can't read patch: {e}")));
+
+                return;
+            }
+        };
+
+        let (target, _) = match load_detached_game_data_holder(&folder) {
+            Ok(v) => v,
+            Err(e) => {
+                self.show_dialog(Dialog::ShowWarning(format!(
+                    "Failed to load {folder}\n{}",
+                    e.log
+                )));
+
+                return;
+            }
+        };
+
+        let mut conflicts = vec![];
+        let mut applied = 0;
+
+        for (i, entry) in patch.entries.iter().enumerate() {
+            match Patch::check(entry, &target) {
+                PatchCheck::Applied => applied += 1,
+                PatchCheck::Clean => {}
+                PatchCheck::Conflict(reason) => conflicts.push((i, reason)),
+            }
+        }
+
+        self.patch.pending = Some(PendingPatch {
+            patch,
+            folder,
+            target,
+            conflicts,
+            applied,
+        });
+        self.patch.opened = true;
+    }
+
+    /// Writes pending patch to its folder. Conflicting entries are skipped unless `overwrite`
+    pub fn apply_pending_patch(&mut self, overwrite: bool) {
+        // Write batch is global, starting a new one would drop dats staged by running save
+        if IS_SAVING.load(Ordering::Relaxed) {
+            self.show_dialog(Dialog::ShowWarning(
+                "Dats are being saved, apply patch when it's finished".to_string(),
+            ));
+
+            return;
+        }
+
+        let Some(mut pending) = self.patch.pending.take() else {
+            return;
+        };
+
+        let mut written = 0;
+
+        for (i, entry) in pending.patch.entries.iter().enumerate() {
+            let conflict = pending.conflicts.iter().any(|v| v.0 == i);
+
+            if conflict && !overwrite {
+                continue;
+            }
+
+            if conflict || matches!(Patch::check(entry, &pending.target), PatchCheck::Clean) {
+                Patch::apply(entry, &mut pending.target);
+                written += 1;
+            }
+        }
+
+        if written == 0 {
+            logs_mut().add(Log::from_patch_i(format!(
+                "Nothing to write to {}",
+                pending.folder
+            )));
+
+            return;
+        }
+
+        self.begin_dat_backup(&pending.folder, &pending.target.changed_entities());
+
+        //Dats of the target are found by its own locales, kept in the holder
+        let res =
+            pending
+                .target
+                .save_to_binary(&None, RonLayout::default(), DumpFormat::default(), None);
+
+        logs_mut().add(match res {
+            Ok(_) => {
+                Log::from_patch_i(format!("{written} change(s) written to {}", pending.folder))
+            }
+            Err(e) => Log::from_patch_e(format!("Patch apply failed: {e}")),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Patch, PatchCheck, PatchEntry, PatchValue};
+    use crate::backend::holder::{GameDataHolder, HolderMapOps};
+    use crate::common::{ItemId, RecipeId};
+    use crate::entity::recipe::Recipe;
+
+    fn recipe(id: u32, product: u32) -> Recipe {
+        Recipe {
+            id: RecipeId(id),
+            product: ItemId(product),
+            ..Default::default()
+        }
+    }
+
+    fn value(id: u32, product: u32) -> PatchValue {
+        PatchValue::Recipe(Box::new(recipe(id, product)))
+    }
+
+    fn check(entry: PatchEntry, target: &GameDataHolder) -> &'static str {
+        match Patch::check(&entry, target) {
+            PatchCheck::Applied => "applied",
+            PatchCheck::Clean => "clean",
+            PatchCheck::Conflict(_) => "conflict",
+        }
+    }
+
+    #[test]
+    fn test_patch_check() {
+        let mut target = GameDataHolder::default();
+        target.recipe_holder.insert(RecipeId(1), recipe(1, 10));
+
+        let mut deleted = recipe(3, 10);
+        deleted._deleted = true;
+        target.recipe_holder.insert(RecipeId(3), deleted);
+
+        let created = |id, product| PatchEntry::Created {
+            new: value(id, product),
+        };
+        let modified = |old, new| PatchEntry::Modified {
+            old: value(1, old),
+            new: value(1, new),
+        };
+        let removed = |id, product| PatchEntry::Deleted {
+            old: value(id, product),
+        };
+
+        assert_eq!("clean", check(created(2, 10), &target));
+        assert_eq!("applied", check(created(1, 10), &target));
+        assert_eq!("conflict", check(created(1, 11), &target));
+        // Deleted entity is treated as missing
+        assert_eq!("clean", check(created(3, 11), &target));
+
+        assert_eq!("clean", check(modified(10, 11), &target));
+        assert_eq!("applied", check(modified(9, 10), &target));
+        assert_eq!("conflict", check(modified(9, 11), &target));
+        assert_eq!(
+            "conflict",
+            check(
+                PatchEntry::Modified {
+                    old: value(2, 10),
+                    new: value(2, 11)
+                },
+                &target
+            )
+        );
+
+        assert_eq!("clean", check(removed(1, 10), &target));
+        assert_eq!("conflict", check(removed(1, 11), &target));
+        assert_eq!("applied", check(removed(2, 10), &target));
+    }
+}
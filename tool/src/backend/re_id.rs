@@ -0,0 +1,522 @@
+use crate::backend::holder::{GameDataHolder, HolderMapOps};
+use crate::backend::reference_index::{EntityReferences, RefTarget, Remap};
+use crate::common::ItemId;
+use crate::entity::item::Item;
+use crate::entity::{CommonEntity, GameEntityT};
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// What will be touched by moving `from` to `to`
+pub struct ReIdPreview {
+    pub from: GameEntityT,
+    pub to: GameEntityT,
+    pub affected: Vec<(GameEntityT, &'static str, String)>,
+}
+
+/// Applied re-ID, kept to be reverted as a whole
+#[derive(Clone)]
+pub struct ReIdRecord {
+    pub from: GameEntityT,
+    pub to: GameEntityT,
+    pub referrers: Vec<GameEntityT>,
+}
+
+#[derive(Default)]
+pub struct ReIdParams {
+    pub opened: bool,
+    pub entity: Option<GameEntityT>,
+    pub new_id: u32,
+    pub preview: Option<Result<ReIdPreview, String>>,
+    pub history: Vec<ReIdRecord>,
+}
+
+/// Puts `from` under `to`. Source is either kept (copy) or removed (move)
+fn transfer<Id: Hash + Eq + Copy, T: Clone + CommonEntity<Id>, M: HolderMapOps<Id, T>>(
+    keep_source: bool,
+    holder: &mut M,
+    from: Id,
+    to: Id,
+    set_id: impl FnOnce(&mut T, Id),
+) {
+    let v = if keep_source {
+        holder.get(&from).cloned()
+    } else {
+        holder.remove(&from)
+    };
+
+    if let Some(mut v) = v {
+        set_id(&mut v, to);
+        holder.insert(to, v);
+    }
+}
+
+fn transfer_item<T: Clone + CommonEntity<ItemId>, M: HolderMapOps<ItemId, T>>(
+    keep_source: bool,
+    holder: &mut M,
+    items: &mut HashMap<ItemId, Item>,
+    from: ItemId,
+    to: ItemId,
+    set_id: impl FnOnce(&mut T, ItemId),
+) {
+    transfer(keep_source, holder, from, to, set_id);
+
+    if let Some(v) = holder.get(&to) {
+        if !keep_source {
+            items.remove(&from);
+        }
+        items.insert(to, v.into());
+    }
+}
+
+fn remap_entity<
+    Id: Hash + Eq + Copy,
+    T: Clone + PartialEq + CommonEntity<Id> + EntityReferences,
+    M: HolderMapOps<Id, T>,
+>(
+    holder: &mut M,
+    id: Id,
+    remap: &Remap,
+    set_changed: impl FnOnce(&mut T),
+) {
+    let Some(old) = holder.get(&id) else {
+        return;
+    };
+
+    let mut v = old.clone();
+    v.remap_references(remap);
+
+    if v != *old {
+        set_changed(&mut v);
+        holder.insert(id, v);
+    }
+}
+
+impl GameDataHolder {
+    /// Holder has an entry for `entity`, deleted ones included. Items share one id space
+    pub(crate) fn entity_exists(&self, entity: GameEntityT) -> bool {
+        match entity {
+            GameEntityT::Weapon(id) | GameEntityT::Armor(id) | GameEntityT::EtcItem(id) => {
+                self.weapon_holder.get(&id).is_some()
+                    || self.armor_holder.get(&id).is_some()
+                    || self.etc_item_holder.get(&id).is_some()
+            }
+            GameEntityT::Quest(id) => self.quest_holder.get(&id).is_some(),
+            GameEntityT::Skill(id) => self.skill_holder.get(&id).is_some(),
+            GameEntityT::Npc(id) => self.npc_holder.get(&id).is_some(),
+            GameEntityT::ItemSet(id) => self.item_set_holder.get(&id).is_some(),
+            GameEntityT::Recipe(id) => self.recipe_holder.get(&id).is_some(),
+            GameEntityT::HuntingZone(id) => self.hunting_zone_holder.get(&id).is_some(),
+            GameEntityT::Region(id) => self.region_holder.get(&id).is_some(),
+            GameEntityT::RaidInfo(id) => self.raid_info_holder.get(&id).is_some(),
+            GameEntityT::DailyMission(id) => self.daily_mission_holder.get(&id).is_some(),
+            GameEntityT::AnimationCombo(id) => self.animation_combo_holder.get(&id).is_some(),
+            GameEntityT::Residence(id) => self.residence_holder.get(&id).is_some(),
+            GameEntityT::EnsoulOption(id) => self.ensoul_option_holder.get(&id).is_some(),
+            GameEntityT::Henna(id) => self.henna_holder.get(&id).is_some(),
+            GameEntityT::UserAction(id) => self.user_action_holder.get(&id).is_some(),
+            GameEntityT::Product(id) => self.product_holder.get(&id).is_some(),
+            GameEntityT::ClassInfo(id) => self.class_info_holder.get(&id).is_some(),
+        }
+    }
+
+    pub fn re_id_preview(&self, from: GameEntityT, new_id: u32) -> Result<ReIdPreview, String> {
+        let to = from.with_id(new_id);
+
+        if self.get_entity_name(from).is_none() {
+            return Err(format!("{from} [{}] does not exist", from.raw_id()));
+        }
+        if new_id == 0 {
+            return Err("ID can't be 0!".to_string());
+        }
+        if to == from {
+            return Err("New ID is the same as current".to_string());
+        }
+        if self.entity_exists(to) {
+            return Err(format!("{to} [{new_id}] already exists"));
+        }
+
+        if let Some(target) = RefTarget::from_entity(to) {
+            let dangling = self.reference_index.used_by(target).len();
+
+            if dangling > 0 {
+                return Err(format!(
+                    "{target} is already referenced by {dangling} entities, fix them first"
+                ));
+            }
+        }
+
+        Ok(ReIdPreview {
+            from,
+            to,
+            affected: self.get_used_by(from),
+        })
+    }
+
+    /// Moves `from` to `to` and rewrites references to it in `referrers`. Checks are done by
+    /// [`GameDataHolder::re_id_preview`]
+    pub fn re_id(&mut self, from: GameEntityT, to: GameEntityT, referrers: &[GameEntityT]) {
+        self.copy_entity(from, to.raw_id(), false);
+
+        let mut remap = Remap::default();
+
+        if let (Some(from), Some(to)) = (RefTarget::from_entity(from), RefTarget::from_entity(to)) {
+            remap.insert(from, to);
+        }
+
+        if !remap.is_empty() {
+            for referrer in referrers.iter().chain([&to]) {
+                self.remap_entity_references(*referrer, &remap);
+            }
+        }
+
+        self.rebuild_reference_index();
+    }
+
+    /// Puts copy of `from` under `new_id`, or moves it there if `keep_source` is false. Only
+    /// holders are touched, references and index stay as is
+    pub fn copy_entity(&mut self, from: GameEntityT, new_id: u32, keep_source: bool) {
+        match from {
+            GameEntityT::Quest(id) => transfer(
+                keep_source,
+                &mut self.quest_holder,
+                id,
+                new_id.into(),
+                |v, id| {
+                    v.id = id;
+                    v._changed = true;
+                },
+            ),
+            GameEntityT::Skill(id) => transfer(
+                keep_source,
+                &mut self.skill_holder,
+                id,
+                new_id.into(),
+                |v, id| {
+                    v.id = id;
+                    v._changed = true;
+                },
+            ),
+            GameEntityT::Npc(id) => transfer(
+                keep_source,
+                &mut self.npc_holder,
+                id,
+                new_id.into(),
+                |v, id| {
+                    v.id = id;
+                    v._changed = true;
+                },
+            ),
+            GameEntityT::Weapon(id) => transfer_item(
+                keep_source,
+                &mut self.weapon_holder,
+                &mut self.item_holder,
+                id,
+                new_id.into(),
+                |v, id| {
+                    v.base_info.id = id;
+                    v._changed = true;
+                },
+            ),
+            GameEntityT::Armor(id) => transfer_item(
+                keep_source,
+                &mut self.armor_holder,
+                &mut self.item_holder,
+                id,
+                new_id.into(),
+                |v, id| {
+                    v.base_info.id = id;
+                    v._changed = true;
+                },
+            ),
+            GameEntityT::EtcItem(id) => transfer_item(
+                keep_source,
+                &mut self.etc_item_holder,
+                &mut self.item_holder,
+                id,
+                new_id.into(),
+                |v, id| {
+                    v.base_info.id = id;
+                    v._changed = true;
+                },
+            ),
+            GameEntityT::ItemSet(id) => transfer(
+                keep_source,
+                &mut self.item_set_holder,
+                id,
+                new_id.into(),
+                |v, id| {
+                    v.id = id;
+                    v._changed = true;
+                },
+            ),
+            GameEntityT::Recipe(id) => transfer(
+                keep_source,
+                &mut self.recipe_holder,
+                id,
+                new_id.into(),
+                |v, id| {
+                    v.id = id;
+                    v._changed = true;
+                },
+            ),
+            GameEntityT::HuntingZone(id) => transfer(
+                keep_source,
+                &mut self.hunting_zone_holder,
+                id,
+                new_id.into(),
+                |v, id| {
+                    v.id = id;
+                    v._changed = true;
+                },
+            ),
+            GameEntityT::Region(id) => transfer(
+                keep_source,
+                &mut self.region_holder,
+                id,
+                new_id.into(),
+                |v, id| {
+                    v.id = id;
+                    v._changed = true;
+                },
+            ),
+            GameEntityT::RaidInfo(id) => transfer(
+                keep_source,
+                &mut self.raid_info_holder,
+                id,
+                new_id.into(),
+                |v, id| {
+                    v.id = id;
+                    v._changed = true;
+                },
+            ),
+            GameEntityT::DailyMission(id) => transfer(
+                keep_source,
+                &mut self.daily_mission_holder,
+                id,
+                new_id.into(),
+                |v, id| {
+                    v.id = id;
+                    v._changed = true;
+                },
+            ),
+            GameEntityT::AnimationCombo(id) => transfer(
+                keep_source,
+                &mut self.animation_combo_holder,
+                id,
+                new_id.into(),
+                |v, id| {
+                    v.id = id;
+                    v._changed = true;
+                },
+            ),
+            GameEntityT::Residence(id) => transfer(
+                keep_source,
+                &mut self.residence_holder,
+                id,
+                new_id.into(),
+                |v, id| {
+                    v.id = id;
+                    v._changed = true;
+                },
+            ),
+            GameEntityT::EnsoulOption(id) => transfer(
+                keep_source,
+                &mut self.ensoul_option_holder,
+                id,
+                new_id.into(),
+                |v, id| {
+                    v.id = id;
+                    v._changed = true;
+                },
+            ),
+            GameEntityT::Henna(id) => transfer(
+                keep_source,
+                &mut self.henna_holder,
+                id,
+                new_id.into(),
+                |v, id| {
+                    v.id = id;
+                    v._changed = true;
+                },
+            ),
+            GameEntityT::UserAction(id) => transfer(
+                keep_source,
+                &mut self.user_action_holder,
+                id,
+                new_id.into(),
+                |v, id| {
+                    v.id = id;
+                    v._changed = true;
+                },
+            ),
+            GameEntityT::Product(id) => transfer(
+                keep_source,
+                &mut self.product_holder,
+                id,
+                new_id.into(),
+                |v, id| {
+                    v.id = id;
+                    v._changed = true;
+                },
+            ),
+            GameEntityT::ClassInfo(id) => transfer(
+                keep_source,
+                &mut self.class_info_holder,
+                id,
+                new_id.into(),
+                |v, id| {
+                    v.id = id;
+                    v._changed = true;
+                },
+            ),
+        }
+    }
+
+    pub fn remap_entity_references(&mut self, entity: GameEntityT, remap: &Remap) {
+        match entity {
+            GameEntityT::Quest(id) => {
+                remap_entity(&mut self.quest_holder, id, remap, |v| v._changed = true)
+            }
+            GameEntityT::Skill(id) => {
+                remap_entity(&mut self.skill_holder, id, remap, |v| v._changed = true)
+            }
+            GameEntityT::Npc(id) => {
+                remap_entity(&mut self.npc_holder, id, remap, |v| v._changed = true)
+            }
+            GameEntityT::Weapon(id) => {
+                remap_entity(&mut self.weapon_holder, id, remap, |v| v._changed = true)
+            }
+            GameEntityT::Armor(id) => {
+                remap_entity(&mut self.armor_holder, id, remap, |v| v._changed = true)
+            }
+            GameEntityT::EtcItem(id) => {
+                remap_entity(&mut self.etc_item_holder, id, remap, |v| v._changed = true)
+            }
+            GameEntityT::ItemSet(id) => {
+                remap_entity(&mut self.item_set_holder, id, remap, |v| v._changed = true)
+            }
+            GameEntityT::Recipe(id) => {
+                remap_entity(&mut self.recipe_holder, id, remap, |v| v._changed = true)
+            }
+            GameEntityT::HuntingZone(id) => {
+                remap_entity(&mut self.hunting_zone_holder, id, remap, |v| {
+                    v._changed = true
+                })
+            }
+            GameEntityT::RaidInfo(id) => {
+                remap_entity(&mut self.raid_info_holder, id, remap, |v| v._changed = true)
+            }
+            GameEntityT::DailyMission(id) => {
+                remap_entity(&mut self.daily_mission_holder, id, remap, |v| {
+                    v._changed = true
+                })
+            }
+            GameEntityT::EnsoulOption(id) => {
+                remap_entity(&mut self.ensoul_option_holder, id, remap, |v| {
+                    v._changed = true
+                })
+            }
+            GameEntityT::Henna(id) => {
+                remap_entity(&mut self.henna_holder, id, remap, |v| v._changed = true)
+            }
+            GameEntityT::Product(id) => {
+                remap_entity(&mut self.product_holder, id, remap, |v| v._changed = true)
+            }
+            GameEntityT::ClassInfo(id) => {
+                remap_entity(&mut self.class_info_holder, id, remap, |v| {
+                    v._changed = true
+                })
+            }
+
+            GameEntityT::Region(_)
+            | GameEntityT::AnimationCombo(_)
+            | GameEntityT::Residence(_)
+            | GameEntityT::UserAction(_) => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::backend::holder::HolderMapOps;
+    use crate::backend::holder::tests::items_with_recipe;
+    use crate::backend::reference_index::RefTarget;
+    use crate::common::{ItemId, RecipeId};
+    use crate::entity::GameEntityT;
+
+    #[test]
+    fn test_re_id_preview() {
+        let holder = items_with_recipe();
+
+        let preview = holder
+            .re_id_preview(GameEntityT::EtcItem(ItemId(10)), 20)
+            .unwrap();
+
+        assert_eq!(GameEntityT::EtcItem(ItemId(20)), preview.to);
+        assert_eq!(1, preview.affected.len());
+        assert_eq!(GameEntityT::Recipe(RecipeId(5)), preview.affected[0].0);
+
+        assert!(
+            holder
+                .re_id_preview(GameEntityT::EtcItem(ItemId(10)), 0)
+                .is_err()
+        );
+        assert!(
+            holder
+                .re_id_preview(GameEntityT::EtcItem(ItemId(10)), 10)
+                .is_err()
+        );
+        assert!(
+            holder
+                .re_id_preview(GameEntityT::EtcItem(ItemId(10)), 30)
+                .is_err()
+        );
+        assert!(
+            holder
+                .re_id_preview(GameEntityT::EtcItem(ItemId(99)), 20)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_re_id() {
+        let mut holder = items_with_recipe();
+
+        holder.re_id(
+            GameEntityT::EtcItem(ItemId(10)),
+            GameEntityT::EtcItem(ItemId(20)),
+            &[GameEntityT::Recipe(RecipeId(5))],
+        );
+
+        assert!(holder.etc_item_holder.get(&ItemId(10)).is_none());
+        assert_eq!(
+            ItemId(20),
+            holder
+                .etc_item_holder
+                .get(&ItemId(20))
+                .unwrap()
+                .base_info
+                .id
+        );
+        assert!(holder.item_holder.contains_key(&ItemId(20)));
+        assert!(!holder.item_holder.contains_key(&ItemId(10)));
+
+        let recipe = holder.recipe_holder.get(&RecipeId(5)).unwrap();
+        assert_eq!(ItemId(20), recipe.materials[0].id);
+        assert!(recipe._changed);
+
+        assert!(
+            holder
+                .reference_index
+                .used_by(RefTarget::Item(ItemId(10)))
+                .is_empty()
+        );
+        assert_eq!(
+            1,
+            holder
+                .reference_index
+                .used_by(RefTarget::Item(ItemId(20)))
+                .len()
+        );
+    }
+}
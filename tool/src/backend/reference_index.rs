@@ -0,0 +1,835 @@
+use crate::backend::holder::{GameDataHolder, HolderMapOps};
+use crate::common::{
+    ClassId, EnsoulOptionId, HuntingZoneId, ItemId, ItemSetId, NpcId, QuestId, RegionId, SkillId,
+};
+use crate::entity::class_info::ClassInfo;
+use crate::entity::daily_mission::DailyMission;
+use crate::entity::ensoul_option::EnsoulOption;
+use crate::entity::henna::Henna;
+use crate::entity::hunting_zone::HuntingZone;
+use crate::entity::item::ItemBaseInfo;
+use crate::entity::item::armor::Armor;
+use crate::entity::item::etc_item::EtcItem;
+use crate::entity::item::weapon::Weapon;
+use crate::entity::item_set::ItemSet;
+use crate::entity::npc::Npc;
+use crate::entity::product::Product;
+use crate::entity::quest::{GoalType, Quest};
+use crate::entity::raid_info::RaidInfo;
+use crate::entity::recipe::Recipe;
+use crate::entity::skill::Skill;
+use crate::entity::{CommonEntity, GameEntityT};
+use std::collections::HashMap;
+
+/// Something an entity can point to. Weapons, armor and etc items share one id space, so any
+/// item id is a single target
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
+pub enum RefTarget {
+    Item(ItemId),
+    Npc(NpcId),
+    Skill(SkillId),
+    Quest(QuestId),
+    HuntingZone(HuntingZoneId),
+    Region(RegionId),
+    ClassInfo(ClassId),
+    ItemSet(ItemSetId),
+    EnsoulOption(EnsoulOptionId),
+}
+
+impl RefTarget {
+    pub fn from_entity(entity: GameEntityT) -> Option<Self> {
+        match entity {
+            GameEntityT::Weapon(id) | GameEntityT::Armor(id) | GameEntityT::EtcItem(id) => {
+                Some(Self::Item(id))
+            }
+            GameEntityT::Npc(id) => Some(Self::Npc(id)),
+            GameEntityT::Skill(id) => Some(Self::Skill(id)),
+            GameEntityT::Quest(id) => Some(Self::Quest(id)),
+            GameEntityT::HuntingZone(id) => Some(Self::HuntingZone(id)),
+            GameEntityT::Region(id) => Some(Self::Region(id)),
+            GameEntityT::ClassInfo(id) => Some(Self::ClassInfo(id)),
+            GameEntityT::ItemSet(id) => Some(Self::ItemSet(id)),
+            GameEntityT::EnsoulOption(id) => Some(Self::EnsoulOption(id)),
+
+            _ => None,
+        }
+    }
+}
+
+/// Field name of the referring entity and the entity it points to
+pub type Reference = (&'static str, RefTarget);
+
+pub trait EntityReferences {
+    fn references(&self) -> Vec<Reference>;
+    /// Rewrites every reference listed by [`EntityReferences::references`] found in `remap`
+    fn remap_references(&mut self, remap: &Remap);
+}
+
+/// Typed id that can be stored as [`RefTarget`]
+pub trait RefId: Copy {
+    fn target(self) -> RefTarget;
+    fn from_target(target: RefTarget) -> Option<Self>;
+}
+
+macro_rules! ref_id {
+    ($id:ty, $variant:ident) => {
+        impl RefId for $id {
+            fn target(self) -> RefTarget {
+                RefTarget::$variant(self)
+            }
+
+            fn from_target(target: RefTarget) -> Option<Self> {
+                if let RefTarget::$variant(v) = target {
+                    Some(v)
+                } else {
+                    None
+                }
+            }
+        }
+    };
+}
+
+ref_id!(ItemId, Item);
+ref_id!(NpcId, Npc);
+ref_id!(SkillId, Skill);
+ref_id!(QuestId, Quest);
+ref_id!(HuntingZoneId, HuntingZone);
+ref_id!(RegionId, Region);
+ref_id!(ClassId, ClassInfo);
+ref_id!(ItemSetId, ItemSet);
+ref_id!(EnsoulOptionId, EnsoulOption);
+
+/// Old target -> new target substitutions. Targets of different kinds are never mixed
+#[derive(Default, Clone, Debug)]
+pub struct Remap(HashMap<RefTarget, RefTarget>);
+
+impl Remap {
+    pub fn insert(&mut self, from: RefTarget, to: RefTarget) {
+        self.0.insert(from, to);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn apply<Id: RefId>(&self, id: &mut Id) {
+        if let Some(v) = self.0.get(&id.target()).and_then(|v| Id::from_target(*v)) {
+            *id = v;
+        }
+    }
+
+    fn apply_all<'a, Id: RefId + 'a>(&self, ids: impl IntoIterator<Item = &'a mut Id>) {
+        for v in ids {
+            self.apply(v);
+        }
+    }
+
+    /// Remaps ids of list entries. Entries remapped to an empty id are removed instead of being
+    /// kept as zero ids
+    fn apply_list<T, Id: RefId>(&self, list: &mut Vec<T>, id: impl Fn(&mut T) -> &mut Id) {
+        list.retain_mut(|v| {
+            let id = id(v);
+            let before = id.target();
+
+            self.apply(id);
+
+            id.target() == before || !target_is_empty(id.target())
+        });
+    }
+}
+
+/// Reverse index: who points to a given entity. Zero ids are treated as "not set" and skipped
+#[derive(Default)]
+pub struct ReferenceIndex {
+    used_by: HashMap<RefTarget, Vec<(GameEntityT, &'static str)>>,
+    uses: HashMap<GameEntityT, Vec<RefTarget>>,
+}
+
+impl ReferenceIndex {
+    pub fn used_by(&self, target: RefTarget) -> &[(GameEntityT, &'static str)] {
+        self.used_by.get(&target).map_or(&[], |v| v.as_slice())
+    }
+
+    pub fn targets(&self) -> impl Iterator<Item = RefTarget> + '_ {
+        self.used_by.keys().copied()
+    }
+
+    pub fn remove(&mut self, referrer: GameEntityT) {
+        let Some(targets) = self.uses.remove(&referrer) else {
+            return;
+        };
+
+        for target in targets {
+            if let Some(v) = self.used_by.get_mut(&target) {
+                v.retain(|(e, _)| *e != referrer);
+
+                if v.is_empty() {
+                    self.used_by.remove(&target);
+                }
+            }
+        }
+    }
+
+    pub fn update<Id, T: EntityReferences + CommonEntity<Id>>(
+        &mut self,
+        referrer: GameEntityT,
+        entity: &T,
+    ) {
+        self.remove(referrer);
+
+        if entity.deleted() {
+            return;
+        }
+
+        let mut targets = vec![];
+
+        for (field, target) in entity.references() {
+            if target_is_empty(target) {
+                continue;
+            }
+
+            let referrers = self.used_by.entry(target).or_default();
+
+            if !referrers.contains(&(referrer, field)) {
+                referrers.push((referrer, field));
+            }
+
+            if !targets.contains(&target) {
+                targets.push(target);
+            }
+        }
+
+        if !targets.is_empty() {
+            self.uses.insert(referrer, targets);
+        }
+    }
+}
+
+pub(crate) fn target_is_empty(target: RefTarget) -> bool {
+    match target {
+        RefTarget::Item(v) => v.0 == 0,
+        RefTarget::Npc(v) => v.0 == 0,
+        RefTarget::Skill(v) => v.0 == 0,
+        RefTarget::Quest(v) => v.0 == 0,
+        RefTarget::HuntingZone(v) => v.0 == 0,
+        RefTarget::Region(v) => v.0 == 0,
+        RefTarget::ClassInfo(_) => false,
+        RefTarget::ItemSet(v) => v.0 == 0,
+        RefTarget::EnsoulOption(v) => v.0 == 0,
+    }
+}
+
+impl GameDataHolder {
+    pub fn rebuild_reference_index(&mut self) {
+        let mut index = ReferenceIndex::default();
+
+        for v in self.npc_holder.values() {
+            index.update(GameEntityT::Npc(v.id), v);
+        }
+        for v in self.quest_holder.values() {
+            index.update(GameEntityT::Quest(v.id), v);
+        }
+        for v in self.skill_holder.values() {
+            index.update(GameEntityT::Skill(v.id), v);
+        }
+        for v in self.weapon_holder.values() {
+            index.update(GameEntityT::Weapon(v.id()), v);
+        }
+        for v in self.armor_holder.values() {
+            index.update(GameEntityT::Armor(v.id()), v);
+        }
+        for v in self.etc_item_holder.values() {
+            index.update(GameEntityT::EtcItem(v.id()), v);
+        }
+        for v in self.item_set_holder.values() {
+            index.update(GameEntityT::ItemSet(v.id), v);
+        }
+        for v in self.recipe_holder.values() {
+            index.update(GameEntityT::Recipe(v.id), v);
+        }
+        for v in self.hunting_zone_holder.values() {
+            index.update(GameEntityT::HuntingZone(v.id), v);
+        }
+        for v in self.raid_info_holder.values() {
+            index.update(GameEntityT::RaidInfo(v.id), v);
+        }
+        for v in self.daily_mission_holder.values() {
+            index.update(GameEntityT::DailyMission(v.id), v);
+        }
+        for v in self.ensoul_option_holder.values() {
+            index.update(GameEntityT::EnsoulOption(v.id), v);
+        }
+        for v in self.henna_holder.values() {
+            index.update(GameEntityT::Henna(v.id), v);
+        }
+        for v in self.product_holder.values() {
+            index.update(GameEntityT::Product(v.id), v);
+        }
+        for v in self.class_info_holder.values() {
+            index.update(GameEntityT::ClassInfo(v.id), v);
+        }
+
+        self.reference_index = index;
+    }
+
+    /// Refreshes index entries of a single entity, deleted ones are dropped from the index
+    pub fn update_entity_references(&mut self, entity: GameEntityT) {
+        let index = &mut self.reference_index;
+
+        match entity {
+            GameEntityT::Npc(id) => self.npc_holder.get(&id).map(|v| index.update(entity, v)),
+            GameEntityT::Quest(id) => self.quest_holder.get(&id).map(|v| index.update(entity, v)),
+            GameEntityT::Skill(id) => self.skill_holder.get(&id).map(|v| index.update(entity, v)),
+            GameEntityT::Weapon(id) => self.weapon_holder.get(&id).map(|v| index.update(entity, v)),
+            GameEntityT::Armor(id) => self.armor_holder.get(&id).map(|v| index.update(entity, v)),
+            GameEntityT::EtcItem(id) => self
+                .etc_item_holder
+                .get(&id)
+                .map(|v| index.update(entity, v)),
+            GameEntityT::ItemSet(id) => self
+                .item_set_holder
+                .get(&id)
+                .map(|v| index.update(entity, v)),
+            GameEntityT::Recipe(id) => self.recipe_holder.get(&id).map(|v| index.update(entity, v)),
+            GameEntityT::HuntingZone(id) => self
+                .hunting_zone_holder
+                .get(&id)
+                .map(|v| index.update(entity, v)),
+            GameEntityT::RaidInfo(id) => self
+                .raid_info_holder
+                .get(&id)
+                .map(|v| index.update(entity, v)),
+            GameEntityT::DailyMission(id) => self
+                .daily_mission_holder
+                .get(&id)
+                .map(|v| index.update(entity, v)),
+            GameEntityT::EnsoulOption(id) => self
+                .ensoul_option_holder
+                .get(&id)
+                .map(|v| index.update(entity, v)),
+            GameEntityT::Henna(id) => self.henna_holder.get(&id).map(|v| index.update(entity, v)),
+            GameEntityT::Product(id) => self
+                .product_holder
+                .get(&id)
+                .map(|v| index.update(entity, v)),
+            GameEntityT::ClassInfo(id) => self
+                .class_info_holder
+                .get(&id)
+                .map(|v| index.update(entity, v)),
+
+            GameEntityT::Region(_)
+            | GameEntityT::AnimationCombo(_)
+            | GameEntityT::Residence(_)
+            | GameEntityT::UserAction(_) => None,
+        };
+    }
+
+    /// Name of existing not deleted entity
+    pub fn get_entity_name(&self, entity: GameEntityT) -> Option<String> {
+        fn name<Id, T: CommonEntity<Id>>(v: Option<&T>) -> Option<String> {
+            v.filter(|v| !v.deleted()).map(|v| v.name())
+        }
+
+        match entity {
+            GameEntityT::Quest(id) => name(self.quest_holder.get(&id)),
+            GameEntityT::Skill(id) => name(self.skill_holder.get(&id)),
+            GameEntityT::Npc(id) => name(self.npc_holder.get(&id)),
+            GameEntityT::Weapon(id) => name(self.weapon_holder.get(&id)),
+            GameEntityT::Armor(id) => name(self.armor_holder.get(&id)),
+            GameEntityT::EtcItem(id) => name(self.etc_item_holder.get(&id)),
+            GameEntityT::ItemSet(id) => name(self.item_set_holder.get(&id)),
+            GameEntityT::Recipe(id) => name(self.recipe_holder.get(&id)),
+            GameEntityT::HuntingZone(id) => name(self.hunting_zone_holder.get(&id)),
+            GameEntityT::Region(id) => name(self.region_holder.get(&id)),
+            GameEntityT::RaidInfo(id) => name(self.raid_info_holder.get(&id)),
+            GameEntityT::DailyMission(id) => name(self.daily_mission_holder.get(&id)),
+            GameEntityT::AnimationCombo(id) => name(self.animation_combo_holder.get(&id)),
+            GameEntityT::Residence(id) => name(self.residence_holder.get(&id)),
+            GameEntityT::EnsoulOption(id) => name(self.ensoul_option_holder.get(&id)),
+            GameEntityT::Henna(id) => name(self.henna_holder.get(&id)),
+            GameEntityT::UserAction(id) => name(self.user_action_holder.get(&id)),
+            GameEntityT::Product(id) => name(self.product_holder.get(&id)),
+            GameEntityT::ClassInfo(id) => name(self.class_info_holder.get(&id)),
+        }
+    }
+
+    /// Entities that point to `entity` and are not deleted
+    pub fn get_used_by(&self, entity: GameEntityT) -> Vec<(GameEntityT, &'static str, String)> {
+        let Some(target) = RefTarget::from_entity(entity) else {
+            return vec![];
+        };
+
+        self.reference_index
+            .used_by(target)
+            .iter()
+            .filter(|(e, _)| *e != entity)
+            .filter_map(|(e, field)| self.get_entity_name(*e).map(|name| (*e, *field, name)))
+            .collect()
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+//                                          Entity References
+//--------------------------------------------------------------------------------------------------
+
+fn classes(classes: &Option<Vec<ClassId>>, res: &mut Vec<Reference>) {
+    if let Some(classes) = classes {
+        for v in classes {
+            res.push(("Allowed Classes", RefTarget::ClassInfo(*v)));
+        }
+    }
+}
+
+fn remap_classes(classes: &mut Option<Vec<ClassId>>, remap: &Remap) {
+    if let Some(classes) = classes {
+        remap.apply_all(classes);
+    }
+}
+
+fn remap_item_base(base: &mut ItemBaseInfo, remap: &Remap) {
+    remap.apply(&mut base.set_id);
+    remap.apply_list(&mut base.related_quests, |v| v);
+    remap.apply_list(&mut base.additional_info.inner.include_items, |v| v);
+}
+
+fn item_base_references(base: &ItemBaseInfo) -> Vec<Reference> {
+    let mut res = vec![("Set", RefTarget::ItemSet(base.set_id))];
+
+    for v in &base.related_quests {
+        res.push(("Related Quests", RefTarget::Quest(*v)));
+    }
+    for v in &base.additional_info.inner.include_items {
+        res.push(("Include Items", RefTarget::Item(*v)));
+    }
+
+    res
+}
+
+impl EntityReferences for Quest {
+    fn references(&self) -> Vec<Reference> {
+        let mut res = vec![
+            (
+                "Required Completed Quest",
+                RefTarget::Quest(self.required_completed_quest_id),
+            ),
+            ("Search Zone", RefTarget::HuntingZone(self.search_zone_id)),
+        ];
+
+        for v in &self.start_npc_ids {
+            res.push(("Start Npc", RefTarget::Npc(*v)));
+        }
+        for v in &self.rewards {
+            res.push(("Rewards", RefTarget::Item(v.reward_id)));
+        }
+        for v in &self.quest_items {
+            res.push(("Quest Items", RefTarget::Item(*v)));
+        }
+        for step in &self.steps {
+            for goal in &step.goals {
+                match goal.goal_type {
+                    GoalType::KillNpc => {
+                        res.push(("Step Goals", RefTarget::Npc(NpcId(goal.target_id))))
+                    }
+                    GoalType::CollectItem => {
+                        res.push(("Step Goals", RefTarget::Item(ItemId(goal.target_id))))
+                    }
+                    GoalType::Other => {}
+                }
+            }
+        }
+
+        classes(&self.allowed_classes, &mut res);
+
+        res
+    }
+
+    fn remap_references(&mut self, remap: &Remap) {
+        remap.apply(&mut self.required_completed_quest_id);
+        remap.apply(&mut self.search_zone_id);
+        remap.apply_list(&mut self.start_npc_ids, |v| v);
+        remap.apply_list(&mut self.rewards, |v| &mut v.reward_id);
+        remap.apply_list(&mut self.quest_items, |v| v);
+
+        for step in &mut self.steps {
+            step.goals.retain_mut(|goal| {
+                let before = goal.target_id;
+
+                match goal.goal_type {
+                    GoalType::KillNpc => {
+                        let mut id = NpcId(goal.target_id);
+                        remap.apply(&mut id);
+                        goal.target_id = id.0;
+                    }
+                    GoalType::CollectItem => {
+                        let mut id = ItemId(goal.target_id);
+                        remap.apply(&mut id);
+                        goal.target_id = id.0;
+                    }
+                    GoalType::Other => {}
+                }
+
+                goal.target_id == before || goal.target_id != 0
+            });
+        }
+
+        remap_classes(&mut self.allowed_classes, remap);
+    }
+}
+
+impl EntityReferences for Skill {
+    fn references(&self) -> Vec<Reference> {
+        let mut res = vec![("Origin Skill", RefTarget::Skill(self.origin_skill))];
+
+        if let Some(cond) = &self.use_condition {
+            res.push((
+                "Use Condition",
+                RefTarget::Item(cond.inner.consumable_item_id),
+            ));
+
+            for v in cond
+                .inner
+                .caster_prior_skill
+                .iter()
+                .chain(&cond.inner.target_prior_skill)
+            {
+                res.push(("Use Condition", RefTarget::Skill(v.id)));
+            }
+        }
+
+        res
+    }
+
+    fn remap_references(&mut self, remap: &Remap) {
+        remap.apply(&mut self.origin_skill);
+
+        if let Some(cond) = &mut self.use_condition {
+            let cond = &mut cond.inner;
+
+            remap.apply(&mut cond.consumable_item_id);
+            remap.apply_list(&mut cond.caster_prior_skill, |v| &mut v.id);
+            remap.apply_list(&mut cond.target_prior_skill, |v| &mut v.id);
+        }
+    }
+}
+
+impl EntityReferences for Npc {
+    fn references(&self) -> Vec<Reference> {
+        let equip = &self.equipment_params.inner;
+
+        let mut res = vec![
+            ("Equipment", RefTarget::Item(equip.left_hand)),
+            ("Equipment", RefTarget::Item(equip.right_hand)),
+            ("Equipment", RefTarget::Item(equip.chest)),
+        ];
+
+        if let Some(parts) = &self.additional_parts.inner {
+            for v in [
+                parts.chest,
+                parts.legs,
+                parts.gloves,
+                parts.feet,
+                parts.back,
+                parts.hair_accessory,
+                parts.right_hand,
+                parts.left_hand,
+            ] {
+                res.push(("Additional Parts", RefTarget::Item(v)));
+            }
+        }
+
+        for v in &self.properties {
+            res.push(("Properties", RefTarget::Skill(v.id)));
+        }
+        for v in &self.skill_animations.inner {
+            res.push(("Skill Animations", RefTarget::Skill(v.id)));
+        }
+        for v in &self.quest_infos {
+            res.push(("Quests", RefTarget::Quest(v.id)));
+        }
+
+        res
+    }
+
+    fn remap_references(&mut self, remap: &Remap) {
+        let equip = &mut self.equipment_params.inner;
+
+        remap.apply(&mut equip.left_hand);
+        remap.apply(&mut equip.right_hand);
+        remap.apply(&mut equip.chest);
+
+        if let Some(parts) = &mut self.additional_parts.inner {
+            remap.apply_all([
+                &mut parts.chest,
+                &mut parts.legs,
+                &mut parts.gloves,
+                &mut parts.feet,
+                &mut parts.back,
+                &mut parts.hair_accessory,
+                &mut parts.right_hand,
+                &mut parts.left_hand,
+            ]);
+        }
+
+        remap.apply_list(&mut self.properties, |v| &mut v.id);
+        remap.apply_list(&mut self.skill_animations.inner, |v| &mut v.id);
+        remap.apply_list(&mut self.quest_infos, |v| &mut v.id);
+    }
+}
+
+impl EntityReferences for Weapon {
+    fn references(&self) -> Vec<Reference> {
+        item_base_references(&self.base_info)
+    }
+
+    fn remap_references(&mut self, remap: &Remap) {
+        remap_item_base(&mut self.base_info, remap);
+    }
+}
+
+impl EntityReferences for Armor {
+    fn references(&self) -> Vec<Reference> {
+        item_base_references(&self.base_info)
+    }
+
+    fn remap_references(&mut self, remap: &Remap) {
+        remap_item_base(&mut self.base_info, remap);
+    }
+}
+
+impl EntityReferences for EtcItem {
+    fn references(&self) -> Vec<Reference> {
+        let mut res = item_base_references(&self.base_info);
+
+        if let Some(stone) = &self.ensoul_stone {
+            for v in &stone.options {
+                res.push(("Ensoul Options", RefTarget::EnsoulOption(*v)));
+            }
+        }
+
+        res
+    }
+
+    fn remap_references(&mut self, remap: &Remap) {
+        remap_item_base(&mut self.base_info, remap);
+
+        if let Some(stone) = &mut self.ensoul_stone {
+            remap.apply_list(&mut stone.options, |v| v);
+        }
+    }
+}
+
+impl EntityReferences for ItemSet {
+    fn references(&self) -> Vec<Reference> {
+        let mut res = vec![];
+
+        for v in self.base_items.iter().flatten() {
+            res.push(("Base Items", RefTarget::Item(*v)));
+        }
+        for v in self.additional_items.iter().flatten() {
+            res.push(("Additional Items", RefTarget::Item(*v)));
+        }
+
+        res
+    }
+
+    fn remap_references(&mut self, remap: &Remap) {
+        for v in self.base_items.iter_mut().chain(&mut self.additional_items) {
+            remap.apply_list(v, |v| v);
+        }
+    }
+}
+
+impl EntityReferences for Recipe {
+    fn references(&self) -> Vec<Reference> {
+        let mut res = vec![
+            ("Recipe Item", RefTarget::Item(self.recipe_item)),
+            ("Product", RefTarget::Item(self.product)),
+        ];
+
+        for v in &self.materials {
+            res.push(("Materials", RefTarget::Item(v.id)));
+        }
+
+        res
+    }
+
+    fn remap_references(&mut self, remap: &Remap) {
+        remap.apply(&mut self.recipe_item);
+        remap.apply(&mut self.product);
+        remap.apply_list(&mut self.materials, |v| &mut v.id);
+    }
+}
+
+impl EntityReferences for HuntingZone {
+    fn references(&self) -> Vec<Reference> {
+        let mut res = vec![
+            ("Npc", RefTarget::Npc(self.npc_id)),
+            ("Search Zone", RefTarget::Region(self.search_zone_id)),
+        ];
+
+        for v in &self.quests {
+            res.push(("Quests", RefTarget::Quest(*v)));
+        }
+
+        res
+    }
+
+    fn remap_references(&mut self, remap: &Remap) {
+        remap.apply(&mut self.npc_id);
+        remap.apply(&mut self.search_zone_id);
+        remap.apply_list(&mut self.quests, |v| v);
+    }
+}
+
+impl EntityReferences for RaidInfo {
+    fn references(&self) -> Vec<Reference> {
+        vec![
+            ("Raid", RefTarget::Npc(self.raid_id)),
+            ("Search Zone", RefTarget::HuntingZone(self.search_zone_id)),
+        ]
+    }
+
+    fn remap_references(&mut self, remap: &Remap) {
+        remap.apply(&mut self.raid_id);
+        remap.apply(&mut self.search_zone_id);
+    }
+}
+
+impl EntityReferences for DailyMission {
+    fn references(&self) -> Vec<Reference> {
+        let mut res = vec![];
+
+        for v in &self.rewards {
+            res.push(("Rewards", RefTarget::Item(v.item_id)));
+        }
+
+        classes(&self.allowed_classes, &mut res);
+
+        res
+    }
+
+    fn remap_references(&mut self, remap: &Remap) {
+        remap.apply_list(&mut self.rewards, |v| &mut v.item_id);
+        remap_classes(&mut self.allowed_classes, remap);
+    }
+}
+
+impl EntityReferences for EnsoulOption {
+    fn references(&self) -> Vec<Reference> {
+        vec![("Extraction Item", RefTarget::Item(self.extraction_item_id))]
+    }
+
+    fn remap_references(&mut self, remap: &Remap) {
+        remap.apply(&mut self.extraction_item_id);
+    }
+}
+
+impl EntityReferences for Henna {
+    fn references(&self) -> Vec<Reference> {
+        let mut res = vec![("Dye Item", RefTarget::Item(self.dye_item_id))];
+
+        classes(&self.allowed_classes, &mut res);
+
+        res
+    }
+
+    fn remap_references(&mut self, remap: &Remap) {
+        remap.apply(&mut self.dye_item_id);
+        remap_classes(&mut self.allowed_classes, remap);
+    }
+}
+
+impl EntityReferences for Product {
+    fn references(&self) -> Vec<Reference> {
+        self.items
+            .iter()
+            .map(|v| ("Items", RefTarget::Item(v.item_id)))
+            .collect()
+    }
+
+    fn remap_references(&mut self, remap: &Remap) {
+        remap.apply_list(&mut self.items, |v| &mut v.item_id);
+    }
+}
+
+impl EntityReferences for ClassInfo {
+    fn references(&self) -> Vec<Reference> {
+        self.parent
+            .iter()
+            .map(|v| ("Parent", RefTarget::ClassInfo(*v)))
+            .collect()
+    }
+
+    fn remap_references(&mut self, remap: &Remap) {
+        remap.apply_all(&mut self.parent);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EntityReferences, RefTarget, Remap};
+    use crate::common::{ItemId, RecipeId};
+    use crate::entity::recipe::{Recipe, RecipeMaterial};
+
+    fn material(id: u32) -> RecipeMaterial {
+        RecipeMaterial {
+            id: ItemId(id),
+            count: 1,
+            recipe_id: RecipeId(1),
+        }
+    }
+
+    #[test]
+    fn test_remap_apply() {
+        let mut remap = Remap::default();
+        remap.insert(RefTarget::Item(ItemId(1)), RefTarget::Item(ItemId(2)));
+
+        let mut id = ItemId(1);
+        remap.apply(&mut id);
+        assert_eq!(ItemId(2), id);
+
+        let mut id = ItemId(3);
+        remap.apply(&mut id);
+        assert_eq!(ItemId(3), id);
+    }
+
+    #[test]
+    fn test_remap_references() {
+        let mut recipe = Recipe {
+            id: RecipeId(1),
+            recipe_item: ItemId(1),
+            product: ItemId(2),
+            materials: vec![material(1), material(3)],
+            ..Default::default()
+        };
+
+        let mut remap = Remap::default();
+        remap.insert(RefTarget::Item(ItemId(1)), RefTarget::Item(ItemId(10)));
+
+        recipe.remap_references(&remap);
+
+        assert_eq!(ItemId(10), recipe.recipe_item);
+        assert_eq!(ItemId(2), recipe.product);
+        assert_eq!(vec![material(10), material(3)], recipe.materials);
+    }
+
+    #[test]
+    fn test_remap_to_empty_drops_list_entries() {
+        let mut recipe = Recipe {
+            id: RecipeId(1),
+            recipe_item: ItemId(1),
+            product: ItemId(2),
+            materials: vec![material(0), material(1), material(3)],
+            ..Default::default()
+        };
+
+        let mut remap = Remap::default();
+        remap.insert(RefTarget::Item(ItemId(1)), RefTarget::Item(ItemId(0)));
+
+        recipe.remap_references(&remap);
+
+        // Scalar is cleared, list entry is removed, the one that was already empty stays
+        assert_eq!(ItemId(0), recipe.recipe_item);
+        assert_eq!(vec![material(0), material(3)], recipe.materials);
+    }
+}
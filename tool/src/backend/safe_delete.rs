@@ -0,0 +1,276 @@
+use crate::backend::holder::{HolderMapOps, HolderOps};
+use crate::backend::log_holder::{Log, LogLevel};
+use crate::backend::reference_index::{RefTarget, Remap, target_is_empty};
+use crate::backend::{Backend, Dialog, DialogAnswer};
+use crate::common::ItemId;
+use crate::entity::item::Item;
+use crate::entity::{CommonEntity, GameEntity, GameEntityT};
+use crate::logs_mut;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Flips deleted flag with `toggle` and keeps holder deleted counter in sync. Returns new state
+fn toggle<Id: Hash + Eq + Copy, T: Clone + CommonEntity<Id>, M: HolderMapOps<Id, T> + HolderOps>(
+    holder: &mut M,
+    id: Id,
+    toggle: impl FnOnce(&mut T),
+) -> Option<bool> {
+    let v = holder.get_mut(&id)?;
+
+    toggle(v);
+
+    let deleted = v.deleted();
+
+    if deleted {
+        holder.inc_deleted();
+    } else {
+        holder.dec_deleted();
+    }
+
+    Some(deleted)
+}
+
+fn toggle_item<T: Clone + CommonEntity<ItemId>, M: HolderMapOps<ItemId, T> + HolderOps>(
+    holder: &mut M,
+    items: &mut HashMap<ItemId, Item>,
+    id: ItemId,
+    toggle_fn: impl FnOnce(&mut T),
+) -> Option<bool> {
+    let deleted = toggle(holder, id, toggle_fn)?;
+
+    if deleted {
+        items.remove(&id);
+    } else if let Some(v) = holder.get(&id) {
+        items.insert(id, v.into());
+    }
+
+    Some(deleted)
+}
+
+impl Backend {
+    /// Deleting entity that is still referenced asks what to do with references first
+    pub fn toggle_deleted(&mut self, entity: GameEntityT) {
+        if self
+            .holders
+            .game_data_holder
+            .get_entity_name(entity)
+            .is_some()
+        {
+            let used_by = self.holders.game_data_holder.get_used_by(entity);
+
+            if !used_by.is_empty() {
+                self.show_dialog(Dialog::ConfirmDelete {
+                    entity,
+                    used_by,
+                    substitute: 0,
+                });
+
+                return;
+            }
+        }
+
+        self.toggle_deleted_force(entity);
+    }
+
+    pub(crate) fn answer_delete(
+        &mut self,
+        entity: GameEntityT,
+        substitute: u32,
+        answer: DialogAnswer,
+    ) -> Dialog {
+        let used_by = self.holders.game_data_holder.get_used_by(entity);
+
+        match answer {
+            DialogAnswer::Abort => {}
+
+            DialogAnswer::Confirm => {
+                for (referrer, field, name) in &used_by {
+                    logs_mut().add(Log {
+                        level: LogLevel::Warning,
+                        producer: "Delete".to_string(),
+                        log: format!(
+                            "{entity} [{}] deleted, but still used by {referrer} [{}] {name} ({field})",
+                            entity.raw_id(),
+                            referrer.raw_id()
+                        ),
+                    });
+                }
+
+                self.toggle_deleted_force(entity);
+            }
+
+            DialogAnswer::Cascade => {
+                let Some(from) = RefTarget::from_entity(entity) else {
+                    return Dialog::None;
+                };
+
+                let to = entity.with_id(substitute);
+                let to_target = RefTarget::from_entity(to).unwrap();
+
+                //Zero is a real id for some targets (classes), it has to exist like any other one
+                if !target_is_empty(to_target) {
+                    if to == entity {
+                        return Dialog::ShowWarning(
+                            "Substitute can't be the deleted entity itself".to_string(),
+                        );
+                    }
+
+                    let exists = match to {
+                        GameEntityT::Weapon(_)
+                        | GameEntityT::Armor(_)
+                        | GameEntityT::EtcItem(_) => [
+                            GameEntityT::Weapon(substitute.into()),
+                            GameEntityT::Armor(substitute.into()),
+                            GameEntityT::EtcItem(substitute.into()),
+                        ]
+                        .into_iter()
+                        .any(|v| self.holders.game_data_holder.get_entity_name(v).is_some()),
+                        _ => self.holders.game_data_holder.get_entity_name(to).is_some(),
+                    };
+
+                    if !exists {
+                        return Dialog::ShowWarning(format!(
+                            "Substitute {to} [{substitute}] does not exist"
+                        ));
+                    }
+                }
+
+                if let Some((v, _, _)) = used_by
+                    .iter()
+                    .find(|(v, _, _)| self.editors.has_unsaved_changes(*v))
+                {
+                    return Dialog::ShowWarning(format!(
+                        "{v} [{}] has unsaved changes, save or close it first",
+                        v.raw_id()
+                    ));
+                }
+
+                let mut remap = Remap::default();
+                remap.insert(from, to_target);
+
+                for (referrer, _, _) in &used_by {
+                    self.editors.close_if_opened(*referrer);
+                    self.holders
+                        .game_data_holder
+                        .remap_entity_references(*referrer, &remap);
+                }
+
+                self.holders.game_data_holder.rebuild_reference_index();
+
+                self.toggle_deleted_force(entity);
+            }
+        }
+
+        Dialog::None
+    }
+
+    /// Flips deleted flag without looking at references
+    pub fn toggle_deleted_force(&mut self, entity: GameEntityT) {
+        let holder = &mut self.holders.game_data_holder;
+
+        let deleted = match entity {
+            GameEntityT::Quest(id) => {
+                toggle(&mut holder.quest_holder, id, |v| v._deleted = !v._deleted)
+            }
+            GameEntityT::Skill(id) => {
+                toggle(&mut holder.skill_holder, id, |v| v._deleted = !v._deleted)
+            }
+            GameEntityT::Npc(id) => {
+                toggle(&mut holder.npc_holder, id, |v| v._deleted = !v._deleted)
+            }
+            GameEntityT::Weapon(id) => toggle_item(
+                &mut holder.weapon_holder,
+                &mut holder.item_holder,
+                id,
+                |v| v._deleted = !v._deleted,
+            ),
+            GameEntityT::Armor(id) => {
+                toggle_item(&mut holder.armor_holder, &mut holder.item_holder, id, |v| {
+                    v._deleted = !v._deleted
+                })
+            }
+            GameEntityT::EtcItem(id) => toggle_item(
+                &mut holder.etc_item_holder,
+                &mut holder.item_holder,
+                id,
+                |v| v._deleted = !v._deleted,
+            ),
+            GameEntityT::ItemSet(id) => toggle(&mut holder.item_set_holder, id, |v| {
+                v._deleted = !v._deleted
+            }),
+            GameEntityT::Recipe(id) => {
+                toggle(&mut holder.recipe_holder, id, |v| v._deleted = !v._deleted)
+            }
+            GameEntityT::HuntingZone(id) => toggle(&mut holder.hunting_zone_holder, id, |v| {
+                v._deleted = !v._deleted
+            }),
+            GameEntityT::Region(id) => {
+                toggle(&mut holder.region_holder, id, |v| v._deleted = !v._deleted)
+            }
+            GameEntityT::RaidInfo(id) => toggle(&mut holder.raid_info_holder, id, |v| {
+                v._deleted = !v._deleted
+            }),
+            GameEntityT::DailyMission(id) => toggle(&mut holder.daily_mission_holder, id, |v| {
+                v._deleted = !v._deleted
+            }),
+            GameEntityT::AnimationCombo(id) => {
+                toggle(&mut holder.animation_combo_holder, id, |v| {
+                    v._deleted = !v._deleted
+                })
+            }
+            GameEntityT::Residence(id) => toggle(&mut holder.residence_holder, id, |v| {
+                v._deleted = !v._deleted
+            }),
+            GameEntityT::EnsoulOption(id) => toggle(&mut holder.ensoul_option_holder, id, |v| {
+                v._deleted = !v._deleted
+            }),
+            GameEntityT::Henna(id) => {
+                toggle(&mut holder.henna_holder, id, |v| v._deleted = !v._deleted)
+            }
+            GameEntityT::UserAction(id) => toggle(&mut holder.user_action_holder, id, |v| {
+                v._deleted = !v._deleted
+            }),
+            GameEntityT::Product(id) => {
+                toggle(&mut holder.product_holder, id, |v| v._deleted = !v._deleted)
+            }
+            GameEntityT::ClassInfo(id) => toggle(&mut holder.class_info_holder, id, |v| {
+                v._deleted = !v._deleted
+            }),
+        };
+
+        if deleted == Some(true) {
+            self.editors.close_if_opened(entity);
+        }
+
+        self.holders
+            .game_data_holder
+            .update_entity_references(entity);
+
+        self.filter_entity_catalog(entity.into());
+        self.check_for_unwrote_changed();
+    }
+
+    pub fn filter_entity_catalog(&mut self, entity: GameEntity) {
+        match entity {
+            GameEntity::Quest => self.filter_quests(),
+            GameEntity::Skill => self.filter_skills(),
+            GameEntity::Npc => self.filter_npcs(),
+            GameEntity::Weapon => self.filter_weapons(),
+            GameEntity::Armor => self.filter_armor(),
+            GameEntity::EtcItem => self.filter_etc_items(),
+            GameEntity::ItemSet => self.filter_item_sets(),
+            GameEntity::Recipe => self.filter_recipes(),
+            GameEntity::HuntingZone => self.filter_hunting_zones(),
+            GameEntity::Region => self.filter_regions(),
+            GameEntity::RaidInfo => self.filter_raid_info(),
+            GameEntity::DailyMission => self.filter_daily_mission(),
+            GameEntity::AnimationCombo => self.filter_animation_combo(),
+            GameEntity::Residence => self.filter_residences(),
+            GameEntity::EnsoulOption => self.filter_ensoul_option(),
+            GameEntity::Henna => self.filter_henna(),
+            GameEntity::UserAction => self.filter_user_action(),
+            GameEntity::Product => self.filter_product(),
+            GameEntity::ClassInfo => self.filter_class_info(),
+        }
+    }
+}
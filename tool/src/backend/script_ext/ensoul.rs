@@ -8,7 +8,7 @@ use rhai::{Dynamic, Engine, TypeBuilder};
 impl EnsoulOption {
     /// Additional API's
     pub fn build_extra(builder: &mut TypeBuilder<Self>) {
-        builder.on_print(|v| format!("EnsoulOption(id: {}, name: {})", v.id.0, v.name.ru));
+        builder.on_print(|v| format!("EnsoulOption(id: {}, name: {})", v.id.0, v.name.main()));
     }
 }
 impl EnsoulOptionId {
@@ -0,0 +1,68 @@
+use crate::backend::Backend;
+use crate::backend::holder::HolderMapOps;
+use crate::backend::script_ext::ChangedEntities;
+use crate::common::HennaId;
+use crate::entity::henna::{Henna, HennaStats};
+use rhai::{Dynamic, Engine, TypeBuilder};
+
+impl Henna {
+    /// Additional API's
+    pub fn build_extra(builder: &mut TypeBuilder<Self>) {
+        builder.on_print(|v| format!("Henna(id: {}, name: {})", v.id.0, v.name.main()));
+    }
+}
+impl HennaId {
+    /// Additional API's
+    pub fn build_extra(builder: &mut TypeBuilder<Self>) {
+        builder.on_print(|v| format!("HennaId({})", v.0));
+    }
+}
+
+pub fn proceed(backend: &mut Backend, henna: Vec<Henna>) {
+    for mut v in henna {
+        v._changed = true;
+        backend.editors.force_update_henna(&v);
+        backend.save_henna_force(v);
+    }
+}
+
+pub fn reg(engine: &mut Engine, changed_entities_ptr: *mut ChangedEntities, ptr: *const Backend) {
+    //Eq Overloads
+    {
+        engine.register_fn("==", |lhs: HennaId, rhs: i64| -> bool {
+            lhs.0 as i64 == rhs
+        });
+        engine.register_fn("==", |lhs: i64, rhs: HennaId| -> bool {
+            lhs == rhs.0 as i64
+        });
+    }
+
+    unsafe {
+        engine.register_fn("save", move |x: Henna| {
+            (*changed_entities_ptr).henna.push(x);
+        });
+        engine.register_fn("delete", move |mut x: Henna| {
+            x._deleted = true;
+
+            (*changed_entities_ptr).henna.push(x);
+        });
+
+        engine.register_fn("henna_list", move || -> Dynamic {
+            (*ptr)
+                .holders
+                .game_data_holder
+                .henna_holder
+                .values()
+                .cloned()
+                .collect::<Vec<_>>()
+                .into()
+        });
+    }
+
+    engine.build_type::<Henna>();
+    engine.build_type::<HennaId>();
+    engine.build_type::<HennaStats>();
+    engine.register_fn("set_id", |v: &mut Henna, id: i64| {
+        v.id.0 = id as u32;
+    });
+}
@@ -1,9 +1,11 @@
 mod ensoul;
+mod henna;
 mod items;
 mod skill;
 
 use crate::backend::Backend;
 use crate::entity::ensoul_option::EnsoulOption;
+use crate::entity::henna::Henna;
 use crate::entity::item::armor::Armor;
 use crate::entity::item::etc_item::EtcItem;
 use crate::entity::item::weapon::Weapon;
@@ -19,11 +21,14 @@ struct ChangedEntities {
 
     ensoul_option: Vec<EnsoulOption>,
 
+    henna: Vec<Henna>,
+
     skill: Vec<Skill>,
 }
 
 impl Backend {
-    pub fn run_script(&mut self, script: &str) -> String {
+    /// Output of `print` calls, error if script failed. Changes are applied only on success
+    pub fn run_script(&mut self, script: &str) -> Result<String, String> {
         let mut engine = Engine::new();
 
         let mut changed_entities: ChangedEntities = ChangedEntities::default();
@@ -43,6 +48,7 @@ impl Backend {
 
             items::reg(&mut engine, changed_entities_ptr, ptr);
             ensoul::reg(&mut engine, changed_entities_ptr, ptr);
+            henna::reg(&mut engine, changed_entities_ptr, ptr);
             skill::reg(&mut engine, changed_entities_ptr, ptr);
 
             let log_ptr: *mut Vec<String> = &mut log;
@@ -59,15 +65,17 @@ impl Backend {
                     weapon,
                     etc,
                     ensoul_option,
+                    henna,
                     skill,
                 } = changed_entities;
 
                 items::proceed(self, armor, weapon, etc);
                 ensoul::proceed(self, ensoul_option);
+                henna::proceed(self, henna);
                 skill::proceed(self, skill);
 
                 if log.is_empty() {
-                    "Completed".to_string()
+                    Ok("Completed".to_string())
                 } else {
                     let mut res = String::new();
 
@@ -75,12 +83,10 @@ impl Backend {
                         res += &format!("{log}\n");
                     }
 
-                    res
+                    Ok(res)
                 }
             }
-            Err(err) => {
-                format!("{err:?}")
-            }
+            Err(err) => Err(format!("{err:?}")),
         }
     }
 }
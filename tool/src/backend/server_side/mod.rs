@@ -1,6 +1,6 @@
-use crate::backend::Config;
 use crate::backend::dat_loader::StrUtils;
-use crate::backend::holder::GameDataHolder;
+use crate::backend::holder::{GameDataHolder, HolderMapOps};
+use crate::backend::{Config, Localization};
 use crate::common::{ItemId, NpcId, QuestId};
 use crate::entity::quest::{GoalType, Quest};
 use std::collections::HashMap;
@@ -78,7 +78,11 @@ impl ServerDataHolder {
         quest: &Quest,
         game_data_holder: &GameDataHolder,
     ) -> String {
-        let quest_name = format!("_{}_{}", quest.id.0, quest.title.ru.to_ascii_camel_case());
+        let quest_name = format!(
+            "_{}_{}",
+            quest.id.0,
+            quest.title.main().to_ascii_camel_case()
+        );
         let is_party = false; //TODO: !
 
         let mut start_npc_declaration = "".to_string();
@@ -222,9 +226,21 @@ impl ServerDataHolder {
             let mut val = "addClassIdCheck(".to_string();
 
             for (i, class) in classes.iter().enumerate() {
+                let name = if let Some(v) = game_data_holder.class_info_holder.get(class) {
+                    let name = &v.name[Localization::main()];
+
+                    if name.is_empty() {
+                        v.name.main().clone()
+                    } else {
+                        name.clone()
+                    }
+                } else {
+                    format!("UNK_{}", class.0)
+                };
+
                 val.push_str(&format!(
                     "ClassId.{}{}",
-                    class.to_string().to_ascii_snake_case(),
+                    name.to_ascii_snake_case(),
                     if i == classes.len() - 1 { ");\n" } else { ", " }
                 ))
             }
@@ -0,0 +1,558 @@
+use crate::backend::Backend;
+use crate::backend::holder::{GameDataHolder, HolderMapOps};
+use crate::backend::log_holder::{Log, LogLevel};
+use crate::backend::patch::PatchValue;
+use crate::entity::{CommonEntity, Dictionary, Entity, GameEntity};
+use crate::logs_mut;
+use rusqlite::Connection;
+use rusqlite::types::Value as SqlValue;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::path::Path;
+use strum::IntoEnumIterator;
+
+/// Joins field names into column names and table names of nested vectors:
+/// `battle_stats__p_attack`, `skill__skill_levels`
+const SEPARATOR: &str = "__";
+
+/// Weapons, armor and etc items share one id space, item ids point to this table
+const ITEM_TABLE: &str = "item";
+
+/// Table, column holding a typed id and the table it points to. Ids of 0 usually mean nothing
+/// is referenced, so `PRAGMA foreign_key_check` lists them along with really broken ones
+const FOREIGN_KEYS: &[(&str, &str, &str)] = &[
+    ("quest", "required_completed_quest_id", "quest"),
+    ("quest", "search_zone_id", "hunting_zone"),
+    ("quest__start_npc_ids", "value", "npc"),
+    ("quest__quest_items", "value", ITEM_TABLE),
+    ("quest__rewards", "reward_id", ITEM_TABLE),
+    ("quest__allowed_classes", "value", "class_info"),
+    ("skill", "origin_skill", "skill"),
+    ("skill", "use_condition__consumable_item_id", ITEM_TABLE),
+    ("skill__use_condition__caster_prior_skill", "id", "skill"),
+    ("skill__use_condition__target_prior_skill", "id", "skill"),
+    ("npc", "equipment_params__left_hand", ITEM_TABLE),
+    ("npc", "equipment_params__right_hand", ITEM_TABLE),
+    ("npc", "equipment_params__chest", ITEM_TABLE),
+    ("npc", "additional_parts__chest", ITEM_TABLE),
+    ("npc", "additional_parts__legs", ITEM_TABLE),
+    ("npc", "additional_parts__gloves", ITEM_TABLE),
+    ("npc", "additional_parts__feet", ITEM_TABLE),
+    ("npc", "additional_parts__back", ITEM_TABLE),
+    ("npc", "additional_parts__hair_accessory", ITEM_TABLE),
+    ("npc", "additional_parts__right_hand", ITEM_TABLE),
+    ("npc", "additional_parts__left_hand", ITEM_TABLE),
+    ("npc__properties", "id", "skill"),
+    ("npc__quest_infos", "id", "quest"),
+    ("npc__skill_animations", "id", "skill"),
+    ("weapon", "base_info__set_id", "item_set"),
+    ("weapon__base_info__related_quests", "value", "quest"),
+    (
+        "weapon__base_info__additional_info__include_items",
+        "value",
+        ITEM_TABLE,
+    ),
+    ("armor", "base_info__set_id", "item_set"),
+    ("armor__base_info__related_quests", "value", "quest"),
+    (
+        "armor__base_info__additional_info__include_items",
+        "value",
+        ITEM_TABLE,
+    ),
+    ("etc_item", "base_info__set_id", "item_set"),
+    ("etc_item__base_info__related_quests", "value", "quest"),
+    (
+        "etc_item__base_info__additional_info__include_items",
+        "value",
+        ITEM_TABLE,
+    ),
+    ("etc_item__ensoul_stone__options", "value", "ensoul_option"),
+    ("item_set__base_items__value", "value", ITEM_TABLE),
+    ("item_set__additional_items__value", "value", ITEM_TABLE),
+    ("recipe", "recipe_item", ITEM_TABLE),
+    ("recipe", "product", ITEM_TABLE),
+    ("recipe__materials", "id", ITEM_TABLE),
+    ("hunting_zone", "npc_id", "npc"),
+    ("hunting_zone", "search_zone_id", "region"),
+    ("hunting_zone__quests", "value", "quest"),
+    ("raid_info", "raid_id", "npc"),
+    ("raid_info", "search_zone_id", "hunting_zone"),
+    ("daily_mission__rewards", "item_id", ITEM_TABLE),
+    ("daily_mission__allowed_classes", "value", "class_info"),
+    ("ensoul_option", "extraction_item_id", ITEM_TABLE),
+    ("henna", "dye_item_id", ITEM_TABLE),
+    ("henna__allowed_classes", "value", "class_info"),
+    ("product__items", "item_id", ITEM_TABLE),
+    ("class_info", "parent", "class_info"),
+];
+
+/// `EtcItem` -> `etc_item`
+fn table_name(entity: Entity) -> String {
+    let mut res = String::new();
+
+    for c in entity.to_string().chars() {
+        if c.is_uppercase() && !res.is_empty() {
+            res.push('_');
+        }
+
+        res.push(c.to_ascii_lowercase());
+    }
+
+    res
+}
+
+fn sql_type(value: &Value) -> Option<&'static str> {
+    match value {
+        Value::Bool(_) => Some("INTEGER"),
+        Value::Number(v) if v.is_f64() => Some("REAL"),
+        Value::Number(_) => Some("INTEGER"),
+        Value::String(_) => Some("TEXT"),
+        _ => None,
+    }
+}
+
+fn to_sql(value: &Value) -> SqlValue {
+    match value {
+        Value::Bool(v) => SqlValue::Integer(*v as i64),
+        // Floats are mostly f32, stored the short way when nothing is lost
+        Value::Number(v) if v.is_f64() => {
+            let v = v.as_f64().unwrap_or_default();
+
+            if (v as f32) as f64 == v {
+                SqlValue::Real((v as f32).to_string().parse().unwrap_or(v))
+            } else {
+                SqlValue::Real(v)
+            }
+        }
+        Value::Number(v) => v
+            .as_i64()
+            .map(SqlValue::Integer)
+            .unwrap_or_else(|| SqlValue::Text(v.to_string())),
+        Value::String(v) => SqlValue::Text(v.clone()),
+        _ => SqlValue::Null,
+    }
+}
+
+fn quote(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\"\""))
+}
+
+struct Row {
+    key: i64,
+    parent: Option<i64>,
+    index: Option<usize>,
+    values: BTreeMap<String, SqlValue>,
+}
+
+#[derive(Default)]
+struct Table {
+    /// Table rows of nested vector belong to, `None` for entity tables
+    parent: Option<String>,
+    /// Type is `None` while only nulls were met
+    columns: BTreeMap<String, Option<&'static str>>,
+    rows: Vec<Row>,
+}
+
+impl Table {
+    fn key_column(&self) -> &'static str {
+        if self.parent.is_some() {
+            "row_id"
+        } else {
+            "id"
+        }
+    }
+}
+
+/// Tables are filled in memory first, columns are known only after all values are seen
+#[derive(Default)]
+struct Schema {
+    tables: BTreeMap<String, Table>,
+}
+
+impl Schema {
+    /// Adds row made of `value` to `table`. Nested objects become columns, vectors become child
+    /// tables
+    fn add_row(
+        &mut self,
+        table: &str,
+        parent: Option<(&str, i64)>,
+        index: Option<usize>,
+        key: Option<i64>,
+        value: &Value,
+    ) {
+        let t = self
+            .tables
+            .entry(table.to_string())
+            .or_insert_with(|| Table {
+                parent: parent.map(|v| v.0.to_string()),
+                ..Default::default()
+            });
+
+        let key = key.unwrap_or(t.rows.len() as i64 + 1);
+
+        t.rows.push(Row {
+            key,
+            parent: parent.map(|v| v.1),
+            index,
+            values: BTreeMap::new(),
+        });
+
+        self.fill(table, key, String::new(), value);
+    }
+
+    fn fill(&mut self, table: &str, key: i64, path: String, value: &Value) {
+        match value {
+            Value::Object(v) => {
+                for (k, v) in v {
+                    // Entity tables take id from holder key
+                    if path.is_empty() && k == "id" && self.tables[table].parent.is_none() {
+                        continue;
+                    }
+
+                    let path = if path.is_empty() {
+                        k.clone()
+                    } else {
+                        format!("{path}{SEPARATOR}{k}")
+                    };
+
+                    self.fill(table, key, path, v);
+                }
+            }
+
+            Value::Array(v) => {
+                let child = format!("{table}{SEPARATOR}{path}");
+
+                for (i, v) in v.iter().enumerate() {
+                    if v.is_object() {
+                        self.add_row(&child, Some((table, key)), Some(i), None, v);
+                    } else {
+                        let v =
+                            Value::Object([("value".to_string(), v.clone())].into_iter().collect());
+
+                        self.add_row(&child, Some((table, key)), Some(i), None, &v);
+                    }
+                }
+            }
+
+            v => {
+                let t = self.tables.get_mut(table).unwrap();
+
+                let column = t.columns.entry(path.clone()).or_default();
+
+                if column.is_none() {
+                    *column = sql_type(v);
+                }
+
+                t.rows.last_mut().unwrap().values.insert(path, to_sql(v));
+            }
+        }
+    }
+
+    fn add_entity(&mut self, holder: &GameDataHolder, entity: Entity) {
+        let table = table_name(entity);
+
+        // Empty holders still get their table
+        self.tables.entry(table.clone()).or_default();
+
+        for value in PatchValue::all_of(holder, entity) {
+            let Ok(Value::Object(json)) = serde_json::to_value(&value) else {
+                continue;
+            };
+            let Some(json) = json.values().next() else {
+                continue;
+            };
+
+            self.add_row(&table, None, None, Some(value.id() as i64), json);
+        }
+    }
+
+    /// One row per weapon, armor and etc item, so item ids have a single table to point to
+    fn add_items(&mut self, holder: &GameDataHolder) {
+        let mut items = Table::default();
+
+        items.columns.insert("kind".to_string(), Some("TEXT"));
+        items.columns.insert("name".to_string(), Some("TEXT"));
+
+        let all = holder
+            .weapon_holder
+            .values()
+            .map(|v| (GameEntity::Weapon, v.base_info.id, v.name(), v.deleted()))
+            .chain(
+                holder
+                    .armor_holder
+                    .values()
+                    .map(|v| (GameEntity::Armor, v.base_info.id, v.name(), v.deleted())),
+            )
+            .chain(
+                holder
+                    .etc_item_holder
+                    .values()
+                    .map(|v| (GameEntity::EtcItem, v.base_info.id, v.name(), v.deleted())),
+            );
+
+        for (kind, id, name, deleted) in all {
+            if deleted {
+                continue;
+            }
+
+            items.rows.push(Row {
+                key: id.0 as i64,
+                parent: None,
+                index: None,
+                values: [
+                    ("kind".to_string(), SqlValue::Text(kind.to_string())),
+                    ("name".to_string(), SqlValue::Text(name)),
+                ]
+                .into_iter()
+                .collect(),
+            });
+        }
+
+        items.rows.sort_by_key(|v| v.key);
+
+        self.tables.insert(ITEM_TABLE.to_string(), items);
+    }
+
+    /// `None` of an optional struct or vector leaves a column of nulls next to the columns or
+    /// the child table made of `Some` values
+    fn remove_empty_columns(&mut self) {
+        let names: Vec<_> = self.tables.keys().cloned().collect();
+
+        for (name, table) in &mut self.tables {
+            let columns: Vec<_> = table.columns.keys().cloned().collect();
+
+            table.columns.retain(|column, kind| {
+                let prefix = format!("{column}{SEPARATOR}");
+
+                kind.is_some()
+                    || !(names.contains(&format!("{name}{SEPARATOR}{column}"))
+                        || columns.iter().any(|v| v.starts_with(&prefix)))
+            });
+        }
+    }
+
+    fn create_statement(&self, name: &str, table: &Table) -> String {
+        let mut columns = vec![format!("{} INTEGER PRIMARY KEY", quote(table.key_column()))];
+
+        if let Some(parent) = &table.parent {
+            columns.push(format!(
+                "\"parent_id\" INTEGER NOT NULL REFERENCES {}({})",
+                quote(parent),
+                quote(self.tables[parent].key_column())
+            ));
+            columns.push("\"idx\" INTEGER NOT NULL".to_string());
+        }
+
+        for (column, kind) in &table.columns {
+            let mut v = quote(column);
+
+            if let Some(kind) = kind {
+                v.push(' ');
+                v.push_str(kind);
+            }
+
+            if let Some((_, _, target)) = FOREIGN_KEYS
+                .iter()
+                .find(|v| v.0 == name && v.1 == column)
+                .filter(|v| self.tables.contains_key(v.2))
+            {
+                v.push_str(&format!(
+                    " REFERENCES {}({})",
+                    quote(target),
+                    quote(self.tables[*target].key_column())
+                ));
+            }
+
+            columns.push(v);
+        }
+
+        format!("CREATE TABLE {} ({})", quote(name), columns.join(", "))
+    }
+
+    fn write(&self, path: &Path) -> anyhow::Result<usize> {
+        if path.exists() {
+            std::fs::remove_file(path)?;
+        }
+
+        self.write_to(&mut Connection::open(path)?)
+    }
+
+    fn write_to(&self, connection: &mut Connection) -> anyhow::Result<usize> {
+        // Broken references are still written
+        connection.execute("PRAGMA foreign_keys = OFF", [])?;
+
+        let transaction = connection.transaction()?;
+
+        for (name, table) in &self.tables {
+            transaction.execute(&self.create_statement(name, table), [])?;
+        }
+
+        for (name, table) in &self.tables {
+            let mut columns = vec![quote(table.key_column())];
+
+            if table.parent.is_some() {
+                columns.push("\"parent_id\"".to_string());
+                columns.push("\"idx\"".to_string());
+            }
+
+            columns.extend(table.columns.keys().map(|v| quote(v)));
+
+            let mut statement = transaction.prepare(&format!(
+                "INSERT INTO {} ({}) VALUES ({})",
+                quote(name),
+                columns.join(", "),
+                vec!["?"; columns.len()].join(", ")
+            ))?;
+
+            for row in &table.rows {
+                let mut values = vec![SqlValue::Integer(row.key)];
+
+                if let (Some(parent), Some(index)) = (row.parent, row.index) {
+                    values.push(SqlValue::Integer(parent));
+                    values.push(SqlValue::Integer(index as i64));
+                }
+
+                values.extend(
+                    table
+                        .columns
+                        .keys()
+                        .map(|v| row.values.get(v).cloned().unwrap_or(SqlValue::Null)),
+                );
+
+                statement.execute(rusqlite::params_from_iter(values))?;
+            }
+        }
+
+        transaction.commit()?;
+
+        Ok(self.tables.len())
+    }
+}
+
+impl GameDataHolder {
+    /// Writes every entity and dictionary to a new SQLite database at `path`. Returns amount of
+    /// created tables
+    pub fn write_sqlite(&self, path: &Path) -> anyhow::Result<usize> {
+        self.sqlite_schema().write(path)
+    }
+
+    fn sqlite_schema(&self) -> Schema {
+        let mut schema = Schema::default();
+
+        for e in GameEntity::iter() {
+            schema.add_entity(self, e.into());
+        }
+
+        for e in Dictionary::iter() {
+            schema.add_entity(self, e.into());
+        }
+
+        schema.add_items(self);
+        schema.remove_empty_columns();
+
+        schema
+    }
+}
+
+impl Backend {
+    pub fn export_sqlite(&self, path: &Path) {
+        logs_mut().add(match self.holders.game_data_holder.write_sqlite(path) {
+            Ok(count) => Log {
+                level: LogLevel::Info,
+                producer: "Sqlite".to_string(),
+                log: format!("{count} table(s) exported to {path:?}"),
+            },
+            Err(e) => Log {
+                level: LogLevel::Error,
+                producer: "Sqlite".to_string(),
+                log: format!("Export to {path:?} failed: {e}"),
+            },
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::backend::holder::{GameDataHolder, HolderMapOps};
+    use crate::common::{ItemId, ItemSetId, RecipeId};
+    use crate::entity::item::etc_item::EtcItem;
+    use crate::entity::item_set::ItemSet;
+    use crate::entity::recipe::{Recipe, RecipeMaterial};
+    use rusqlite::Connection;
+
+    #[test]
+    fn test_write_sqlite() {
+        let mut holder = GameDataHolder::default();
+
+        holder.item_set_holder.insert(
+            ItemSetId(1),
+            ItemSet {
+                id: ItemSetId(1),
+                base_items: vec![vec![ItemId(2), ItemId(3)]],
+                ..Default::default()
+            },
+        );
+
+        for id in [2, 3] {
+            let mut item = EtcItem::default();
+            item.base_info.id = ItemId(id);
+            item.base_info.set_id = ItemSetId(1);
+
+            holder.etc_item_holder.insert(ItemId(id), item);
+        }
+
+        holder.recipe_holder.insert(
+            RecipeId(1),
+            Recipe {
+                id: RecipeId(1),
+                recipe_item: ItemId(2),
+                product: ItemId(3),
+                materials: vec![
+                    RecipeMaterial {
+                        id: ItemId(2),
+                        ..Default::default()
+                    },
+                    RecipeMaterial {
+                        id: ItemId(99),
+                        ..Default::default()
+                    },
+                ],
+                ..Default::default()
+            },
+        );
+
+        let mut connection = Connection::open_in_memory().unwrap();
+        holder.sqlite_schema().write_to(&mut connection).unwrap();
+
+        let broken: Vec<(String, String)> = connection
+            .prepare("PRAGMA foreign_key_check")
+            .unwrap()
+            .query_map([], |v| Ok((v.get(0)?, v.get(2)?)))
+            .unwrap()
+            .map(|v| v.unwrap())
+            .collect();
+
+        assert_eq!(
+            broken,
+            [("recipe__materials".to_string(), "item".to_string())]
+        );
+
+        let count = |table: &str| -> i64 {
+            connection
+                .query_row(&format!("SELECT COUNT(*) FROM \"{table}\""), [], |v| {
+                    v.get(0)
+                })
+                .unwrap()
+        };
+
+        assert_eq!(count("item"), 2);
+        assert_eq!(count("etc_item"), 2);
+        assert_eq!(count("item_set"), 1);
+        assert_eq!(count("item_set__base_items"), 1);
+        assert_eq!(count("item_set__base_items__value"), 2);
+        assert_eq!(count("recipe"), 1);
+        assert_eq!(count("recipe__materials"), 2);
+        assert_eq!(count("weapon"), 0);
+        assert_eq!(count("npc"), 0);
+    }
+}
@@ -1,55 +1,139 @@
 use crate::backend::Localization;
+use serde::de::{MapAccess, Visitor};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::BTreeMap;
 use std::fmt::{Display, Formatter};
+use std::marker::PhantomData;
 use std::ops::{Index, IndexMut};
 use std::str::FromStr;
 use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
 use strum::IntoEnumIterator;
 
-#[derive(Clone, Serialize, Deserialize, PartialEq, Debug)]
+/// Value per locale code. Locales without a value read as `T::default()`
+#[derive(Clone, Debug)]
 pub struct Localized<T: Clone + Serialize + PartialEq + Sized + Default> {
-    pub ru: T,
-    pub eu: T,
+    values: BTreeMap<Localization, T>,
+    missing: T,
+}
+
+impl<T: Clone + Serialize + PartialEq + Sized + Default> Localized<T> {
+    pub fn from_fn(f: impl FnMut(Localization) -> T) -> Self {
+        Self::from_fn_in(Localization::iter(), f)
+    }
+
+    /// Same as [`Localized::from_fn`] for the given locales instead of the known ones
+    pub fn from_fn_in(
+        localizations: impl IntoIterator<Item = Localization>,
+        mut f: impl FnMut(Localization) -> T,
+    ) -> Self {
+        localizations.into_iter().map(|l| (l, f(l))).collect()
+    }
+
+    /// Value for the [`Localization::main`] locale
+    pub fn main(&self) -> &T {
+        &self[Localization::main()]
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Localization, &T)> {
+        self.values.iter().map(|(l, v)| (*l, v))
+    }
 }
 
+/// First value goes to the main locale, second one to every other known locale
 impl<T: Clone + Serialize + PartialEq + Sized + Default> From<(T, T)> for Localized<T> {
     fn from(value: (T, T)) -> Self {
+        Self::from_fn(|l| {
+            if l.is_main() {
+                value.0.clone()
+            } else {
+                value.1.clone()
+            }
+        })
+    }
+}
+
+impl<T: Clone + Serialize + PartialEq + Sized + Default> FromIterator<(Localization, T)>
+    for Localized<T>
+{
+    fn from_iter<I: IntoIterator<Item = (Localization, T)>>(iter: I) -> Self {
         Self {
-            ru: value.0,
-            eu: value.1,
+            values: iter.into_iter().collect(),
+            missing: T::default(),
         }
     }
 }
 
-impl Localized<StringCow> {
-    pub fn lowered_contains(&self, s: &str) -> bool {
-        for locale in Localization::iter() {
-            if self[locale].as_str().to_lowercase().contains(s) {
-                return true;
-            }
+impl<T: Clone + Serialize + PartialEq + Sized + Default> PartialEq for Localized<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.values
+            .keys()
+            .chain(other.values.keys())
+            .all(|l| self[*l] == other[*l])
+    }
+}
+
+impl<T: Clone + Serialize + PartialEq + Sized + Default> Serialize for Localized<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.values.serialize(serializer)
+    }
+}
+
+/// Accepts `{"ru": .., "eu": ..}` map as well as `(ru: .., eu: ..)` struct written by older versions
+struct LocalizedVisitor<T>(PhantomData<T>);
+
+impl<'de, T: Clone + Serialize + Deserialize<'de> + PartialEq + Sized + Default> Visitor<'de>
+    for LocalizedVisitor<T>
+{
+    type Value = Localized<T>;
+
+    fn expecting(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "map of locale code to value")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut res = Localized::default();
+
+        while let Some((l, v)) = map.next_entry::<Localization, T>()? {
+            res.values.insert(l, v);
         }
 
-        false
+        Ok(res)
     }
 }
 
-impl Localized<String> {
-    pub fn lowered_contains(&self, s: &str) -> bool {
-        for locale in Localization::iter() {
-            if self[locale].as_str().to_lowercase().contains(s) {
-                return true;
-            }
+impl<'de, T: Clone + Serialize + Deserialize<'de> + PartialEq + Sized + Default> Deserialize<'de>
+    for Localized<T>
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Only self describing formats can tell old struct from map, binary ones are always maps
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(LocalizedVisitor(PhantomData))
+        } else {
+            deserializer.deserialize_map(LocalizedVisitor(PhantomData))
         }
+    }
+}
+
+impl Localized<StringCow> {
+    pub fn lowered_contains(&self, s: &str) -> bool {
+        self.values
+            .values()
+            .any(|v| v.as_str().to_lowercase().contains(s))
+    }
+}
 
-        false
+impl Localized<String> {
+    pub fn lowered_contains(&self, s: &str) -> bool {
+        self.values.values().any(|v| v.to_lowercase().contains(s))
     }
 }
 
 impl<T: Default + Clone + Serialize + PartialEq> Default for Localized<T> {
     fn default() -> Self {
         Self {
-            ru: T::default(),
-            eu: T::default(),
+            values: BTreeMap::new(),
+            missing: T::default(),
         }
     }
 }
@@ -58,19 +142,13 @@ impl<T: Clone + Serialize + PartialEq + Sized + Default> Index<Localization> for
     type Output = T;
 
     fn index(&self, index: Localization) -> &Self::Output {
-        match index {
-            Localization::RU => &self.ru,
-            Localization::EU => &self.eu,
-        }
+        self.values.get(&index).unwrap_or(&self.missing)
     }
 }
 
 impl<T: Clone + Serialize + PartialEq + Sized + Default> IndexMut<Localization> for Localized<T> {
     fn index_mut(&mut self, index: Localization) -> &mut Self::Output {
-        match index {
-            Localization::RU => &mut self.ru,
-            Localization::EU => &mut self.eu,
-        }
+        self.values.entry(index).or_default()
     }
 }
 
@@ -208,3 +286,67 @@ pub fn is_in_range(range_str: &str, val: u32) -> bool {
         _ => false,
     }
 }
+
+/// `YYYY-MM-DD_HH-MM-SS` in UTC, safe to be used as a file name
+pub fn timestamp_name(time: SystemTime) -> String {
+    let secs = time.duration_since(UNIX_EPOCH).map_or(0, |v| v.as_secs());
+
+    let days = (secs / 86_400) as i64;
+    let rem = secs % 86_400;
+
+    //Days to civil date, proleptic Gregorian calendar
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02}_{:02}-{:02}-{:02}",
+        rem / 3_600,
+        rem % 3_600 / 60,
+        rem % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Localized;
+    use crate::backend::Localization;
+
+    fn sample() -> Localized<String> {
+        [
+            (Localization::new("ru"), "Меч".to_string()),
+            (Localization::new("eu"), "Sword".to_string()),
+        ]
+        .into_iter()
+        .collect()
+    }
+
+    #[test]
+    fn test_localized_round_trip() {
+        let v = sample();
+
+        let ron = ron::to_string(&v).unwrap();
+        assert_eq!(v, ron::from_str::<Localized<String>>(&ron).unwrap());
+
+        let json = serde_json::to_string(&v).unwrap();
+        assert_eq!(v, serde_json::from_str::<Localized<String>>(&json).unwrap());
+
+        let bin = bincode::serialize(&v).unwrap();
+        assert_eq!(v, bincode::deserialize::<Localized<String>>(&bin).unwrap());
+    }
+
+    #[test]
+    fn test_localized_old_format() {
+        let v: Localized<String> = ron::from_str(r#"(ru: "Меч", eu: "Sword")"#).unwrap();
+        assert_eq!(sample(), v);
+
+        let v: Localized<String> = serde_json::from_str(r#"{"ru": "Меч", "eu": "Sword"}"#).unwrap();
+        assert_eq!(sample(), v);
+    }
+}
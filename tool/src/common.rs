@@ -501,6 +501,119 @@ impl From<u32> for EnsoulOptionId {
     }
 }
 
+#[derive(
+    Serialize,
+    Deserialize,
+    Copy,
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    Hash,
+    Default,
+    PartialOrd,
+    Ord,
+    CustomType,
+)]
+#[rhai_type(extra = Self::build_extra)]
+pub struct HennaId(#[rhai_type(name = "val")] pub u32);
+
+impl From<HennaId> for u32 {
+    fn from(val: HennaId) -> Self {
+        val.0
+    }
+}
+
+impl From<u32> for HennaId {
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+
+#[derive(
+    Serialize,
+    Deserialize,
+    Copy,
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    Hash,
+    Default,
+    PartialOrd,
+    Ord,
+    CustomType,
+)]
+pub struct UserActionId(pub u32);
+
+impl From<UserActionId> for u32 {
+    fn from(val: UserActionId) -> Self {
+        val.0
+    }
+}
+
+impl From<u32> for UserActionId {
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+
+#[derive(
+    Serialize,
+    Deserialize,
+    Copy,
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    Hash,
+    Default,
+    PartialOrd,
+    Ord,
+    CustomType,
+)]
+pub struct ProductId(pub u32);
+
+impl From<ProductId> for u32 {
+    fn from(val: ProductId) -> Self {
+        val.0
+    }
+}
+
+impl From<u32> for ProductId {
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+
+#[derive(
+    Serialize,
+    Deserialize,
+    Copy,
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    Hash,
+    Default,
+    PartialOrd,
+    Ord,
+    CustomType,
+)]
+pub struct ClassId(pub u32);
+
+impl From<ClassId> for u32 {
+    fn from(val: ClassId) -> Self {
+        val.0
+    }
+}
+
+impl From<u32> for ClassId {
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+
 #[derive(
     Serialize,
     Deserialize,
@@ -0,0 +1,56 @@
+use crate::backend::util::Localized;
+use crate::common::ClassId;
+use crate::entity::{CommonEntity, GetEditParams};
+use serde::{Deserialize, Serialize};
+
+impl GetEditParams<()> for ClassInfo {
+    fn edit_params(&self) {}
+}
+
+impl CommonEntity<ClassId> for ClassInfo {
+    fn name(&self) -> String {
+        self.name.main().clone()
+    }
+
+    fn desc(&self) -> String {
+        self.name
+            .iter()
+            .find(|(l, _)| !l.is_main())
+            .map_or(String::new(), |(_, v)| v.clone())
+    }
+
+    fn id(&self) -> ClassId {
+        self.id
+    }
+
+    fn changed(&self) -> bool {
+        self._changed
+    }
+
+    fn deleted(&self) -> bool {
+        self._deleted
+    }
+
+    fn new(id: ClassId) -> Self {
+        ClassInfo {
+            id,
+            name: ("Новый Класс".to_string(), "New Class".to_string()).into(),
+            parent: None,
+
+            _changed: false,
+            _deleted: false,
+        }
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, Default, PartialEq)]
+pub struct ClassInfo {
+    pub id: ClassId,
+    pub name: Localized<String>,
+    pub parent: Option<ClassId>,
+
+    #[serde(skip)]
+    pub _changed: bool,
+    #[serde(skip)]
+    pub _deleted: bool,
+}
@@ -1,5 +1,5 @@
 use crate::backend::util::Localized;
-use crate::common::{DailyMissionId, ItemId, PlayerClass};
+use crate::common::{ClassId, DailyMissionId, ItemId};
 use crate::entity::{CommonEntity, GetEditParams};
 use num_derive::{FromPrimitive, ToPrimitive};
 use serde::{Deserialize, Serialize};
@@ -11,11 +11,11 @@ impl GetEditParams<()> for DailyMission {
 
 impl CommonEntity<DailyMissionId> for DailyMission {
     fn name(&self) -> String {
-        self.name.ru.clone()
+        self.name.main().clone()
     }
 
     fn desc(&self) -> String {
-        self.desc.ru.clone()
+        self.desc.main().clone()
     }
 
     fn id(&self) -> DailyMissionId {
@@ -106,7 +106,7 @@ pub struct DailyMission {
     pub category: Localized<String>,
     pub category_type: u32,
 
-    pub allowed_classes: Option<Vec<PlayerClass>>,
+    pub allowed_classes: Option<Vec<ClassId>>,
 
     pub repeat_type: DailyMissionRepeatType,
     pub unk2: u32,
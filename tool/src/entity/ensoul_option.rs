@@ -10,11 +10,11 @@ impl GetEditParams<()> for EnsoulOption {
 
 impl CommonEntity<EnsoulOptionId> for EnsoulOption {
     fn name(&self) -> String {
-        self.name.ru.clone()
+        self.name.main().clone()
     }
 
     fn desc(&self) -> String {
-        self.desc.ru.clone()
+        self.desc.main().clone()
     }
 
     fn id(&self) -> EnsoulOptionId {
@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Serialize, Deserialize, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct GameTip {
+    pub text: String,
+    pub min_lvl: u32,
+    pub max_lvl: u32,
+    pub condition: u32,
+}
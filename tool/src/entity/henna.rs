@@ -0,0 +1,74 @@
+use crate::backend::util::{Localized, StringCow};
+use crate::common::{ClassId, HennaId, ItemId};
+use crate::entity::{CommonEntity, GetEditParams};
+use rhai::CustomType;
+use serde::{Deserialize, Serialize};
+
+impl GetEditParams<()> for Henna {
+    fn edit_params(&self) {}
+}
+
+impl CommonEntity<HennaId> for Henna {
+    fn name(&self) -> String {
+        self.name.main().clone()
+    }
+
+    fn desc(&self) -> String {
+        self.desc.main().clone()
+    }
+
+    fn id(&self) -> HennaId {
+        self.id
+    }
+
+    fn changed(&self) -> bool {
+        self._changed
+    }
+
+    fn deleted(&self) -> bool {
+        self._deleted
+    }
+
+    fn new(id: HennaId) -> Self {
+        Henna {
+            id,
+            name: ("Новая Краска".to_string(), "New Dye".to_string()).into(),
+            icon: "".into(),
+            icon_panel: "".into(),
+
+            _changed: false,
+            _deleted: false,
+
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, Default, PartialEq, Eq, CustomType)]
+pub struct HennaStats {
+    pub str: i32,
+    pub dex: i32,
+    pub con: i32,
+    pub int: i32,
+    pub men: i32,
+    pub wit: i32,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, Default, PartialEq, CustomType)]
+#[rhai_type(extra = Self::build_extra)]
+pub struct Henna {
+    pub id: HennaId,
+    pub dye_item_id: ItemId,
+    pub name: Localized<String>,
+    pub additional_name: Localized<String>,
+    pub desc: Localized<String>,
+    pub icon: StringCow,
+    pub icon_panel: StringCow,
+    pub stats: HennaStats,
+    pub allowed_classes: Option<Vec<ClassId>>,
+
+    #[serde(skip)]
+    pub _changed: bool,
+    #[serde(skip)]
+    pub _deleted: bool,
+}
@@ -13,11 +13,11 @@ impl GetEditParams<()> for HuntingZone {
 
 impl CommonEntity<HuntingZoneId> for HuntingZone {
     fn name(&self) -> String {
-        self.name.clone().ru
+        self.name.main().clone()
     }
 
     fn desc(&self) -> String {
-        self.desc.clone().ru
+        self.desc.main().clone()
     }
 
     fn id(&self) -> HuntingZoneId {
@@ -16,11 +16,11 @@ impl GetEditParams<()> for Armor {
 
 impl CommonEntity<ItemId> for Armor {
     fn name(&self) -> String {
-        self.base_info.name.ru.to_string()
+        self.base_info.name.main().to_string()
     }
 
     fn desc(&self) -> String {
-        self.base_info.desc.ru.clone()
+        self.base_info.desc.main().clone()
     }
 
     fn id(&self) -> ItemId {
@@ -39,8 +39,7 @@ impl CommonEntity<ItemId> for Armor {
         let mut s = Self::default();
 
         s.base_info.id = id;
-        s.base_info.name.ru = "Новый Armor".into();
-        s.base_info.name.eu = "New Armor".into();
+        s.base_info.name = ("Новый Armor".into(), "New Armor".into()).into();
 
         s
     }
@@ -14,11 +14,11 @@ impl GetEditParams<()> for EtcItem {
 
 impl CommonEntity<ItemId> for EtcItem {
     fn name(&self) -> String {
-        self.base_info.name.ru.to_string()
+        self.base_info.name.main().to_string()
     }
 
     fn desc(&self) -> String {
-        self.base_info.desc.ru.clone()
+        self.base_info.desc.main().clone()
     }
 
     fn id(&self) -> ItemId {
@@ -37,8 +37,7 @@ impl CommonEntity<ItemId> for EtcItem {
         let mut s = Self::default();
 
         s.base_info.id = id;
-        s.base_info.name.ru = "Новый EtcItem".into();
-        s.base_info.name.eu = "New EtcItem".into();
+        s.base_info.name = ("Новый EtcItem".into(), "New EtcItem".into()).into();
 
         s
     }
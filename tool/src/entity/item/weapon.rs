@@ -18,11 +18,11 @@ impl GetEditParams<()> for Weapon {
 
 impl CommonEntity<ItemId> for Weapon {
     fn name(&self) -> String {
-        self.base_info.name.ru.to_string()
+        self.base_info.name.main().to_string()
     }
 
     fn desc(&self) -> String {
-        self.base_info.desc.ru.clone()
+        self.base_info.desc.main().clone()
     }
 
     fn id(&self) -> ItemId {
@@ -41,8 +41,7 @@ impl CommonEntity<ItemId> for Weapon {
         let mut s = Self::default();
 
         s.base_info.id = id;
-        s.base_info.name.ru = "Новый Weapon".into();
-        s.base_info.name.eu = "New Weapon".into();
+        s.base_info.name = ("Новый Weapon".into(), "New Weapon".into()).into();
 
         s
     }
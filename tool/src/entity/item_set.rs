@@ -18,7 +18,7 @@ impl CommonEntity<ItemSetId> for ItemSet {
             .iter()
             .enumerate()
             .fold(String::new(), |mut res, v| {
-                let _ = writeln!(res, "{}: {}", v.0 + 1, v.1.ru);
+                let _ = writeln!(res, "{}: {}", v.0 + 1, v.1.main());
 
                 res
             })
@@ -1,29 +1,37 @@
 use crate::backend::holder::DictItem;
 use crate::common::{
-    AnimationComboId, DailyMissionId, EnsoulOptionId, HuntingZoneId, ItemId, ItemSetId, NpcId,
-    QuestId, RaidInfoId, RecipeId, RegionId, ResidenceId, SkillId,
+    AnimationComboId, ClassId, DailyMissionId, EnsoulOptionId, HennaId, HuntingZoneId, ItemId,
+    ItemSetId, NpcId, ProductId, QuestId, RaidInfoId, RecipeId, RegionId, ResidenceId, SkillId,
+    UserActionId,
 };
+use serde::{Deserialize, Serialize};
 use std::fmt::{Debug, Display, Formatter};
 use strum_macros::{Display, EnumIter};
 
 pub mod animation_combo;
+pub mod class_info;
 pub mod daily_mission;
 pub mod ensoul_option;
+pub mod game_tip;
+pub mod henna;
 pub mod hunting_zone;
 pub mod item;
 pub mod item_set;
 pub mod npc;
+pub mod product;
 pub mod quest;
 pub mod raid_info;
 pub mod recipe;
 pub mod region;
 pub mod residence;
 pub mod skill;
+pub mod user_action;
 
 #[derive(Display, Debug, EnumIter, Eq, PartialEq, Copy, Clone)]
 pub enum Dictionary {
     SystemStrings,
     NpcStrings,
+    GameTips,
 }
 
 #[derive(Eq, PartialEq, Copy, Clone)]
@@ -62,7 +70,7 @@ impl Debug for Entity {
     }
 }
 
-#[derive(Display, Debug, EnumIter, Eq, PartialEq, Copy, Clone)]
+#[derive(Display, Debug, EnumIter, Eq, PartialEq, Hash, Copy, Clone, Serialize, Deserialize)]
 pub enum GameEntity {
     Npc,
     Quest,
@@ -79,9 +87,13 @@ pub enum GameEntity {
     AnimationCombo,
     Residence,
     EnsoulOption,
+    Henna,
+    UserAction,
+    Product,
+    ClassInfo,
 }
 
-#[derive(Display, Debug, EnumIter, Eq, PartialEq, Copy, Clone)]
+#[derive(Display, Debug, EnumIter, Eq, PartialEq, Hash, Copy, Clone)]
 pub enum GameEntityT {
     Quest(QuestId),
     Skill(SkillId),
@@ -98,6 +110,110 @@ pub enum GameEntityT {
     AnimationCombo(AnimationComboId),
     Residence(ResidenceId),
     EnsoulOption(EnsoulOptionId),
+    Henna(HennaId),
+    UserAction(UserActionId),
+    Product(ProductId),
+    ClassInfo(ClassId),
+}
+
+impl GameEntityT {
+    pub fn raw_id(&self) -> u32 {
+        match *self {
+            GameEntityT::Quest(id) => id.into(),
+            GameEntityT::Skill(id) => id.into(),
+            GameEntityT::Npc(id) => id.into(),
+            GameEntityT::Weapon(id) => id.into(),
+            GameEntityT::Armor(id) => id.into(),
+            GameEntityT::EtcItem(id) => id.into(),
+            GameEntityT::ItemSet(id) => id.into(),
+            GameEntityT::Recipe(id) => id.into(),
+            GameEntityT::HuntingZone(id) => id.into(),
+            GameEntityT::Region(id) => id.into(),
+            GameEntityT::RaidInfo(id) => id.into(),
+            GameEntityT::DailyMission(id) => id.into(),
+            GameEntityT::AnimationCombo(id) => id.into(),
+            GameEntityT::Residence(id) => id.into(),
+            GameEntityT::EnsoulOption(id) => id.into(),
+            GameEntityT::Henna(id) => id.into(),
+            GameEntityT::UserAction(id) => id.into(),
+            GameEntityT::Product(id) => id.into(),
+            GameEntityT::ClassInfo(id) => id.into(),
+        }
+    }
+    pub fn from_raw(entity: GameEntity, id: u32) -> GameEntityT {
+        match entity {
+            GameEntity::Quest => GameEntityT::Quest(id.into()),
+            GameEntity::Skill => GameEntityT::Skill(id.into()),
+            GameEntity::Npc => GameEntityT::Npc(id.into()),
+            GameEntity::Weapon => GameEntityT::Weapon(id.into()),
+            GameEntity::Armor => GameEntityT::Armor(id.into()),
+            GameEntity::EtcItem => GameEntityT::EtcItem(id.into()),
+            GameEntity::ItemSet => GameEntityT::ItemSet(id.into()),
+            GameEntity::Recipe => GameEntityT::Recipe(id.into()),
+            GameEntity::HuntingZone => GameEntityT::HuntingZone(id.into()),
+            GameEntity::Region => GameEntityT::Region(id.into()),
+            GameEntity::RaidInfo => GameEntityT::RaidInfo(id.into()),
+            GameEntity::DailyMission => GameEntityT::DailyMission(id.into()),
+            GameEntity::AnimationCombo => GameEntityT::AnimationCombo(id.into()),
+            GameEntity::Residence => GameEntityT::Residence(id.into()),
+            GameEntity::EnsoulOption => GameEntityT::EnsoulOption(id.into()),
+            GameEntity::Henna => GameEntityT::Henna(id.into()),
+            GameEntity::UserAction => GameEntityT::UserAction(id.into()),
+            GameEntity::Product => GameEntityT::Product(id.into()),
+            GameEntity::ClassInfo => GameEntityT::ClassInfo(id.into()),
+        }
+    }
+
+    /// Same entity type with another id
+    pub fn with_id(&self, id: u32) -> GameEntityT {
+        match *self {
+            GameEntityT::Quest(_) => GameEntityT::Quest(id.into()),
+            GameEntityT::Skill(_) => GameEntityT::Skill(id.into()),
+            GameEntityT::Npc(_) => GameEntityT::Npc(id.into()),
+            GameEntityT::Weapon(_) => GameEntityT::Weapon(id.into()),
+            GameEntityT::Armor(_) => GameEntityT::Armor(id.into()),
+            GameEntityT::EtcItem(_) => GameEntityT::EtcItem(id.into()),
+            GameEntityT::ItemSet(_) => GameEntityT::ItemSet(id.into()),
+            GameEntityT::Recipe(_) => GameEntityT::Recipe(id.into()),
+            GameEntityT::HuntingZone(_) => GameEntityT::HuntingZone(id.into()),
+            GameEntityT::Region(_) => GameEntityT::Region(id.into()),
+            GameEntityT::RaidInfo(_) => GameEntityT::RaidInfo(id.into()),
+            GameEntityT::DailyMission(_) => GameEntityT::DailyMission(id.into()),
+            GameEntityT::AnimationCombo(_) => GameEntityT::AnimationCombo(id.into()),
+            GameEntityT::Residence(_) => GameEntityT::Residence(id.into()),
+            GameEntityT::EnsoulOption(_) => GameEntityT::EnsoulOption(id.into()),
+            GameEntityT::Henna(_) => GameEntityT::Henna(id.into()),
+            GameEntityT::UserAction(_) => GameEntityT::UserAction(id.into()),
+            GameEntityT::Product(_) => GameEntityT::Product(id.into()),
+            GameEntityT::ClassInfo(_) => GameEntityT::ClassInfo(id.into()),
+        }
+    }
+}
+
+impl From<GameEntityT> for GameEntity {
+    fn from(value: GameEntityT) -> Self {
+        match value {
+            GameEntityT::Quest(_) => GameEntity::Quest,
+            GameEntityT::Skill(_) => GameEntity::Skill,
+            GameEntityT::Npc(_) => GameEntity::Npc,
+            GameEntityT::Weapon(_) => GameEntity::Weapon,
+            GameEntityT::Armor(_) => GameEntity::Armor,
+            GameEntityT::EtcItem(_) => GameEntity::EtcItem,
+            GameEntityT::ItemSet(_) => GameEntity::ItemSet,
+            GameEntityT::Recipe(_) => GameEntity::Recipe,
+            GameEntityT::HuntingZone(_) => GameEntity::HuntingZone,
+            GameEntityT::Region(_) => GameEntity::Region,
+            GameEntityT::RaidInfo(_) => GameEntity::RaidInfo,
+            GameEntityT::DailyMission(_) => GameEntity::DailyMission,
+            GameEntityT::AnimationCombo(_) => GameEntity::AnimationCombo,
+            GameEntityT::Residence(_) => GameEntity::Residence,
+            GameEntityT::EnsoulOption(_) => GameEntity::EnsoulOption,
+            GameEntityT::Henna(_) => GameEntity::Henna,
+            GameEntityT::UserAction(_) => GameEntity::UserAction,
+            GameEntityT::Product(_) => GameEntity::Product,
+            GameEntityT::ClassInfo(_) => GameEntity::ClassInfo,
+        }
+    }
 }
 
 pub trait GetEditParams<EditParams> {
@@ -139,7 +255,7 @@ impl CommonEntity<u32> for String {
     }
 }
 
-impl CommonEntity<u32> for DictItem<u32, String> {
+impl<T: Clone + Ord + Eq + Serialize + Default> CommonEntity<u32> for DictItem<u32, T> {
     fn name(&self) -> String {
         unreachable!()
     }
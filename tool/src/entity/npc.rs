@@ -15,7 +15,7 @@ impl GetEditParams<()> for Npc {
 
 impl CommonEntity<NpcId> for Npc {
     fn name(&self) -> String {
-        self.name.ru.clone()
+        self.name.main().clone()
     }
 
     fn desc(&self) -> String {
@@ -0,0 +1,66 @@
+use crate::backend::util::Localized;
+use crate::common::{ItemId, ProductId};
+use crate::entity::{CommonEntity, GetEditParams};
+use serde::{Deserialize, Serialize};
+
+impl GetEditParams<()> for Product {
+    fn edit_params(&self) {}
+}
+
+impl CommonEntity<ProductId> for Product {
+    fn name(&self) -> String {
+        self.name.main().clone()
+    }
+
+    fn desc(&self) -> String {
+        "".to_string()
+    }
+
+    fn id(&self) -> ProductId {
+        self.id
+    }
+
+    fn changed(&self) -> bool {
+        self._changed
+    }
+
+    fn deleted(&self) -> bool {
+        self._deleted
+    }
+
+    fn new(id: ProductId) -> Self {
+        Product {
+            id,
+            name: ("Новый Товар".to_string(), "New Product".to_string()).into(),
+            icon: "".to_string(),
+            category: 0,
+            items: vec![ProductItem {
+                item_id: ItemId(57),
+                count: 1,
+            }],
+
+            _changed: false,
+            _deleted: false,
+        }
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, Default, PartialEq)]
+pub struct ProductItem {
+    pub(crate) item_id: ItemId,
+    pub(crate) count: u32,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, Default, PartialEq)]
+pub struct Product {
+    pub(crate) id: ProductId,
+    pub(crate) name: Localized<String>,
+    pub(crate) icon: String,
+    pub(crate) category: u32,
+    pub(crate) items: Vec<ProductItem>,
+
+    #[serde(skip)]
+    pub _changed: bool,
+    #[serde(skip)]
+    pub _deleted: bool,
+}
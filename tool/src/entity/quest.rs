@@ -1,6 +1,6 @@
 use crate::backend::editor::WindowParams;
 use crate::backend::util::Localized;
-use crate::common::{HuntingZoneId, ItemId, Location, NpcId, PlayerClass, QuestId};
+use crate::common::{ClassId, HuntingZoneId, ItemId, Location, NpcId, QuestId};
 use crate::entity::CommonEntity;
 use eframe::egui::Pos2;
 use num_derive::{FromPrimitive, ToPrimitive};
@@ -9,11 +9,11 @@ use strum_macros::{Display, EnumIter};
 
 impl CommonEntity<QuestId> for Quest {
     fn name(&self) -> String {
-        self.title.ru.clone()
+        self.title.main().clone()
     }
 
     fn desc(&self) -> String {
-        self.intro.ru.clone()
+        self.intro.main().clone()
     }
 
     fn id(&self) -> QuestId {
@@ -198,7 +198,7 @@ pub struct Quest {
     pub mark_type: MarkType,
     pub min_lvl: u32,
     pub max_lvl: u32,
-    pub allowed_classes: Option<Vec<PlayerClass>>,
+    pub allowed_classes: Option<Vec<ClassId>>,
     pub required_completed_quest_id: QuestId,
     pub search_zone_id: HuntingZoneId,
     pub _is_clan_pet_quest: bool,
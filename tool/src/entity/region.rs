@@ -11,7 +11,7 @@ impl GetEditParams<()> for Region {
 
 impl CommonEntity<RegionId> for Region {
     fn name(&self) -> String {
-        self.name.ru.clone()
+        self.name.main().clone()
     }
 
     fn desc(&self) -> String {
@@ -9,11 +9,11 @@ impl GetEditParams<()> for Residence {
 
 impl CommonEntity<ResidenceId> for Residence {
     fn name(&self) -> String {
-        self.name.ru.clone()
+        self.name.main().clone()
     }
 
     fn desc(&self) -> String {
-        self.desc.ru.clone()
+        self.desc.main().clone()
     }
 
     fn id(&self) -> ResidenceId {
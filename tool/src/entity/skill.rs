@@ -23,11 +23,11 @@ impl GetEditParams<SkillEditWindowParams> for Skill {
 
 impl CommonEntity<SkillId> for Skill {
     fn name(&self) -> String {
-        self.name.ru.to_string()
+        self.name.main().to_string()
     }
 
     fn desc(&self) -> String {
-        self.description.ru.to_string()
+        self.description.main().to_string()
     }
 
     fn id(&self) -> SkillId {
@@ -0,0 +1,61 @@
+use crate::backend::util::{Localized, StringCow};
+use crate::common::UserActionId;
+use crate::entity::{CommonEntity, GetEditParams};
+use serde::{Deserialize, Serialize};
+
+impl GetEditParams<()> for UserAction {
+    fn edit_params(&self) {}
+}
+
+impl CommonEntity<UserActionId> for UserAction {
+    fn name(&self) -> String {
+        self.name.main().clone()
+    }
+
+    fn desc(&self) -> String {
+        self.desc.main().clone()
+    }
+
+    fn id(&self) -> UserActionId {
+        self.id
+    }
+
+    fn changed(&self) -> bool {
+        self._changed
+    }
+
+    fn deleted(&self) -> bool {
+        self._deleted
+    }
+
+    fn new(id: UserActionId) -> Self {
+        UserAction {
+            id,
+            name: ("Новое Действие".to_string(), "New Action".to_string()).into(),
+            icon: "".into(),
+
+            _changed: false,
+            _deleted: false,
+
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, Default, PartialEq)]
+pub struct UserAction {
+    pub id: UserActionId,
+    pub tag: u32,
+    pub action_type: u32,
+    pub category: u32,
+    pub toggle_group_id: u32,
+    pub command: String,
+    pub icon: StringCow,
+    pub name: Localized<String>,
+    pub desc: Localized<String>,
+
+    #[serde(skip)]
+    pub _changed: bool,
+    #[serde(skip)]
+    pub _deleted: bool,
+}
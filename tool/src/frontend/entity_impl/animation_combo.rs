@@ -1,6 +1,6 @@
 use crate::backend::Backend;
 use crate::backend::editor::{CurrentEntity, EditParamsCommonOps};
-use crate::backend::holder::{DataHolder, HolderMapOps, HolderOps};
+use crate::backend::holder::DataHolder;
 use crate::entity::GameEntityT;
 use crate::entity::animation_combo::AnimationCombo;
 use crate::frontend::entity_impl::EntityInfoState;
@@ -160,21 +160,9 @@ impl Frontend {
                 });
             });
 
-            if let Some(id) = changed
-                && let Some(v) = holder.get_mut(&id) {
-                    v._deleted = !v._deleted;
-
-                    if v._deleted {
-                        edit_params.close_if_opened(GameEntityT::AnimationCombo(id));
-                        holder.inc_deleted();
-                    } else {
-                        holder.dec_deleted();
-                    }
-
-                    catalog.filter(holder, *filter_mode);
-
-                    backend.check_for_unwrote_changed();
-                }
+            if let Some(id) = changed {
+                backend.toggle_deleted(GameEntityT::AnimationCombo(id));
+            }
         });
     }
 }
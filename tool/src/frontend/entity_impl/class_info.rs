@@ -0,0 +1,191 @@
+use crate::backend::Backend;
+use crate::backend::editor::{CurrentEntity, EditParamsCommonOps};
+use crate::backend::entity_impl::class_info::ClassInfoAction;
+use crate::backend::holder::DataHolder;
+use crate::common::ClassId;
+use crate::entity::GameEntityT;
+use crate::entity::class_info::ClassInfo;
+use crate::frontend::entity_impl::EntityInfoState;
+use crate::frontend::util::{
+    DrawAsTooltip, close_entity_button, format_button_text, num_row, text_row,
+};
+use crate::frontend::{DrawEntity, Frontend};
+use eframe::egui::{Button, Color32, Context, ScrollArea, Stroke, Ui};
+use std::sync::RwLock;
+
+impl DrawEntity<ClassInfoAction, ()> for ClassInfo {
+    fn draw_entity(
+        &mut self,
+        ui: &mut Ui,
+        _ctx: &Context,
+        _action: &RwLock<ClassInfoAction>,
+        holders: &mut DataHolder,
+        _params: &mut (),
+    ) {
+        ui.horizontal(|ui| {
+            ui.set_height(100.);
+
+            ui.vertical(|ui| {
+                ui.set_width(300.);
+                num_row(ui, &mut self.id.0, "Id");
+
+                text_row(ui, &mut self.name[holders.localization], "Name");
+            });
+
+            ui.separator();
+
+            ui.vertical(|ui| {
+                ui.set_width(200.);
+
+                ui.horizontal(|ui| {
+                    ui.label("Parent Class");
+                    if self.parent.is_some() {
+                        if ui.checkbox(&mut true, "").changed() {
+                            self.parent = None;
+                        }
+                    } else if ui.checkbox(&mut false, "").changed() {
+                        self.parent = Some(ClassId(0));
+                    }
+                });
+
+                if let Some(parent) = &mut self.parent {
+                    num_row(ui, &mut parent.0, "Id")
+                        .on_hover_text(holders.game_data_holder.get_class_name(parent));
+                }
+            });
+        });
+
+        ui.separator();
+    }
+}
+
+impl Frontend {
+    pub fn draw_class_info_tabs(&mut self, ui: &mut Ui) {
+        for (i, (title, id, is_changed)) in self
+            .backend
+            .editors
+            .get_opened_class_info_info()
+            .iter()
+            .enumerate()
+        {
+            let mut button = Button::new(format_button_text(&format!(
+                "{}[{}] {}",
+                if *is_changed { "*" } else { "" },
+                id.0,
+                title
+            )))
+            .fill(Color32::from_rgb(96, 47, 133))
+            .min_size([150., 10.].into());
+
+            let is_current = CurrentEntity::ClassInfo(i) == self.backend.editors.current_entity;
+
+            if is_current {
+                button = button.stroke(Stroke::new(1.0, Color32::LIGHT_GRAY));
+            }
+
+            if ui
+                .add(button)
+                .on_hover_text(format!(
+                    "ClassInfo: [{}] {}{}",
+                    id.0,
+                    title,
+                    if *is_changed { "\nModified!" } else { "" },
+                ))
+                .clicked()
+                && !self.backend.dialog_showing
+            {
+                self.backend.editors.set_current_class_info(i);
+            }
+
+            close_entity_button(
+                ui,
+                CurrentEntity::ClassInfo(i),
+                &mut self.backend,
+                *is_changed,
+            );
+
+            ui.separator();
+        }
+    }
+
+    pub(crate) fn draw_class_info_selector(backend: &mut Backend, ui: &mut Ui, width: f32) {
+        ui.vertical(|ui| {
+            ui.set_width(width);
+
+            let holder = &mut backend.holders.game_data_holder.class_info_holder;
+            let catalog = &mut backend.entity_catalogs.class_info;
+            let filter_mode = &mut backend.entity_catalogs.filter_mode;
+            let edit_params = &mut backend.editors;
+
+            if catalog
+                .draw_search_and_add_buttons(ui, holder, filter_mode, catalog.len())
+                .clicked()
+            {
+                edit_params.create_new_class_info();
+            }
+
+            ui.separator();
+
+            let mut changed = None;
+
+            ui.push_id(ui.next_auto_id(), |ui| {
+                ScrollArea::vertical().show_rows(ui, 36., catalog.catalog.len(), |ui, range| {
+                    ui.set_width(width - 5.);
+
+                    for v in range {
+                        let q = &catalog.catalog[v];
+
+                        let mut has_unsaved_changes = false;
+
+                        let info_state = if let Some((ind, v)) = edit_params
+                            .class_infos
+                            .opened
+                            .iter()
+                            .enumerate()
+                            .find(|(_, v)| v.inner.initial_id == q.id)
+                        {
+                            has_unsaved_changes = v.is_changed();
+
+                            if edit_params.current_entity == CurrentEntity::ClassInfo(ind) {
+                                EntityInfoState::Current
+                            } else {
+                                EntityInfoState::Opened
+                            }
+                        } else {
+                            EntityInfoState::Nothing
+                        };
+
+                        ui.horizontal(|ui| {
+                            if q.draw_catalog_buttons(
+                                ui,
+                                &mut changed,
+                                info_state,
+                                has_unsaved_changes,
+                            )
+                            .clicked()
+                                && backend.dialog.is_none()
+                                && !q.deleted
+                            {
+                                if ui.input(|i| i.modifiers.ctrl) && !has_unsaved_changes {
+                                    edit_params.close_if_opened(GameEntityT::ClassInfo(q.id));
+                                } else {
+                                    edit_params.open_class_info(q.id, holder);
+                                }
+                            }
+                        });
+                    }
+                });
+            });
+
+            if let Some(id) = changed {
+                backend.toggle_deleted(GameEntityT::ClassInfo(id));
+            }
+        });
+    }
+}
+
+impl DrawAsTooltip for ClassInfo {
+    fn draw_as_tooltip(&self, ui: &mut Ui) {
+        ui.label(format!("ID: {}\n {}", self.id.0, self.name.main()));
+    }
+}
@@ -1,8 +1,7 @@
 use crate::backend::Backend;
 use crate::backend::editor::{CurrentEntity, EditParamsCommonOps};
 use crate::backend::entity_impl::daily_missions::DailyMissionAction;
-use crate::backend::holder::{DataHolder, HolderMapOps, HolderOps};
-use crate::common::PlayerClass;
+use crate::backend::holder::{DataHolder, HolderMapOps};
 use crate::entity::GameEntityT;
 use crate::entity::daily_mission::{DailyMission, DailyMissionReward, DailyMissionUnk7};
 use crate::frontend::entity_impl::EntityInfoState;
@@ -14,7 +13,6 @@ use crate::frontend::util::{
 use crate::frontend::{DELETE_ICON, DrawEntity, Frontend};
 use eframe::egui::{Button, Color32, Context, Response, ScrollArea, Stroke, Ui};
 use std::sync::RwLock;
-use strum::IntoEnumIterator;
 
 impl DrawEntity<DailyMissionAction, ()> for DailyMission {
     fn draw_entity(
@@ -74,14 +72,18 @@ impl DrawEntity<DailyMissionAction, ()> for DailyMission {
                         ui.menu_button("+", |ui| {
                             ui.push_id(ui.next_auto_id(), |ui| {
                                 ScrollArea::vertical().show(ui, |ui| {
-                                    let mut classes: Vec<_> = PlayerClass::iter()
-                                        .filter(|v| !allowed_classes.contains(v))
+                                    let mut classes: Vec<_> = holders
+                                        .game_data_holder
+                                        .class_info_holder
+                                        .values()
+                                        .filter(|v| !v._deleted && !allowed_classes.contains(&v.id))
+                                        .map(|v| (v.id, v.name.main().clone()))
                                         .collect();
-                                    classes.sort_by(|a, b| format!("{a}").cmp(&format!("{b}")));
+                                    classes.sort_by(|a, b| a.1.cmp(&b.1));
 
-                                    for v in classes {
-                                        if ui.button(format!("{v}")).clicked() {
-                                            allowed_classes.push(v);
+                                    for (id, name) in classes {
+                                        if ui.button(name).clicked() {
+                                            allowed_classes.push(id);
                                         }
                                     }
                                 });
@@ -95,7 +97,7 @@ impl DrawEntity<DailyMissionAction, ()> for DailyMission {
                         ScrollArea::vertical().show(ui, |ui| {
                             for (i, class) in allowed_classes.clone().iter().enumerate() {
                                 ui.horizontal(|ui| {
-                                    ui.label(format!("{class}"));
+                                    ui.label(holders.game_data_holder.get_class_name(class));
                                     if ui.button(DELETE_ICON).clicked() {
                                         allowed_classes.remove(i);
                                     }
@@ -306,27 +308,15 @@ impl Frontend {
                 });
             });
 
-            if let Some(id) = deleted_status
-                && let Some(v) = holder.get_mut(&id) {
-                    v._deleted = !v._deleted;
-
-                    if v._deleted {
-                        edit_params.close_if_opened(GameEntityT::DailyMission(id));
-                        holder.inc_deleted();
-                    } else {
-                        holder.dec_deleted();
-                    }
-
-                    catalog.filter(holder, *filter_mode);
-
-                    backend.check_for_unwrote_changed();
-                }
+            if let Some(id) = deleted_status {
+                backend.toggle_deleted(GameEntityT::DailyMission(id));
+            }
         });
     }
 }
 
 impl DrawAsTooltip for DailyMission {
     fn draw_as_tooltip(&self, ui: &mut Ui) {
-        ui.label(format!("[{}] {}", self.id.0, self.name.ru));
+        ui.label(format!("[{}] {}", self.id.0, self.name.main()));
     }
 }
@@ -1,7 +1,7 @@
 use crate::backend::Backend;
 use crate::backend::editor::{CurrentEntity, EditParamsCommonOps};
 use crate::backend::entity_impl::ensoul_option::EnsoulOptionAction;
-use crate::backend::holder::{DataHolder, HolderMapOps, HolderOps};
+use crate::backend::holder::DataHolder;
 use crate::entity::GameEntityT;
 use crate::entity::ensoul_option::EnsoulOption;
 use crate::frontend::entity_impl::EntityInfoState;
@@ -173,21 +173,9 @@ impl Frontend {
                 });
             });
 
-            if let Some(id) = changed
-                && let Some(v) = holder.get_mut(&id) {
-                    v._deleted = !v._deleted;
-
-                    if v._deleted {
-                        edit_params.close_if_opened(GameEntityT::EnsoulOption(id));
-                        holder.inc_deleted();
-                    } else {
-                        holder.dec_deleted();
-                    }
-
-                    catalog.filter(holder, *filter_mode);
-
-                    backend.check_for_unwrote_changed();
-                }
+            if let Some(id) = changed {
+                backend.toggle_deleted(GameEntityT::EnsoulOption(id));
+            }
         });
     }
 }
@@ -196,7 +184,10 @@ impl DrawAsTooltip for EnsoulOption {
     fn draw_as_tooltip(&self, ui: &mut Ui) {
         ui.label(format!(
             "ID: {}\n {} {} {}",
-            self.id.0, self.name.ru, self.option_type, self.step
+            self.id.0,
+            self.name.main(),
+            self.option_type,
+            self.step
         ));
     }
 }
@@ -0,0 +1,251 @@
+use crate::backend::Backend;
+use crate::backend::editor::{CurrentEntity, EditParamsCommonOps};
+use crate::backend::entity_impl::henna::HennaAction;
+use crate::backend::holder::{DataHolder, HolderMapOps};
+use crate::entity::GameEntityT;
+use crate::entity::henna::Henna;
+use crate::frontend::entity_impl::EntityInfoState;
+use crate::frontend::util::{
+    Draw, DrawAsTooltip, close_entity_button, format_button_text, num_row, text_row, text_row_c,
+    text_row_multiline,
+};
+use crate::frontend::{DELETE_ICON, DrawEntity, Frontend};
+use eframe::egui::{Button, Color32, Context, ScrollArea, Stroke, Ui};
+use std::sync::RwLock;
+
+impl DrawEntity<HennaAction, ()> for Henna {
+    fn draw_entity(
+        &mut self,
+        ui: &mut Ui,
+        _ctx: &Context,
+        _action: &RwLock<HennaAction>,
+        holders: &mut DataHolder,
+        _params: &mut (),
+    ) {
+        ui.horizontal(|ui| {
+            ui.set_height(250.);
+
+            ui.vertical(|ui| {
+                ui.set_width(300.);
+                num_row(ui, &mut self.id.0, "Id");
+
+                num_row(ui, &mut self.dye_item_id.0, "Dye Item ID").on_hover_ui(|ui| {
+                    self.dye_item_id.draw(ui, holders);
+                });
+
+                text_row(ui, &mut self.name[holders.localization], "Name");
+                text_row(
+                    ui,
+                    &mut self.additional_name[holders.localization],
+                    "Additional Name",
+                );
+                text_row_multiline(ui, &mut self.desc[holders.localization], "Description");
+
+                text_row_c(ui, &mut self.icon, "Icon");
+                text_row_c(ui, &mut self.icon_panel, "Icon Panel");
+            });
+
+            ui.separator();
+
+            ui.vertical(|ui| {
+                ui.set_width(100.);
+
+                ui.label("Stats");
+                num_row(ui, &mut self.stats.str, "STR");
+                num_row(ui, &mut self.stats.dex, "DEX");
+                num_row(ui, &mut self.stats.con, "CON");
+                num_row(ui, &mut self.stats.int, "INT");
+                num_row(ui, &mut self.stats.men, "MEN");
+                num_row(ui, &mut self.stats.wit, "WIT");
+            });
+
+            ui.separator();
+
+            ui.vertical(|ui| {
+                ui.set_width(150.);
+
+                ui.horizontal(|ui| {
+                    ui.label("Allowed Classes");
+                    if self.allowed_classes.is_some() {
+                        if ui.checkbox(&mut true, "").changed() {
+                            self.allowed_classes = None;
+                        }
+                    } else if ui.checkbox(&mut false, "").changed() {
+                        self.allowed_classes = Some(vec![]);
+                    }
+
+                    if let Some(allowed_classes) = &mut self.allowed_classes {
+                        ui.menu_button("+", |ui| {
+                            ui.push_id(ui.next_auto_id(), |ui| {
+                                ScrollArea::vertical().show(ui, |ui| {
+                                    let mut classes: Vec<_> = holders
+                                        .game_data_holder
+                                        .class_info_holder
+                                        .values()
+                                        .filter(|v| !v._deleted && !allowed_classes.contains(&v.id))
+                                        .map(|v| (v.id, v.name.main().clone()))
+                                        .collect();
+                                    classes.sort_by(|a, b| a.1.cmp(&b.1));
+
+                                    for (id, name) in classes {
+                                        if ui.button(name).clicked() {
+                                            allowed_classes.push(id);
+                                        }
+                                    }
+                                });
+                            });
+                        });
+                    }
+                });
+
+                if let Some(allowed_classes) = &mut self.allowed_classes {
+                    ui.push_id(ui.next_auto_id(), |ui| {
+                        ScrollArea::vertical().show(ui, |ui| {
+                            for (i, class) in allowed_classes.clone().iter().enumerate() {
+                                ui.horizontal(|ui| {
+                                    ui.label(holders.game_data_holder.get_class_name(class));
+                                    if ui.button(DELETE_ICON).clicked() {
+                                        allowed_classes.remove(i);
+                                    }
+                                });
+                            }
+                        });
+                    });
+                }
+            });
+        });
+
+        ui.separator();
+    }
+}
+
+impl Frontend {
+    pub fn draw_henna_tabs(&mut self, ui: &mut Ui) {
+        for (i, (title, id, is_changed)) in self
+            .backend
+            .editors
+            .get_opened_henna_info()
+            .iter()
+            .enumerate()
+        {
+            let mut button = Button::new(format_button_text(&format!(
+                "{}[{}] {}",
+                if *is_changed { "*" } else { "" },
+                id.0,
+                title
+            )))
+            .fill(Color32::from_rgb(133, 47, 47))
+            .min_size([150., 10.].into());
+
+            let is_current = CurrentEntity::Henna(i) == self.backend.editors.current_entity;
+
+            if is_current {
+                button = button.stroke(Stroke::new(1.0, Color32::LIGHT_GRAY));
+            }
+
+            if ui
+                .add(button)
+                .on_hover_text(format!(
+                    "Henna: [{}] {}{}",
+                    id.0,
+                    title,
+                    if *is_changed { "\nModified!" } else { "" },
+                ))
+                .clicked()
+                && !self.backend.dialog_showing
+            {
+                self.backend.editors.set_current_henna(i);
+            }
+
+            close_entity_button(ui, CurrentEntity::Henna(i), &mut self.backend, *is_changed);
+
+            ui.separator();
+        }
+    }
+
+    pub(crate) fn draw_henna_selector(backend: &mut Backend, ui: &mut Ui, width: f32) {
+        ui.vertical(|ui| {
+            ui.set_width(width);
+
+            let holder = &mut backend.holders.game_data_holder.henna_holder;
+            let catalog = &mut backend.entity_catalogs.henna;
+            let filter_mode = &mut backend.entity_catalogs.filter_mode;
+            let edit_params = &mut backend.editors;
+
+            if catalog
+                .draw_search_and_add_buttons(ui, holder, filter_mode, catalog.len())
+                .clicked()
+            {
+                edit_params.create_new_henna();
+            }
+
+            ui.separator();
+
+            let mut changed = None;
+
+            ui.push_id(ui.next_auto_id(), |ui| {
+                ScrollArea::vertical().show_rows(ui, 36., catalog.catalog.len(), |ui, range| {
+                    ui.set_width(width - 5.);
+
+                    for v in range {
+                        let q = &catalog.catalog[v];
+
+                        let mut has_unsaved_changes = false;
+
+                        let info_state = if let Some((ind, v)) = edit_params
+                            .hennas
+                            .opened
+                            .iter()
+                            .enumerate()
+                            .find(|(_, v)| v.inner.initial_id == q.id)
+                        {
+                            has_unsaved_changes = v.is_changed();
+
+                            if edit_params.current_entity == CurrentEntity::Henna(ind) {
+                                EntityInfoState::Current
+                            } else {
+                                EntityInfoState::Opened
+                            }
+                        } else {
+                            EntityInfoState::Nothing
+                        };
+
+                        ui.horizontal(|ui| {
+                            if q.draw_catalog_buttons(
+                                ui,
+                                &mut changed,
+                                info_state,
+                                has_unsaved_changes,
+                            )
+                            .clicked()
+                                && backend.dialog.is_none()
+                                && !q.deleted
+                            {
+                                if ui.input(|i| i.modifiers.ctrl) && !has_unsaved_changes {
+                                    edit_params.close_if_opened(GameEntityT::Henna(q.id));
+                                } else {
+                                    edit_params.open_henna(q.id, holder);
+                                }
+                            }
+                        });
+                    }
+                });
+            });
+
+            if let Some(id) = changed {
+                backend.toggle_deleted(GameEntityT::Henna(id));
+            }
+        });
+    }
+}
+
+impl DrawAsTooltip for Henna {
+    fn draw_as_tooltip(&self, ui: &mut Ui) {
+        ui.label(format!(
+            "ID: {}\n {} {}",
+            self.id.0,
+            self.name.main(),
+            self.additional_name.main()
+        ));
+    }
+}
@@ -1,7 +1,7 @@
 use crate::backend::Backend;
 use crate::backend::editor::{CurrentEntity, EditParamsCommonOps, WindowParams};
 use crate::backend::entity_impl::hunting_zone::{HuntingZoneAction, MapObjectAction};
-use crate::backend::holder::{DataHolder, HolderMapOps, HolderOps};
+use crate::backend::holder::{DataHolder, HolderMapOps};
 use crate::entity::GameEntityT;
 use crate::entity::hunting_zone::{HuntingZone, MapObject};
 use crate::frontend::entity_impl::EntityInfoState;
@@ -275,21 +275,9 @@ impl Frontend {
                 });
             });
 
-            if let Some(id) = changed
-                && let Some(v) = holder.get_mut(&id) {
-                    v._deleted = !v._deleted;
-
-                    if v._deleted {
-                        edit_params.close_if_opened(GameEntityT::HuntingZone(id));
-                        holder.inc_deleted();
-                    } else {
-                        holder.dec_deleted();
-                    }
-
-                    catalog.filter(holder, *filter_mode);
-
-                    backend.check_for_unwrote_changed();
-                }
+            if let Some(id) = changed {
+                backend.toggle_deleted(GameEntityT::HuntingZone(id));
+            }
         });
     }
 }
@@ -297,10 +285,10 @@ impl Frontend {
 impl DrawAsTooltip for HuntingZone {
     fn draw_as_tooltip(&self, ui: &mut Ui) {
         ui.vertical(|ui| {
-            ui.label(format!("[{}]\n {}", self.id.0, self.name.ru));
+            ui.label(format!("[{}]\n {}", self.id.0, self.name.main()));
 
-            if !self.desc.ru.is_empty() {
-                ui.label(self.desc.ru.to_string());
+            if !self.desc.main().is_empty() {
+                ui.label(self.desc.main().to_string());
             }
         });
     }
@@ -1,7 +1,7 @@
 use crate::backend::Backend;
 use crate::backend::editor::{CurrentEntity, EditParamsCommonOps};
 use crate::backend::entity_impl::item::armor::ArmorAction;
-use crate::backend::holder::{DataHolder, HolderMapOps, HolderOps};
+use crate::backend::holder::DataHolder;
 use crate::entity::GameEntityT;
 use crate::entity::item::armor::{
     Armor, ArmorMeshAdditionalF, ArmorMeshInfo, ArmorMeshes, CurrentArmorMesh,
@@ -76,7 +76,7 @@ impl DrawEntity<ArmorAction, ()> for Armor {
                     ctx,
                     holders,
                     "   Mesh Params   ",
-                    &format!("Mesh Params {}", self.base_info.name.ru),
+                    &format!("Mesh Params {}", self.base_info.name.main()),
                     &format!("{} armor_mesh_params", self.base_info.id.0),
                     init_rect,
                 );
@@ -191,7 +191,6 @@ impl Frontend {
             ui.set_width(width);
 
             let holder = &mut backend.holders.game_data_holder.armor_holder;
-            let item_holder = &mut backend.holders.game_data_holder.item_holder;
             let catalog = &mut backend.entity_catalogs.armor;
             let filter_mode = &mut backend.entity_catalogs.filter_mode;
             let edit_params = &mut backend.editors;
@@ -256,23 +255,9 @@ impl Frontend {
                 });
             });
 
-            if let Some(id) = changed
-                && let Some(v) = holder.get_mut(&id) {
-                    v._deleted = !v._deleted;
-
-                    if v._deleted {
-                        item_holder.remove(&id);
-                        edit_params.close_if_opened(GameEntityT::Armor(id));
-                        holder.inc_deleted();
-                    } else {
-                        item_holder.insert(id, (&(*v)).into());
-                        holder.dec_deleted();
-                    }
-
-                    catalog.filter(holder, *filter_mode);
-
-                    backend.check_for_unwrote_changed();
-                }
+            if let Some(id) = changed {
+                backend.toggle_deleted(GameEntityT::Armor(id));
+            }
         });
     }
 }
@@ -1,7 +1,7 @@
 use crate::backend::Backend;
 use crate::backend::editor::{CurrentEntity, EditParamsCommonOps};
 use crate::backend::entity_impl::item::etc_item::EtcItemAction;
-use crate::backend::holder::{DataHolder, HolderMapOps, HolderOps};
+use crate::backend::holder::DataHolder;
 use crate::entity::GameEntityT;
 use crate::entity::item::etc_item::{EnsoulStone, EtcItem, EtcMeshInfo};
 use crate::frontend::entity_impl::EntityInfoState;
@@ -136,7 +136,6 @@ impl Frontend {
             ui.set_width(width);
 
             let holder = &mut backend.holders.game_data_holder.etc_item_holder;
-            let item_holder = &mut backend.holders.game_data_holder.item_holder;
             let catalog = &mut backend.entity_catalogs.etc_item;
             let filter_mode = &mut backend.entity_catalogs.filter_mode;
             let edit_params = &mut backend.editors;
@@ -201,23 +200,9 @@ impl Frontend {
                 });
             });
 
-            if let Some(id) = changed
-                && let Some(v) = holder.get_mut(&id) {
-                    v._deleted = !v._deleted;
-
-                    if v._deleted {
-                        item_holder.remove(&id);
-                        edit_params.close_if_opened(GameEntityT::EtcItem(id));
-                        holder.inc_deleted();
-                    } else {
-                        item_holder.insert(id, (&(*v)).into());
-                        holder.dec_deleted();
-                    }
-
-                    catalog.filter(holder, *filter_mode);
-
-                    backend.check_for_unwrote_changed();
-                }
+            if let Some(id) = changed {
+                backend.toggle_deleted(GameEntityT::EtcItem(id));
+            }
         });
     }
 }
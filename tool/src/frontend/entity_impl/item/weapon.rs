@@ -3,7 +3,7 @@ use crate::backend::editor::{CurrentEntity, EditParamsCommonOps};
 use crate::backend::entity_impl::item::weapon::{
     WeaponAction, WeaponEnchantAction, WeaponSoundAction, WeaponVariationAction,
 };
-use crate::backend::holder::{DataHolder, HolderMapOps, HolderOps};
+use crate::backend::holder::DataHolder;
 use crate::entity::GameEntityT;
 use crate::entity::item::weapon::{
     Weapon, WeaponEnchantInfo, WeaponEnchantParams, WeaponMeshInfo, WeaponSounds,
@@ -98,7 +98,7 @@ impl DrawEntity<WeaponAction, ()> for Weapon {
                         ctx,
                         holders,
                         "   Enchant Params   ",
-                        &format!("Enchant Params {}", self.base_info.name.ru),
+                        &format!("Enchant Params {}", self.base_info.name.main()),
                         &format!("{} weapon_enchant_params", self.base_info.id.0),
                         init_rect,
                     );
@@ -108,7 +108,7 @@ impl DrawEntity<WeaponAction, ()> for Weapon {
                         ctx,
                         holders,
                         "   Variation Params   ",
-                        &format!("Variation Params {}", self.base_info.name.ru),
+                        &format!("Variation Params {}", self.base_info.name.main()),
                         &format!("{} weapon_variation_params", self.base_info.id.0),
                         init_rect,
                     );
@@ -118,7 +118,7 @@ impl DrawEntity<WeaponAction, ()> for Weapon {
                         ctx,
                         holders,
                         "   Sounds   ",
-                        &format!("Sounds {}", self.base_info.name.ru),
+                        &format!("Sounds {}", self.base_info.name.main()),
                         &format!("{} weapon_sounds", self.base_info.id.0),
                         init_rect,
                     );
@@ -334,7 +334,6 @@ impl Frontend {
             ui.set_width(width);
 
             let holder = &mut backend.holders.game_data_holder.weapon_holder;
-            let item_holder = &mut backend.holders.game_data_holder.item_holder;
             let catalog = &mut backend.entity_catalogs.weapon;
             let filter_mode = &mut backend.entity_catalogs.filter_mode;
             let edit_params = &mut backend.editors;
@@ -399,23 +398,9 @@ impl Frontend {
                 });
             });
 
-            if let Some(id) = changed
-                && let Some(v) = holder.get_mut(&id) {
-                    v._deleted = !v._deleted;
-
-                    if v._deleted {
-                        item_holder.remove(&id);
-                        edit_params.close_if_opened(GameEntityT::Weapon(id));
-                        holder.inc_deleted();
-                    } else {
-                        item_holder.insert(id, (&(*v)).into());
-                        holder.dec_deleted();
-                    }
-
-                    catalog.filter(holder, *filter_mode);
-
-                    backend.check_for_unwrote_changed();
-                }
+            if let Some(id) = changed {
+                backend.toggle_deleted(GameEntityT::Weapon(id));
+            }
         });
     }
 }
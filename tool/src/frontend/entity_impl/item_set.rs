@@ -1,7 +1,7 @@
 use crate::backend::Backend;
 use crate::backend::editor::{CurrentEntity, EditParamsCommonOps};
 use crate::backend::entity_impl::item_set::ItemSetAction;
-use crate::backend::holder::{DataHolder, HolderMapOps, HolderOps};
+use crate::backend::holder::{DataHolder, HolderMapOps};
 use crate::entity::item_set::{ItemSet, ItemSetEnchantInfo};
 use crate::entity::{CommonEntity, GameEntityT};
 use crate::frontend::entity_impl::EntityInfoState;
@@ -392,21 +392,9 @@ impl Frontend {
                 });
             });
 
-            if let Some(id) = changed
-                && let Some(v) = holder.get_mut(&id) {
-                    v._deleted = !v._deleted;
-
-                    if v._deleted {
-                        edit_params.close_if_opened(GameEntityT::ItemSet(id));
-                        holder.inc_deleted();
-                    } else {
-                        holder.dec_deleted();
-                    }
-
-                    catalog.filter(holder, *filter_mode);
-
-                    backend.check_for_unwrote_changed();
-                }
+            if let Some(id) = changed {
+                backend.toggle_deleted(GameEntityT::ItemSet(id));
+            }
         });
     }
 }
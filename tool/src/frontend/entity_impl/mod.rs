@@ -3,18 +3,22 @@ use eframe::egui::{Button, Color32, FontFamily, Response, RichText, Stroke, Ui};
 use std::cmp::PartialEq;
 
 pub mod animation_combo;
+pub mod class_info;
 pub mod daily_mission;
 pub mod ensoul_option;
+pub mod henna;
 pub mod hunting_zone;
 pub mod item;
 pub mod item_set;
 pub mod npc;
+pub mod product;
 pub mod quest;
 pub mod raid_info;
 pub mod recipe;
 pub mod region;
 pub mod residence;
 pub mod skill;
+pub mod user_action;
 
 #[derive(Clone, Copy, Eq, PartialEq)]
 enum EntityInfoState {
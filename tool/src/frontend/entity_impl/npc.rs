@@ -3,7 +3,7 @@ use crate::backend::editor::{CurrentEntity, EditParamsCommonOps};
 use crate::backend::entity_impl::npc::{
     NpcAction, NpcMeshAction, NpcSkillAnimationAction, NpcSoundAction,
 };
-use crate::backend::holder::{DataHolder, HolderMapOps, HolderOps};
+use crate::backend::holder::{DataHolder, HolderMapOps};
 use crate::entity::GameEntityT;
 use crate::entity::npc::{
     Npc, NpcAdditionalParts, NpcDecorationEffect, NpcEquipParams, NpcMeshParams, NpcProperty,
@@ -226,11 +226,11 @@ impl DrawEntity<NpcAction, ()> for Npc {
 impl DrawAsTooltip for Npc {
     fn draw_as_tooltip(&self, ui: &mut Ui) {
         ui.vertical(|ui| {
-            if !self.title.ru.is_empty() {
-                ui.colored_label(self.title_color, self.title.ru.to_string());
+            if !self.title.main().is_empty() {
+                ui.colored_label(self.title_color, self.title.main().to_string());
             };
 
-            ui.label(format!("{} [{}]", self.name.ru, self.id.0));
+            ui.label(format!("{} [{}]", self.name.main(), self.id.0));
         });
     }
 }
@@ -725,21 +725,9 @@ impl Frontend {
                 });
             });
 
-            if let Some(id) = changed
-                && let Some(v) = holder.get_mut(&id) {
-                    v._deleted = !v._deleted;
-
-                    if v._deleted {
-                        edit_params.close_if_opened(GameEntityT::Npc(id));
-                        holder.inc_deleted();
-                    } else {
-                        holder.dec_deleted();
-                    }
-
-                    catalog.filter(holder, *filter_mode);
-
-                    backend.check_for_unwrote_changed();
-                }
+            if let Some(id) = changed {
+                backend.toggle_deleted(GameEntityT::Npc(id));
+            }
         });
     }
 }
@@ -0,0 +1,211 @@
+use crate::backend::Backend;
+use crate::backend::editor::{CurrentEntity, EditParamsCommonOps};
+use crate::backend::entity_impl::product::ProductAction;
+use crate::backend::holder::DataHolder;
+use crate::entity::GameEntityT;
+use crate::entity::product::{Product, ProductItem};
+use crate::frontend::entity_impl::EntityInfoState;
+use crate::frontend::util::{
+    Draw, DrawAsTooltip, DrawUtils, close_entity_button, format_button_text, num_row, text_row,
+};
+use crate::frontend::{DrawEntity, Frontend};
+use eframe::egui::{Button, Color32, Context, Response, ScrollArea, Stroke, Ui};
+use std::sync::RwLock;
+
+impl DrawEntity<ProductAction, ()> for Product {
+    fn draw_entity(
+        &mut self,
+        ui: &mut Ui,
+        _ctx: &Context,
+        action: &RwLock<ProductAction>,
+        holders: &mut DataHolder,
+        _params: &mut (),
+    ) {
+        ui.horizontal(|ui| {
+            ui.set_height(300.);
+
+            ui.vertical(|ui| {
+                ui.set_width(300.);
+                num_row(ui, &mut self.id.0, "Id");
+
+                text_row(ui, &mut self.name[holders.localization], "Name");
+                text_row(ui, &mut self.icon, "Icon");
+                num_row(ui, &mut self.category, "Category");
+            });
+
+            ui.separator();
+
+            ui.vertical(|ui| {
+                ui.set_width(350.);
+
+                self.items.draw_vertical(
+                    ui,
+                    "Items",
+                    |v| *action.write().unwrap() = ProductAction::RemoveItem(v),
+                    holders,
+                    true,
+                    true,
+                );
+            });
+        });
+
+        ui.separator();
+    }
+}
+
+impl Draw for ProductItem {
+    fn draw(&mut self, ui: &mut Ui, holders: &DataHolder) -> Response {
+        let name = holders.game_data_holder.get_item_name(&self.item_id);
+        let exists = holders
+            .game_data_holder
+            .item_holder
+            .contains_key(&self.item_id);
+
+        num_row(ui, &mut self.item_id.0, "Id").on_hover_ui(|ui| {
+            holders
+                .game_data_holder
+                .item_holder
+                .get(&self.item_id)
+                .draw_as_tooltip(ui);
+        });
+
+        num_row(ui, &mut self.count, "Count");
+
+        if exists {
+            ui.label(name)
+        } else {
+            ui.colored_label(Color32::LIGHT_RED, name)
+        }
+    }
+}
+
+impl Frontend {
+    pub fn draw_product_tabs(&mut self, ui: &mut Ui) {
+        for (i, (title, id, is_changed)) in self
+            .backend
+            .editors
+            .get_opened_product_info()
+            .iter()
+            .enumerate()
+        {
+            let mut button = Button::new(format_button_text(&format!(
+                "{}[{}] {}",
+                if *is_changed { "*" } else { "" },
+                id.0,
+                title
+            )))
+            .fill(Color32::from_rgb(133, 110, 47))
+            .min_size([150., 10.].into());
+
+            let is_current = CurrentEntity::Product(i) == self.backend.editors.current_entity;
+
+            if is_current {
+                button = button.stroke(Stroke::new(1.0, Color32::LIGHT_GRAY));
+            }
+
+            if ui
+                .add(button)
+                .on_hover_text(format!(
+                    "Product: [{}] {}{}",
+                    id.0,
+                    title,
+                    if *is_changed { "\nModified!" } else { "" },
+                ))
+                .clicked()
+                && !self.backend.dialog_showing
+            {
+                self.backend.editors.set_current_product(i);
+            }
+
+            close_entity_button(
+                ui,
+                CurrentEntity::Product(i),
+                &mut self.backend,
+                *is_changed,
+            );
+
+            ui.separator();
+        }
+    }
+
+    pub(crate) fn draw_product_selector(backend: &mut Backend, ui: &mut Ui, width: f32) {
+        ui.vertical(|ui| {
+            ui.set_width(width);
+
+            let holder = &mut backend.holders.game_data_holder.product_holder;
+            let catalog = &mut backend.entity_catalogs.product;
+            let filter_mode = &mut backend.entity_catalogs.filter_mode;
+            let edit_params = &mut backend.editors;
+
+            if catalog
+                .draw_search_and_add_buttons(ui, holder, filter_mode, catalog.len())
+                .clicked()
+            {
+                edit_params.create_new_product();
+            }
+
+            ui.separator();
+
+            let mut changed = None;
+
+            ui.push_id(ui.next_auto_id(), |ui| {
+                ScrollArea::vertical().show_rows(ui, 36., catalog.catalog.len(), |ui, range| {
+                    ui.set_width(width - 5.);
+
+                    for v in range {
+                        let q = &catalog.catalog[v];
+
+                        let mut has_unsaved_changes = false;
+
+                        let info_state = if let Some((ind, v)) = edit_params
+                            .products
+                            .opened
+                            .iter()
+                            .enumerate()
+                            .find(|(_, v)| v.inner.initial_id == q.id)
+                        {
+                            has_unsaved_changes = v.is_changed();
+
+                            if edit_params.current_entity == CurrentEntity::Product(ind) {
+                                EntityInfoState::Current
+                            } else {
+                                EntityInfoState::Opened
+                            }
+                        } else {
+                            EntityInfoState::Nothing
+                        };
+
+                        ui.horizontal(|ui| {
+                            if q.draw_catalog_buttons(
+                                ui,
+                                &mut changed,
+                                info_state,
+                                has_unsaved_changes,
+                            )
+                            .clicked()
+                                && backend.dialog.is_none()
+                                && !q.deleted
+                            {
+                                if ui.input(|i| i.modifiers.ctrl) && !has_unsaved_changes {
+                                    edit_params.close_if_opened(GameEntityT::Product(q.id));
+                                } else {
+                                    edit_params.open_product(q.id, holder);
+                                }
+                            }
+                        });
+                    }
+                });
+            });
+
+            if let Some(id) = changed {
+                backend.toggle_deleted(GameEntityT::Product(id));
+            }
+        });
+    }
+}
+
+impl DrawAsTooltip for Product {
+    fn draw_as_tooltip(&self, ui: &mut Ui) {
+        ui.label(format!("ID: {}\n {}", self.id.0, self.name.main()));
+    }
+}
@@ -3,8 +3,8 @@ pub mod steps_editor;
 use crate::backend::Backend;
 use crate::backend::editor::{CurrentEntity, EditParamsCommonOps};
 use crate::backend::entity_impl::quest::QuestAction;
-use crate::backend::holder::{DataHolder, HolderMapOps, HolderOps};
-use crate::common::{ItemId, NpcId, PlayerClass};
+use crate::backend::holder::{DataHolder, HolderMapOps};
+use crate::common::{ItemId, NpcId};
 use crate::entity::quest::{GoalType, Quest, QuestReward, StepGoal};
 use crate::entity::{CommonEntity, GameEntityT, GetEditParams};
 use crate::frontend::entity_impl::EntityInfoState;
@@ -20,7 +20,6 @@ use eframe::egui::scroll_area::ScrollBarVisibility;
 use eframe::egui::{Button, Color32, Context, Response, ScrollArea, Stroke, Ui, Vec2};
 use num_traits::pow;
 use std::sync::RwLock;
-use strum::IntoEnumIterator;
 
 impl GetEditParams<QuestStepsEditorParams> for Quest {
     fn edit_params(&self) -> QuestStepsEditorParams {
@@ -224,14 +223,18 @@ impl DrawEntity<QuestAction, QuestStepsEditorParams> for Quest {
                         ui.menu_button("+", |ui| {
                             ui.push_id(ui.next_auto_id(), |ui| {
                                 ScrollArea::vertical().show(ui, |ui| {
-                                    let mut classes: Vec<_> = PlayerClass::iter()
-                                        .filter(|v| !allowed_classes.contains(v))
+                                    let mut classes: Vec<_> = holders
+                                        .game_data_holder
+                                        .class_info_holder
+                                        .values()
+                                        .filter(|v| !v._deleted && !allowed_classes.contains(&v.id))
+                                        .map(|v| (v.id, v.name.main().clone()))
                                         .collect();
-                                    classes.sort_by(|a, b| format!("{a}").cmp(&format!("{b}")));
+                                    classes.sort_by(|a, b| a.1.cmp(&b.1));
 
-                                    for v in classes {
-                                        if ui.button(format!("{v}")).clicked() {
-                                            allowed_classes.push(v);
+                                    for (id, name) in classes {
+                                        if ui.button(name).clicked() {
+                                            allowed_classes.push(id);
                                         }
                                     }
                                 });
@@ -245,7 +248,7 @@ impl DrawEntity<QuestAction, QuestStepsEditorParams> for Quest {
                         ScrollArea::vertical().show(ui, |ui| {
                             for (i, class) in allowed_classes.clone().iter().enumerate() {
                                 ui.horizontal(|ui| {
-                                    ui.label(format!("{class}"));
+                                    ui.label(holders.game_data_holder.get_class_name(class));
                                     if ui.button(DELETE_ICON).clicked() {
                                         allowed_classes.remove(i);
                                     }
@@ -386,7 +389,7 @@ impl Quest {
 
 impl DrawAsTooltip for Quest {
     fn draw_as_tooltip(&self, ui: &mut Ui) {
-        ui.label(format!("[{}]\n{}", self.id.0, self.title.ru));
+        ui.label(format!("[{}]\n{}", self.id.0, self.title.main()));
     }
 }
 
@@ -568,21 +571,9 @@ impl Frontend {
                 });
             });
 
-            if let Some(id) = changed
-                && let Some(v) = holder.get_mut(&id) {
-                    v._deleted = !v._deleted;
-
-                    if v._deleted {
-                        edit_params.close_if_opened(GameEntityT::Quest(id));
-                        holder.inc_deleted();
-                    } else {
-                        holder.dec_deleted();
-                    }
-
-                    catalog.filter(holder, *filter_mode);
-
-                    backend.check_for_unwrote_changed();
-                }
+            if let Some(id) = changed {
+                backend.toggle_deleted(GameEntityT::Quest(id));
+            }
         });
     }
 }
@@ -1,6 +1,6 @@
 use crate::backend::Backend;
 use crate::backend::editor::{CurrentEntity, EditParamsCommonOps};
-use crate::backend::holder::{DataHolder, HolderMapOps, HolderOps};
+use crate::backend::holder::{DataHolder, HolderMapOps};
 use crate::entity::GameEntityT;
 use crate::entity::region::{MapInfo, Region};
 use crate::frontend::entity_impl::EntityInfoState;
@@ -211,27 +211,15 @@ impl Frontend {
                 });
             });
 
-            if let Some(id) = changed
-                && let Some(v) = holder.get_mut(&id) {
-                    v._deleted = !v._deleted;
-
-                    if v._deleted {
-                        edit_params.close_if_opened(GameEntityT::Region(id));
-                        holder.inc_deleted();
-                    } else {
-                        holder.dec_deleted();
-                    }
-
-                    catalog.filter(holder, *filter_mode);
-
-                    backend.check_for_unwrote_changed();
-                }
+            if let Some(id) = changed {
+                backend.toggle_deleted(GameEntityT::Region(id));
+            }
         });
     }
 }
 
 impl DrawAsTooltip for Region {
     fn draw_as_tooltip(&self, ui: &mut Ui) {
-        ui.label(format!("[{}]\n {}", self.id.0, self.name.ru));
+        ui.label(format!("[{}]\n {}", self.id.0, self.name.main()));
     }
 }
@@ -1,7 +1,7 @@
 use crate::backend::Backend;
 use crate::backend::editor::{CurrentEntity, EditParamsCommonOps};
 use crate::backend::entity_impl::residence::ResidenceAction;
-use crate::backend::holder::{DataHolder, HolderMapOps, HolderOps};
+use crate::backend::holder::{DataHolder, HolderMapOps};
 use crate::entity::GameEntityT;
 use crate::entity::residence::Residence;
 use crate::frontend::entity_impl::EntityInfoState;
@@ -181,27 +181,15 @@ impl Frontend {
                 });
             });
 
-            if let Some(id) = changed
-                && let Some(v) = holder.get_mut(&id) {
-                    v._deleted = !v._deleted;
-
-                    if v._deleted {
-                        edit_params.close_if_opened(GameEntityT::Residence(id));
-                        holder.inc_deleted();
-                    } else {
-                        holder.dec_deleted();
-                    }
-
-                    catalog.filter(holder, *filter_mode);
-
-                    backend.check_for_unwrote_changed();
-                }
+            if let Some(id) = changed {
+                backend.toggle_deleted(GameEntityT::Residence(id));
+            }
         });
     }
 }
 
 impl DrawAsTooltip for Residence {
     fn draw_as_tooltip(&self, ui: &mut Ui) {
-        ui.label(format!("ID: {}\n{}", self.id.0, self.name.ru));
+        ui.label(format!("ID: {}\n{}", self.id.0, self.name.main()));
     }
 }
@@ -4,7 +4,7 @@ use crate::backend::entity_impl::skill::{
     SkillAction, SkillEditWindowParams, SkillEnchantAction, SkillEnchantEditWindowParams,
     SkillUceConditionAction,
 };
-use crate::backend::holder::{DataHolder, HolderMapOps, HolderOps};
+use crate::backend::holder::{DataHolder, HolderMapOps};
 use crate::common::ItemId;
 use crate::entity::GameEntityT;
 use crate::entity::skill::{
@@ -193,7 +193,9 @@ impl DrawAsTooltip for Skill {
     fn draw_as_tooltip(&self, ui: &mut Ui) {
         ui.label(format!(
             "[{}]\n{}\n{}",
-            self.id.0, self.name.ru, self.description.ru
+            self.id.0,
+            self.name.main(),
+            self.description.main()
         ));
     }
 }
@@ -205,14 +207,14 @@ impl DrawAsTooltip for (&Skill, usize) {
             "[{}]\n{}\n{}",
             self.0.id.0,
             if let Some(Some(n)) = s.map(|v| &v.name) {
-                &n.ru
+                n.main()
             } else {
-                &self.0.name.ru
+                self.0.name.main()
             },
             if let Some(Some(n)) = s.map(|v| &v.description) {
-                &n.ru
+                n.main()
             } else {
-                &self.0.description.ru
+                self.0.description.main()
             },
         ));
     }
@@ -920,21 +922,9 @@ impl Frontend {
                 });
             });
 
-            if let Some(id) = changed
-                && let Some(v) = holder.get_mut(&id) {
-                    v._deleted = !v._deleted;
-
-                    if v._deleted {
-                        edit_params.close_if_opened(GameEntityT::Skill(id));
-                        holder.inc_deleted();
-                    } else {
-                        holder.dec_deleted();
-                    }
-
-                    catalog.filter(holder, *filter_mode);
-
-                    backend.check_for_unwrote_changed();
-                }
+            if let Some(id) = changed {
+                backend.toggle_deleted(GameEntityT::Skill(id));
+            }
         });
     }
 }
@@ -0,0 +1,188 @@
+use crate::backend::Backend;
+use crate::backend::editor::{CurrentEntity, EditParamsCommonOps};
+use crate::backend::entity_impl::user_action::UserActionAction;
+use crate::backend::holder::DataHolder;
+use crate::entity::GameEntityT;
+use crate::entity::user_action::UserAction;
+use crate::frontend::entity_impl::EntityInfoState;
+use crate::frontend::util::{
+    DrawAsTooltip, close_entity_button, format_button_text, num_row, text_row, text_row_c,
+    text_row_multiline,
+};
+use crate::frontend::{DrawEntity, Frontend};
+use eframe::egui::{Button, Color32, Context, ScrollArea, Stroke, Ui};
+use std::sync::RwLock;
+
+impl DrawEntity<UserActionAction, ()> for UserAction {
+    fn draw_entity(
+        &mut self,
+        ui: &mut Ui,
+        _ctx: &Context,
+        _action: &RwLock<UserActionAction>,
+        holders: &mut DataHolder,
+        _params: &mut (),
+    ) {
+        ui.horizontal(|ui| {
+            ui.set_height(210.);
+
+            ui.vertical(|ui| {
+                ui.set_width(300.);
+                num_row(ui, &mut self.id.0, "Id");
+
+                text_row(ui, &mut self.name[holders.localization], "Name");
+                text_row_multiline(ui, &mut self.desc[holders.localization], "Description");
+
+                text_row(ui, &mut self.command, "Command");
+                text_row_c(ui, &mut self.icon, "Icon");
+            });
+
+            ui.separator();
+
+            ui.vertical(|ui| {
+                ui.set_width(200.);
+                num_row(ui, &mut self.tag, "Tag");
+                num_row(ui, &mut self.action_type, "Type");
+                num_row(ui, &mut self.category, "Category");
+                num_row(ui, &mut self.toggle_group_id, "Toggle Group Id");
+            });
+        });
+
+        ui.separator();
+    }
+}
+
+impl Frontend {
+    pub fn draw_user_action_tabs(&mut self, ui: &mut Ui) {
+        for (i, (title, id, is_changed)) in self
+            .backend
+            .editors
+            .get_opened_user_action_info()
+            .iter()
+            .enumerate()
+        {
+            let mut button = Button::new(format_button_text(&format!(
+                "{}[{}] {}",
+                if *is_changed { "*" } else { "" },
+                id.0,
+                title
+            )))
+            .fill(Color32::from_rgb(47, 96, 133))
+            .min_size([150., 10.].into());
+
+            let is_current = CurrentEntity::UserAction(i) == self.backend.editors.current_entity;
+
+            if is_current {
+                button = button.stroke(Stroke::new(1.0, Color32::LIGHT_GRAY));
+            }
+
+            if ui
+                .add(button)
+                .on_hover_text(format!(
+                    "UserAction: [{}] {}{}",
+                    id.0,
+                    title,
+                    if *is_changed { "\nModified!" } else { "" },
+                ))
+                .clicked()
+                && !self.backend.dialog_showing
+            {
+                self.backend.editors.set_current_user_action(i);
+            }
+
+            close_entity_button(
+                ui,
+                CurrentEntity::UserAction(i),
+                &mut self.backend,
+                *is_changed,
+            );
+
+            ui.separator();
+        }
+    }
+
+    pub(crate) fn draw_user_action_selector(backend: &mut Backend, ui: &mut Ui, width: f32) {
+        ui.vertical(|ui| {
+            ui.set_width(width);
+
+            let holder = &mut backend.holders.game_data_holder.user_action_holder;
+            let catalog = &mut backend.entity_catalogs.user_action;
+            let filter_mode = &mut backend.entity_catalogs.filter_mode;
+            let edit_params = &mut backend.editors;
+
+            if catalog
+                .draw_search_and_add_buttons(ui, holder, filter_mode, catalog.len())
+                .clicked()
+            {
+                edit_params.create_new_user_action();
+            }
+
+            ui.separator();
+
+            let mut changed = None;
+
+            ui.push_id(ui.next_auto_id(), |ui| {
+                ScrollArea::vertical().show_rows(ui, 36., catalog.catalog.len(), |ui, range| {
+                    ui.set_width(width - 5.);
+
+                    for v in range {
+                        let q = &catalog.catalog[v];
+
+                        let mut has_unsaved_changes = false;
+
+                        let info_state = if let Some((ind, v)) = edit_params
+                            .user_actions
+                            .opened
+                            .iter()
+                            .enumerate()
+                            .find(|(_, v)| v.inner.initial_id == q.id)
+                        {
+                            has_unsaved_changes = v.is_changed();
+
+                            if edit_params.current_entity == CurrentEntity::UserAction(ind) {
+                                EntityInfoState::Current
+                            } else {
+                                EntityInfoState::Opened
+                            }
+                        } else {
+                            EntityInfoState::Nothing
+                        };
+
+                        ui.horizontal(|ui| {
+                            if q.draw_catalog_buttons(
+                                ui,
+                                &mut changed,
+                                info_state,
+                                has_unsaved_changes,
+                            )
+                            .clicked()
+                                && backend.dialog.is_none()
+                                && !q.deleted
+                            {
+                                if ui.input(|i| i.modifiers.ctrl) && !has_unsaved_changes {
+                                    edit_params.close_if_opened(GameEntityT::UserAction(q.id));
+                                } else {
+                                    edit_params.open_user_action(q.id, holder);
+                                }
+                            }
+                        });
+                    }
+                });
+            });
+
+            if let Some(id) = changed {
+                backend.toggle_deleted(GameEntityT::UserAction(id));
+            }
+        });
+    }
+}
+
+impl DrawAsTooltip for UserAction {
+    fn draw_as_tooltip(&self, ui: &mut Ui) {
+        ui.label(format!(
+            "ID: {}\n {} {}",
+            self.id.0,
+            self.name.main(),
+            self.command
+        ));
+    }
+}
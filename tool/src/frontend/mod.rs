@@ -4,13 +4,15 @@ mod script_runner;
 mod spawn_editor;
 mod util;
 
+use crate::backend::client_diff::DiffKind;
 use crate::backend::editor::{CurrentEntity, WindowParams, entity::ChangeTrackedParams};
 use crate::backend::entity_catalog::{EntityCatalog, EntityInfo, FilterMode};
-use crate::backend::holder::{ChangeStatus, DataHolder, DictEditItem, HolderMapOps};
+use crate::backend::holder::{ChangeStatus, DataHolder, DictEditItem, DumpFormat, HolderMapOps};
 use crate::backend::log_holder::{LogHolder, LogHolderParams, LogLevel, LogLevelFilter};
 use crate::backend::{Backend, Dialog, DialogAnswer, Localization};
 use crate::common::{EnsoulOptionId, ItemId, Location, NpcId, Position, QuestId};
-use crate::entity::{CommonEntity, Dictionary, GameEntity};
+use crate::entity::game_tip::GameTip;
+use crate::entity::{CommonEntity, Dictionary, Entity, GameEntity, GameEntityT};
 use crate::frontend::map_icons_editor::MapIconsEditor;
 use crate::frontend::script_runner::ScriptRunner;
 use crate::frontend::spawn_editor::SpawnEditor;
@@ -49,6 +51,10 @@ const DAILY_MISSION_ICON: &[u8] = include_bytes!("../../../files/daily_mission.p
 const ANIMATION_COMBO_ICON: &[u8] = include_bytes!("../../../files/animation_combo.png");
 const RESIDENCE_ICON: &[u8] = include_bytes!("../../../files/residence.png");
 const ENSOUL_OPTION_ICON: &[u8] = include_bytes!("../../../files/ensoul_option.png");
+const HENNA_ICON: &[u8] = include_bytes!("../../../files/henna.png");
+const USER_ACTION_ICON: &[u8] = include_bytes!("../../../files/user_action.png");
+const PRODUCT_ICON: &[u8] = include_bytes!("../../../files/product.png");
+const CLASS_INFO_ICON: &[u8] = include_bytes!("../../../files/class_info.png");
 
 pub const NOT_FOUND: &[u8] = include_bytes!("../../../files/none.png");
 
@@ -80,6 +86,7 @@ pub struct Frontend {
 
     show_npc_string_editor: bool,
     show_system_string_editor: bool,
+    show_game_tips_editor: bool,
 }
 
 impl Frontend {
@@ -88,7 +95,7 @@ impl Frontend {
             let mut c = HashMap::new();
 
             for npc in self.backend.holders.game_data_holder.npc_holder.values() {
-                c.insert(npc.id.0, format!("{} [{}]", npc.name.ru, npc.id.0));
+                c.insert(npc.id.0, format!("{} [{}]", npc.name.main(), npc.id.0));
             }
 
             self.spawn_editor.update_spawn_path(
@@ -175,6 +182,21 @@ impl Frontend {
             CurrentEntity::EnsoulOption(index) => self.backend.editors.ensoul_options.opened[index]
                 .draw_window(ui, ctx, &mut self.backend.holders),
 
+            CurrentEntity::Henna(index) => self.backend.editors.hennas.opened[index].draw_window(
+                ui,
+                ctx,
+                &mut self.backend.holders,
+            ),
+
+            CurrentEntity::UserAction(index) => self.backend.editors.user_actions.opened[index]
+                .draw_window(ui, ctx, &mut self.backend.holders),
+
+            CurrentEntity::Product(index) => self.backend.editors.products.opened[index]
+                .draw_window(ui, ctx, &mut self.backend.holders),
+
+            CurrentEntity::ClassInfo(index) => self.backend.editors.class_infos.opened[index]
+                .draw_window(ui, ctx, &mut self.backend.holders),
+
             CurrentEntity::None => {}
         }
 
@@ -203,27 +225,125 @@ impl Frontend {
 
                 ui.separator();
 
-                if ui
-                    .button(RichText::new("\u{f56e}").family(FontFamily::Name("icons".into())))
-                    .on_hover_text("Export in RON format to copy buffer")
-                    .clicked()
-                    && let Some(v) = self.backend.export_entity_as_ron_string() {
-                        ctx.copy_text(v);
-                        // ui.output_mut(|o| o.copied_text = v);
-                    }
+                ui.menu_button(
+                    RichText::new("\u{f56e}").family(FontFamily::Name("icons".into())),
+                    |ui| {
+                        for format in DumpFormat::iter() {
+                            if ui.button(format.to_string()).clicked() {
+                                if let Some(v) = self.backend.export_entity_as_string(format) {
+                                    ctx.copy_text(v);
+                                    // ui.output_mut(|o| o.copied_text = v);
+                                }
+
+                                ui.close_menu();
+                            }
+                        }
+                    },
+                )
+                .response
+                .on_hover_text("Export to copy buffer");
 
                 if ui
                     .button(RichText::new("\u{f56f}").family(FontFamily::Name("icons".into())))
-                    .on_hover_text("Import from RON format from copy buffer")
+                    .on_hover_text("Import from copy buffer, RON or JSON")
                     .clicked()
                 {
-                    self.backend.import_entity_from_ron_string(
+                    self.backend.import_entity_from_string(
                         &ClipboardContext::new().unwrap().get_contents().unwrap(),
                     );
                 }
 
                 ui.separator();
 
+                if let Some((can_undo, can_redo, labels, position)) =
+                    self.backend.current_entity_history()
+                {
+                    if ui
+                        .add_enabled(
+                            can_undo,
+                            Button::new(
+                                RichText::new("\u{f0e2}").family(FontFamily::Name("icons".into())),
+                            ),
+                        )
+                        .on_hover_text("Undo\n(Ctrl+Z)")
+                        .clicked()
+                    {
+                        self.backend.undo_current_entity();
+                    }
+
+                    if ui
+                        .add_enabled(
+                            can_redo,
+                            Button::new(
+                                RichText::new("\u{f01e}").family(FontFamily::Name("icons".into())),
+                            ),
+                        )
+                        .on_hover_text("Redo\n(Ctrl+Shift+Z)")
+                        .clicked()
+                    {
+                        self.backend.redo_current_entity();
+                    }
+
+                    ui.menu_button(
+                        RichText::new("\u{f1da}").family(FontFamily::Name("icons".into())),
+                        |ui| {
+                            ScrollArea::vertical().max_height(300.).show(ui, |ui| {
+                                for (i, label) in labels.iter().enumerate().rev() {
+                                    if ui.selectable_label(i == position, label).clicked() {
+                                        self.backend.go_to_current_entity_history_record(i);
+                                        ui.close_menu();
+                                    }
+                                }
+                            });
+                        },
+                    )
+                    .response
+                    .on_hover_text("History");
+
+                    ui.separator();
+                }
+
+                let used_by = self.backend.current_entity_used_by();
+
+                ui.add_enabled_ui(!used_by.is_empty(), |ui| {
+                    ui.menu_button(
+                        RichText::new("\u{f0c1}").family(FontFamily::Name("icons".into())),
+                        |ui| {
+                            ScrollArea::vertical().max_height(300.).show(ui, |ui| {
+                                for (entity, field, name) in &used_by {
+                                    if ui
+                                        .button(format!(
+                                            "{entity} [{}] {name} ({field})",
+                                            entity.raw_id()
+                                        ))
+                                        .clicked()
+                                    {
+                                        self.backend.open_entity(*entity);
+                                        ui.close_menu();
+                                    }
+                                }
+                            });
+                        },
+                    )
+                    .response
+                    .on_hover_text(format!("Used by ({})", used_by.len()));
+                });
+
+                if ui
+                    .add_enabled(
+                        self.backend.editors.current_game_entity().is_some(),
+                        Button::new(
+                            RichText::new("\u{f0ec}").family(FontFamily::Name("icons".into())),
+                        ),
+                    )
+                    .on_hover_text("Change ID")
+                    .clicked()
+                {
+                    self.backend.open_re_id_for_current_entity();
+                }
+
+                ui.separator();
+
                 ui.vertical(|ui| {
                     ui.push_id(ui.next_auto_id(), |ui| {
                         ScrollArea::horizontal()
@@ -245,6 +365,10 @@ impl Frontend {
                                     self.draw_animation_combo_tabs(ui);
                                     self.draw_residence_tabs(ui);
                                     self.draw_ensoul_option_tabs(ui);
+                                    self.draw_henna_tabs(ui);
+                                    self.draw_user_action_tabs(ui);
+                                    self.draw_product_tabs(ui);
+                                    self.draw_class_info_tabs(ui);
                                 });
                             });
                     });
@@ -262,6 +386,18 @@ impl Frontend {
                         .input_mut(|i| i.consume_key(Modifiers::CTRL, Key::W))
                     {
                         self.backend.close_current_entity();
+                    } else if !ui.ctx().wants_keyboard_input()
+                        && ui.ctx().input_mut(|i| {
+                            i.consume_key(Modifiers::CTRL | Modifiers::SHIFT, Key::Z)
+                        })
+                    {
+                        self.backend.redo_current_entity();
+                    } else if !ui.ctx().wants_keyboard_input()
+                        && ui
+                            .ctx()
+                            .input_mut(|i| i.consume_key(Modifiers::CTRL, Key::Z))
+                    {
+                        self.backend.undo_current_entity();
                     }
                 }
             });
@@ -286,10 +422,37 @@ impl Frontend {
                         && let Some(path) = rfd::FileDialog::new().pick_folder() {
                             self.backend.update_system_path(path)
                         }
-                    if ui.button("Select .ron dumps folder").clicked()
+                    if ui.button("Select dumps folder").clicked()
                         && let Some(path) = rfd::FileDialog::new().pick_folder() {
                             self.backend.update_ron_dumps_path(path)
                         }
+
+                    let mut layout = self.backend.config.ron_dump_layout;
+
+                    combo_box_row(ui, &mut layout, "Dump layout");
+
+                    if layout != self.backend.config.ron_dump_layout {
+                        self.backend.update_ron_dump_layout(layout);
+                    }
+
+                    let mut format = self.backend.config.dump_format;
+
+                    combo_box_row(ui, &mut format, "Dump and patch format");
+
+                    if format != self.backend.config.dump_format {
+                        self.backend.update_dump_format(format);
+                    }
+
+                    if ui
+                        .button("Select .dat output folder")
+                        .on_hover_text(
+                            "Changed .dat files are written there instead of the system folder",
+                        )
+                        .clicked()
+                        && let Some(path) = rfd::FileDialog::new().pick_folder()
+                    {
+                        self.backend.update_dat_output_path(Some(path))
+                    }
                     if ui
                         .button("Select textures folders")
                         .on_hover_text("Textures should be unpacked as TGA/PNG")
@@ -305,6 +468,40 @@ impl Frontend {
                         && let Some(path) = rfd::FileDialog::new().pick_folder() {
                             self.update_npc_spawn_path(path)
                         }
+
+                    if let Some(p) = &self.backend.config.dat_output_folder_path {
+                        ui.label(format!("Output: {p}"));
+
+                        let mut copy = self.backend.config.copy_unchanged_dats;
+
+                        if ui
+                            .checkbox(&mut copy, "Copy unchanged .dat files")
+                            .on_hover_text("Makes output a complete system folder")
+                            .changed()
+                        {
+                            self.backend.update_copy_unchanged_dats(copy);
+                        }
+
+                        if ui.button("Write to system folder again").clicked() {
+                            self.backend.update_dat_output_path(None);
+                        }
+                    }
+
+                    ui.separator();
+
+                    let mut keep = self.backend.backups_to_keep();
+
+                    if num_row(ui, &mut keep, "Dat backups to keep")
+                        .on_hover_text("0 turns backups off")
+                        .changed()
+                    {
+                        self.backend.update_backups_to_keep(keep);
+                    }
+
+                    if ui.button("Restore dat backup").clicked() {
+                        self.backend.open_backups();
+                        ui.close_menu();
+                    }
                 },
             )
             .response
@@ -321,7 +518,13 @@ impl Frontend {
                 .add(b)
                 .on_hover_text(if self.backend.is_changed() {
                     format!(
-                        "Write changes to .dat\n\nChanged:\n{:#?}",
+                        "Write changes to .dat{}\n\nChanged:\n{:#?}",
+                        self.backend
+                            .config
+                            .dat_output_folder_path
+                            .as_ref()
+                            .map(|v| format!(" in {v}"))
+                            .unwrap_or_default(),
                         self.backend.holders.game_data_holder.changed_entities()
                     )
                 } else {
@@ -339,14 +542,35 @@ impl Frontend {
                     .add(Button::new(
                         RichText::new(" \u{f019} ").family(FontFamily::Name("icons".into())),
                     ))
-                    .on_hover_text("Write all data to .ron")
+                    .on_hover_text(format!(
+                        "Write all data to .{}",
+                        self.backend.config.dump_format.extension()
+                    ))
                     .clicked()
                 {
-                    self.backend.save_to_ron(p);
+                    let p = p.clone();
+
+                    self.backend.save_to_ron(&p);
 
                     ui.close_menu();
                 }
 
+            if ui
+                .button(RichText::new(" \u{f093} ").family(FontFamily::Name("icons".into())))
+                .on_hover_text("Load all data from .ron/.json dump")
+                .clicked()
+            {
+                let mut dialog = rfd::FileDialog::new();
+
+                if let Some(p) = &self.backend.config.ron_dumps_folder_path {
+                    dialog = dialog.set_directory(p);
+                }
+
+                if let Some(path) = dialog.pick_folder() {
+                    self.backend.load_from_ron_dump(path.to_str().unwrap());
+                }
+            }
+
             if ui
                 .button(RichText::new(" \u{f121} ").family(FontFamily::Name("icons".into())))
                 .on_hover_text("Run Script")
@@ -355,6 +579,80 @@ impl Frontend {
                 self.script_runner.opened = true;
             }
 
+            if ui
+                .button(RichText::new(" \u{f0ae} ").family(FontFamily::Name("icons".into())))
+                .on_hover_text("Check Integrity")
+                .clicked()
+            {
+                self.backend.check_integrity();
+            }
+
+            if ui
+                .button(RichText::new(" \u{f24d} ").family(FontFamily::Name("icons".into())))
+                .on_hover_text("Clone Range")
+                .clicked()
+            {
+                self.backend.clone_range.opened = true;
+            }
+
+            if ui
+                .button(RichText::new(" \u{f0db} ").family(FontFamily::Name("icons".into())))
+                .on_hover_text("Compare with Another Client")
+                .clicked()
+            {
+                self.backend.client_diff.opened = true;
+            }
+
+            if ui
+                .button(RichText::new(" \u{f0ce} ").family(FontFamily::Name("icons".into())))
+                .on_hover_text("CSV Tables")
+                .clicked()
+            {
+                self.backend.csv.opened = true;
+                self.backend.refresh_csv_columns();
+            }
+
+            if ui
+                .button(RichText::new(" \u{f1c0} ").family(FontFamily::Name("icons".into())))
+                .on_hover_text("Export to SQLite")
+                .clicked()
+                && let Some(path) = rfd::FileDialog::new()
+                    .set_file_name("l2.sqlite")
+                    .save_file()
+            {
+                self.backend.export_sqlite(&path);
+            }
+
+            ui.menu_button(
+                RichText::new(" \u{f0ea} ").family(FontFamily::Name("icons".into())),
+                |ui| {
+                    if ui.button("Export unsaved changes as patch").clicked() {
+                        let format = self.backend.config.dump_format;
+
+                        if let Some(path) = rfd::FileDialog::new()
+                            .set_file_name(format!("changes.patch.{}", format.extension()))
+                            .save_file()
+                        {
+                            self.backend.export_patch(&path, format);
+                        }
+
+                        ui.close_menu();
+                    }
+
+                    if ui.button("Apply patch to another system folder").clicked() {
+                        if let Some(patch) = rfd::FileDialog::new().pick_file()
+                            && let Some(folder) = rfd::FileDialog::new().pick_folder()
+                        {
+                            self.backend.prepare_patch(patch, folder);
+                        }
+
+                        ui.close_menu();
+                    }
+                },
+            )
+            .response
+            .on_hover_text("Patches");
+
             ui.menu_button(
                 RichText::new(" \u{f1a7} ").family(FontFamily::Name("icons".into())),
                 |ui| {
@@ -370,6 +668,12 @@ impl Frontend {
                         self.backend.fill_npc_strings_editor();
                         ui.close_menu();
                     }
+
+                    if ui.button("Edit Game Tips").clicked() && !self.show_game_tips_editor {
+                        self.show_game_tips_editor = true;
+                        self.backend.fill_game_tips_editor();
+                        ui.close_menu();
+                    }
                 },
             )
             .response
@@ -385,7 +689,7 @@ impl Frontend {
                         let mut c = HashMap::new();
 
                         for npc in self.backend.holders.game_data_holder.npc_holder.values() {
-                            c.insert(npc.id.0, format!("{} [{}]", npc.name.ru, npc.id.0));
+                            c.insert(npc.id.0, format!("{} [{}]", npc.name.main(), npc.id.0));
                         }
 
                         self.spawn_editor.show(
@@ -623,6 +927,50 @@ impl Frontend {
                     {
                         self.search_params.current_entity = GameEntity::EnsoulOption;
                     };
+
+                    if ui
+                        .add(egui::ImageButton::new(Image::from_bytes(
+                            "bytes://henna.png",
+                            HENNA_ICON,
+                        )))
+                        .on_hover_text("Henna")
+                        .clicked()
+                    {
+                        self.search_params.current_entity = GameEntity::Henna;
+                    };
+
+                    if ui
+                        .add(egui::ImageButton::new(Image::from_bytes(
+                            "bytes://user_action.png",
+                            USER_ACTION_ICON,
+                        )))
+                        .on_hover_text("User Action")
+                        .clicked()
+                    {
+                        self.search_params.current_entity = GameEntity::UserAction;
+                    };
+
+                    if ui
+                        .add(egui::ImageButton::new(Image::from_bytes(
+                            "bytes://product.png",
+                            PRODUCT_ICON,
+                        )))
+                        .on_hover_text("Product")
+                        .clicked()
+                    {
+                        self.search_params.current_entity = GameEntity::Product;
+                    };
+
+                    if ui
+                        .add(egui::ImageButton::new(Image::from_bytes(
+                            "bytes://class_info.png",
+                            CLASS_INFO_ICON,
+                        )))
+                        .on_hover_text("Class Info")
+                        .clicked()
+                    {
+                        self.search_params.current_entity = GameEntity::ClassInfo;
+                    };
                 });
 
                 ui.separator();
@@ -676,78 +1024,929 @@ impl Frontend {
                         Self::draw_daily_missions_selector(&mut self.backend, ui, LIBRARY_WIDTH)
                     }
 
-                    GameEntity::AnimationCombo => {
-                        Self::draw_animation_combo_selector(&mut self.backend, ui, LIBRARY_WIDTH)
+                    GameEntity::AnimationCombo => {
+                        Self::draw_animation_combo_selector(&mut self.backend, ui, LIBRARY_WIDTH)
+                    }
+
+                    GameEntity::Residence => {
+                        Self::draw_residence_selector(&mut self.backend, ui, LIBRARY_WIDTH)
+                    }
+
+                    GameEntity::EnsoulOption => {
+                        Self::draw_ensoul_option_selector(&mut self.backend, ui, LIBRARY_WIDTH)
+                    }
+
+                    GameEntity::Henna => {
+                        Self::draw_henna_selector(&mut self.backend, ui, LIBRARY_WIDTH)
+                    }
+
+                    GameEntity::UserAction => {
+                        Self::draw_user_action_selector(&mut self.backend, ui, LIBRARY_WIDTH)
+                    }
+
+                    GameEntity::Product => {
+                        Self::draw_product_selector(&mut self.backend, ui, LIBRARY_WIDTH)
+                    }
+
+                    GameEntity::ClassInfo => {
+                        Self::draw_class_info_selector(&mut self.backend, ui, LIBRARY_WIDTH)
+                    }
+                }
+            });
+    }
+
+    fn draw_spawn_editor(&mut self, ctx: &egui::Context) {
+        if self.spawn_editor.showing
+            && let Some(v) = &mut self.spawn_editor.editor {
+                ctx.show_viewport_immediate(
+                    egui::ViewportId::from_hash_of("_spawn_editor_"),
+                    egui::ViewportBuilder::default()
+                        .with_title("Spawn Viewer")
+                        .with_inner_size([600.0, 300.0]),
+                    |ctx, class| {
+                        assert!(
+                            class == egui::ViewportClass::Immediate,
+                            "This egui backend doesn't support multiple viewports"
+                        );
+
+                        egui::CentralPanel::default().show(ctx, |ui| {
+                            v.show(ctx, ui);
+                        });
+
+                        if ctx.input(|i| i.viewport().close_requested()) {
+                            // Tell parent viewport that we should not show next frame:
+                            self.spawn_editor.showing = false;
+                        }
+                    },
+                );
+            }
+    }
+
+    fn draw(&mut self, ctx: &egui::Context) {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            self.show_dialog(ctx, ui.min_size());
+
+            self.draw_entity_library(ctx);
+
+            self.draw_script_runner(ui, ctx);
+
+            self.draw_integrity_report(ctx);
+
+            self.draw_re_id(ctx);
+
+            self.draw_clone_range(ctx);
+
+            self.draw_client_diff(ctx);
+
+            self.draw_patch(ctx);
+
+            self.draw_backups(ctx);
+
+            self.draw_csv(ctx);
+
+            self.draw_top_menu(ui, ctx);
+
+            ui.separator();
+
+            self.draw_tabs(ui, ctx);
+
+            self.draw_editor(ui, ctx);
+
+            self.draw_dino(ui);
+
+            if IS_SAVING.load(Ordering::Relaxed) {
+                egui::Window::new("SAVING IN PROGRESS")
+                    .id(egui::Id::new("_saving_"))
+                    .resizable(false)
+                    .collapsible(false)
+                    .show(ctx, |ui| {
+                        ui.vertical_centered(|ui| {
+                            ui.spinner();
+                        })
+                    });
+            }
+        });
+    }
+
+    fn draw_integrity_report(&mut self, ctx: &egui::Context) {
+        let mut opened = self.backend.integrity_report.opened;
+        let mut to_open = None;
+
+        egui::Window::new("Integrity Problems")
+            .id(egui::Id::new("_integrity_report_"))
+            .collapsible(true)
+            .resizable(true)
+            .open(&mut opened)
+            .show(ctx, |ui| {
+                ui.set_min_width(500.);
+
+                let report = &self.backend.integrity_report;
+
+                if report.problems.is_empty() {
+                    ui.label("No problems found");
+
+                    return;
+                }
+
+                ScrollArea::vertical().show(ui, |ui| {
+                    for (level, title) in
+                        [(LogLevel::Error, "Errors"), (LogLevel::Warning, "Warnings")]
+                    {
+                        let count = report.count(level);
+
+                        if count == 0 {
+                            continue;
+                        }
+
+                        egui::CollapsingHeader::new(
+                            RichText::new(format!("{title} ({count})")).color(level),
+                        )
+                        .id_salt(title)
+                        .default_open(true)
+                        .show(ui, |ui| {
+                            for v in report.problems.iter().filter(|v| v.level == level) {
+                                ui.horizontal(|ui| {
+                                    if ui
+                                        .button(format!("{} [{}]", v.entity, v.entity.raw_id()))
+                                        .on_hover_text("Open")
+                                        .clicked()
+                                    {
+                                        to_open = Some(v.entity);
+                                    }
+
+                                    ui.label(&v.message);
+                                });
+                            }
+                        });
+                    }
+                });
+            });
+
+        self.backend.integrity_report.opened = opened;
+
+        if let Some(v) = to_open {
+            self.backend.open_entity(v);
+        }
+    }
+
+    fn draw_re_id(&mut self, ctx: &egui::Context) {
+        let mut opened = self.backend.re_id.opened;
+        let mut preview = false;
+        let mut apply = false;
+        let mut undo = false;
+        let mut to_open = None;
+
+        egui::Window::new("Change ID")
+            .id(egui::Id::new("_re_id_"))
+            .collapsible(true)
+            .resizable(true)
+            .open(&mut opened)
+            .show(ctx, |ui| {
+                ui.set_min_width(400.);
+
+                let params = &mut self.backend.re_id;
+
+                if let Some(entity) = params.entity {
+                    ui.horizontal(|ui| {
+                        ui.label(format!("{entity} [{}]", entity.raw_id()));
+                        ui.label("->");
+
+                        if num_row(ui, &mut params.new_id, "New ID").changed() {
+                            params.preview = None;
+                        }
+
+                        if ui.button("Preview").clicked() {
+                            preview = true;
+                        }
+                    });
+                }
+
+                match &params.preview {
+                    Some(Ok(v)) => {
+                        ui.separator();
+
+                        ui.label(format!(
+                            "{} [{}] -> [{}]. References will be updated in {} entities:",
+                            v.from,
+                            v.from.raw_id(),
+                            v.to.raw_id(),
+                            v.affected.len()
+                        ));
+
+                        ScrollArea::vertical().max_height(300.).show(ui, |ui| {
+                            for (entity, field, name) in &v.affected {
+                                if ui
+                                    .button(format!(
+                                        "{entity} [{}] {name} ({field})",
+                                        entity.raw_id()
+                                    ))
+                                    .clicked()
+                                {
+                                    to_open = Some(*entity);
+                                }
+                            }
+                        });
+
+                        if ui.button("Apply").clicked() {
+                            apply = true;
+                        }
+                    }
+
+                    Some(Err(e)) => {
+                        ui.separator();
+                        ui.label(RichText::new(e).color(LogLevel::Error));
+                    }
+
+                    None => {}
+                }
+
+                if let Some(last) = params.history.last() {
+                    ui.separator();
+
+                    if ui
+                        .button(format!(
+                            "Undo {} [{}] -> [{}]",
+                            last.from,
+                            last.from.raw_id(),
+                            last.to.raw_id()
+                        ))
+                        .clicked()
+                    {
+                        undo = true;
+                    }
+                }
+            });
+
+        self.backend.re_id.opened = opened;
+
+        if preview {
+            self.backend.preview_re_id();
+        }
+        if apply {
+            self.backend.apply_re_id();
+        }
+        if undo {
+            self.backend.undo_re_id();
+        }
+        if let Some(v) = to_open {
+            self.backend.open_entity(v);
+        }
+    }
+
+    fn draw_clone_range(&mut self, ctx: &egui::Context) {
+        let mut opened = self.backend.clone_range.opened;
+        let mut preview = false;
+        let mut apply = false;
+
+        egui::Window::new("Clone Range")
+            .id(egui::Id::new("_clone_range_"))
+            .collapsible(true)
+            .resizable(true)
+            .open(&mut opened)
+            .show(ctx, |ui| {
+                ui.set_min_width(400.);
+
+                let params = &mut self.backend.clone_range;
+                let mut changed = false;
+
+                changed |= combo_box_row(ui, &mut params.entity, "Entity").changed();
+
+                ui.horizontal(|ui| {
+                    ui.label("Source IDs");
+                    changed |= ui
+                        .text_edit_singleline(&mut params.range)
+                        .on_hover_text("r:start-end")
+                        .changed();
+                });
+
+                ui.horizontal(|ui| {
+                    changed |= ui
+                        .radio_value(&mut params.use_offset, false, "Start ID")
+                        .changed();
+                    changed |= ui
+                        .radio_value(&mut params.use_offset, true, "ID Offset")
+                        .changed();
+                    changed |= num_row(ui, &mut params.target, "").changed();
+                });
+
+                if matches!(
+                    params.entity,
+                    GameEntity::Weapon | GameEntity::Armor | GameEntity::EtcItem
+                ) {
+                    changed |= ui
+                        .checkbox(&mut params.with_sets, "Bring item sets")
+                        .changed();
+                    changed |= ui
+                        .checkbox(
+                            &mut params.with_recipes,
+                            "Bring recipes for cloned products",
+                        )
+                        .changed();
+                }
+
+                if changed {
+                    params.plan = None;
+                }
+
+                if ui.button("Preview").clicked() {
+                    preview = true;
+                }
+
+                match &params.plan {
+                    Some(Ok(plan)) => {
+                        ui.separator();
+
+                        ui.label(format!("{} entities will be created:", plan.len()));
+
+                        ScrollArea::vertical().max_height(300.).show(ui, |ui| {
+                            for (from, to) in plan {
+                                ui.label(format!(
+                                    "{from} [{}] -> [{}]",
+                                    from.raw_id(),
+                                    to.raw_id()
+                                ));
+                            }
+                        });
+
+                        if ui.button("Apply").clicked() {
+                            apply = true;
+                        }
+                    }
+
+                    Some(Err(e)) => {
+                        ui.separator();
+                        ui.label(RichText::new(e).color(LogLevel::Error));
+                    }
+
+                    None => {}
+                }
+            });
+
+        self.backend.clone_range.opened = opened;
+
+        if preview {
+            self.backend.preview_clone_range();
+        }
+        if apply {
+            self.backend.apply_clone_range();
+        }
+    }
+
+    fn draw_client_diff(&mut self, ctx: &egui::Context) {
+        let mut opened = self.backend.client_diff.opened;
+        let mut load = false;
+        let mut refresh = false;
+        let mut export = None;
+        let mut to_open = None;
+        let mut cherry_pick = false;
+
+        egui::Window::new("Client Diff")
+            .id(egui::Id::new("_client_diff_"))
+            .collapsible(true)
+            .resizable(true)
+            .open(&mut opened)
+            .show(ctx, |ui| {
+                ui.set_min_width(600.);
+
+                let params = &mut self.backend.client_diff;
+
+                ui.horizontal(|ui| {
+                    if ui.button("Load client folder").clicked() {
+                        load = true;
+                    }
+
+                    if let Some(path) = &params.path {
+                        ui.label(path);
+
+                        if ui.button("Refresh").clicked() {
+                            refresh = true;
+                        }
+                        if ui.button("Export RON").clicked() {
+                            export = Some(false);
+                        }
+                        if ui.button("Export JSON").clicked() {
+                            export = Some(true);
+                        }
+                    }
+                });
+
+                if params.path.is_none() {
+                    return;
+                }
+
+                ui.horizontal(|ui| {
+                    ui.label("Filter");
+                    ui.text_edit_singleline(&mut params.filter)
+                        .on_hover_text("ID or name");
+                });
+
+                ui.horizontal(|ui| {
+                    ui.checkbox(&mut params.cherry_pick.keep_ids, "Keep IDs")
+                        .on_hover_text("Otherwise next free ID is used");
+                    ui.checkbox(&mut params.cherry_pick.with_dependents, "With dependents")
+                        .on_hover_text("Item sets of picked items");
+
+                    if ui
+                        .add_enabled(
+                            !params.cherry_pick.selected.is_empty(),
+                            Button::new(format!(
+                                "Copy {} selected into current client",
+                                params.cherry_pick.selected.len()
+                            )),
+                        )
+                        .clicked()
+                    {
+                        cherry_pick = true;
+                    }
+                });
+
+                ui.separator();
+
+                let filter = params.filter.to_lowercase();
+                let selected = &mut params.cherry_pick.selected;
+
+                ScrollArea::vertical().show(ui, |ui| {
+                    for group in &params.diff.groups {
+                        if group.entries.is_empty() {
+                            continue;
+                        }
+
+                        egui::CollapsingHeader::new(format!(
+                            "{} (+{} -{} ~{})",
+                            group.entity,
+                            group.count(DiffKind::Added),
+                            group.count(DiffKind::Removed),
+                            group.count(DiffKind::Changed),
+                        ))
+                        .id_salt(group.entity.to_string())
+                        .show(ui, |ui| {
+                            for v in group.entries.iter().filter(|v| {
+                                filter.is_empty()
+                                    || v.id.to_string() == filter
+                                    || v.name.to_lowercase().contains(&filter)
+                            }) {
+                                let (sign, color) = match v.kind {
+                                    DiffKind::Added => ("+", Color32::from_rgb(98, 200, 98)),
+                                    DiffKind::Removed => ("-", Color32::from_rgb(238, 62, 62)),
+                                    DiffKind::Changed => ("~", Color32::from_rgb(238, 146, 62)),
+                                };
+
+                                let title = RichText::new(format!("{sign} [{}] {}", v.id, v.name))
+                                    .color(color);
+
+                                ui.horizontal(|ui| {
+                                    if let Entity::GameEntity(e) = group.entity {
+                                        let entity = GameEntityT::from_raw(e, v.id);
+
+                                        if v.kind != DiffKind::Removed {
+                                            let mut checked = selected.contains(&entity);
+
+                                            if ui.checkbox(&mut checked, "").changed() {
+                                                if checked {
+                                                    selected.insert(entity);
+                                                } else {
+                                                    selected.remove(&entity);
+                                                }
+                                            }
+                                        }
+
+                                        if v.kind != DiffKind::Added && ui.button("Open").clicked()
+                                        {
+                                            to_open = Some(entity);
+                                        }
+                                    }
+
+                                    if v.fields.is_empty() {
+                                        ui.label(title);
+
+                                        return;
+                                    }
+
+                                    ui.vertical(|ui| {
+                                        egui::CollapsingHeader::new(title)
+                                            .id_salt(format!("{}_{}", group.entity, v.id))
+                                            .show(ui, |ui| {
+                                                egui::Grid::new(format!(
+                                                    "_diff_{}_{}_",
+                                                    group.entity, v.id
+                                                ))
+                                                .striped(true)
+                                                .show(ui, |ui| {
+                                                    ui.label("Field");
+                                                    ui.label("Current");
+                                                    ui.label("Other");
+                                                    ui.end_row();
+
+                                                    for f in &v.fields {
+                                                        ui.label(&f.path);
+                                                        ui.label(&f.current);
+                                                        ui.label(&f.other);
+                                                        ui.end_row();
+                                                    }
+                                                });
+                                            });
+                                    });
+                                });
+                            }
+                        });
+                    }
+                });
+            });
+
+        self.backend.client_diff.opened = opened;
+
+        if load && let Some(path) = rfd::FileDialog::new().pick_folder() {
+            self.backend.load_other_client(path);
+        }
+        if refresh {
+            self.backend.refresh_client_diff();
+        }
+        if cherry_pick {
+            self.backend.cherry_pick_selected();
+        }
+        if let Some(json) = export
+            && let Some(path) = rfd::FileDialog::new()
+                .set_file_name(if json { "diff.json" } else { "diff.ron" })
+                .save_file()
+        {
+            self.backend.export_client_diff(&path, json);
+        }
+        if let Some(v) = to_open {
+            self.backend.open_entity(v);
+        }
+    }
+
+    fn draw_patch(&mut self, ctx: &egui::Context) {
+        let Some(pending) = &self.backend.patch.pending else {
+            return;
+        };
+
+        let mut opened = self.backend.patch.opened;
+        let mut apply = None;
+
+        egui::Window::new("Apply Patch")
+            .id(egui::Id::new("_patch_"))
+            .collapsible(true)
+            .resizable(true)
+            .open(&mut opened)
+            .show(ctx, |ui| {
+                ui.set_min_width(400.);
+
+                ui.label(format!(
+                    "{}\n{} change(s), {} already applied, {} conflict(s)",
+                    pending.folder,
+                    pending.patch.entries.len(),
+                    pending.applied,
+                    pending.conflicts.len()
+                ));
+
+                if !pending.conflicts.is_empty() {
+                    ui.separator();
+
+                    ScrollArea::vertical().max_height(300.).show(ui, |ui| {
+                        for (i, reason) in &pending.conflicts {
+                            let entry = &pending.patch.entries[*i];
+
+                            ui.label(
+                                RichText::new(format!(
+                                    "{} {} [{}]: {reason}",
+                                    entry.kind(),
+                                    entry.entity(),
+                                    entry.id()
+                                ))
+                                .color(LogLevel::Warning),
+                            );
+                        }
+                    });
+                }
+
+                ui.separator();
+
+                ui.horizontal(|ui| {
+                    if pending.conflicts.is_empty() {
+                        if ui.button("Write").clicked() {
+                            apply = Some(false);
+                        }
+                    } else {
+                        if ui.button("Write skipping conflicts").clicked() {
+                            apply = Some(false);
+                        }
+                        if ui.button("Write overwriting conflicts").clicked() {
+                            apply = Some(true);
+                        }
+                    }
+                });
+            });
+
+        if let Some(overwrite) = apply {
+            self.backend.apply_pending_patch(overwrite);
+            opened = false;
+        }
+
+        if !opened {
+            self.backend.patch.pending = None;
+        }
+
+        self.backend.patch.opened = opened;
+    }
+
+    fn draw_backups(&mut self, ctx: &egui::Context) {
+        let mut opened = self.backend.backups.opened;
+        let mut select = None;
+        let mut restore = false;
+        let unwritten = self.backend.is_changed();
+
+        egui::Window::new("Dat Backups")
+            .id(egui::Id::new("_dat_backups_"))
+            .collapsible(true)
+            .resizable(true)
+            .open(&mut opened)
+            .show(ctx, |ui| {
+                ui.set_min_width(500.);
+
+                let params = &mut self.backend.backups;
+
+                if params.batches.is_empty() {
+                    ui.label("No backups yet");
+
+                    return;
+                }
+
+                ui.horizontal_top(|ui| {
+                    ScrollArea::vertical()
+                        .id_salt("_dat_backups_batches_")
+                        .max_height(400.)
+                        .show(ui, |ui| {
+                            ui.vertical(|ui| {
+                                for (i, v) in params.batches.iter().enumerate() {
+                                    if ui
+                                        .selectable_label(params.selected_batch == Some(i), &v.name)
+                                        .clicked()
+                                    {
+                                        select = Some(i);
+                                    }
+                                }
+                            });
+                        });
+
+                    ui.separator();
+
+                    let Some(batch) = params.selected_batch.and_then(|v| params.batches.get(v))
+                    else {
+                        return;
+                    };
+
+                    ui.vertical(|ui| {
+                        ui.label(&batch.manifest.system_folder);
+
+                        if !batch.manifest.entities.is_empty() {
+                            ui.label(format!("Changed: {}", batch.manifest.entities.join(", ")));
+                        }
+
+                        ui.separator();
+
+                        ScrollArea::vertical()
+                            .id_salt("_dat_backups_files_")
+                            .max_height(300.)
+                            .show(ui, |ui| {
+                                for (i, v) in batch.manifest.files.iter().enumerate() {
+                                    let mut checked = params.selected_files.contains(&i);
+
+                                    if ui.checkbox(&mut checked, &v.name).changed() {
+                                        if checked {
+                                            params.selected_files.push(i);
+                                        } else {
+                                            params.selected_files.retain(|v| *v != i);
+                                        }
+                                    }
+                                }
+                            });
+
+                        ui.separator();
+
+                        if unwritten {
+                            ui.label(
+                                RichText::new(
+                                    "Unwritten changes will be lost if current client is restored",
+                                )
+                                .color(LogLevel::Warning),
+                            );
+                        }
+
+                        if ui
+                            .add_enabled(
+                                !params.selected_files.is_empty(),
+                                Button::new("Restore selected files"),
+                            )
+                            .clicked()
+                        {
+                            restore = true;
+                        }
+                    });
+                });
+            });
+
+        self.backend.backups.opened = opened;
+
+        if let Some(v) = select {
+            self.backend.select_backup(v);
+        }
+        if restore {
+            self.backend.restore_backup();
+        }
+    }
+
+    fn draw_csv(&mut self, ctx: &egui::Context) {
+        let mut opened = self.backend.csv.opened;
+        let mut refresh = false;
+        let mut export = false;
+        let mut import = false;
+        let mut apply = false;
+        let mut discard = false;
+
+        egui::Window::new("CSV Tables")
+            .id(egui::Id::new("_csv_tables_"))
+            .collapsible(true)
+            .resizable(true)
+            .open(&mut opened)
+            .show(ctx, |ui| {
+                ui.set_min_width(500.);
+
+                let params = &mut self.backend.csv;
+
+                ui.horizontal(|ui| {
+                    let entity = params.entity;
+
+                    combo_box_row(ui, &mut params.entity, "Entity");
+
+                    refresh = entity != params.entity;
+
+                    ui.checkbox(&mut params.tsv, "TSV");
+                });
+
+                ui.horizontal(|ui| {
+                    ui.label("Columns").on_hover_text(
+                        "[*] columns give a row per array element, its index goes to [#] column.\n\
+                         On import [#] equal to the array length appends a copy of the last \
+                         element with the row applied, larger ones are rejected",
+                    );
+                    ui.text_edit_singleline(&mut params.filter);
+
+                    if ui.button("Select shown").clicked() {
+                        for v in &params.columns {
+                            if v.contains(&params.filter) {
+                                params.selected.insert(v.clone());
+                            }
+                        }
+                    }
+
+                    if ui.button("Clear").clicked() {
+                        params.selected.clear();
                     }
+                });
 
-                    GameEntity::Residence => {
-                        Self::draw_residence_selector(&mut self.backend, ui, LIBRARY_WIDTH)
+                ScrollArea::vertical()
+                    .id_salt("_csv_tables_columns_")
+                    .max_height(300.)
+                    .show(ui, |ui| {
+                        for v in params.columns.iter().filter(|v| v.contains(&params.filter)) {
+                            let mut checked = params.selected.contains(v);
+
+                            if ui.checkbox(&mut checked, v).changed() {
+                                if checked {
+                                    params.selected.insert(v.clone());
+                                } else {
+                                    params.selected.remove(v);
+                                }
+                            }
+                        }
+                    });
+
+                ui.separator();
+
+                ui.horizontal(|ui| {
+                    if ui
+                        .add_enabled(
+                            !params.selected.is_empty(),
+                            Button::new(format!("Export {} column(s)", params.selected.len())),
+                        )
+                        .on_hover_text("[*] columns give a row per element")
+                        .clicked()
+                    {
+                        export = true;
                     }
 
-                    GameEntity::EnsoulOption => {
-                        Self::draw_ensoul_option_selector(&mut self.backend, ui, LIBRARY_WIDTH)
+                    if ui
+                        .button("Import")
+                        .on_hover_text("Rows update entities with the same id")
+                        .clicked()
+                    {
+                        import = true;
                     }
-                }
-            });
-    }
+                });
 
-    fn draw_spawn_editor(&mut self, ctx: &egui::Context) {
-        if self.spawn_editor.showing
-            && let Some(v) = &mut self.spawn_editor.editor {
-                ctx.show_viewport_immediate(
-                    egui::ViewportId::from_hash_of("_spawn_editor_"),
-                    egui::ViewportBuilder::default()
-                        .with_title("Spawn Viewer")
-                        .with_inner_size([600.0, 300.0]),
-                    |ctx, class| {
-                        assert!(
-                            class == egui::ViewportClass::Immediate,
-                            "This egui backend doesn't support multiple viewports"
-                        );
+                let Some(pending) = &params.import else {
+                    return;
+                };
 
-                        egui::CentralPanel::default().show(ctx, |ui| {
-                            v.show(ctx, ui);
-                        });
+                ui.separator();
 
-                        if ctx.input(|i| i.viewport().close_requested()) {
-                            // Tell parent viewport that we should not show next frame:
-                            self.spawn_editor.showing = false;
-                        }
-                    },
-                );
-            }
-    }
+                ui.label(format!(
+                    "{}\n{} changed entities, {} error(s)",
+                    pending.path,
+                    pending.changes.len(),
+                    pending.errors.len()
+                ));
 
-    fn draw(&mut self, ctx: &egui::Context) {
-        egui::CentralPanel::default().show(ctx, |ui| {
-            self.show_dialog(ctx, ui.min_size());
+                ScrollArea::vertical()
+                    .id_salt("_csv_tables_import_")
+                    .max_height(400.)
+                    .show(ui, |ui| {
+                        for e in &pending.errors {
+                            ui.label(RichText::new(e).color(LogLevel::Warning));
+                        }
 
-            self.draw_entity_library(ctx);
+                        for v in &pending.changes {
+                            egui::CollapsingHeader::new(format!(
+                                "[{}] {} ({})",
+                                v.value.id(),
+                                v.name,
+                                v.fields.len()
+                            ))
+                            .id_salt(format!("_csv_{}_", v.value.id()))
+                            .show(ui, |ui| {
+                                egui::Grid::new(format!("_csv_grid_{}_", v.value.id()))
+                                    .striped(true)
+                                    .show(ui, |ui| {
+                                        ui.label("Field");
+                                        ui.label("Current");
+                                        ui.label("New");
+                                        ui.end_row();
+
+                                        for f in &v.fields {
+                                            ui.label(&f.path);
+                                            ui.label(&f.current);
+                                            ui.label(&f.other);
+                                            ui.end_row();
+                                        }
+                                    });
+                            });
+                        }
+                    });
 
-            self.draw_script_runner(ui, ctx);
+                ui.separator();
 
-            self.draw_top_menu(ui, ctx);
+                ui.horizontal(|ui| {
+                    if ui
+                        .add_enabled(
+                            !pending.changes.is_empty(),
+                            Button::new(format!("Apply {} change(s)", pending.changes.len())),
+                        )
+                        .clicked()
+                    {
+                        apply = true;
+                    }
 
-            ui.separator();
+                    if ui.button("Discard").clicked() {
+                        discard = true;
+                    }
+                });
+            });
 
-            self.draw_tabs(ui, ctx);
+        self.backend.csv.opened = opened;
 
-            self.draw_editor(ui, ctx);
+        if refresh {
+            self.backend.csv.import = None;
+            self.backend.refresh_csv_columns();
+        }
 
-            self.draw_dino(ui);
+        if export {
+            let ext = if self.backend.csv.tsv { "tsv" } else { "csv" };
 
-            if IS_SAVING.load(Ordering::Relaxed) {
-                egui::Window::new("SAVING IN PROGRESS")
-                    .id(egui::Id::new("_saving_"))
-                    .resizable(false)
-                    .collapsible(false)
-                    .show(ctx, |ui| {
-                        ui.vertical_centered(|ui| {
-                            ui.spinner();
-                        })
-                    });
+            if let Some(path) = rfd::FileDialog::new()
+                .set_file_name(format!("{}.{ext}", self.backend.csv.entity))
+                .add_filter(ext, &[ext])
+                .save_file()
+            {
+                self.backend.export_csv(&path);
             }
-        });
+        }
+
+        if import
+            && let Some(path) = rfd::FileDialog::new()
+                .add_filter("csv, tsv", &["csv", "tsv"])
+                .pick_file()
+        {
+            self.backend.prepare_csv_import(&path);
+        }
+
+        if apply {
+            self.backend.apply_csv_import();
+        }
+
+        if discard || !opened {
+            self.backend.csv.import = None;
+        }
     }
 
     fn draw_dino(&mut self, ui: &mut egui::Ui) {
@@ -816,6 +2015,13 @@ impl Frontend {
             | Dialog::ConfirmAnimationComboSave { message, .. }
             | Dialog::ConfirmResidenceSave { message, .. }
             | Dialog::ConfirmEnsoulOptionSave { message, .. }
+            | Dialog::ConfirmHennaSave { message, .. }
+            | Dialog::ConfirmUserActionSave { message, .. }
+            | Dialog::ConfirmProductSave { message, .. }
+            | Dialog::ConfirmClassInfoSave { message, .. }
+            | Dialog::ConfirmDatSave { message }
+            | Dialog::ConfirmJournalReplay { message }
+            | Dialog::ConfirmCherryPick { message }
             | Dialog::ConfirmSkillSave { message, .. } => {
                 let m = message.clone();
 
@@ -867,6 +2073,74 @@ impl Frontend {
                     });
             }
 
+            Dialog::ConfirmDelete {
+                entity,
+                used_by,
+                substitute,
+            } => {
+                let entity = *entity;
+                let used_by = used_by.clone();
+                let mut substitute = *substitute;
+                let mut answer = None;
+
+                egui::Window::new("Confirm Delete")
+                    .default_pos([rect.x / 2.0, rect.y / 2.0])
+                    .pivot(Align2::CENTER_CENTER)
+                    .id(egui::Id::new("_confirm_delete_"))
+                    .collapsible(false)
+                    .resizable(false)
+                    .show(ctx, |ui| {
+                        ui.set_width(400.);
+
+                        ui.label(format!(
+                            "{entity} [{}] is used by {} entities:",
+                            entity.raw_id(),
+                            used_by.len()
+                        ));
+
+                        ScrollArea::vertical().max_height(200.).show(ui, |ui| {
+                            for (v, field, name) in &used_by {
+                                ui.label(format!("{v} [{}] {name} ({field})", v.raw_id()));
+                            }
+                        });
+
+                        ui.separator();
+
+                        num_row(ui, &mut substitute, "Substitute ID")
+                            .on_hover_text("0 clears single references and removes list entries pointing to deleted entity");
+
+                        ui.add_space(5.);
+
+                        ui.horizontal(|ui| {
+                            if ui.button("Cancel").clicked() {
+                                answer = Some(DialogAnswer::Abort);
+                            }
+                            if ui
+                                .button("Delete anyway")
+                                .on_hover_text("References are left as is and logged")
+                                .clicked()
+                            {
+                                answer = Some(DialogAnswer::Confirm);
+                            }
+                            if ui
+                                .button("Replace references and delete")
+                                .on_hover_text("References are set to Substitute ID")
+                                .clicked()
+                            {
+                                answer = Some(DialogAnswer::Cascade);
+                            }
+                        });
+                    });
+
+                if let Dialog::ConfirmDelete { substitute: v, .. } = &mut self.backend.dialog {
+                    *v = substitute;
+                }
+
+                if let Some(answer) = answer {
+                    self.backend.answer(answer);
+                }
+            }
+
             Dialog::ConfirmClose(_) => {
                 egui::Window::new("Confirm Close")
                     .default_pos([rect.x / 2.0, rect.y / 2.0])
@@ -923,6 +2197,7 @@ impl Frontend {
 
             show_system_string_editor: false,
             show_npc_string_editor: false,
+            show_game_tips_editor: false,
             script_runner: ScriptRunner::new(),
         }
     }
@@ -943,7 +2218,10 @@ impl eframe::App for Frontend {
         if self.script_runner.execute_requested {
             self.script_runner.execute_requested = false;
 
-            self.script_runner.output = self.backend.run_script(&self.script_runner.script);
+            self.script_runner.output = self
+                .backend
+                .run_script(&self.script_runner.script)
+                .unwrap_or_else(|e| e);
         }
     }
 
@@ -1283,6 +2561,98 @@ impl<
     }
 }
 
+impl DictEditItem<u32, GameTip> {
+    fn draw(
+        &mut self,
+        ui: &mut Ui,
+        changed: &mut ChangeStatus,
+        ident: usize,
+        localization: Localization,
+    ) {
+        ui.horizontal(|ui| {
+            let mut t = RichText::new(format!(
+                "{}ID: {:<ident$}",
+                if self.changed { "*" } else { " " },
+                self.id
+            ))
+            .monospace();
+
+            if self.changed || !self.matches_initial {
+                t = t.color(Color32::from_rgb(242, 192, 124));
+            }
+
+            ui.label(t);
+
+            let mut params_changed = false;
+
+            let tip = &mut self.item[localization];
+
+            params_changed |= ui
+                .add(NumberValue::new(&mut tip.min_lvl).prefix("Lvl: "))
+                .on_hover_text("Min level")
+                .changed();
+            params_changed |= ui
+                .add(NumberValue::new(&mut tip.max_lvl).prefix("- "))
+                .on_hover_text("Max level")
+                .changed();
+            params_changed |= ui
+                .add(NumberValue::new(&mut tip.condition).prefix("Cond: "))
+                .on_hover_text("Condition")
+                .changed();
+
+            if params_changed {
+                let tip = self.item[localization].clone();
+
+                for l in Localization::iter() {
+                    self.item[l].min_lvl = tip.min_lvl;
+                    self.item[l].max_lvl = tip.max_lvl;
+                    self.item[l].condition = tip.condition;
+                }
+            }
+
+            let field = ui.add(
+                egui::TextEdit::singleline(&mut self.item[localization].text)
+                    .desired_width(f32::INFINITY),
+            );
+
+            if field.changed() || params_changed {
+                *changed = self.check_changed_status();
+            }
+
+            if self.changed || !self.matches_initial {
+                field.on_hover_text(format!(
+                    "{}{}{}",
+                    if self.changed {
+                        let v = &self.previous[localization];
+
+                        format!(
+                            "Previous: [{}-{}, {}] {}",
+                            v.min_lvl, v.max_lvl, v.condition, v.text
+                        )
+                    } else {
+                        "".to_string()
+                    },
+                    if self.changed && !self.matches_initial {
+                        "\n"
+                    } else {
+                        ""
+                    },
+                    if !self.matches_initial {
+                        let v = &self.initial[localization];
+
+                        format!(
+                            "Initial: [{}-{}, {}] {}",
+                            v.min_lvl, v.max_lvl, v.condition, v.text
+                        )
+                    } else {
+                        "".to_string()
+                    },
+                ));
+            }
+        });
+    }
+}
+
 impl Frontend {
     fn draw_dict_editors(&mut self, ctx: &egui::Context) {
         if self.show_system_string_editor {
@@ -1456,5 +2826,91 @@ impl Frontend {
                 self.backend.apply_search(Dictionary::NpcStrings);
             }
         }
+
+        if self.show_game_tips_editor {
+            let mut save = false;
+            let mut apply_search = false;
+
+            let dict = &mut self.backend.editors.dictionaries.game_tips;
+            let dict_changed = dict.changed();
+
+            egui::Window::new(if dict_changed {
+                "Game Tips *"
+            } else {
+                "Game Tips"
+            })
+            .id(egui::Id::new("_game_tips_editor_"))
+            .collapsible(true)
+            .resizable(true)
+            .open(&mut self.show_game_tips_editor)
+            .show(ctx, |ui| {
+                ui.vertical(|ui| {
+                    ui.set_min_width(900.);
+
+                    ui.horizontal(|ui| {
+                        if ui.button("Add new").clicked() {
+                            dict.add_new()
+                        }
+
+                        if ui
+                            .button("Save")
+                            .on_hover_text(if dict_changed {
+                                "Save changes"
+                            } else {
+                                "No changes"
+                            })
+                            .clicked()
+                            && dict_changed
+                        {
+                            save = true;
+                        }
+
+                        if ui
+                            .text_edit_singleline(&mut dict.search)
+                            .on_hover_text("Search by id\nr:start\nr:start-end")
+                            .changed()
+                        {
+                            apply_search = true;
+                        }
+                    });
+
+                    ui.separator();
+
+                    ui.push_id(ui.next_auto_id(), |ui| {
+                        ScrollArea::vertical().show_rows(
+                            ui,
+                            21.,
+                            dict.filtered_indexes.len(),
+                            |ui, range| {
+                                let mut changed = ChangeStatus::Same;
+
+                                for i in &dict.filtered_indexes[range] {
+                                    dict.items[*i].draw(
+                                        ui,
+                                        &mut changed,
+                                        5,
+                                        self.backend.holders.localization,
+                                    );
+                                }
+
+                                match changed {
+                                    ChangeStatus::BecameChanged => dict.inc_changed(),
+                                    ChangeStatus::BecameUnChanged => dict.dec_changed(),
+                                    ChangeStatus::Same => (),
+                                }
+                            },
+                        );
+                    });
+                });
+            });
+
+            if save {
+                self.backend.store_dict(Dictionary::GameTips);
+            }
+
+            if apply_search {
+                self.backend.apply_search(Dictionary::GameTips);
+            }
+        }
     }
 }
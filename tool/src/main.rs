@@ -40,6 +40,10 @@ fn log_multiple(logs: Vec<Log>) {
 fn main() -> Result<(), eframe::Error> {
     APP_LOGS.set(RwLock::new(LogHolder::new())).unwrap();
 
+    if std::env::args().nth(1).as_deref() == Some("--headless") {
+        std::process::exit(backend::batch::run(std::env::args().skip(2)));
+    }
+
     let icon = image::load_from_memory(include_bytes!("../../files/logo.png"))
         .expect("Failed to open icon path")
         .to_rgba8();